@@ -0,0 +1,140 @@
+use crate::models::{Event, MasterList};
+use eyre::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Differences between two master lists
+#[derive(Debug, Default)]
+pub struct EventDiff<'a> {
+    /// Events in the new list whose key isn't in the old one
+    pub added: Vec<&'a Event>,
+    /// Events in both lists that have a transcript now but didn't before
+    pub gained_transcript: Vec<&'a Event>,
+    /// Number of old events whose key is missing from the new list
+    pub removed: usize,
+    pub old_counts: EventCounts,
+    pub new_counts: EventCounts,
+}
+
+/// Event totals by type and transcript status
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EventCounts {
+    pub total: usize,
+    pub with_transcript: usize,
+    pub floor_speeches: usize,
+    pub hearings: usize,
+    pub media_appearances: usize,
+}
+
+impl EventCounts {
+    pub fn from_events(events: &[Event]) -> Self {
+        let mut counts = Self {
+            total: events.len(),
+            ..Self::default()
+        };
+        for event in events {
+            if event.has_transcript() {
+                counts.with_transcript += 1;
+            }
+            match event {
+                Event::FloorSpeech(_) => counts.floor_speeches += 1,
+                Event::Hearing(_) => counts.hearings += 1,
+                Event::MediaAppearance(_) => counts.media_appearances += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// Load a master list written by `merge` (YAML, or JSON since YAML is a superset)
+pub fn load_master_list(path: &Path) -> Result<MasterList> {
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+    serde_yaml::from_str(&content).wrap_err_with(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Compare two event lists by [`Event::key`]
+pub fn diff_events<'a>(old: &[Event], new: &'a [Event]) -> EventDiff<'a> {
+    let old_by_key: HashMap<String, &Event> = old.iter().map(|e| (e.key(), e)).collect();
+
+    let mut diff = EventDiff {
+        old_counts: EventCounts::from_events(old),
+        new_counts: EventCounts::from_events(new),
+        ..EventDiff::default()
+    };
+    let mut still_present: HashSet<String> = HashSet::new();
+    for event in new {
+        let key = event.key();
+        match old_by_key.get(&key) {
+            None => diff.added.push(event),
+            Some(previous) => {
+                if event.has_transcript() && !previous.has_transcript() {
+                    diff.gained_transcript.push(event);
+                }
+                still_present.insert(key);
+            }
+        }
+    }
+    diff.removed = old_by_key.len() - still_present.len();
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Chamber, FloorSpeech, Hearing};
+
+    fn hearing(event_id: &str, transcript: Option<&str>) -> Event {
+        Event::Hearing(Hearing {
+            event_id: event_id.to_string(),
+            date: "2024-03-01".to_string(),
+            chamber: Chamber::Senate,
+            committee: None,
+            title: "Budget".to_string(),
+            transcript: transcript.map(str::to_string),
+            video: None,
+            congress: Some(118),
+        })
+    }
+
+    fn speech(event_id: &str) -> Event {
+        Event::FloorSpeech(FloorSpeech {
+            event_id: event_id.to_string(),
+            date: "2024-03-02".to_string(),
+            chamber: Chamber::House,
+            title: "Farm Bill".to_string(),
+            transcript: Some("https://example.com/t".to_string()),
+            video: None,
+            granule_id: None,
+        })
+    }
+
+    #[test]
+    fn test_diff_reports_added_gained_and_removed() {
+        let old = vec![hearing("h1", None), hearing("h2", None), speech("s1")];
+        let new = vec![
+            hearing("h1", Some("https://example.com/h1")),
+            speech("s1"),
+            speech("s2"),
+        ];
+
+        let diff = diff_events(&old, &new);
+        let keys = |events: &[&Event]| events.iter().map(|e| e.key()).collect::<Vec<_>>();
+        assert_eq!(keys(&diff.added), vec!["floor_speech:s2"]);
+        assert_eq!(keys(&diff.gained_transcript), vec!["hearing:h1"]);
+        assert_eq!(diff.removed, 1);
+        assert_eq!(diff.old_counts.with_transcript, 1);
+        assert_eq!(diff.new_counts.with_transcript, 3);
+        assert_eq!(diff.new_counts.floor_speeches, 2);
+    }
+
+    #[test]
+    fn test_same_id_different_type_is_a_new_event() {
+        let old = vec![hearing("x1", None)];
+        let new = vec![speech("x1")];
+
+        let diff = diff_events(&old, &new);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.removed, 1);
+    }
+}
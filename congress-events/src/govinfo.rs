@@ -2,16 +2,32 @@
 
 use crate::models::{Chamber, FloorSpeech, Hearing};
 use eyre::{Context, Result};
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const GOVINFO_BASE_URL: &str = "https://api.govinfo.gov";
-const RATE_LIMIT_DELAY_MS: u64 = 100;
+
+/// Default delay between consecutive API requests
+pub const DEFAULT_REQUEST_DELAY_MS: u64 = 100;
+
+/// Wait used on a 429 without usable rate limit headers (doubles on each consecutive 429)
+const RATE_LIMIT_FALLBACK_SECS: u64 = 60;
+
+/// Upper bound on a single rate limit wait (data.gov limits are hourly)
+const MAX_RATE_LIMIT_WAIT_SECS: u64 = 3600;
+
+/// Consecutive 429s tolerated for one request before giving up
+pub(crate) const MAX_RATE_LIMIT_RETRIES: u32 = 10;
+
+/// Retries for network errors and 5xx responses, with exponential backoff from 1s
+pub(crate) const MAX_TRANSIENT_RETRIES: u32 = 5;
 
 pub struct GovInfoClient {
     api_key: String,
     client: reqwest::blocking::Client,
+    request_delay: Duration,
 }
 
 // Search API request/response structs
@@ -55,6 +71,83 @@ impl GovInfoClient {
                 .timeout(Duration::from_secs(60))
                 .build()
                 .expect("Failed to create HTTP client"),
+            request_delay: Duration::from_millis(DEFAULT_REQUEST_DELAY_MS),
+        }
+    }
+
+    /// Set the delay between consecutive API requests
+    pub fn with_request_delay(mut self, delay: Duration) -> Self {
+        self.request_delay = delay;
+        self
+    }
+
+    /// POST a search request, waiting out rate limits and retrying transient failures
+    ///
+    /// Retries happen in place, so pagination resumes from the same offset mark.
+    fn post_search(&self, request: &SearchRequest, collection: &str) -> Result<SearchResponse> {
+        let url = format!("{}/search?api_key={}", GOVINFO_BASE_URL, self.api_key);
+        let mut rate_limited = 0;
+        let mut transient = 0;
+
+        loop {
+            thread::sleep(self.request_delay);
+
+            let response = match self.client.post(&url).json(request).send() {
+                Ok(response) => response,
+                Err(e) if transient < MAX_TRANSIENT_RETRIES => {
+                    let wait = Duration::from_secs(1 << transient);
+                    transient += 1;
+                    eprintln!(
+                        "\nRequest to search {} failed ({}), retrying in {}s...",
+                        collection,
+                        e,
+                        wait.as_secs()
+                    );
+                    thread::sleep(wait);
+                    continue;
+                }
+                Err(e) => {
+                    return Err(e).wrap_err_with(|| format!("Failed to search {}", collection));
+                }
+            };
+
+            let status = response.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                if rate_limited >= MAX_RATE_LIMIT_RETRIES {
+                    eyre::bail!(
+                        "Rate limited {} times in a row searching {}",
+                        rate_limited,
+                        collection
+                    );
+                }
+                let wait = rate_limit_wait(response.headers(), unix_now(), rate_limited);
+                rate_limited += 1;
+                eprintln!("\nRate limited, waiting {}s...", wait.as_secs());
+                thread::sleep(wait);
+                continue;
+            }
+
+            if status.is_server_error() && transient < MAX_TRANSIENT_RETRIES {
+                let wait = Duration::from_secs(1 << transient);
+                transient += 1;
+                eprintln!(
+                    "\nHTTP {} searching {}, retrying in {}s...",
+                    status,
+                    collection,
+                    wait.as_secs()
+                );
+                thread::sleep(wait);
+                continue;
+            }
+
+            if !status.is_success() {
+                let body = response.text().unwrap_or_default();
+                eyre::bail!("HTTP {} for search: {}", status, body);
+            }
+
+            return response
+                .json()
+                .wrap_err_with(|| format!("Failed to parse {} search response", collection));
         }
     }
 
@@ -71,35 +164,13 @@ impl GovInfoClient {
         eprintln!("Searching CREC for {} to {}...", start_date, end_date);
 
         loop {
-            thread::sleep(Duration::from_millis(RATE_LIMIT_DELAY_MS));
-
             let request = SearchRequest {
                 query: query.clone(),
                 page_size: 1000,
                 offset_mark: offset_mark.clone(),
             };
 
-            let url = format!("{}/search?api_key={}", GOVINFO_BASE_URL, self.api_key);
-            let response = self
-                .client
-                .post(&url)
-                .json(&request)
-                .send()
-                .wrap_err("Failed to search CREC")?;
-
-            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                eprintln!("Rate limited, waiting 60s...");
-                thread::sleep(Duration::from_secs(60));
-                continue;
-            }
-
-            let status = response.status();
-            if !status.is_success() {
-                let body = response.text().unwrap_or_default();
-                eyre::bail!("HTTP {} for search: {}", status, body);
-            }
-
-            let search_response: SearchResponse = response.json()?;
+            let search_response = self.post_search(&request, "CREC")?;
 
             if let Some(results) = search_response.results {
                 let count = results.len();
@@ -194,35 +265,13 @@ impl GovInfoClient {
         eprintln!("Searching CHRG for {} to {}...", start_date, end_date);
 
         loop {
-            thread::sleep(Duration::from_millis(RATE_LIMIT_DELAY_MS));
-
             let request = SearchRequest {
                 query: query.clone(),
                 page_size: 1000,
                 offset_mark: offset_mark.clone(),
             };
 
-            let url = format!("{}/search?api_key={}", GOVINFO_BASE_URL, self.api_key);
-            let response = self
-                .client
-                .post(&url)
-                .json(&request)
-                .send()
-                .wrap_err("Failed to search CHRG")?;
-
-            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                eprintln!("Rate limited, waiting 60s...");
-                thread::sleep(Duration::from_secs(60));
-                continue;
-            }
-
-            let status = response.status();
-            if !status.is_success() {
-                let body = response.text().unwrap_or_default();
-                eyre::bail!("HTTP {} for search: {}", status, body);
-            }
-
-            let search_response: SearchResponse = response.json()?;
+            let search_response = self.post_search(&request, "CHRG")?;
 
             if let Some(results) = search_response.results {
                 let count = results.len();
@@ -308,6 +357,43 @@ impl GovInfoClient {
     }
 }
 
+/// Seconds since the Unix epoch
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// How long to wait after a 429 response
+///
+/// Prefers `Retry-After` (seconds), then `X-RateLimit-Reset` (either a Unix
+/// timestamp or seconds until reset). Without either header, backs off
+/// exponentially from 60s based on how many 429s came before this one.
+pub(crate) fn rate_limit_wait(headers: &HeaderMap, now: u64, previous_attempts: u32) -> Duration {
+    let header_secs = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+    };
+
+    let secs = header_secs("retry-after")
+        .or_else(|| {
+            // values this large are timestamps rather than durations
+            header_secs("x-ratelimit-reset").map(|reset| {
+                if reset > 1_000_000_000 {
+                    reset.saturating_sub(now)
+                } else {
+                    reset
+                }
+            })
+        })
+        .unwrap_or_else(|| RATE_LIMIT_FALLBACK_SECS << previous_attempts.min(6));
+
+    // always wait at least a second so a zero or stale reset doesn't spin
+    Duration::from_secs(secs.clamp(1, MAX_RATE_LIMIT_WAIT_SECS))
+}
+
 fn is_floor_speech_by_id(granule_id: &str) -> bool {
     // Granule ID patterns: CREC-2024-01-01-pt1-PgS123 (Senate), PgH456 (House), PgE789 (Extensions)
     granule_id.contains("-PgS") || granule_id.contains("-PgH") || granule_id.contains("-PgE") || granule_id.contains("-PgD")
@@ -371,3 +457,46 @@ fn extract_chamber_from_chrg_id(package_id: &str) -> Chamber {
         _ => Chamber::Unknown,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_rate_limit_wait_prefers_retry_after() {
+        let h = headers(&[("retry-after", "42"), ("x-ratelimit-reset", "900")]);
+        assert_eq!(rate_limit_wait(&h, 0, 0), Duration::from_secs(42));
+    }
+
+    #[test]
+    fn test_rate_limit_wait_reset_as_duration_or_timestamp() {
+        let h = headers(&[("x-ratelimit-reset", "300")]);
+        assert_eq!(rate_limit_wait(&h, 1_700_000_000, 0), Duration::from_secs(300));
+
+        let h = headers(&[("x-ratelimit-reset", "1700000120")]);
+        assert_eq!(rate_limit_wait(&h, 1_700_000_000, 0), Duration::from_secs(120));
+
+        // a reset time already in the past still waits briefly
+        assert_eq!(rate_limit_wait(&h, 1_700_000_500, 0), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_rate_limit_wait_fallback_backs_off_and_caps() {
+        let h = HeaderMap::new();
+        assert_eq!(rate_limit_wait(&h, 0, 0), Duration::from_secs(60));
+        assert_eq!(rate_limit_wait(&h, 0, 2), Duration::from_secs(240));
+        assert_eq!(rate_limit_wait(&h, 0, 9), Duration::from_secs(MAX_RATE_LIMIT_WAIT_SECS));
+
+        let h = headers(&[("retry-after", "86400")]);
+        assert_eq!(rate_limit_wait(&h, 0, 0), Duration::from_secs(MAX_RATE_LIMIT_WAIT_SECS));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod congress_api;
+pub mod diff;
 pub mod govinfo;
 pub mod models;
 pub mod output;
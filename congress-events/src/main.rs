@@ -1,18 +1,21 @@
 use clap::{Parser, Subcommand};
 use color_eyre::eyre::{Context, Result};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod congress_api;
+mod diff;
 mod govinfo;
 mod models;
 mod output;
 mod transcript_parser;
 
 use congress_api::{load_hearings_from_yaml, HearingsStats};
-use govinfo::GovInfoClient;
+use diff::{diff_events, load_master_list, EventCounts};
+use govinfo::{GovInfoClient, DEFAULT_REQUEST_DELAY_MS};
 use models::Event;
-use output::{write_floor_speeches, write_hearings, write_master_list};
-use transcript_parser::TranscriptFetcher;
+use output::{write_floor_speeches, write_hearings, write_master_list, OutputFormat};
+use transcript_parser::{TranscriptFetcher, DEFAULT_TRANSCRIPT_DELAY_MS};
 
 #[derive(Parser)]
 #[command(name = "congress-events")]
@@ -37,6 +40,10 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "floor_speeches.yaml")]
         output: PathBuf,
+
+        /// Delay between GovInfo API requests in milliseconds
+        #[arg(long, default_value_t = DEFAULT_REQUEST_DELAY_MS)]
+        request_delay_ms: u64,
     },
 
     /// Fetch hearings from GovInfo CHRG collection
@@ -52,6 +59,10 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "hearings.yaml")]
         output: PathBuf,
+
+        /// Delay between GovInfo API requests in milliseconds
+        #[arg(long, default_value_t = DEFAULT_REQUEST_DELAY_MS)]
+        request_delay_ms: u64,
     },
 
     /// Load hearings from existing YAML and convert to our format
@@ -83,6 +94,10 @@ enum Commands {
         #[arg(short, long, default_value = "master_congressional_events.yaml")]
         output: PathBuf,
 
+        /// Output format for the master list
+        #[arg(long, value_enum, default_value_t = OutputFormat::Yaml)]
+        format: OutputFormat,
+
         /// Start date for floor speeches (YYYY-MM-DD)
         #[arg(long, default_value = "2020-01-01")]
         start_date: String,
@@ -94,6 +109,25 @@ enum Commands {
         /// Skip fetching floor speeches (use existing file or skip entirely)
         #[arg(long)]
         skip_speeches: bool,
+
+        /// Delay between GovInfo API requests in milliseconds
+        #[arg(long, default_value_t = DEFAULT_REQUEST_DELAY_MS)]
+        request_delay_ms: u64,
+    },
+
+    /// Compare two master lists and report new events and transcript changes
+    Diff {
+        /// Previous master list
+        #[arg(long)]
+        old: PathBuf,
+
+        /// Current master list
+        #[arg(long)]
+        new: PathBuf,
+
+        /// Write just the new events as a master list YAML for incremental ingestion
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 
     /// Show statistics about hearings
@@ -120,6 +154,14 @@ enum Commands {
         /// Skip transcripts that already exist in output directory
         #[arg(long)]
         skip_existing: bool,
+
+        /// Maximum transcripts fetched at once
+        #[arg(long, default_value = "8")]
+        concurrency: usize,
+
+        /// Minimum delay between transcript requests in milliseconds
+        #[arg(long, default_value_t = DEFAULT_TRANSCRIPT_DELAY_MS)]
+        request_delay_ms: u64,
     },
 }
 
@@ -133,16 +175,18 @@ fn main() -> Result<()> {
             start_date,
             end_date,
             output,
+            request_delay_ms,
         } => {
-            fetch_floor_speeches(&start_date, &end_date, &output)?;
+            fetch_floor_speeches(&start_date, &end_date, &output, request_delay_ms)?;
         }
 
         Commands::FetchHearings {
             start_date,
             end_date,
             output,
+            request_delay_ms,
         } => {
-            fetch_hearings_from_govinfo(&start_date, &end_date, &output)?;
+            fetch_hearings_from_govinfo(&start_date, &end_date, &output, request_delay_ms)?;
         }
 
         Commands::Hearings { input, output } => {
@@ -154,21 +198,29 @@ fn main() -> Result<()> {
             hearings,
             media,
             output,
+            format,
             start_date,
             end_date,
             skip_speeches,
+            request_delay_ms,
         } => {
             merge_events(
                 speeches.as_deref(),
                 &hearings,
                 &media,
                 &output,
+                format,
                 &start_date,
                 &end_date,
                 skip_speeches,
+                request_delay_ms,
             )?;
         }
 
+        Commands::Diff { old, new, output } => {
+            diff_master_lists(&old, &new, output.as_deref())?;
+        }
+
         Commands::Stats { input } => {
             show_stats(&input)?;
         }
@@ -178,8 +230,17 @@ fn main() -> Result<()> {
             output_dir,
             limit,
             skip_existing,
+            concurrency,
+            request_delay_ms,
         } => {
-            parse_transcripts(&input, &output_dir, limit, skip_existing)?;
+            parse_transcripts(
+                &input,
+                &output_dir,
+                limit,
+                skip_existing,
+                concurrency,
+                Duration::from_millis(request_delay_ms),
+            )?;
         }
     }
 
@@ -191,9 +252,15 @@ fn get_api_key() -> Result<String> {
         .wrap_err("CONGRESS_API_KEY environment variable not set.\nGet a free API key at: https://api.data.gov/signup/")
 }
 
-fn fetch_floor_speeches(start_date: &str, end_date: &str, output: &PathBuf) -> Result<()> {
+fn fetch_floor_speeches(
+    start_date: &str,
+    end_date: &str,
+    output: &PathBuf,
+    request_delay_ms: u64,
+) -> Result<()> {
     let api_key = get_api_key()?;
-    let client = GovInfoClient::new(api_key);
+    let client =
+        GovInfoClient::new(api_key).with_request_delay(Duration::from_millis(request_delay_ms));
 
     eprintln!("Fetching floor speeches from {} to {}...", start_date, end_date);
 
@@ -219,9 +286,15 @@ fn fetch_floor_speeches(start_date: &str, end_date: &str, output: &PathBuf) -> R
     Ok(())
 }
 
-fn fetch_hearings_from_govinfo(start_date: &str, end_date: &str, output: &PathBuf) -> Result<()> {
+fn fetch_hearings_from_govinfo(
+    start_date: &str,
+    end_date: &str,
+    output: &PathBuf,
+    request_delay_ms: u64,
+) -> Result<()> {
     let api_key = get_api_key()?;
-    let client = GovInfoClient::new(api_key);
+    let client =
+        GovInfoClient::new(api_key).with_request_delay(Duration::from_millis(request_delay_ms));
 
     eprintln!("Fetching hearings from {} to {}...", start_date, end_date);
 
@@ -284,9 +357,11 @@ fn merge_events(
     hearings_path: &Path,
     media_paths: &[PathBuf],
     output: &Path,
+    format: OutputFormat,
     start_date: &str,
     end_date: &str,
     skip_speeches: bool,
+    request_delay_ms: u64,
 ) -> Result<()> {
     let mut events: Vec<Event> = Vec::new();
 
@@ -317,7 +392,8 @@ fn merge_events(
         } else {
             // Fetch from API
             let api_key = get_api_key()?;
-            let client = GovInfoClient::new(api_key);
+            let client = GovInfoClient::new(api_key)
+                .with_request_delay(Duration::from_millis(request_delay_ms));
 
             eprintln!(
                 "Fetching floor speeches from {} to {}...",
@@ -398,13 +474,71 @@ fn merge_events(
         }
     );
 
-    write_master_list(&events, output)?;
+    write_master_list(&events, output, format)?;
     eprintln!();
     eprintln!("Output written to: {}", output.display());
 
     Ok(())
 }
 
+fn diff_master_lists(old_path: &Path, new_path: &Path, output: Option<&Path>) -> Result<()> {
+    eprintln!("Loading {}...", old_path.display());
+    let old = load_master_list(old_path)?;
+    eprintln!("Loading {}...", new_path.display());
+    let new = load_master_list(new_path)?;
+
+    let diff = diff_events(&old.events, &new.events);
+
+    eprintln!();
+    eprintln!("=== Master List Diff ===");
+    eprintln!("New events: {}", diff.added.len());
+    for (kind, count) in count_by_kind(&diff.added) {
+        eprintln!("  {}: {}", kind, count);
+    }
+    eprintln!("Gained transcript: {}", diff.gained_transcript.len());
+    eprintln!("No longer listed: {}", diff.removed);
+    eprintln!();
+    eprintln!("{:<20} {:>8} {:>8} {:>8}", "", "old", "new", "delta");
+    let rows: [(&str, fn(&EventCounts) -> usize); 5] = [
+        ("Total events", |c| c.total),
+        ("With transcript", |c| c.with_transcript),
+        ("Floor speeches", |c| c.floor_speeches),
+        ("Hearings", |c| c.hearings),
+        ("Media appearances", |c| c.media_appearances),
+    ];
+    for (label, count) in rows {
+        let (before, after) = (count(&diff.old_counts), count(&diff.new_counts));
+        eprintln!(
+            "{:<20} {:>8} {:>8} {:>+8}",
+            label,
+            before,
+            after,
+            after as i64 - before as i64
+        );
+    }
+
+    if let Some(output) = output {
+        let added: Vec<Event> = diff.added.iter().map(|e| (*e).clone()).collect();
+        write_master_list(&added, output, OutputFormat::Yaml)?;
+        eprintln!();
+        eprintln!("New events written to: {}", output.display());
+    }
+
+    Ok(())
+}
+
+/// Number of events per type, in first-seen order
+fn count_by_kind(events: &[&Event]) -> Vec<(&'static str, usize)> {
+    let mut counts: Vec<(&'static str, usize)> = Vec::new();
+    for event in events {
+        match counts.iter_mut().find(|(kind, _)| *kind == event.kind()) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((event.kind(), 1)),
+        }
+    }
+    counts
+}
+
 fn show_stats(input: &PathBuf) -> Result<()> {
     eprintln!("Loading hearings from {}...", input.display());
 
@@ -477,9 +611,10 @@ fn parse_transcripts(
     output_dir: &Path,
     limit: Option<usize>,
     skip_existing: bool,
+    concurrency: usize,
+    request_delay: Duration,
 ) -> Result<()> {
-    use rayon::prelude::*;
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use futures::stream::{self, StreamExt};
 
     eprintln!("Loading hearings from {}...", input.display());
     let hearings = load_hearings_from_yaml(input)?;
@@ -519,7 +654,12 @@ fn parse_transcripts(
         to_process
     };
 
-    eprintln!("Will parse {} transcripts (using parallel processing)", to_process.len());
+    let concurrency = concurrency.max(1);
+    eprintln!(
+        "Will parse {} transcripts ({} concurrent requests)",
+        to_process.len(),
+        concurrency
+    );
 
     if to_process.is_empty() {
         eprintln!("Nothing to process.");
@@ -530,61 +670,78 @@ fn parse_transcripts(
     std::fs::create_dir_all(output_dir)
         .wrap_err_with(|| format!("Failed to create output directory: {}", output_dir.display()))?;
 
-    let success_count = AtomicUsize::new(0);
-    let error_count = AtomicUsize::new(0);
-    let processed_count = AtomicUsize::new(0);
     let total = to_process.len();
-    let output_dir = output_dir.to_path_buf();
-
-    // Process in parallel
-    to_process.par_iter().for_each(|hearing| {
-        let fetcher = TranscriptFetcher::new();
-        let transcript_url = hearing.transcript.as_ref().unwrap();
-
-        let package_id = match TranscriptFetcher::extract_package_id(transcript_url) {
-            Some(id) => id,
-            None => {
-                error_count.fetch_add(1, Ordering::Relaxed);
-                return;
-            }
-        };
-
-        let output_file = output_dir.join(format!("{}.json", package_id));
-        let current = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
-
-        eprint!("\r  [{}/{}] Parsing {}...                    ", current, total, package_id);
-
-        match fetcher.parse_hearing_transcript(hearing) {
-            Ok(Some(parsed)) => {
-                match serde_json::to_string_pretty(&parsed) {
-                    Ok(json) => {
-                        if std::fs::write(&output_file, json).is_ok() {
-                            success_count.fetch_add(1, Ordering::Relaxed);
-                        } else {
-                            error_count.fetch_add(1, Ordering::Relaxed);
-                        }
-                    }
-                    Err(_) => {
-                        error_count.fetch_add(1, Ordering::Relaxed);
-                    }
+    let fetcher = TranscriptFetcher::new(request_delay);
+    let runtime = tokio::runtime::Runtime::new().wrap_err("Failed to start async runtime")?;
+
+    let (success_count, error_count) = runtime.block_on(async {
+        let mut success_count = 0;
+        let mut error_count = 0;
+        let mut processed_count = 0;
+
+        // each task writes its transcript as soon as it's parsed, so at most
+        // `concurrency` transcripts are held in memory at once
+        let mut results = stream::iter(&to_process)
+            .map(|hearing| parse_and_write_transcript(&fetcher, hearing, output_dir))
+            .buffer_unordered(concurrency);
+
+        while let Some((package_id, result)) = results.next().await {
+            processed_count += 1;
+            eprint!("\r  [{}/{}] Parsed {}...                    ", processed_count, total, package_id);
+
+            match result {
+                Ok(true) => success_count += 1,
+                Ok(false) => error_count += 1,
+                Err(e) => {
+                    eprintln!("\n  Error parsing {}: {}", package_id, e);
+                    error_count += 1;
                 }
             }
-            Ok(None) => {
-                error_count.fetch_add(1, Ordering::Relaxed);
-            }
-            Err(e) => {
-                eprintln!("\n  Error parsing {}: {}", package_id, e);
-                error_count.fetch_add(1, Ordering::Relaxed);
-            }
         }
+
+        (success_count, error_count)
     });
 
     eprintln!();
     eprintln!();
     eprintln!("=== Parsing Complete ===");
-    eprintln!("Successfully parsed: {}", success_count.load(Ordering::Relaxed));
-    eprintln!("Errors:              {}", error_count.load(Ordering::Relaxed));
+    eprintln!("Successfully parsed: {}", success_count);
+    eprintln!("Errors:              {}", error_count);
     eprintln!("Output directory:    {}", output_dir.display());
 
     Ok(())
 }
+
+/// Fetch, parse, and write one hearing's transcript, returning its package ID
+/// (or transcript URL if none could be extracted) with the outcome
+async fn parse_and_write_transcript(
+    fetcher: &TranscriptFetcher,
+    hearing: &models::Hearing,
+    output_dir: &Path,
+) -> (String, Result<bool>) {
+    let transcript_url = hearing.transcript.clone().unwrap_or_default();
+    let Some(package_id) = TranscriptFetcher::extract_package_id(&transcript_url) else {
+        return (transcript_url, Ok(false));
+    };
+
+    let output_file = output_dir.join(format!("{}.json", package_id));
+    let result = write_transcript(fetcher, hearing, &output_file).await;
+    (package_id, result)
+}
+
+/// Returns `Ok(true)` when the JSON was written and `Ok(false)` when there was
+/// nothing to parse
+async fn write_transcript(
+    fetcher: &TranscriptFetcher,
+    hearing: &models::Hearing,
+    output_file: &Path,
+) -> Result<bool> {
+    let Some(parsed) = fetcher.parse_hearing_transcript(hearing).await? else {
+        return Ok(false);
+    };
+    let json = serde_json::to_string_pretty(&parsed)?;
+    tokio::fs::write(output_file, json)
+        .await
+        .wrap_err_with(|| format!("Failed to write {}", output_file.display()))?;
+    Ok(true)
+}
@@ -52,9 +52,56 @@ impl Event {
         }
     }
 
+    /// Variant name as used in the `type` tag (e.g. "floor_speech")
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Event::FloorSpeech(_) => "floor_speech",
+            Event::Hearing(_) => "hearing",
+            Event::MediaAppearance(_) => "media_appearance",
+        }
+    }
+
+    /// Stable identity across master list runs: the event type plus its event ID
+    ///
+    /// The type is included because IDs come from different sources and are
+    /// only unique within one event type.
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.kind(), self.event_id())
+    }
+
     pub fn is_media_appearance(&self) -> bool {
         matches!(self, Event::MediaAppearance(_))
     }
+
+    /// Column names for [`Event::to_csv_row`]
+    pub const CSV_HEADER: [&'static str; 5] =
+        ["type", "date", "title", "has_transcript", "source_url"];
+
+    /// Flatten the event to the columns shared by every variant
+    ///
+    /// `source_url` is the transcript link when there is one, otherwise the video
+    /// (or audio, for media appearances).
+    pub fn to_csv_row(&self) -> [String; 5] {
+        let (title, source_url) = match self {
+            Event::FloorSpeech(s) => (&s.title, s.transcript.as_ref().or(s.video.as_ref())),
+            Event::Hearing(h) => (&h.title, h.transcript.as_ref().or(h.video.as_ref())),
+            Event::MediaAppearance(m) => (
+                &m.title,
+                m.media
+                    .transcript_url
+                    .as_ref()
+                    .or(m.media.video_url.as_ref())
+                    .or(m.media.audio_url.as_ref()),
+            ),
+        };
+        [
+            self.kind().to_string(),
+            self.date_string(),
+            title.clone(),
+            self.has_transcript().to_string(),
+            source_url.cloned().unwrap_or_default(),
+        ]
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
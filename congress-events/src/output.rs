@@ -5,8 +5,20 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
-/// Write the master list to a YAML file
-pub fn write_master_list(events: &[Event], output_path: &Path) -> Result<()> {
+/// File format for the master list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Structured YAML (metadata plus events)
+    #[default]
+    Yaml,
+    /// Structured JSON (metadata plus events)
+    Json,
+    /// One row per event with the columns common to all event types
+    Csv,
+}
+
+/// Write the master list in the given format
+pub fn write_master_list(events: &[Event], output_path: &Path, format: OutputFormat) -> Result<()> {
     let with_transcript = events.iter().filter(|e| e.has_transcript()).count();
     let without_transcript = events.len() - with_transcript;
 
@@ -36,18 +48,48 @@ pub fn write_master_list(events: &[Event], output_path: &Path) -> Result<()> {
         events: events.to_vec(),
     };
 
-    let yaml =
-        serde_yaml::to_string(&master_list).wrap_err("Failed to serialize master list to YAML")?;
+    let contents = match format {
+        OutputFormat::Yaml => serde_yaml::to_string(&master_list)
+            .wrap_err("Failed to serialize master list to YAML")?,
+        OutputFormat::Json => serde_json::to_string_pretty(&master_list)
+            .wrap_err("Failed to serialize master list to JSON")?,
+        OutputFormat::Csv => events_to_csv(&master_list.events),
+    };
 
     let mut file =
         File::create(output_path).wrap_err_with(|| format!("Failed to create {}", output_path.display()))?;
 
-    file.write_all(yaml.as_bytes())
+    file.write_all(contents.as_bytes())
         .wrap_err_with(|| format!("Failed to write to {}", output_path.display()))?;
 
     Ok(())
 }
 
+/// Render events as CSV with a header row
+fn events_to_csv(events: &[Event]) -> String {
+    let mut csv = csv_line(Event::CSV_HEADER.iter().copied());
+    for event in events {
+        csv.push_str(&csv_line(event.to_csv_row().iter().map(String::as_str)));
+    }
+    csv
+}
+
+/// Join fields into one CSV line, quoting any that contain separators or quotes
+fn csv_line<'a>(fields: impl Iterator<Item = &'a str>) -> String {
+    let mut line = fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
 /// Write floor speeches to a YAML file
 pub fn write_floor_speeches(speeches: &[FloorSpeech], output_path: &Path) -> Result<()> {
     let with_transcript = speeches.iter().filter(|s| s.transcript.is_some()).count();
@@ -128,3 +170,36 @@ struct HearingsMetadata {
     without_transcript: usize,
     with_video: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Chamber, Hearing};
+
+    #[test]
+    fn test_csv_line_quotes_special_fields() {
+        let line = csv_line(["plain", "a, b", "say \"hi\"", "two\nlines"].into_iter());
+        assert_eq!(line, "plain,\"a, b\",\"say \"\"hi\"\"\",\"two\nlines\"\n");
+    }
+
+    #[test]
+    fn test_events_to_csv() {
+        let hearing = Event::Hearing(Hearing {
+            event_id: "h1".to_string(),
+            date: "2024-03-01".to_string(),
+            chamber: Chamber::Senate,
+            committee: None,
+            title: "Budget, 2025".to_string(),
+            transcript: None,
+            video: Some("https://example.com/v".to_string()),
+            congress: Some(118),
+        });
+
+        let csv = events_to_csv(&[hearing]);
+        assert_eq!(
+            csv,
+            "type,date,title,has_transcript,source_url\n\
+             hearing,2024-03-01,\"Budget, 2025\",false,https://example.com/v\n"
+        );
+    }
+}
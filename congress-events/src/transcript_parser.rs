@@ -1,25 +1,82 @@
 //! Transcript parser for congressional hearing transcripts from GovInfo
 
+use crate::govinfo::{rate_limit_wait, unix_now, MAX_RATE_LIMIT_RETRIES, MAX_TRANSIENT_RETRIES};
 use crate::models::{Hearing, ParsedTranscript, Statement};
 use eyre::{Context, Result};
 use regex::Regex;
 use std::collections::HashSet;
-use std::thread;
 use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, sleep_until, Instant};
 
-const RATE_LIMIT_DELAY_MS: u64 = 200;
+/// Default minimum spacing between transcript requests
+pub const DEFAULT_TRANSCRIPT_DELAY_MS: u64 = 200;
 
+/// Spaces request starts at least `interval` apart across all concurrent tasks
+struct RateLimiter {
+    interval: Duration,
+    slots: Mutex<Slots>,
+}
+
+struct Slots {
+    next: Instant,
+    /// No request starts before this, set when any task is rate limited
+    paused_until: Instant,
+}
+
+impl RateLimiter {
+    fn new(interval: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            interval,
+            slots: Mutex::new(Slots {
+                next: now,
+                paused_until: now,
+            }),
+        }
+    }
+
+    /// Wait for this caller's slot
+    ///
+    /// A pause that starts while waiting pushes the caller past it too.
+    async fn acquire(&self) {
+        loop {
+            let slot = {
+                let mut slots = self.slots.lock().await;
+                let slot = slots.next.max(slots.paused_until).max(Instant::now());
+                slots.next = slot + self.interval;
+                slot
+            };
+            sleep_until(slot).await;
+
+            if self.slots.lock().await.paused_until <= Instant::now() {
+                return;
+            }
+        }
+    }
+
+    /// Hold back every caller until `deadline`
+    async fn pause_until(&self, deadline: Instant) {
+        let mut slots = self.slots.lock().await;
+        slots.paused_until = slots.paused_until.max(deadline);
+    }
+}
+
+/// Fetches transcripts over one shared HTTP client, so concurrent tasks reuse
+/// connections and share a single rate limit
 pub struct TranscriptFetcher {
-    client: reqwest::blocking::Client,
+    client: reqwest::Client,
+    limiter: RateLimiter,
 }
 
 impl TranscriptFetcher {
-    pub fn new() -> Self {
+    pub fn new(request_delay: Duration) -> Self {
         Self {
-            client: reqwest::blocking::Client::builder()
+            client: reqwest::Client::builder()
                 .timeout(Duration::from_secs(60))
                 .build()
                 .expect("Failed to create HTTP client"),
+            limiter: RateLimiter::new(request_delay),
         }
     }
 
@@ -52,41 +109,70 @@ impl TranscriptFetcher {
         )
     }
 
-    /// Fetch the raw HTML content of a transcript
-    pub fn fetch_transcript_html(&self, package_id: &str) -> Result<String> {
-        thread::sleep(Duration::from_millis(RATE_LIMIT_DELAY_MS));
-
+    /// Fetch the raw HTML content of a transcript, waiting out rate limits
+    /// and retrying transient failures
+    pub async fn fetch_transcript_html(&self, package_id: &str) -> Result<String> {
         let url = Self::build_html_url(package_id);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .wrap_err_with(|| format!("Failed to fetch {}", url))?;
-
-        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            eprintln!("Rate limited, waiting 60s...");
-            thread::sleep(Duration::from_secs(60));
-            return self.fetch_transcript_html(package_id);
-        }
+        let mut rate_limited = 0;
+        let mut transient = 0;
+
+        loop {
+            self.limiter.acquire().await;
+
+            let response = match self.client.get(&url).send().await {
+                Ok(response) => response,
+                Err(e) if transient < MAX_TRANSIENT_RETRIES => {
+                    let wait = Duration::from_secs(1 << transient);
+                    transient += 1;
+                    eprintln!("\n  Request for {} failed ({}), retrying in {}s...", package_id, e, wait.as_secs());
+                    sleep(wait).await;
+                    continue;
+                }
+                Err(e) => return Err(e).wrap_err_with(|| format!("Failed to fetch {}", url)),
+            };
 
-        let status = response.status();
-        if !status.is_success() {
-            eyre::bail!("HTTP {} for {}", status, url);
-        }
+            let status = response.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                if rate_limited >= MAX_RATE_LIMIT_RETRIES {
+                    eyre::bail!("Rate limited {} times in a row fetching {}", rate_limited, url);
+                }
+                let wait = rate_limit_wait(response.headers(), unix_now(), rate_limited);
+                rate_limited += 1;
+                eprintln!("\n  Rate limited, pausing all requests for {}s...", wait.as_secs());
+                // every task backs off, not just the one that got the 429
+                self.limiter.pause_until(Instant::now() + wait).await;
+                continue;
+            }
+
+            if status.is_server_error() && transient < MAX_TRANSIENT_RETRIES {
+                let wait = Duration::from_secs(1 << transient);
+                transient += 1;
+                eprintln!("\n  HTTP {} for {}, retrying in {}s...", status, package_id, wait.as_secs());
+                sleep(wait).await;
+                continue;
+            }
 
-        response
-            .text()
-            .wrap_err_with(|| format!("Failed to read response from {}", url))
+            if !status.is_success() {
+                eyre::bail!("HTTP {} for {}", status, url);
+            }
+
+            return response
+                .text()
+                .await
+                .wrap_err_with(|| format!("Failed to read response from {}", url));
+        }
     }
 
-    /// Parse a transcript from a hearing
-    pub fn parse_hearing_transcript(&self, hearing: &Hearing) -> Result<Option<ParsedTranscript>> {
+    /// Fetch and parse a transcript from a hearing
+    ///
+    /// Parsing runs on the blocking pool so it doesn't stall in-flight requests.
+    pub async fn parse_hearing_transcript(&self, hearing: &Hearing) -> Result<Option<ParsedTranscript>> {
         let transcript_url = match &hearing.transcript {
-            Some(url) => url,
+            Some(url) => url.clone(),
             None => return Ok(None),
         };
 
-        let package_id = match Self::extract_package_id(transcript_url) {
+        let package_id = match Self::extract_package_id(&transcript_url) {
             Some(id) => id,
             None => {
                 eprintln!("  Warning: Could not extract package ID from {}", transcript_url);
@@ -94,8 +180,13 @@ impl TranscriptFetcher {
             }
         };
 
-        let html = self.fetch_transcript_html(&package_id)?;
-        let parsed = parse_transcript_html(&html, hearing, &package_id, transcript_url)?;
+        let html = self.fetch_transcript_html(&package_id).await?;
+        let hearing = hearing.clone();
+        let parsed = tokio::task::spawn_blocking(move || {
+            parse_transcript_html(&html, &hearing, &package_id, &transcript_url)
+        })
+        .await
+        .wrap_err("Transcript parsing task failed")??;
 
         Ok(Some(parsed))
     }
@@ -303,6 +394,46 @@ fn extract_committee(text: &str) -> Option<String> {
 mod tests {
     use super::*;
 
+    fn run<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime")
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_pause_holds_back_later_requests() {
+        run(async {
+            let limiter = RateLimiter::new(Duration::ZERO);
+            let start = Instant::now();
+            limiter.pause_until(start + Duration::from_millis(100)).await;
+            limiter.acquire().await;
+            assert!(start.elapsed() >= Duration::from_millis(100));
+        });
+    }
+
+    #[test]
+    fn test_pause_holds_back_requests_already_waiting() {
+        run(async {
+            let limiter = RateLimiter::new(Duration::from_millis(50));
+            let start = Instant::now();
+            limiter.acquire().await;
+
+            // the second caller already holds the slot at 50ms when another task hits a 429
+            let waiting = async {
+                limiter.acquire().await;
+                start.elapsed()
+            };
+            let rate_limited = async {
+                sleep(Duration::from_millis(10)).await;
+                limiter.pause_until(start + Duration::from_millis(150)).await;
+            };
+            let (waited, ()) = futures::join!(waiting, rate_limited);
+            assert!(waited >= Duration::from_millis(150));
+        });
+    }
+
     #[test]
     fn test_extract_package_id() {
         assert_eq!(
@@ -19,7 +19,7 @@ export type ContentType = "hearing" | "floor_speech" | "vote" | "all";
 export type ApiChamber = "house" | "senate";
 
 /** Context scope for RAG mode */
-export type ContextScope = "same" | "related";
+export type ContextScope = "statement" | "document";
 
 /** Search request parameters */
 export interface SearchParams {
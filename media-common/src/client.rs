@@ -4,17 +4,106 @@ use serde::de::DeserializeOwned;
 use std::time::Duration;
 use tracing::{debug, warn};
 
+/// Idle connections kept open per host by default
+///
+/// Requests are sequential, so one idle connection per host is enough to reuse;
+/// a few extra cover callers that share a client across threads.
+pub const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 4;
+
+/// How long an idle pooled connection is kept open by default
+pub const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
 /// Rate-limited HTTP client with retry support
+///
+/// Holds a single `reqwest` client, so every `fetch_*` call reuses pooled
+/// TCP/TLS connections to the same host instead of opening a new one.
 pub struct HttpClient {
     client: Client,
     rate_limit_ms: u64,
     max_retries: u32,
 }
 
+/// Builder for [`HttpClient`] with connection pool tuning
+#[derive(Debug, Clone)]
+pub struct HttpClientBuilder {
+    rate_limit_ms: u64,
+    max_retries: u32,
+    timeout: Duration,
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+}
+
+impl Default for HttpClientBuilder {
+    fn default() -> Self {
+        Self {
+            rate_limit_ms: 200,
+            max_retries: 3,
+            timeout: Duration::from_secs(30),
+            pool_max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            pool_idle_timeout: Some(DEFAULT_POOL_IDLE_TIMEOUT),
+        }
+    }
+}
+
+impl HttpClientBuilder {
+    /// Delay between requests in milliseconds
+    pub fn with_rate_limit_ms(mut self, rate_limit_ms: u64) -> Self {
+        self.rate_limit_ms = rate_limit_ms;
+        self
+    }
+
+    /// Maximum number of retry attempts on failure
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Request timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Maximum idle connections kept open per host (0 disables reuse)
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = max_idle;
+        self
+    }
+
+    /// How long idle connections are kept open (`None` keeps them indefinitely)
+    pub fn with_pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = timeout;
+        self
+    }
+
+    /// Build the client
+    pub fn build(self) -> Result<HttpClient> {
+        let client = Client::builder()
+            .timeout(self.timeout)
+            .user_agent("legislator-search/1.0")
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .tcp_keepalive(Duration::from_secs(60))
+            .build()
+            .wrap_err("failed to build HTTP client")?;
+
+        Ok(HttpClient {
+            client,
+            rate_limit_ms: self.rate_limit_ms,
+            max_retries: self.max_retries,
+        })
+    }
+}
+
 impl HttpClient {
     /// Create a new HTTP client with default settings
     pub fn new() -> Result<Self> {
-        Self::with_config(200, 3, 30)
+        Self::builder().build()
+    }
+
+    /// Start building a client with custom rate limiting, retries, or pooling
+    pub fn builder() -> HttpClientBuilder {
+        HttpClientBuilder::default()
     }
 
     /// Create a new HTTP client with custom configuration
@@ -24,17 +113,11 @@ impl HttpClient {
     /// * `max_retries` - Maximum number of retry attempts on failure
     /// * `timeout_secs` - Request timeout in seconds
     pub fn with_config(rate_limit_ms: u64, max_retries: u32, timeout_secs: u64) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(timeout_secs))
-            .user_agent("legislator-search/1.0")
+        Self::builder()
+            .with_rate_limit_ms(rate_limit_ms)
+            .with_max_retries(max_retries)
+            .with_timeout(Duration::from_secs(timeout_secs))
             .build()
-            .wrap_err("failed to build HTTP client")?;
-
-        Ok(Self {
-            client,
-            rate_limit_ms,
-            max_retries,
-        })
     }
 
     /// Fetch JSON from a URL with rate limiting and retries
@@ -138,3 +221,84 @@ impl Default for HttpClient {
         Self::new().expect("failed to create default HTTP client")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serve keep-alive "ok" responses, counting accepted TCP connections
+    fn spawn_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test server");
+        let addr = listener.local_addr().expect("test server address");
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+                    let mut stream = stream;
+                    loop {
+                        // read one request head; GETs have no body
+                        let mut line = String::new();
+                        loop {
+                            line.clear();
+                            match reader.read_line(&mut line) {
+                                Ok(0) | Err(_) => return,
+                                Ok(_) if line == "\r\n" => break,
+                                Ok(_) => {}
+                            }
+                        }
+                        let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok";
+                        if stream.write_all(response.as_bytes()).is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        (format!("http://{}", addr), connections)
+    }
+
+    #[test]
+    fn test_reuses_connection_across_fetches() {
+        let (base, connections) = spawn_server();
+        let client = HttpClient::builder()
+            .with_rate_limit_ms(0)
+            .build()
+            .expect("build client");
+
+        for i in 0..3 {
+            let body = client
+                .fetch_text(&format!("{}/file{}", base, i))
+                .expect("fetch");
+            assert_eq!(body, "ok");
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_pool_disabled_opens_new_connections() {
+        let (base, connections) = spawn_server();
+        let client = HttpClient::builder()
+            .with_rate_limit_ms(0)
+            .with_pool_max_idle_per_host(0)
+            .build()
+            .expect("build client");
+
+        for i in 0..3 {
+            client
+                .fetch_text(&format!("{}/file{}", base, i))
+                .expect("fetch");
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 3);
+    }
+}
@@ -7,7 +7,11 @@ pub mod client;
 /// Member of Congress lookup and search
 pub mod members;
 
-pub use client::HttpClient;
+/// Tracing subscriber setup shared by the media binaries
+pub mod logging;
+
+pub use client::{HttpClient, HttpClientBuilder};
+pub use logging::init_logging;
 pub use members::{Chamber, Member, MemberLookup, Party};
 pub use types::{
     MediaAppearance, MediaAppearanceOutput, MediaInfo, Outlet, OutletType, OutputMetadata,
@@ -20,10 +24,61 @@ pub fn generate_event_id(source: SourceType, external_id: &str) -> String {
 }
 
 /// Write media appearances to a YAML file
+///
+/// Writes to a temporary file and renames it into place, so an interrupted
+/// write never leaves a truncated file behind.
 pub fn write_yaml(output: &MediaAppearanceOutput, path: &str) -> eyre::Result<()> {
     use eyre::Context;
     let content = serde_yaml::to_string(output).wrap_err("failed to serialize to YAML")?;
-    std::fs::write(path, content).wrap_err_with(|| format!("failed to write to {}", path))?;
+    let tmp_path = format!("{}.tmp", path);
+    std::fs::write(&tmp_path, content)
+        .wrap_err_with(|| format!("failed to write to {}", tmp_path))?;
+    std::fs::rename(&tmp_path, path)
+        .wrap_err_with(|| format!("failed to move {} to {}", tmp_path, path))?;
+    Ok(())
+}
+
+/// Write collected appearances sorted by date descending
+///
+/// Used both for periodic checkpoints during long fetches and for the final
+/// output, so an interrupted run leaves the appearances collected so far.
+pub fn write_appearances(
+    source_type: SourceType,
+    appearances: &[MediaAppearance],
+    path: &str,
+) -> eyre::Result<usize> {
+    let mut sorted = appearances.to_vec();
+    sorted.sort_by_key(|a| std::cmp::Reverse(a.date));
+
+    let output = MediaAppearanceOutput::new(source_type, sorted);
+    write_yaml(&output, path)?;
+    Ok(output.metadata.total_appearances)
+}
+
+/// Checkpoint a long fetch after `members_done` of `members_total` members
+///
+/// Writes the appearances collected so far every `checkpoint_every` members
+/// (0 = never), so an interrupted run keeps partial results.
+pub fn checkpoint_appearances(
+    source_type: SourceType,
+    appearances: &[MediaAppearance],
+    path: &str,
+    members_done: usize,
+    members_total: usize,
+    checkpoint_every: usize,
+) -> eyre::Result<()> {
+    if checkpoint_every == 0 || !members_done.is_multiple_of(checkpoint_every) {
+        return Ok(());
+    }
+
+    let written = write_appearances(source_type, appearances, path)?;
+    tracing::info!(
+        "Checkpoint: {} appearances from {}/{} members written to {}",
+        written,
+        members_done,
+        members_total,
+        path
+    );
     Ok(())
 }
 
@@ -0,0 +1,20 @@
+use tracing_subscriber::EnvFilter;
+
+/// Install the global tracing subscriber
+///
+/// `quiet` limits output to warnings and errors, `verbose` enables debug
+/// output (e.g., request URLs). `RUST_LOG`, when set, overrides both.
+pub fn init_logging(quiet: bool, verbose: bool) -> eyre::Result<()> {
+    let level = if quiet {
+        "warn"
+    } else if verbose {
+        "debug"
+    } else {
+        "info"
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter).finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+    Ok(())
+}
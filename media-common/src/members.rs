@@ -59,17 +59,24 @@ impl Member {
 }
 
 /// Lookup service for members of Congress
+///
+/// Members are stored once in load order; bioguide IDs, name variants, and
+/// normalized last names index into that list.
 pub struct MemberLookup {
-    by_bioguide: HashMap<String, Member>,
-    by_name_lower: HashMap<String, String>, // lowercase name -> bioguide_id
+    members: Vec<Member>,
+    by_bioguide: HashMap<String, usize>,
+    by_name_lower: HashMap<String, usize>, // lowercase name -> member index
+    by_last_name: HashMap<String, Vec<usize>>, // normalized last name -> member indices
 }
 
 impl MemberLookup {
     /// Create an empty lookup
     pub fn new() -> Self {
         Self {
+            members: Vec::new(),
             by_bioguide: HashMap::new(),
             by_name_lower: HashMap::new(),
+            by_last_name: HashMap::new(),
         }
     }
 
@@ -155,59 +162,112 @@ impl MemberLookup {
     }
 
     /// Add a member to the lookup
+    ///
+    /// A member whose bioguide ID is already present replaces the earlier entry.
     pub fn add_member(&mut self, member: Member) {
+        let index = match self.by_bioguide.get(&member.bioguide_id) {
+            Some(&index) => {
+                let previous = std::mem::replace(&mut self.members[index], member);
+                for name in previous.search_names() {
+                    let name = name.to_lowercase();
+                    if self.by_name_lower.get(&name) == Some(&index) {
+                        self.by_name_lower.remove(&name);
+                    }
+                }
+                let previous_key = normalize_last_name(&previous.last_name);
+                if let Some(indices) = self.by_last_name.get_mut(&previous_key) {
+                    indices.retain(|&i| i != index);
+                }
+                index
+            }
+            None => {
+                self.members.push(member);
+                self.members.len() - 1
+            }
+        };
+        let member = &self.members[index];
+
         // index by various name forms
         for name in member.search_names() {
-            self.by_name_lower
-                .insert(name.to_lowercase(), member.bioguide_id.clone());
+            self.by_name_lower.insert(name.to_lowercase(), index);
         }
-
-        self.by_bioguide.insert(member.bioguide_id.clone(), member);
+        self.by_last_name
+            .entry(normalize_last_name(&member.last_name))
+            .or_default()
+            .push(index);
+        self.by_bioguide.insert(member.bioguide_id.clone(), index);
     }
 
     /// Get a member by bioguide ID
     pub fn by_bioguide(&self, id: &str) -> Option<&Member> {
-        self.by_bioguide.get(id)
+        self.by_bioguide.get(id).map(|&index| &self.members[index])
     }
 
     /// Find a member by name (case-insensitive)
     pub fn by_name(&self, name: &str) -> Option<&Member> {
-        let bioguide_id = self.by_name_lower.get(&name.to_lowercase())?;
-        self.by_bioguide.get(bioguide_id)
+        self.by_name_lower
+            .get(&name.to_lowercase())
+            .map(|&index| &self.members[index])
+    }
+
+    /// Find members by last name, ignoring case and punctuation (e.g. "O'Rourke" = "orourke")
+    pub fn find_by_last_name(&self, last_name: &str) -> Vec<&Member> {
+        self.by_last_name
+            .get(&normalize_last_name(last_name))
+            .map(|indices| indices.iter().map(|&index| &self.members[index]).collect())
+            .unwrap_or_default()
     }
 
-    /// Search for members matching a partial name
+    /// Search for members matching a name or partial name
+    ///
+    /// Last names and indexed name forms are answered from the indexes; only
+    /// other queries fall back to a substring scan over all members.
     pub fn search(&self, query: &str) -> Vec<&Member> {
-        let query_lower = query.to_lowercase();
-        let mut matches: Vec<&Member> = self
-            .by_bioguide
-            .values()
-            .filter(|m| {
-                m.name.to_lowercase().contains(&query_lower)
-                    || m.last_name.to_lowercase().contains(&query_lower)
-            })
-            .collect();
+        let mut matches = self.find_by_last_name(query);
+        if matches.is_empty() {
+            matches.extend(self.by_name(query));
+        }
+        if matches.is_empty() {
+            let query_lower = query.to_lowercase();
+            matches = self
+                .members
+                .iter()
+                .filter(|m| {
+                    m.name.to_lowercase().contains(&query_lower)
+                        || m.last_name.to_lowercase().contains(&query_lower)
+                })
+                .collect();
+        }
 
         matches.sort_by(|a, b| a.last_name.cmp(&b.last_name));
         matches
     }
 
-    /// Get all members
-    pub fn all_members(&self) -> impl Iterator<Item = &Member> {
-        self.by_bioguide.values()
+    /// Get all members, in load order
+    pub fn all_members(&self) -> &[Member] {
+        &self.members
     }
 
     /// Get member count
     pub fn len(&self) -> usize {
-        self.by_bioguide.len()
+        self.members.len()
     }
 
     /// Check if empty
     pub fn is_empty(&self) -> bool {
-        self.by_bioguide.is_empty()
+        self.members.is_empty()
     }
 }
 
+/// Lowercase a last name and drop everything but letters and digits
+fn normalize_last_name(last_name: &str) -> String {
+    last_name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
 impl Default for MemberLookup {
     fn default() -> Self {
         Self::new()
@@ -242,3 +302,172 @@ struct LegislatorTerm {
     party: Option<String>,
     district: Option<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGISLATORS_YAML: &str = r#"
+- id:
+    bioguide: O000170
+  name:
+    first: Beto
+    last: O'Rourke
+  terms:
+    - type: rep
+      state: TX
+      party: Democrat
+      district: 16
+- id:
+    bioguide: S000148
+  name:
+    first: Charles
+    last: Schumer
+  terms:
+    - type: sen
+      state: NY
+      party: Democrat
+- id:
+    bioguide: S001150
+  name:
+    first: Adam
+    last: Schiff
+  terms:
+    - type: rep
+      state: CA
+      party: Democrat
+      district: 28
+- id:
+    bioguide: S001217
+  name:
+    first: Rick
+    last: Scott
+  terms:
+    - type: sen
+      state: FL
+      party: Republican
+- id:
+    bioguide: S001189
+  name:
+    first: Austin
+    last: Scott
+  terms:
+    - type: rep
+      state: GA
+      party: Republican
+      district: 8
+"#;
+
+    #[test]
+    fn test_lookups_after_load() {
+        let path = std::env::temp_dir().join(format!("members-{}.yaml", std::process::id()));
+        std::fs::write(&path, LEGISLATORS_YAML).expect("write legislators");
+        let lookup = MemberLookup::from_legislators_yaml(path.to_str().expect("utf-8 path"), None)
+            .expect("load legislators");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(lookup.len(), 5);
+        let ids: Vec<&str> = lookup.all_members().iter().map(|m| m.bioguide_id.as_str()).collect();
+        assert_eq!(ids, ["O000170", "S000148", "S001150", "S001217", "S001189"]);
+
+        let schumer = lookup.by_bioguide("S000148").expect("schumer");
+        assert_eq!(schumer.last_name, "Schumer");
+        assert_eq!(schumer.chamber, Chamber::Senate);
+        assert!(lookup.by_bioguide("X000000").is_none());
+
+        assert_eq!(
+            lookup.by_name("sen. schumer").map(|m| m.bioguide_id.as_str()),
+            Some("S000148")
+        );
+
+        let scotts: Vec<&str> = lookup
+            .find_by_last_name("SCOTT")
+            .iter()
+            .map(|m| m.bioguide_id.as_str())
+            .collect();
+        assert_eq!(scotts, ["S001217", "S001189"]);
+        assert_eq!(lookup.find_by_last_name("orourke").len(), 1);
+        assert!(lookup.find_by_last_name("Smith").is_empty());
+    }
+
+    #[test]
+    fn test_search_uses_indexes_then_scans() {
+        let path = std::env::temp_dir().join(format!("members-search-{}.yaml", std::process::id()));
+        std::fs::write(&path, LEGISLATORS_YAML).expect("write legislators");
+        let lookup = MemberLookup::from_legislators_yaml(path.to_str().expect("utf-8 path"), None)
+            .expect("load legislators");
+        std::fs::remove_file(&path).ok();
+
+        let ids = |query: &str| -> Vec<String> {
+            lookup.search(query).iter().map(|m| m.bioguide_id.clone()).collect()
+        };
+
+        // last names, normalized like find_by_last_name
+        assert_eq!(ids("scott"), ["S001217", "S001189"]);
+        assert_eq!(ids("orourke"), ["O000170"]);
+        // indexed name forms
+        assert_eq!(ids("Sen. Schumer"), ["S000148"]);
+        assert_eq!(ids("charles schumer"), ["S000148"]);
+        // partial names scan
+        assert_eq!(ids("sch"), ["S001150", "S000148"]);
+        assert_eq!(ids("Beto O'R"), ["O000170"]);
+        assert!(ids("nobody").is_empty());
+    }
+
+    #[test]
+    fn test_re_adding_member_replaces_entry() {
+        let mut lookup = MemberLookup::new();
+        let member = Member {
+            bioguide_id: "S000148".to_string(),
+            name: "Charles Schumer".to_string(),
+            first_name: "Charles".to_string(),
+            last_name: "Schumer".to_string(),
+            state: "NY".to_string(),
+            chamber: Chamber::House,
+            party: Party::Democrat,
+            district: Some("9".to_string()),
+        };
+        lookup.add_member(member.clone());
+        lookup.add_member(Member {
+            chamber: Chamber::Senate,
+            district: None,
+            ..member
+        });
+
+        assert_eq!(lookup.len(), 1);
+        assert_eq!(lookup.find_by_last_name("Schumer").len(), 1);
+        assert_eq!(
+            lookup.by_bioguide("S000148").map(|m| m.chamber),
+            Some(Chamber::Senate)
+        );
+    }
+
+    #[test]
+    fn test_re_adding_renamed_member_drops_old_names() {
+        let mut lookup = MemberLookup::new();
+        let member = Member {
+            bioguide_id: "H001234".to_string(),
+            name: "Jane Doe".to_string(),
+            first_name: "Jane".to_string(),
+            last_name: "Doe".to_string(),
+            state: "OH".to_string(),
+            chamber: Chamber::House,
+            party: Party::Republican,
+            district: Some("3".to_string()),
+        };
+        lookup.add_member(member.clone());
+        lookup.add_member(Member {
+            name: "Jane Smith".to_string(),
+            last_name: "Smith".to_string(),
+            ..member
+        });
+
+        assert!(lookup.by_name("jane doe").is_none());
+        assert!(lookup.by_name("rep. doe").is_none());
+        assert!(lookup.find_by_last_name("Doe").is_empty());
+        assert_eq!(
+            lookup.by_name("Rep. Smith").map(|m| m.bioguide_id.as_str()),
+            Some("H001234")
+        );
+    }
+}
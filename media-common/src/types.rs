@@ -1,8 +1,9 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Type of media source
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SourceType {
     Cspan,
@@ -193,6 +194,13 @@ pub struct OutputMetadata {
     pub total_appearances: usize,
     pub with_transcript: usize,
     pub without_transcript: usize,
+
+    /// Appearance counts per source, so merged files show their coverage
+    ///
+    /// A `BTreeMap` keeps the YAML key order stable; files written before this
+    /// field existed deserialize with an empty map.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub by_source: BTreeMap<SourceType, usize>,
 }
 
 impl MediaAppearanceOutput {
@@ -200,6 +208,13 @@ impl MediaAppearanceOutput {
         let with_transcript = appearances.iter().filter(|a| a.has_transcript()).count();
         let without_transcript = appearances.len() - with_transcript;
 
+        // counted from each appearance rather than `source_type`, which only
+        // names the primary source of a merged collection
+        let mut by_source = BTreeMap::new();
+        for appearance in &appearances {
+            *by_source.entry(appearance.source_type).or_insert(0) += 1;
+        }
+
         Self {
             metadata: OutputMetadata {
                 generated_at: chrono::Utc::now().to_rfc3339(),
@@ -207,8 +222,32 @@ impl MediaAppearanceOutput {
                 total_appearances: appearances.len(),
                 with_transcript,
                 without_transcript,
+                by_source,
             },
             appearances,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_without_by_source_deserializes() {
+        let yaml = r#"
+metadata:
+  generated_at: "2024-01-01T00:00:00+00:00"
+  source_type: youtube
+  total_appearances: 0
+  with_transcript: 0
+  without_transcript: 0
+appearances: []
+"#;
+        let output: MediaAppearanceOutput = serde_yaml::from_str(yaml).expect("parse output");
+
+        assert_eq!(output.metadata.source_type, SourceType::Youtube);
+        assert!(output.metadata.by_source.is_empty());
+        assert!(output.appearances.is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use color_eyre::eyre::Result;
-use media_common::{write_yaml, MediaAppearanceOutput, MemberLookup, SourceType};
-use tracing::{info, Level};
-use tracing_subscriber::FmtSubscriber;
+use media_common::{
+    checkpoint_appearances, init_logging, write_appearances, write_yaml, MediaAppearanceOutput,
+    MemberLookup, SourceType,
+};
+use tracing::info;
 
 mod api;
 use api::PodcastClient;
@@ -18,6 +20,14 @@ struct Cli {
     /// Listen Notes API key (or set LISTEN_NOTES_API_KEY env var)
     #[arg(long, env = "LISTEN_NOTES_API_KEY", global = true)]
     api_key: Option<String>,
+
+    /// Only log warnings and errors
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Log debug output, including API request URLs
+    #[arg(short, long, global = true)]
+    verbose: bool,
 }
 
 #[derive(Subcommand)]
@@ -70,6 +80,10 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "media_podcasts.yaml")]
         output: String,
+
+        /// Write collected appearances to the output every N members (0 = only at the end)
+        #[arg(long, default_value = "10")]
+        checkpoint_every: usize,
     },
 
     /// Test the Listen Notes API with a sample search
@@ -83,12 +97,8 @@ enum Commands {
 fn main() -> Result<()> {
     color_eyre::install()?;
 
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)?;
-
     let cli = Cli::parse();
+    init_logging(cli.quiet, cli.verbose)?;
 
     // get API key from args or env
     let api_key = cli.api_key.unwrap_or_else(|| {
@@ -140,6 +150,7 @@ fn main() -> Result<()> {
             end_date,
             max_results,
             output,
+            checkpoint_every,
         } => {
             let start = start_date
                 .as_ref()
@@ -156,7 +167,7 @@ fn main() -> Result<()> {
 
             let mut all_appearances = Vec::new();
 
-            for member in members.all_members() {
+            for (i, member) in members.all_members().iter().enumerate() {
                 match client.fetch_member_appearances(
                     &member.name,
                     &member.bioguide_id,
@@ -171,18 +182,20 @@ fn main() -> Result<()> {
                         tracing::warn!("Failed to fetch appearances for {}: {}", member.name, e);
                     }
                 }
+
+                checkpoint_appearances(
+                    SourceType::Podcast,
+                    &all_appearances,
+                    &output,
+                    i + 1,
+                    members.len(),
+                    checkpoint_every,
+                )?;
             }
 
-            // sort by date descending
-            all_appearances.sort_by(|a, b| b.date.cmp(&a.date));
+            let written = write_appearances(SourceType::Podcast, &all_appearances, &output)?;
 
-            let output_data = MediaAppearanceOutput::new(SourceType::Podcast, all_appearances);
-            write_yaml(&output_data, &output)?;
-
-            info!(
-                "Wrote {} appearances to {}",
-                output_data.metadata.total_appearances, output
-            );
+            info!("Wrote {} appearances to {}", written, output);
         }
 
         Commands::Test { query } => {
@@ -1,9 +1,11 @@
 use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use color_eyre::eyre::Result;
-use media_common::{write_yaml, MediaAppearanceOutput, MemberLookup, SourceType};
-use tracing::{info, Level};
-use tracing_subscriber::FmtSubscriber;
+use media_common::{
+    checkpoint_appearances, init_logging, write_appearances, write_yaml, MediaAppearanceOutput,
+    MemberLookup, SourceType,
+};
+use tracing::info;
 
 mod api;
 use api::TvArchiveClient;
@@ -14,6 +16,14 @@ use api::TvArchiveClient;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Only log warnings and errors
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Log debug output, including API request URLs
+    #[arg(short, long, global = true)]
+    verbose: bool,
 }
 
 #[derive(Subcommand)]
@@ -66,6 +76,10 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "media_tv_archive.yaml")]
         output: String,
+
+        /// Write collected appearances to the output every N members (0 = only at the end)
+        #[arg(long, default_value = "10")]
+        checkpoint_every: usize,
     },
 
     /// Test the TV Archive API with a sample search
@@ -83,12 +97,8 @@ enum Commands {
 fn main() -> Result<()> {
     color_eyre::install()?;
 
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)?;
-
     let cli = Cli::parse();
+    init_logging(cli.quiet, cli.verbose)?;
 
     match cli.command {
         Commands::Search {
@@ -128,6 +138,7 @@ fn main() -> Result<()> {
             end_date,
             max_results,
             output,
+            checkpoint_every,
         } => {
             let start = start_date
                 .as_ref()
@@ -145,7 +156,7 @@ fn main() -> Result<()> {
             let client = TvArchiveClient::new()?;
             let mut all_appearances = Vec::new();
 
-            for member in members.all_members() {
+            for (i, member) in members.all_members().iter().enumerate() {
                 match client.fetch_member_appearances(
                     &member.name,
                     &member.bioguide_id,
@@ -160,18 +171,20 @@ fn main() -> Result<()> {
                         tracing::warn!("Failed to fetch appearances for {}: {}", member.name, e);
                     }
                 }
+
+                checkpoint_appearances(
+                    SourceType::TvArchive,
+                    &all_appearances,
+                    &output,
+                    i + 1,
+                    members.len(),
+                    checkpoint_every,
+                )?;
             }
 
-            // sort by date descending
-            all_appearances.sort_by(|a, b| b.date.cmp(&a.date));
+            let written = write_appearances(SourceType::TvArchive, &all_appearances, &output)?;
 
-            let output_data = MediaAppearanceOutput::new(SourceType::TvArchive, all_appearances);
-            write_yaml(&output_data, &output)?;
-
-            info!(
-                "Wrote {} appearances to {}",
-                output_data.metadata.total_appearances, output
-            );
+            info!("Wrote {} appearances to {}", written, output);
         }
 
         Commands::Test { query, limit } => {
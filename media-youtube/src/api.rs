@@ -7,11 +7,36 @@ use serde::Deserialize;
 use tracing::{debug, info, warn};
 
 const YOUTUBE_API_BASE: &str = "https://www.googleapis.com/youtube/v3";
+const TIMEDTEXT_BASE: &str = "https://www.youtube.com/api/timedtext";
 
 /// YouTube Data API v3 client
 pub struct YoutubeClient {
     http: HttpClient,
     api_key: String,
+    captions: CaptionClient,
+    fetch_transcripts: bool,
+}
+
+/// Client for the timedtext caption endpoint, which needs no API key
+pub struct CaptionClient {
+    http: HttpClient,
+}
+
+/// Caption text of a video, whole and split into timed segments
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    /// All caption text joined with spaces
+    pub text: String,
+    pub segments: Vec<TranscriptSegment>,
+}
+
+/// One caption cue
+#[derive(Debug, Clone)]
+pub struct TranscriptSegment {
+    /// Offset from the start of the video
+    pub start_seconds: f64,
+    pub duration_seconds: f64,
+    pub text: String,
 }
 
 impl YoutubeClient {
@@ -23,7 +48,18 @@ impl YoutubeClient {
 
         // youtube API is rate-limited, use 200ms between requests
         let http = HttpClient::with_config(200, 3, 30)?;
-        Ok(Self { http, api_key })
+        Ok(Self {
+            http,
+            api_key,
+            captions: CaptionClient::new()?,
+            fetch_transcripts: false,
+        })
+    }
+
+    /// Fetch each video's captions in `fetch_member_appearances`
+    pub fn with_transcripts(mut self, fetch_transcripts: bool) -> Self {
+        self.fetch_transcripts = fetch_transcripts;
+        self
     }
 
     /// Create a client from the YOUTUBE_API_KEY environment variable
@@ -182,6 +218,14 @@ impl YoutubeClient {
                             media = media.with_duration(secs);
                         }
 
+                    if self.fetch_transcripts {
+                        match self.captions.fetch_transcript(&video.id) {
+                            Ok(Some(transcript)) => media = media.with_transcript(transcript.text),
+                            Ok(None) => {}
+                            Err(e) => warn!("Failed to fetch captions for {}: {}", video.id, e),
+                        }
+                    }
+
                     // determine outlet from channel
                     let channel_name = video.snippet.channel_title.clone();
                     let outlet_type = determine_outlet_type(&channel_name);
@@ -248,6 +292,78 @@ impl YoutubeClient {
     }
 }
 
+impl CaptionClient {
+    /// Create a new caption client
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            http: HttpClient::with_config(200, 3, 30)?,
+        })
+    }
+
+    /// Fetch the English captions of a video from the timedtext endpoint
+    ///
+    /// Uploaded caption tracks are preferred over automatic (ASR) ones.
+    /// Returns `None` if the video has no English captions. A track that
+    /// fails to fetch or parse is skipped; an error is returned only if both
+    /// tracks fail.
+    pub fn fetch_transcript(&self, video_id: &str) -> Result<Option<Transcript>> {
+        let kinds = ["", "&kind=asr"];
+        let mut failures = 0;
+        let mut last_error = None;
+
+        for kind in kinds {
+            let url = format!(
+                "{}?v={}&lang=en&fmt=json3{}",
+                TIMEDTEXT_BASE,
+                urlencoding::encode(video_id),
+                kind
+            );
+            debug!("YouTube captions: {}", url);
+
+            // videos without a matching track return an empty body
+            let body = match self.http.fetch_text(&url) {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("Failed to fetch captions for {}: {}", video_id, e);
+                    failures += 1;
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            if body.trim().is_empty() {
+                continue;
+            }
+
+            let captions: TimedTextResponse = match serde_json::from_str(&body) {
+                Ok(captions) => captions,
+                Err(e) => {
+                    warn!("Failed to parse captions for {}: {}", video_id, e);
+                    failures += 1;
+                    last_error = Some(eyre::eyre!(
+                        "failed to parse captions for {}: {}",
+                        video_id,
+                        e
+                    ));
+                    continue;
+                }
+            };
+            if let Some(transcript) = captions.into_transcript() {
+                return Ok(Some(transcript));
+            }
+        }
+
+        // a missing track is not an error, but both tracks failing is
+        if failures == kinds.len()
+            && let Some(e) = last_error
+        {
+            return Err(e);
+        }
+
+        debug!("No English captions for {}", video_id);
+        Ok(None)
+    }
+}
+
 /// Parse YouTube ISO 8601 date format
 fn parse_youtube_date(date_str: &str) -> Option<NaiveDate> {
     // format: 2024-01-15T10:30:00Z
@@ -422,6 +538,63 @@ pub struct ChannelSnippet {
     description: String,
 }
 
+/// Caption track in YouTube's `json3` timedtext format
+#[derive(Debug, Deserialize)]
+struct TimedTextResponse {
+    #[serde(default)]
+    events: Vec<TimedTextEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimedTextEvent {
+    #[serde(rename = "tStartMs", default)]
+    start_ms: u64,
+    #[serde(rename = "dDurationMs", default)]
+    duration_ms: u64,
+    #[serde(default)]
+    segs: Vec<TimedTextSeg>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimedTextSeg {
+    #[serde(default)]
+    utf8: String,
+}
+
+impl TimedTextResponse {
+    /// Convert caption events to a transcript, or `None` if they hold no text
+    fn into_transcript(self) -> Option<Transcript> {
+        let segments: Vec<TranscriptSegment> = self
+            .events
+            .into_iter()
+            .filter_map(|event| {
+                let raw: String = event.segs.iter().map(|seg| seg.utf8.as_str()).collect();
+                // cues carry line breaks and window-only events are just "\n"
+                let text = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.is_empty() {
+                    return None;
+                }
+                Some(TranscriptSegment {
+                    start_seconds: event.start_ms as f64 / 1000.0,
+                    duration_seconds: event.duration_ms as f64 / 1000.0,
+                    text,
+                })
+            })
+            .collect();
+
+        if segments.is_empty() {
+            return None;
+        }
+
+        let text = segments
+            .iter()
+            .map(|seg| seg.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(Transcript { text, segments })
+    }
+}
+
 // URL encoding helper
 mod urlencoding {
     pub fn encode(input: &str) -> String {
@@ -441,3 +614,50 @@ mod urlencoding {
         encoded
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Option<Transcript> {
+        let response: TimedTextResponse = serde_json::from_str(json).expect("parse json3");
+        response.into_transcript()
+    }
+
+    #[test]
+    fn test_empty_events_have_no_transcript() {
+        assert!(parse("{}").is_none());
+        assert!(parse(r#"{"events": []}"#).is_none());
+        assert!(parse(r#"{"events": [{"tStartMs": 0, "dDurationMs": 1000}]}"#).is_none());
+    }
+
+    #[test]
+    fn test_segs_without_utf8_are_skipped() {
+        let json = r#"{"events": [
+            {"tStartMs": 0, "dDurationMs": 500, "segs": [{}]},
+            {"tStartMs": 1500, "dDurationMs": 2000,
+             "segs": [{"utf8": "hello"}, {}, {"utf8": " world"}]}
+        ]}"#;
+        let transcript = parse(json).expect("transcript");
+
+        assert_eq!(transcript.segments.len(), 1);
+        assert_eq!(transcript.segments[0].text, "hello world");
+        assert_eq!(transcript.segments[0].start_seconds, 1.5);
+        assert_eq!(transcript.segments[0].duration_seconds, 2.0);
+    }
+
+    #[test]
+    fn test_newline_only_segs_are_dropped() {
+        let json = r#"{"events": [
+            {"tStartMs": 0, "dDurationMs": 4000, "segs": [{"utf8": "Mr. Speaker,\nI rise"}]},
+            {"tStartMs": 2000, "segs": [{"utf8": "\n"}]},
+            {"tStartMs": 4000, "dDurationMs": 3000, "segs": [{"utf8": "today"}]}
+        ]}"#;
+        let transcript = parse(json).expect("transcript");
+
+        let texts: Vec<&str> = transcript.segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["Mr. Speaker, I rise", "today"]);
+        assert_eq!(transcript.text, "Mr. Speaker, I rise today");
+        assert!(parse(r#"{"events": [{"segs": [{"utf8": "\n"}, {"utf8": "\n"}]}]}"#).is_none());
+    }
+}
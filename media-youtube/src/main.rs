@@ -1,12 +1,14 @@
 use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 use color_eyre::eyre::Result;
-use media_common::{write_yaml, MediaAppearanceOutput, MemberLookup, SourceType};
-use tracing::{info, Level};
-use tracing_subscriber::FmtSubscriber;
+use media_common::{
+    checkpoint_appearances, init_logging, write_appearances, write_yaml, MediaAppearanceOutput,
+    MemberLookup, SourceType,
+};
+use tracing::info;
 
 mod api;
-use api::YoutubeClient;
+use api::{CaptionClient, YoutubeClient};
 
 #[derive(Parser)]
 #[command(name = "media-youtube")]
@@ -18,6 +20,14 @@ struct Cli {
     /// YouTube Data API key (or set YOUTUBE_API_KEY env var)
     #[arg(long, env = "YOUTUBE_API_KEY", global = true)]
     api_key: Option<String>,
+
+    /// Only log warnings and errors
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Log debug output, including API request URLs
+    #[arg(short, long, global = true)]
+    verbose: bool,
 }
 
 #[derive(Subcommand)]
@@ -51,6 +61,10 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "media_youtube.yaml")]
         output: String,
+
+        /// Fetch each video's English captions as its transcript
+        #[arg(long)]
+        transcripts: bool,
     },
 
     /// Fetch appearances for all members in a legislators file
@@ -78,6 +92,14 @@ enum Commands {
         /// Output file path
         #[arg(short, long, default_value = "media_youtube.yaml")]
         output: String,
+
+        /// Write collected appearances to the output every N members (0 = only at the end)
+        #[arg(long, default_value = "10")]
+        checkpoint_every: usize,
+
+        /// Fetch each video's English captions as its transcript
+        #[arg(long)]
+        transcripts: bool,
     },
 
     /// Test the YouTube API with a sample search
@@ -90,24 +112,28 @@ enum Commands {
         #[arg(short, long, default_value = "5")]
         max_results: u32,
     },
+
+    /// Print the timestamped captions of a video
+    Transcript {
+        /// YouTube video ID (the `v` parameter of a watch URL)
+        video_id: String,
+    },
 }
 
 fn main() -> Result<()> {
     color_eyre::install()?;
 
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)?;
-
     let cli = Cli::parse();
+    init_logging(cli.quiet, cli.verbose)?;
 
-    // get API key from args or env
-    let api_key = cli.api_key.unwrap_or_else(|| {
-        std::env::var("YOUTUBE_API_KEY").expect("YOUTUBE_API_KEY not set and --api-key not provided")
-    });
-
-    let client = YoutubeClient::new(api_key)?;
+    // only the Data API commands need a key; captions are fetched without one
+    let api_key = cli.api_key;
+    let client = || {
+        let api_key = api_key.clone().ok_or_else(|| {
+            color_eyre::eyre::eyre!("YOUTUBE_API_KEY not set and --api-key not provided")
+        })?;
+        YoutubeClient::new(api_key)
+    };
 
     match cli.command {
         Commands::Search {
@@ -118,7 +144,9 @@ fn main() -> Result<()> {
             max_results,
             max_pages,
             output,
+            transcripts,
         } => {
+            let client = client()?.with_transcripts(transcripts);
             let start = start_date
                 .as_ref()
                 .map(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d"))
@@ -154,7 +182,10 @@ fn main() -> Result<()> {
             max_results,
             max_pages,
             output,
+            checkpoint_every,
+            transcripts,
         } => {
+            let client = client()?.with_transcripts(transcripts);
             let start = start_date
                 .as_ref()
                 .map(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d"))
@@ -170,7 +201,7 @@ fn main() -> Result<()> {
 
             let mut all_appearances = Vec::new();
 
-            for member in members.all_members() {
+            for (i, member) in members.all_members().iter().enumerate() {
                 match client.fetch_member_appearances(
                     &member.name,
                     &member.bioguide_id,
@@ -186,22 +217,24 @@ fn main() -> Result<()> {
                         tracing::warn!("Failed to fetch appearances for {}: {}", member.name, e);
                     }
                 }
-            }
 
-            // sort by date descending
-            all_appearances.sort_by(|a, b| b.date.cmp(&a.date));
+                checkpoint_appearances(
+                    SourceType::Youtube,
+                    &all_appearances,
+                    &output,
+                    i + 1,
+                    members.len(),
+                    checkpoint_every,
+                )?;
+            }
 
-            let output_data = MediaAppearanceOutput::new(SourceType::Youtube, all_appearances);
-            write_yaml(&output_data, &output)?;
+            let written = write_appearances(SourceType::Youtube, &all_appearances, &output)?;
 
-            info!(
-                "Wrote {} appearances to {}",
-                output_data.metadata.total_appearances, output
-            );
+            info!("Wrote {} appearances to {}", written, output);
         }
 
         Commands::Test { query, max_results } => {
-            let response = client.search(&query, max_results, None, None, None)?;
+            let response = client()?.search(&query, max_results, None, None, None)?;
 
             info!(
                 "Found {} results",
@@ -217,7 +250,32 @@ fn main() -> Result<()> {
                 println!("  Published: {}", item.snippet.published_at);
             }
         }
+
+        Commands::Transcript { video_id } => match CaptionClient::new()?.fetch_transcript(&video_id)? {
+            Some(transcript) => {
+                for segment in &transcript.segments {
+                    println!(
+                        "[{} - {}] {}",
+                        format_timestamp(segment.start_seconds),
+                        format_timestamp(segment.start_seconds + segment.duration_seconds),
+                        segment.text
+                    );
+                }
+                info!(
+                    "{} segments, {} words",
+                    transcript.segments.len(),
+                    transcript.text.split_whitespace().count()
+                );
+            }
+            None => info!("No English captions available for {}", video_id),
+        },
     }
 
     Ok(())
 }
+
+/// Format an offset in seconds as `HH:MM:SS`
+fn format_timestamp(seconds: f64) -> String {
+    let secs = seconds as u64;
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
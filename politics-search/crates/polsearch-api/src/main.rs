@@ -1,17 +1,21 @@
 //! REST API server for `PolSearch`
 
 mod error;
+mod metrics;
 mod middleware;
 mod models;
 mod routes;
 
 use axum::{middleware as axum_mw, routing::get, Router};
 use color_eyre::eyre::Result;
+use metrics::SearchMetrics;
 use polsearch_db::Database;
-use polsearch_pipeline::stages::TextEmbedder;
+use polsearch_pipeline::stages::{
+    hybrid_oversample_from_env, EmbeddingModel, TextEmbedder, TextReranker,
+};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::EnvFilter;
@@ -22,22 +26,56 @@ use utoipa_swagger_ui::SwaggerUi;
 pub struct AppState {
     pub db: Database,
     pub embedder: Mutex<TextEmbedder>,
+    /// Model behind `embedder`, checked against the `text_embeddings` table on each search
+    pub embedding_model: EmbeddingModel,
+    /// Cross-encoder loaded on the first `rerank=true` request; a failed load is retried
+    pub reranker: OnceCell<Mutex<TextReranker>>,
     pub lancedb_path: String,
     pub search_timeout: Duration,
+    /// Rows fetched per hybrid component for each result, from `HYBRID_OVERSAMPLE`
+    pub hybrid_oversample: usize,
+    pub metrics: SearchMetrics,
+    /// Committee names for `/autocomplete`, refreshed periodically
+    pub committee_cache: routes::CommitteeCache,
 }
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(routes::health, routes::search, routes::get_content),
+    paths(
+        routes::health,
+        routes::livez,
+        routes::metrics,
+        routes::search,
+        routes::autocomplete,
+        routes::get_content,
+        routes::list_committees,
+        routes::get_committee_hearings,
+        routes::get_vote,
+        routes::get_related_votes
+    ),
     components(schemas(
         models::HealthResponse,
+        models::DatabaseHealth,
         models::SearchResponse,
         models::SearchResult,
         models::SearchMode,
         models::ContentType,
         models::ContextScope,
+        models::ContextFormat,
         models::Chamber,
-        models::ContentDetailResponse
+        models::ContentDetailResponse,
+        models::RelatedItem,
+        models::AutocompleteKind,
+        models::AutocompleteResponse,
+        models::AutocompleteSuggestion,
+        models::CommitteeResponse,
+        models::CommitteeListResponse,
+        models::CommitteeHearingsResponse,
+        models::HearingSummary,
+        models::RelatedVotesResponse,
+        models::VoteDetailResponse,
+        models::PartyVoteCount,
+        models::RelatedVoteResult
     )),
     info(
         title = "PolSearch API",
@@ -75,6 +113,7 @@ async fn main() -> Result<()> {
         .and_then(|s| s.parse().ok())
         .unwrap_or(30);
     let search_timeout = Duration::from_secs(search_timeout_secs);
+    let hybrid_oversample = hybrid_oversample_from_env();
 
     // connect to PostgreSQL
     tracing::info!("Connecting to PostgreSQL...");
@@ -82,23 +121,37 @@ async fn main() -> Result<()> {
 
     // initialize embedding model
     tracing::info!("Loading embedding model...");
-    let embedder = TextEmbedder::new()?;
+    let embedder = TextEmbedder::from_env()?;
+    let embedding_model = embedder.model();
+    tracing::info!("Using embedding model {}", embedding_model);
 
     let state = Arc::new(AppState {
         db,
         embedder: Mutex::new(embedder),
+        embedding_model,
+        reranker: OnceCell::new(),
         lancedb_path,
         search_timeout,
+        hybrid_oversample,
+        metrics: SearchMetrics::default(),
+        committee_cache: routes::CommitteeCache::default(),
     });
 
     // build router with public and protected routes
     let public_routes = Router::new()
         .route("/health", get(routes::health))
+        .route("/livez", get(routes::livez))
+        .route("/metrics", get(routes::metrics))
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()));
 
     let protected_routes = Router::new()
         .route("/search", get(routes::search))
+        .route("/autocomplete", get(routes::autocomplete))
         .route("/content/{id}", get(routes::get_content))
+        .route("/committees", get(routes::list_committees))
+        .route("/committees/{id}/hearings", get(routes::get_committee_hearings))
+        .route("/votes/{id}", get(routes::get_vote))
+        .route("/votes/{id}/related", get(routes::get_related_votes))
         .layer(axum_mw::from_fn(middleware::require_auth));
 
     let app = Router::new()
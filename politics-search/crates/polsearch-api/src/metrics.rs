@@ -0,0 +1,84 @@
+//! In-process search metrics
+
+use polsearch_db::PoolHealth;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters for search requests, exposed via `/metrics`
+#[derive(Debug, Default)]
+pub struct SearchMetrics {
+    searches_total: AtomicU64,
+    fallbacks_total: AtomicU64,
+}
+
+impl SearchMetrics {
+    /// Record a completed search, noting whether the mode fell back
+    pub fn record_search(&self, fallback: bool) {
+        self.searches_total.fetch_add(1, Ordering::Relaxed);
+        if fallback {
+            self.fallbacks_total.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Render counters in Prometheus text exposition format
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let counters = [
+            (
+                "polsearch_searches_total",
+                "Total completed search requests",
+                &self.searches_total,
+            ),
+            (
+                "polsearch_search_fallbacks_total",
+                "Searches where the mode used differed from the requested mode",
+                &self.fallbacks_total,
+            ),
+        ];
+
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} counter");
+            let _ = writeln!(out, "{name} {}", value.load(Ordering::Relaxed));
+        }
+
+        out
+    }
+}
+
+/// Render database pool gauges in Prometheus text exposition format
+#[must_use]
+pub fn render_pool_health(health: &PoolHealth) -> String {
+    let mut out = String::new();
+    let gauges = [
+        (
+            "polsearch_db_up",
+            "Whether the database answered a SELECT 1 probe",
+            f64::from(u8::from(health.connected)),
+        ),
+        (
+            "polsearch_db_pool_connections",
+            "Open database connections, idle or in use",
+            f64::from(health.pool_size),
+        ),
+        (
+            "polsearch_db_pool_idle_connections",
+            "Open database connections not in use",
+            health.idle_connections as f64,
+        ),
+        (
+            "polsearch_db_probe_latency_seconds",
+            "Round-trip time of the last database probe",
+            health.latency.map_or(0.0, |d| d.as_secs_f64()),
+        ),
+    ];
+
+    for (name, help, value) in gauges {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        let _ = writeln!(out, "{name} {value}");
+    }
+
+    out
+}
@@ -1,5 +1,6 @@
 //! Request models for API endpoints
 
+use polsearch_core::ContentType as CoreContentType;
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 
@@ -12,7 +13,7 @@ pub enum SearchMode {
     Hybrid,
     /// Semantic similarity using embeddings only
     Vector,
-    /// Keyword-based full-text search
+    /// Keyword-based full-text search (supports `AND`, `OR`, and `-term`)
     Fts,
     /// Exact phrase matching
     Phrase,
@@ -25,18 +26,126 @@ pub enum ContentType {
     Hearing,
     FloorSpeech,
     Vote,
+    Bill,
     All,
 }
 
+impl ContentType {
+    /// The core content type this filter selects
+    #[must_use]
+    pub const fn as_core(&self) -> CoreContentType {
+        match self {
+            Self::Hearing => CoreContentType::Hearing,
+            Self::FloorSpeech => CoreContentType::FloorSpeech,
+            Self::Vote => CoreContentType::Vote,
+            Self::Bill => CoreContentType::Bill,
+            Self::All => CoreContentType::All,
+        }
+    }
+}
+
+impl From<CoreContentType> for ContentType {
+    fn from(content_type: CoreContentType) -> Self {
+        match content_type {
+            CoreContentType::Hearing => Self::Hearing,
+            CoreContentType::FloorSpeech => Self::FloorSpeech,
+            CoreContentType::Vote => Self::Vote,
+            CoreContentType::Bill => Self::Bill,
+            CoreContentType::All => Self::All,
+        }
+    }
+}
+
 /// Context scope for RAG mode
-#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase", try_from = "String")]
 pub enum ContextScope {
-    /// Only segments from the same content
+    /// Only segments from the same statement (single speaker)
+    Statement,
+    /// Neighboring segments across statements in the same content
+    #[default]
+    Document,
+}
+
+impl TryFrom<String> for ContextScope {
+    type Error = String;
+
+    /// Accepts the old `same` scope as `document`; the old `related` scope
+    /// (similar segments from other content) was never implemented
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "statement" => Ok(Self::Statement),
+            "document" | "same" => Ok(Self::Document),
+            "related" => Err(
+                "context_scope `related` is no longer supported; use `statement` or `document`"
+                    .to_string(),
+            ),
+            other => Err(format!(
+                "unknown context_scope `{other}`, expected `statement` or `document`"
+            )),
+        }
+    }
+}
+
+/// Layout of the assembled `context_text` in RAG mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ContextFormat {
+    /// One line per segment prefixed with `[Speaker]`
     #[default]
-    Same,
-    /// Include semantically similar segments from other content
-    Related,
+    Labeled,
+    /// Segment text only, joined with spaces
+    Plain,
+    /// Consecutive segments merged into `Speaker: text` turns, separated by blank lines
+    Turns,
+}
+
+/// One segment of a context window, in document order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextSegment {
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+impl ContextFormat {
+    /// Assemble context segments into a single text block
+    #[must_use]
+    pub fn render(self, segments: &[ContextSegment]) -> String {
+        match self {
+            Self::Labeled => segments
+                .iter()
+                .map(|s| {
+                    s.speaker
+                        .as_ref()
+                        .map_or_else(|| s.text.clone(), |speaker| format!("[{speaker}] {}", s.text))
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Plain => segments
+                .iter()
+                .map(|s| s.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+            Self::Turns => {
+                let mut turns: Vec<(Option<&str>, Vec<&str>)> = Vec::new();
+                for segment in segments {
+                    let speaker = segment.speaker.as_deref();
+                    match turns.last_mut() {
+                        Some((last, texts)) if *last == speaker => texts.push(&segment.text),
+                        _ => turns.push((speaker, vec![segment.text.as_str()])),
+                    }
+                }
+                turns
+                    .into_iter()
+                    .map(|(speaker, texts)| {
+                        let text = texts.join(" ");
+                        speaker.map_or_else(|| text.clone(), |s| format!("{s}: {text}"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            }
+        }
+    }
 }
 
 /// Chamber filter
@@ -49,7 +158,6 @@ pub enum Chamber {
 
 impl Chamber {
     #[must_use]
-    #[allow(dead_code)]
     pub const fn as_str(&self) -> &'static str {
         match self {
             Self::House => "house",
@@ -73,7 +181,7 @@ pub struct SearchParams {
     #[serde(default)]
     pub mode: SearchMode,
 
-    /// Content types to search (comma-separated: `hearing,floor_speech,vote,all`)
+    /// Content types to search (comma-separated: `hearing,floor_speech,vote,bill,all`)
     #[serde(default, rename = "type")]
     #[param(value_type = Option<String>)]
     pub content_type: Option<String>,
@@ -96,11 +204,14 @@ pub struct SearchParams {
     #[param(minimum = 0, maximum = 10)]
     pub context: usize,
 
-    /// Context scope (same content or related)
+    /// Context scope (`statement` or `document`)
     #[serde(default)]
-    #[allow(dead_code)]
     pub context_scope: ContextScope,
 
+    /// Layout of `context_text` (`labeled`, `plain`, or `turns`)
+    #[serde(default)]
+    pub context_format: ContextFormat,
+
     /// Filter by speaker name (fuzzy match)
     pub speaker: Option<String>,
 
@@ -110,18 +221,50 @@ pub struct SearchParams {
     /// Filter by chamber
     pub chamber: Option<Chamber>,
 
+    /// Floor speech page types to include (comma-separated: `house,senate,extensions,digest`);
+    /// limits results to floor speeches
+    pub page_type: Option<String>,
+
     /// Filter by congress number
     pub congress: Option<i16>,
 
-    /// Start date (YYYY-MM-DD or YYYY-MM)
+    /// Start of congress range, inclusive (overrides `congress`)
+    pub congress_from: Option<i16>,
+
+    /// End of congress range, inclusive (overrides `congress`)
+    pub congress_to: Option<i16>,
+
+    /// Start date (YYYY, YYYY-MM, or YYYY-MM-DD)
     pub from: Option<String>,
 
-    /// End date (YYYY-MM-DD or YYYY-MM)
+    /// End date (YYYY, YYYY-MM, or YYYY-MM-DD)
     pub to: Option<String>,
 
     /// Exclude witnesses from results (only return congressional speakers)
     #[serde(default)]
     pub exclude_witnesses: bool,
+
+    /// Rerank the top candidates with a cross-encoder (skipped if the model is unavailable)
+    #[serde(default)]
+    pub rerank: bool,
+
+    /// Drop results whose text has fewer than this many words (0 = disabled)
+    #[serde(default)]
+    pub min_words: usize,
+
+    /// FTS mode: rank results higher when their speaker is named in the query
+    #[serde(default)]
+    pub speaker_boost: bool,
+
+    /// Search only within a single content item (UUID, or `package_id` for hearings)
+    pub content_id: Option<String>,
+
+    /// Comma-separated result fields to return (omit for all). Available: `content_id`,
+    /// `content_id_str`, `segment_index`, `statement_id`, `text`, `start_time_ms`,
+    /// `end_time_ms`, `score`, `content_type`, `speaker_name`, `speaker_type`, `title`,
+    /// `date`, `source_url`, `committee`, `chamber`, `congress`, `context_before`,
+    /// `context_after`, `context_text`, `match_offsets`
+    pub fields: Option<String>,
 }
 
 impl SearchParams {
@@ -133,14 +276,159 @@ impl SearchParams {
             Some(s) if s.is_empty() => vec![ContentType::All],
             Some(s) => s
                 .split(',')
-                .filter_map(|t| match t.trim().to_lowercase().as_str() {
-                    "hearing" => Some(ContentType::Hearing),
-                    "floor_speech" => Some(ContentType::FloorSpeech),
-                    "vote" => Some(ContentType::Vote),
-                    "all" => Some(ContentType::All),
-                    _ => None,
+                .filter_map(|t| {
+                    CoreContentType::from_db_str(&t.trim().to_lowercase()).map(ContentType::from)
                 })
                 .collect(),
         }
     }
 }
+
+/// Content detail query parameters
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ContentDetailParams {
+    /// Include related content: other hearings from the same committee, or
+    /// floor speeches from the same date and chamber
+    #[serde(default)]
+    pub related: bool,
+}
+
+/// Committee list query parameters
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CommitteeListParams {
+    /// Filter by chamber (joint committees are excluded when set)
+    pub chamber: Option<Chamber>,
+}
+
+/// Committee hearings query parameters
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CommitteeHearingsParams {
+    /// Hearings per page (default: 10, max: 100)
+    #[serde(default = "default_limit")]
+    #[param(minimum = 1, maximum = 100)]
+    pub limit: usize,
+
+    /// Pagination offset
+    #[serde(default)]
+    pub offset: usize,
+}
+
+/// Related votes query parameters
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RelatedVotesParams {
+    /// Number of related votes to return (default: 10, max: 100)
+    #[serde(default = "default_limit")]
+    #[param(minimum = 1, maximum = 100)]
+    pub limit: usize,
+}
+
+/// Kind of name suggested by autocomplete
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AutocompleteKind {
+    /// Speaker names only
+    Speaker,
+    /// Committee names only
+    Committee,
+    /// Speakers and committees
+    #[default]
+    All,
+}
+
+impl AutocompleteKind {
+    /// Whether speaker suggestions are included
+    #[must_use]
+    pub const fn includes_speakers(self) -> bool {
+        matches!(self, Self::Speaker | Self::All)
+    }
+
+    /// Whether committee suggestions are included
+    #[must_use]
+    pub const fn includes_committees(self) -> bool {
+        matches!(self, Self::Committee | Self::All)
+    }
+}
+
+/// Autocomplete query parameters
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AutocompleteParams {
+    /// Partial name typed so far
+    pub q: String,
+
+    /// Which names to suggest (default: all)
+    #[serde(default)]
+    pub kind: AutocompleteKind,
+
+    /// Maximum suggestions to return (default: 10, max: 25)
+    #[serde(default = "default_limit")]
+    #[param(minimum = 1, maximum = 25)]
+    pub limit: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window() -> Vec<ContextSegment> {
+        let segment = |speaker: &str, text: &str| ContextSegment {
+            speaker: Some(speaker.to_string()),
+            text: text.to_string(),
+        };
+        vec![
+            segment("Chair", "The committee will come to order."),
+            segment("Chair", "We welcome our witness."),
+            segment("Dr. Smith", "Thank you for having me."),
+        ]
+    }
+
+    #[test]
+    fn test_render_labeled() {
+        assert_eq!(
+            ContextFormat::Labeled.render(&window()),
+            "[Chair] The committee will come to order.\n\
+             [Chair] We welcome our witness.\n\
+             [Dr. Smith] Thank you for having me."
+        );
+    }
+
+    #[test]
+    fn test_render_plain() {
+        assert_eq!(
+            ContextFormat::Plain.render(&window()),
+            "The committee will come to order. We welcome our witness. Thank you for having me."
+        );
+    }
+
+    #[test]
+    fn test_render_turns() {
+        assert_eq!(
+            ContextFormat::Turns.render(&window()),
+            "Chair: The committee will come to order. We welcome our witness.\n\n\
+             Dr. Smith: Thank you for having me."
+        );
+    }
+
+    #[test]
+    fn test_context_scope_values() {
+        let parse = |value: &str| serde_json::from_value::<ContextScope>(value.into());
+        assert_eq!(parse("statement").ok(), Some(ContextScope::Statement));
+        assert_eq!(parse("document").ok(), Some(ContextScope::Document));
+        assert_eq!(parse("same").ok(), Some(ContextScope::Document));
+
+        let err = parse("related").expect_err("related is rejected");
+        assert!(err.to_string().contains("`related` is no longer supported"));
+        assert!(parse("nearby").is_err());
+    }
+
+    #[test]
+    fn test_render_unknown_speaker_unlabeled() {
+        let segments = [ContextSegment { speaker: None, text: "Inaudible.".to_string() }];
+        assert_eq!(ContextFormat::Labeled.render(&segments), "Inaudible.");
+        assert_eq!(ContextFormat::Turns.render(&segments), "Inaudible.");
+    }
+}
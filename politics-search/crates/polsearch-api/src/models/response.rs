@@ -1,13 +1,16 @@
 //! Response models for API endpoints
 
+use polsearch_db::PoolHealth;
 use serde::Serialize;
-use utoipa::ToSchema;
+use std::sync::LazyLock;
+use utoipa::openapi::{RefOr, Schema};
+use utoipa::{PartialSchema, ToSchema};
 use uuid::Uuid;
 
 /// Individual search result
 #[derive(Debug, Serialize, ToSchema)]
 pub struct SearchResult {
-    /// Content ID (hearing, floor speech, or vote ID)
+    /// Content ID (hearing, floor speech, vote, or bill ID)
     pub content_id: Uuid,
 
     /// Original content ID string (for FTS results using `package_id/event_id`)
@@ -17,6 +20,10 @@ pub struct SearchResult {
     /// Segment index within the content
     pub segment_index: i32,
 
+    /// Statement ID the segment belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_id: Option<String>,
+
     /// The matching text segment
     pub text: String,
 
@@ -29,7 +36,11 @@ pub struct SearchResult {
     /// Normalized relevance score (0-1, higher is better)
     pub score: f32,
 
-    /// Content type (hearing, `floor_speech`, vote)
+    /// Cross-encoder score when the results were reranked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
+
+    /// Content type (hearing, `floor_speech`, vote, bill)
     pub content_type: String,
 
     /// Speaker name if available
@@ -60,7 +71,7 @@ pub struct SearchResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chamber: Option<String>,
 
-    /// Congress number (hearings only, enriched)
+    /// Congress number (hearings and bills, enriched)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub congress: Option<i16>,
 
@@ -71,6 +82,30 @@ pub struct SearchResult {
     /// Context segments after this result
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub context_after: Vec<String>,
+
+    /// Byte ranges `[start, end)` of matched query terms within `text`, for highlighting
+    /// (keyword-based modes only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<Vec<usize>>)]
+    pub match_offsets: Vec<(usize, usize)>,
+
+    /// Context before, this result, and context after assembled per `context_format`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_text: Option<String>,
+}
+
+impl SearchResult {
+    /// Serialized field names, accepted by the `fields` projection
+    ///
+    /// Read from the `OpenAPI` schema, which follows the serde representation.
+    pub fn field_names() -> &'static [String] {
+        static NAMES: LazyLock<Vec<String>> =
+            LazyLock::new(|| match <SearchResult as PartialSchema>::schema() {
+                RefOr::T(Schema::Object(object)) => object.properties.into_keys().collect(),
+                _ => Vec::new(),
+            });
+        &NAMES
+    }
 }
 
 /// Search response
@@ -102,7 +137,34 @@ pub struct SearchResponse {
 /// Health check response
 #[derive(Debug, Serialize, ToSchema)]
 pub struct HealthResponse {
+    /// "ok" when the database is reachable, "degraded" otherwise
     pub status: &'static str,
+    pub database: DatabaseHealth,
+}
+
+/// Database connectivity and connection pool state
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DatabaseHealth {
+    /// Whether a `SELECT 1` probe succeeded
+    pub connected: bool,
+    /// Open connections, idle or in use
+    pub pool_size: u32,
+    /// Open connections not currently in use
+    pub idle_connections: usize,
+    /// Probe round-trip time in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<f64>,
+}
+
+impl From<PoolHealth> for DatabaseHealth {
+    fn from(health: PoolHealth) -> Self {
+        Self {
+            connected: health.connected,
+            pool_size: health.pool_size,
+            idle_connections: health.idle_connections,
+            latency_ms: health.latency.map(|d| d.as_secs_f64() * 1000.0),
+        }
+    }
 }
 
 /// Content detail response for a single hearing, floor speech, or vote
@@ -166,6 +228,26 @@ pub struct ContentDetailResponse {
     /// Vote counts (votes only)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vote_counts: Option<VoteCounts>,
+
+    /// Related hearings or floor speeches (only with `related=true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related: Option<Vec<RelatedItem>>,
+}
+
+/// Content related to a hearing or floor speech, for "see also" navigation
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelatedItem {
+    /// Content ID
+    pub id: Uuid,
+
+    /// Content type (`"hearing"` or `"floor_speech"`)
+    pub content_type: String,
+
+    /// Content title
+    pub title: String,
+
+    /// Content date (YYYY-MM-DD format)
+    pub date: String,
 }
 
 /// Vote count breakdown
@@ -176,3 +258,271 @@ pub struct VoteCounts {
     pub present: i32,
     pub not_voting: i32,
 }
+
+/// Autocomplete response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AutocompleteResponse {
+    /// Query as received
+    pub query: String,
+
+    /// Suggested names, best match first
+    pub suggestions: Vec<AutocompleteSuggestion>,
+}
+
+/// Single autocomplete suggestion
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AutocompleteSuggestion {
+    /// Speaker or committee ID
+    pub id: Uuid,
+
+    /// Display name
+    pub text: String,
+
+    /// "speaker" or "committee"
+    pub kind: String,
+
+    /// Appearances for speakers, hearings for committees
+    pub count: i64,
+}
+
+/// Committee with its hearing count
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeResponse {
+    /// Committee ID
+    pub id: Uuid,
+
+    /// Full committee name
+    pub name: String,
+
+    /// Normalized committee slug
+    pub slug: String,
+
+    /// Chamber: "House", "Senate", or omitted for joint committees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chamber: Option<String>,
+
+    /// Number of hearings held by this committee
+    pub hearing_count: i64,
+}
+
+/// Committee list response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeListResponse {
+    /// Committees, most hearings first
+    pub committees: Vec<CommitteeResponse>,
+
+    /// Number of committees returned
+    pub total_returned: usize,
+}
+
+/// Hearing summary for committee browsing
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HearingSummary {
+    /// Hearing ID
+    pub id: Uuid,
+
+    /// `GovInfo` package ID
+    pub package_id: String,
+
+    /// Hearing title
+    pub title: String,
+
+    /// Hearing date (YYYY-MM-DD format)
+    pub date: String,
+
+    /// Congress number
+    pub congress: i16,
+
+    /// Chamber(s): "House", "Senate", or "House, Senate" for joint hearings
+    pub chambers: String,
+
+    /// Source URL to the original document on `GovInfo`
+    pub source_url: String,
+
+    /// Total number of searchable segments
+    pub total_segments: i32,
+}
+
+/// Paginated hearings for a committee
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeHearingsResponse {
+    /// The committee
+    pub committee: CommitteeResponse,
+
+    /// Hearings, newest first
+    pub hearings: Vec<HearingSummary>,
+
+    /// Number of hearings returned
+    pub total_returned: usize,
+
+    /// Whether more hearings are available
+    pub has_more: bool,
+
+    /// Offset for next page (if `has_more` is true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<usize>,
+}
+
+/// A vote similar to the requested vote
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelatedVoteResult {
+    /// Roll call vote ID
+    pub id: Uuid,
+
+    /// Vote identifier (e.g., "h1-116.2019")
+    pub vote_id: String,
+
+    /// What was voted on
+    pub question: String,
+
+    /// Vote date (YYYY-MM-DD format)
+    pub date: String,
+
+    /// Chamber: "House" or "Senate"
+    pub chamber: String,
+
+    /// Congress number
+    pub congress: i16,
+
+    /// Vote result (e.g., "Passed", "Failed")
+    pub result: String,
+
+    /// Vector distance from the requested vote (lower is more similar)
+    pub distance: f32,
+}
+
+/// Votes related to a given vote by embedding similarity
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelatedVotesResponse {
+    /// The requested vote
+    pub vote_id: Uuid,
+
+    /// Related votes, most similar first
+    pub related: Vec<RelatedVoteResult>,
+
+    /// Number of related votes returned
+    pub total_returned: usize,
+}
+
+/// Individual vote count for one party and position
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PartyVoteCount {
+    /// Party at the time of the vote (e.g., "D", "R", "I")
+    pub party: String,
+
+    /// Vote position (e.g., "Yea", "Nay", "Not Voting")
+    pub position: String,
+
+    /// Number of members of the party who voted this way
+    pub count: i64,
+}
+
+/// Roll call vote with its tallies
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VoteDetailResponse {
+    /// Roll call vote ID
+    pub id: Uuid,
+
+    /// Vote identifier (e.g., "h1-116.2019")
+    pub vote_id: String,
+
+    /// What was voted on
+    pub question: String,
+
+    /// Vote date (YYYY-MM-DD format)
+    pub date: String,
+
+    /// Chamber: "House" or "Senate"
+    pub chamber: String,
+
+    /// Congress number
+    pub congress: i16,
+
+    /// Congressional session
+    pub session: String,
+
+    /// Roll call number within the session
+    pub vote_number: i32,
+
+    /// Vote type (e.g., "On Passage")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vote_type: Option<String>,
+
+    /// Vote category (e.g., "passage", "nomination")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Vote subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+
+    /// Vote result (e.g., "Passed", "Failed")
+    pub result: String,
+
+    /// Full result text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_text: Option<String>,
+
+    /// Required majority (e.g., "1/2", "3/5")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires: Option<String>,
+
+    /// Source URL to the official vote record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+
+    /// Overall vote tallies
+    pub vote_counts: VoteCounts,
+
+    /// Individual vote counts by party and position
+    pub party_breakdown: Vec<PartyVoteCount>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_names_match_serialized_keys() {
+        // every optional field set, so none is skipped when serializing
+        let result = SearchResult {
+            content_id: Uuid::nil(),
+            content_id_str: "CHRG-118hhrg12345".into(),
+            segment_index: 0,
+            statement_id: Some("statement".into()),
+            text: "text".into(),
+            start_time_ms: 0,
+            end_time_ms: 0,
+            score: 1.0,
+            rerank_score: Some(1.0),
+            content_type: "hearing".into(),
+            speaker_name: Some("speaker".into()),
+            speaker_type: Some("senator".into()),
+            title: Some("title".into()),
+            date: Some("2024-01-01".into()),
+            source_url: Some("https://example.com".into()),
+            committee: Some("committee".into()),
+            chamber: Some("Senate".into()),
+            congress: Some(118),
+            context_before: vec!["before".into()],
+            context_after: vec!["after".into()],
+            match_offsets: vec![(0, 4)],
+            context_text: Some("context".into()),
+        };
+        let value = serde_json::to_value(&result).expect("serialize");
+        let mut keys: Vec<&str> = value
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+
+        let mut names: Vec<&str> = SearchResult::field_names()
+            .iter()
+            .map(String::as_str)
+            .collect();
+        names.sort_unstable();
+        assert_eq!(names, keys);
+    }
+}
@@ -0,0 +1,175 @@
+//! Typeahead suggestions for speaker and committee names
+
+use axum::extract::{Query, State};
+use axum::Json;
+use polsearch_core::Committee;
+use polsearch_db::{Database, DbError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use crate::error::ApiError;
+use crate::models::{AutocompleteParams, AutocompleteResponse, AutocompleteSuggestion};
+use crate::AppState;
+
+/// How long the committee list is reused before it is reloaded
+const COMMITTEE_CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// Upper bound on `limit`
+const MAX_SUGGESTIONS: usize = 25;
+
+type CachedCommittees = (Instant, Arc<Vec<(Committee, i64)>>);
+
+/// Committees with hearing counts, reloaded at most every [`COMMITTEE_CACHE_TTL`]
+///
+/// There are only a few hundred committees and they change with ingestion runs,
+/// so autocomplete matches against an in-memory copy instead of querying per keystroke.
+#[derive(Default)]
+pub struct CommitteeCache {
+    entries: RwLock<Option<CachedCommittees>>,
+}
+
+impl CommitteeCache {
+    /// Return the cached committee list, reloading it if missing or stale
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if reloading the list fails
+    pub async fn get(&self, db: &Database) -> Result<Arc<Vec<(Committee, i64)>>, DbError> {
+        if let Some(committees) = fresh(self.entries.read().await.as_ref()) {
+            return Ok(committees);
+        }
+
+        let mut entries = self.entries.write().await;
+        // another request may have reloaded while we waited for the lock
+        if let Some(committees) = fresh(entries.as_ref()) {
+            return Ok(committees);
+        }
+
+        let committees = Arc::new(db.committees().get_with_counts().await?);
+        *entries = Some((Instant::now(), Arc::clone(&committees)));
+        Ok(committees)
+    }
+}
+
+/// The cached list if it was loaded within the TTL
+fn fresh(entry: Option<&CachedCommittees>) -> Option<Arc<Vec<(Committee, i64)>>> {
+    entry
+        .filter(|(loaded_at, _)| loaded_at.elapsed() < COMMITTEE_CACHE_TTL)
+        .map(|(_, committees)| Arc::clone(committees))
+}
+
+/// How well a name matches the query (lower is better)
+///
+/// 0 = name starts with the query, 1 = a later word starts with it,
+/// 2 = it appears mid-word, `None` = no match.
+fn match_rank(name: &str, query: &str) -> Option<u8> {
+    let name = name.to_lowercase();
+    if name.starts_with(query) {
+        Some(0)
+    } else if name
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| word.starts_with(query))
+    {
+        Some(1)
+    } else if name.contains(query) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Autocomplete speaker and committee names
+///
+/// Returns names matching the partial query, best match first. Speakers match
+/// by name prefix; committees also match on later words and substrings
+/// (e.g. "judic" finds "Committee on the Judiciary"). Ties are broken by
+/// appearance or hearing count.
+#[utoipa::path(
+    get,
+    path = "/autocomplete",
+    params(AutocompleteParams),
+    responses(
+        (status = 200, description = "Name suggestions", body = AutocompleteResponse),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn autocomplete(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<AutocompleteParams>,
+) -> Result<Json<AutocompleteResponse>, ApiError> {
+    let query = params.q.trim().to_lowercase();
+    if query.is_empty() {
+        return Ok(Json(AutocompleteResponse {
+            query: params.q,
+            suggestions: Vec::new(),
+        }));
+    }
+    let limit = params.limit.clamp(1, MAX_SUGGESTIONS);
+
+    // (rank, suggestion) pairs merged across kinds
+    let mut ranked: Vec<(u8, AutocompleteSuggestion)> = Vec::new();
+
+    if params.kind.includes_speakers() {
+        let speakers = state.db.speakers().autocomplete(&query, limit as i64).await?;
+        ranked.extend(speakers.into_iter().filter_map(|speaker| {
+            speaker.name.map(|name| {
+                (
+                    0,
+                    AutocompleteSuggestion {
+                        id: speaker.id,
+                        text: name,
+                        kind: "speaker".to_string(),
+                        count: i64::from(speaker.total_appearances),
+                    },
+                )
+            })
+        }));
+    }
+
+    if params.kind.includes_committees() {
+        let committees = state.committee_cache.get(&state.db).await?;
+        ranked.extend(committees.iter().filter_map(|(committee, count)| {
+            match_rank(&committee.name, &query).map(|rank| {
+                (
+                    rank,
+                    AutocompleteSuggestion {
+                        id: committee.id,
+                        text: committee.name.clone(),
+                        kind: "committee".to_string(),
+                        count: *count,
+                    },
+                )
+            })
+        }));
+    }
+
+    ranked.sort_by(|(a_rank, a), (b_rank, b)| {
+        a_rank
+            .cmp(b_rank)
+            .then(b.count.cmp(&a.count))
+            .then_with(|| a.text.cmp(&b.text))
+    });
+
+    Ok(Json(AutocompleteResponse {
+        query: params.q,
+        suggestions: ranked
+            .into_iter()
+            .take(limit)
+            .map(|(_, suggestion)| suggestion)
+            .collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_rank() {
+        assert_eq!(match_rank("Judiciary Committee", "judic"), Some(0));
+        assert_eq!(match_rank("Committee on the Judiciary", "judic"), Some(1));
+        assert_eq!(match_rank("Committee on Appropriations", "propri"), Some(2));
+        assert_eq!(match_rank("Committee on Appropriations", "judic"), None);
+    }
+}
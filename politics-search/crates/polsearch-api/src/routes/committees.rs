@@ -0,0 +1,131 @@
+//! Committee browsing endpoints
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use polsearch_core::{Committee, Hearing};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::models::{
+    CommitteeHearingsParams, CommitteeHearingsResponse, CommitteeListParams,
+    CommitteeListResponse, CommitteeResponse, HearingSummary,
+};
+use crate::AppState;
+
+fn committee_response(committee: Committee, hearing_count: i64) -> CommitteeResponse {
+    CommitteeResponse {
+        id: committee.id,
+        name: committee.name,
+        slug: committee.slug,
+        chamber: committee.chamber,
+        hearing_count,
+    }
+}
+
+fn hearing_summary(hearing: Hearing) -> HearingSummary {
+    HearingSummary {
+        id: hearing.id,
+        package_id: hearing.package_id,
+        title: hearing.title,
+        date: hearing.hearing_date.format("%Y-%m-%d").to_string(),
+        congress: hearing.congress,
+        chambers: hearing.chambers.join(", "),
+        source_url: hearing.source_url,
+        total_segments: hearing.total_segments,
+    }
+}
+
+/// List committees
+///
+/// Returns all committees with hearing counts, optionally filtered by chamber.
+#[utoipa::path(
+    get,
+    path = "/committees",
+    params(CommitteeListParams),
+    responses(
+        (status = 200, description = "Committees with hearing counts", body = CommitteeListResponse),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_committees(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<CommitteeListParams>,
+) -> Result<Json<CommitteeListResponse>, ApiError> {
+    // single aggregation query for counts, filtered in memory like the CLI
+    let committees: Vec<CommitteeResponse> = state
+        .db
+        .committees()
+        .get_with_counts()
+        .await?
+        .into_iter()
+        .filter(|(committee, _)| {
+            params.chamber.as_ref().is_none_or(|chamber| {
+                committee
+                    .chamber
+                    .as_ref()
+                    .is_some_and(|c| c.eq_ignore_ascii_case(chamber.as_str()))
+            })
+        })
+        .map(|(committee, count)| committee_response(committee, count))
+        .collect();
+
+    Ok(Json(CommitteeListResponse {
+        total_returned: committees.len(),
+        committees,
+    }))
+}
+
+/// List hearings for a committee
+///
+/// Returns a page of the committee's hearings, newest first.
+#[utoipa::path(
+    get,
+    path = "/committees/{id}/hearings",
+    params(
+        ("id" = Uuid, Path, description = "Committee ID (UUID)"),
+        CommitteeHearingsParams
+    ),
+    responses(
+        (status = 200, description = "Committee hearings", body = CommitteeHearingsResponse),
+        (status = 404, description = "Committee not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_committee_hearings(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<CommitteeHearingsParams>,
+) -> Result<Json<CommitteeHearingsResponse>, ApiError> {
+    let committee = state
+        .db
+        .committees()
+        .get_by_id(id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("Committee with ID {} not found", id),
+        })?;
+
+    let limit = params.limit.clamp(1, 100);
+    let offset = params.offset;
+
+    let hearings_repo = state.db.hearings();
+    let total = hearings_repo.count_by_committee_slug(&committee.slug).await?;
+    let hearings: Vec<HearingSummary> = hearings_repo
+        .get_by_committee_slug(&committee.slug, limit as i64, offset as i64)
+        .await?
+        .into_iter()
+        .map(hearing_summary)
+        .collect();
+
+    let next = offset + hearings.len();
+    let has_more = (next as i64) < total;
+
+    Ok(Json(CommitteeHearingsResponse {
+        committee: committee_response(committee, total),
+        total_returned: hearings.len(),
+        hearings,
+        has_more,
+        next_offset: has_more.then_some(next),
+    }))
+}
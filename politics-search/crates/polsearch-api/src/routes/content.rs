@@ -1,22 +1,30 @@
 //! Content detail endpoint
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
+use polsearch_core::ContentType as CoreContentType;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::error::ApiError;
-use crate::models::{ContentDetailResponse, VoteCounts};
+use crate::models::{ContentDetailParams, ContentDetailResponse, RelatedItem, VoteCounts};
 use crate::AppState;
 
+/// Related items returned with `related=true`
+const RELATED_LIMIT: i64 = 10;
+
 /// Get content details by ID
 ///
-/// Returns full metadata for a hearing, floor speech, or vote by its ID.
+/// Returns full metadata for a hearing, floor speech, or vote by its ID. With
+/// `related=true`, hearings include other hearings from the same committee
+/// (closest in date first) and floor speeches include other speeches from the
+/// same date and chamber.
 #[utoipa::path(
     get,
     path = "/content/{id}",
     params(
-        ("id" = Uuid, Path, description = "Content ID (UUID)")
+        ("id" = Uuid, Path, description = "Content ID (UUID)"),
+        ContentDetailParams
     ),
     responses(
         (status = 200, description = "Content details", body = ContentDetailResponse),
@@ -27,14 +35,31 @@ use crate::AppState;
 pub async fn get_content(
     State(state): State<Arc<AppState>>,
     Path(id): Path<Uuid>,
+    Query(params): Query<ContentDetailParams>,
 ) -> Result<Json<ContentDetailResponse>, ApiError> {
     // try to find as hearing first
     if let Some(hearing) = state.db.hearings().get_by_id(id).await? {
         let chambers_str = hearing.chambers.join(", ");
+        let related = if params.related {
+            let hearings = state.db.hearings().get_related(id, RELATED_LIMIT).await?;
+            Some(
+                hearings
+                    .into_iter()
+                    .map(|h| RelatedItem {
+                        id: h.id,
+                        content_type: CoreContentType::Hearing.to_string(),
+                        title: h.title,
+                        date: h.hearing_date.format("%Y-%m-%d").to_string(),
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
 
         return Ok(Json(ContentDetailResponse {
             id: hearing.id,
-            content_type: "hearing".to_string(),
+            content_type: CoreContentType::Hearing.to_string(),
             title: hearing.title,
             date: Some(hearing.hearing_date.format("%Y-%m-%d").to_string()),
             source_url: Some(hearing.source_url),
@@ -49,14 +74,32 @@ pub async fn get_content(
             vote_type: None,
             category: None,
             vote_counts: None,
+            related,
         }));
     }
 
     // try to find as floor speech
     if let Some(speech) = state.db.floor_speeches().get_by_id(id).await? {
+        let related = if params.related {
+            let speeches = state.db.floor_speeches().get_related(id, RELATED_LIMIT).await?;
+            Some(
+                speeches
+                    .into_iter()
+                    .map(|s| RelatedItem {
+                        id: s.id,
+                        content_type: CoreContentType::FloorSpeech.to_string(),
+                        title: s.title,
+                        date: s.speech_date.format("%Y-%m-%d").to_string(),
+                    })
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
         return Ok(Json(ContentDetailResponse {
             id: speech.id,
-            content_type: "floor_speech".to_string(),
+            content_type: CoreContentType::FloorSpeech.to_string(),
             title: speech.title,
             date: Some(speech.speech_date.format("%Y-%m-%d").to_string()),
             source_url: Some(speech.source_url),
@@ -71,6 +114,7 @@ pub async fn get_content(
             vote_type: None,
             category: None,
             vote_counts: None,
+            related,
         }));
     }
 
@@ -78,7 +122,7 @@ pub async fn get_content(
     if let Some(vote) = state.db.roll_call_votes().get_by_id(id).await? {
         return Ok(Json(ContentDetailResponse {
             id: vote.id,
-            content_type: "vote".to_string(),
+            content_type: CoreContentType::Vote.to_string(),
             title: vote.question,
             date: Some(vote.vote_date.format("%Y-%m-%d").to_string()),
             source_url: vote.source_url,
@@ -98,6 +142,7 @@ pub async fn get_content(
                 present: vote.present_count,
                 not_voting: vote.not_voting_count,
             }),
+            related: None,
         }));
     }
 
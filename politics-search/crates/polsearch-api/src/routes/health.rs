@@ -1,16 +1,52 @@
 //! Health check endpoint
 
+use axum::extract::State;
+use axum::http::StatusCode;
 use axum::Json;
+use std::sync::Arc;
+
 use crate::models::HealthResponse;
+use crate::AppState;
 
 /// Health check endpoint
+///
+/// Probes the database, so a 503 means the API is up but can't serve searches.
 #[utoipa::path(
     get,
     path = "/health",
     responses(
-        (status = 200, description = "Service is healthy", body = HealthResponse)
+        (status = 200, description = "Service is healthy", body = HealthResponse),
+        (status = 503, description = "Database is unreachable", body = HealthResponse)
+    )
+)]
+pub async fn health(State(state): State<Arc<AppState>>) -> (StatusCode, Json<HealthResponse>) {
+    let database = state.db.health().await;
+    let (status_code, status) = if database.connected {
+        (StatusCode::OK, "ok")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "degraded")
+    };
+
+    (
+        status_code,
+        Json(HealthResponse {
+            status,
+            database: database.into(),
+        }),
+    )
+}
+
+/// Liveness endpoint
+///
+/// Always 200 while the process is serving requests. Doesn't touch the database,
+/// so a database outage doesn't get the pod restarted.
+#[utoipa::path(
+    get,
+    path = "/livez",
+    responses(
+        (status = 200, description = "Process is alive", body = String, content_type = "text/plain")
     )
 )]
-pub async fn health() -> Json<HealthResponse> {
-    Json(HealthResponse { status: "ok" })
+pub async fn livez() -> &'static str {
+    "ok"
 }
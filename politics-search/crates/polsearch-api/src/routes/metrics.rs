@@ -0,0 +1,28 @@
+//! Metrics endpoint
+
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use std::sync::Arc;
+
+use crate::metrics::render_pool_health;
+use crate::AppState;
+
+/// Search and database pool metrics in Prometheus text format
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    responses(
+        (
+            status = 200,
+            description = "Search counters and database pool gauges",
+            body = String,
+            content_type = "text/plain"
+        )
+    )
+)]
+pub async fn metrics(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let mut body = state.metrics.render();
+    body.push_str(&render_pool_health(&state.db.health().await));
+    ([(CONTENT_TYPE, "text/plain; version=0.0.4")], body)
+}
@@ -1,10 +1,29 @@
+mod autocomplete;
+mod committees;
 mod content;
 mod health;
+mod metrics;
 mod search;
+mod votes;
 
+pub use autocomplete::autocomplete;
+pub use autocomplete::__path_autocomplete;
+pub use autocomplete::CommitteeCache;
+pub use committees::get_committee_hearings;
+pub use committees::__path_get_committee_hearings;
+pub use committees::list_committees;
+pub use committees::__path_list_committees;
 pub use content::get_content;
 pub use content::__path_get_content;
 pub use health::health;
 pub use health::__path_health;
+pub use health::livez;
+pub use health::__path_livez;
+pub use metrics::metrics;
+pub use metrics::__path_metrics;
 pub use search::search;
 pub use search::__path_search;
+pub use votes::get_vote;
+pub use votes::__path_get_vote;
+pub use votes::get_related_votes;
+pub use votes::__path_get_related_votes;
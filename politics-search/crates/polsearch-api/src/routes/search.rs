@@ -2,47 +2,46 @@
 
 use arrow_array::{Array, RecordBatch};
 use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
 use futures::TryStreamExt;
 use lancedb::index::scalar::FullTextSearchQuery;
 use lancedb::query::{ExecutableQuery, QueryBase};
-use lancedb::Error as LanceError;
-use polsearch_core::SpeakerType;
-use polsearch_db::Database;
-use polsearch_pipeline::stages::FTS_TABLE_NAME;
+use polsearch_core::{ContentType as CoreContentType, PageType, SpeakerType};
+use polsearch_db::{fetch_all_speakers, Database, SegmentSpeakerLookup};
+use polsearch_pipeline::stages::{
+    apply_ranking, content_id_aliases, content_id_filter, count_indexed_segments,
+    has_speaker_names, hybrid_window, is_missing_fts_index_error, merge_with_rrf, phrase_search,
+    text_fts_query, verify_embedding_model, EmbeddingModel, TextReranker,
+    DEFAULT_RERANK_CANDIDATES, FTS_TABLE_NAME, MAX_FILTER_CONTENT_IDS,
+};
+use polsearch_util::{
+    boost_speaker_matches, parse_date_bound, retain_text_matches, BooleanQuery,
+    BOOLEAN_FTS_OVERFETCH, MIN_WORDS_OVERFETCH, SPEAKER_BOOST_OVERFETCH,
+};
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 use uuid::Uuid;
 
 use crate::error::ApiError;
 use crate::models::{
-    Chamber, ContentType, SearchMode as RequestMode, SearchParams, SearchResponse, SearchResult,
+    Chamber, ContentType, ContextFormat, ContextScope, ContextSegment, SearchMode as RequestMode,
+    SearchParams, SearchResponse, SearchResult,
 };
 use crate::AppState;
 
-/// RRF constant (standard value)
-const RRF_K: usize = 60;
-
-/// Compute Reciprocal Rank Fusion score
-fn rrf_score(rank: usize) -> f32 {
-    1.0 / (RRF_K + rank + 1) as f32
-}
-
-/// Check if a `LanceDB` error is due to a missing FTS inverted index
-fn is_missing_fts_index_error(e: &LanceError) -> bool {
-    let msg = e.to_string();
-    msg.contains("INVERTED index") || msg.contains("full text search")
-}
-
 /// Raw search result from `LanceDB`
 struct RawSearchResult {
     content_id: Uuid,
     content_id_str: String,
     segment_index: i32,
+    statement_id: Option<String>,
     text: String,
     start_time_ms: i32,
     end_time_ms: i32,
     score: f32,
+    rerank_score: Option<f32>,
     content_type: String,
     speaker_name: Option<String>,
     title: Option<String>,
@@ -87,12 +86,8 @@ fn build_content_type_filter(types: &[ContentType]) -> Option<String> {
 
     let type_values: Vec<&str> = types
         .iter()
-        .filter_map(|t| match t {
-            ContentType::All => None,
-            ContentType::Hearing => Some("hearing"),
-            ContentType::FloorSpeech => Some("floor_speech"),
-            ContentType::Vote => Some("vote"),
-        })
+        .filter(|t| !matches!(t, ContentType::All))
+        .map(|t| t.as_core().as_str())
         .collect();
 
     if type_values.is_empty() {
@@ -108,24 +103,94 @@ fn build_content_type_filter(types: &[ContentType]) -> Option<String> {
 /// Filter parameters for `PostgreSQL` pre-filtering
 struct FilterParams<'a> {
     chamber: Option<&'a Chamber>,
+    page_types: Vec<PageType>,
     committee: Option<&'a str>,
-    congress: Option<i16>,
-    from_date: Option<&'a str>,
-    to_date: Option<&'a str>,
+    congress_min: Option<i16>,
+    congress_max: Option<i16>,
+    from_date: Option<String>,
+    to_date: Option<String>,
     speaker: Option<&'a str>,
 }
 
 impl<'a> FilterParams<'a> {
-    const fn has_pg_filters(&self) -> bool {
+    fn from_params(params: &'a SearchParams) -> Result<Self, ApiError> {
+        let (congress_min, congress_max) = resolve_congress_range(params)?;
+        Ok(Self {
+            chamber: params.chamber.as_ref(),
+            page_types: resolve_page_types(params.page_type.as_deref())?,
+            committee: params.committee.as_deref(),
+            congress_min,
+            congress_max,
+            from_date: resolve_date_bound(params.from.as_deref(), true, "from")?,
+            to_date: resolve_date_bound(params.to.as_deref(), false, "to")?,
+            speaker: params.speaker.as_deref(),
+        })
+    }
+
+    fn has_pg_filters(&self) -> bool {
         self.chamber.is_some()
+            || !self.page_types.is_empty()
             || self.committee.is_some()
-            || self.congress.is_some()
+            || self.congress_min.is_some()
+            || self.congress_max.is_some()
             || self.from_date.is_some()
             || self.to_date.is_some()
             || self.speaker.is_some()
     }
 }
 
+/// Resolve the congress range, preferring `congress_from`/`congress_to` over `congress`
+fn resolve_congress_range(params: &SearchParams) -> Result<(Option<i16>, Option<i16>), ApiError> {
+    let (from, to) = (params.congress_from, params.congress_to);
+    polsearch_util::resolve_congress_range(params.congress, from, to).ok_or_else(|| {
+        ApiError::Validation {
+            message: format!(
+                "congress_from ({}) must not be greater than congress_to ({})",
+                from.unwrap_or_default(),
+                to.unwrap_or_default()
+            ),
+            field: Some("congress_from".into()),
+        }
+    })
+}
+
+/// Parse the comma-separated `page_type` param, rejecting unknown page types
+fn resolve_page_types(value: Option<&str>) -> Result<Vec<PageType>, ApiError> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            PageType::parse(p).ok_or_else(|| ApiError::Validation {
+                message: format!(
+                    "Unknown page_type '{p}'. Available page types: house, senate, extensions, digest"
+                ),
+                field: Some("page_type".into()),
+            })
+        })
+        .collect()
+}
+
+/// Normalize a `from`/`to` query param to a "YYYY-MM" bound
+fn resolve_date_bound(
+    value: Option<&str>,
+    is_start: bool,
+    field: &str,
+) -> Result<Option<String>, ApiError> {
+    value
+        .map(|v| {
+            parse_date_bound(v, is_start).ok_or_else(|| ApiError::Validation {
+                message: format!("Invalid date '{v}': expected YYYY, YYYY-MM, or YYYY-MM-DD"),
+                field: Some(field.into()),
+            })
+        })
+        .transpose()
+}
+
 /// Get filtered content IDs from `PostgreSQL` based on filter params
 async fn get_filtered_content_ids(
     db: &Database,
@@ -141,12 +206,16 @@ async fn get_filtered_content_ids(
         Chamber::Senate => "Senate",
     });
 
-    let includes_hearings = content_types.iter().any(|t| matches!(t, ContentType::All | ContentType::Hearing));
+    // page types only exist for floor speeches, so filtering on them excludes hearings
+    let includes_hearings = filters.page_types.is_empty()
+        && content_types.iter().any(|t| matches!(t, ContentType::All | ContentType::Hearing));
     let includes_floor_speeches = content_types.iter().any(|t| matches!(t, ContentType::All | ContentType::FloorSpeech));
 
     let has_non_speaker_filters = filters.chamber.is_some()
+        || !filters.page_types.is_empty()
         || filters.committee.is_some()
-        || filters.congress.is_some()
+        || filters.congress_min.is_some()
+        || filters.congress_max.is_some()
         || filters.from_date.is_some()
         || filters.to_date.is_some();
 
@@ -159,9 +228,10 @@ async fn get_filtered_content_ids(
                 .get_filtered_ids(
                     chamber_str,
                     filters.committee,
-                    filters.congress,
-                    filters.from_date,
-                    filters.to_date,
+                    filters.congress_min,
+                    filters.congress_max,
+                    filters.from_date.as_deref(),
+                    filters.to_date.as_deref(),
                 )
                 .await?;
             ids.extend(hearing_ids);
@@ -169,7 +239,12 @@ async fn get_filtered_content_ids(
         if includes_floor_speeches {
             let floor_speech_ids = db
                 .floor_speeches()
-                .get_filtered_ids(chamber_str, filters.from_date, filters.to_date)
+                .get_filtered_ids(
+                    chamber_str,
+                    &filters.page_types,
+                    filters.from_date.as_deref(),
+                    filters.to_date.as_deref(),
+                )
                 .await?;
             ids.extend(floor_speech_ids);
         }
@@ -212,10 +287,30 @@ fn build_content_id_filter(ids: &HashSet<Uuid>) -> Option<String> {
     }
 
     // limit to avoid overly long filter expressions
-    let id_strs: Vec<String> = ids.iter().take(1000).map(|id| format!("'{id}'")).collect();
+    let id_strs: Vec<String> =
+        ids.iter().take(MAX_FILTER_CONTENT_IDS).map(|id| format!("'{id}'")).collect();
     Some(format!("content_id IN ({})", id_strs.join(", ")))
 }
 
+/// Resolve a single-content scope into a `content_id` filter for `LanceDB`
+///
+/// Embedded content is keyed by UUID while FTS-only content is keyed by
+/// `package_id`/`event_id`, so both forms are included when known.
+async fn build_single_content_filter(db: &Database, content_id: &str) -> Result<String, ApiError> {
+    let content_id = content_id.trim();
+    let ids = content_id_aliases(db, content_id).await?;
+    // an unknown package_id resolves to itself alone
+    if ids.len() == 1 && Uuid::parse_str(content_id).is_err() {
+        return Err(ApiError::Validation {
+            message: format!(
+                "content_id '{content_id}' must be a UUID or a known hearing package_id"
+            ),
+            field: Some("content_id".into()),
+        });
+    }
+    Ok(content_id_filter(&ids))
+}
+
 /// Combine multiple filter expressions with AND
 fn combine_filters(filters: Vec<Option<String>>) -> Option<String> {
     let active: Vec<String> = filters.into_iter().flatten().collect();
@@ -245,13 +340,19 @@ fn normalize_score(score: f32, mode: InternalMode, max_score: f32) -> f32 {
 }
 
 /// Execute search against `LanceDB`
+///
+/// Hybrid searches fetch `limit * hybrid_oversample` rows from each component
+/// before RRF fusion and are trimmed back to `limit` afterwards.
+#[allow(clippy::too_many_arguments)]
 async fn execute_search(
     lancedb_path: &str,
     query: &str,
     query_embedding: Option<Vec<f32>>,
+    embedding_model: EmbeddingModel,
     limit: usize,
     mode: InternalMode,
     type_filter: Option<&str>,
+    hybrid_oversample: usize,
 ) -> Result<(Vec<RawSearchResult>, InternalMode), ApiError> {
     let db = lancedb::connect(lancedb_path).execute().await?;
     let filter_expr = type_filter.map(ToString::to_string);
@@ -260,6 +361,7 @@ async fn execute_search(
     let batches: Vec<RecordBatch> = match mode {
         InternalMode::Vector => {
             let table = db.open_table("text_embeddings").execute().await?;
+            verify_embedding_model(&table, embedding_model).await?;
             let query_embedding = query_embedding
                 .as_ref()
                 .ok_or_else(|| ApiError::Internal("Missing query embedding for vector search".into()))?;
@@ -274,10 +376,9 @@ async fn execute_search(
             let fts_table = db.open_table(FTS_TABLE_NAME).execute().await.ok();
             let embeddings_table = db.open_table("text_embeddings").execute().await?;
 
+            let fts_query = &text_fts_query(query)?;
             let try_fts = |table: lancedb::Table, filter: Option<String>| async move {
-                let mut search = table
-                    .query()
-                    .full_text_search(FullTextSearchQuery::new(query.to_string()));
+                let mut search = table.query().full_text_search(fts_query.clone());
                 if let Some(ref f) = filter {
                     search = search.only_if(f.clone());
                 }
@@ -301,6 +402,7 @@ async fn execute_search(
                 Err(e) if is_missing_fts_index_error(&e) => {
                     tracing::error!("FTS index not found, falling back to vector search");
                     mode_used = InternalMode::Vector;
+                    verify_embedding_model(&embeddings_table, embedding_model).await?;
                     let query_embedding = query_embedding
                         .as_ref()
                         .ok_or_else(|| ApiError::Internal("Missing query embedding for vector fallback".into()))?;
@@ -315,10 +417,14 @@ async fn execute_search(
         }
         InternalMode::Hybrid => {
             let table = db.open_table("text_embeddings").execute().await?;
+            verify_embedding_model(&table, embedding_model).await?;
             let query_embedding = query_embedding
                 .as_ref()
                 .ok_or_else(|| ApiError::Internal("Missing query embedding for hybrid search".into()))?;
 
+            // fuse a wider window from each component, then trim to the limit
+            let window = hybrid_window(limit, hybrid_oversample);
+
             // run hybrid search on text_embeddings (embedded content)
             let mut search = table
                 .vector_search(query_embedding.clone())?
@@ -327,7 +433,7 @@ async fn execute_search(
                 search = search.only_if(filter.clone());
             }
 
-            let embedded_batches: Vec<RecordBatch> = match search.limit(limit).execute().await {
+            let embedded_batches: Vec<RecordBatch> = match search.limit(window).execute().await {
                 Ok(stream) => stream.try_collect().await?,
                 Err(e) if is_missing_fts_index_error(&e) => {
                     tracing::warn!("FTS index not found on text_embeddings, falling back to vector-only for embedded content");
@@ -335,7 +441,7 @@ async fn execute_search(
                     if let Some(ref filter) = filter_expr {
                         vector_search = vector_search.only_if(filter.clone());
                     }
-                    vector_search.limit(limit).execute().await?.try_collect().await?
+                    vector_search.limit(window).execute().await?.try_collect().await?
                 }
                 Err(e) => return Err(e.into()),
             };
@@ -343,16 +449,22 @@ async fn execute_search(
 
             // run FTS-only search on text_fts (FTS-only content, 2020-2024)
             let fts_results =
-                execute_fts_only_search(lancedb_path, query, limit, type_filter).await?;
+                execute_fts_only_search(lancedb_path, query, window, type_filter).await?;
 
             tracing::debug!(
                 embedded_count = embedded_results.len(),
                 fts_count = fts_results.len(),
+                window,
                 "RRF merge: combining embedded and FTS-only results"
             );
 
             // merge with RRF
-            let merged = merge_with_rrf(vec![embedded_results, fts_results]);
+            let mut merged = merge_with_rrf(
+                vec![embedded_results, fts_results],
+                |r| (r.content_type.clone(), r.content_id_str.clone(), r.segment_index),
+                |r| &mut r.score,
+            );
+            merged.truncate(limit);
             return Ok((merged, InternalMode::Hybrid));
         }
         InternalMode::Phrase => {
@@ -361,22 +473,7 @@ async fn execute_search(
                 Err(_) => db.open_table("text_embeddings").execute().await?,
             };
 
-            let escaped_query = query.replace('\'', "''").replace('%', "\\%");
-            let like_filter = format!("text LIKE '%{escaped_query}%'");
-
-            let combined_filter = match filter_expr {
-                Some(ref type_filter) => format!("({type_filter}) AND ({like_filter})"),
-                None => like_filter,
-            };
-
-            table
-                .query()
-                .only_if(combined_filter)
-                .limit(limit)
-                .execute()
-                .await?
-                .try_collect()
-                .await?
+            phrase_search(&table, query, filter_expr.as_deref(), limit).await?
         }
     };
 
@@ -399,9 +496,7 @@ async fn execute_fts_only_search(
         Err(_) => return Ok(vec![]),
     };
 
-    let mut search = fts_table
-        .query()
-        .full_text_search(FullTextSearchQuery::new(query.to_string()));
+    let mut search = fts_table.query().full_text_search(text_fts_query(query)?);
     if let Some(ref f) = filter_expr {
         search = search.only_if(f.clone());
     }
@@ -415,46 +510,6 @@ async fn execute_fts_only_search(
     parse_search_results(&batches, InternalMode::Fts)
 }
 
-/// Unique key for deduplication in RRF merge
-#[derive(Hash, Eq, PartialEq, Clone)]
-struct SegmentKey {
-    content_type: String,
-    content_id_str: String,
-    segment_index: i32,
-}
-
-/// Merge results from multiple sources using Reciprocal Rank Fusion
-fn merge_with_rrf(result_sets: Vec<Vec<RawSearchResult>>) -> Vec<RawSearchResult> {
-    let mut scores: HashMap<SegmentKey, f32> = HashMap::new();
-    let mut results_by_key: HashMap<SegmentKey, RawSearchResult> = HashMap::new();
-
-    for results in result_sets {
-        for (rank, result) in results.into_iter().enumerate() {
-            let key = SegmentKey {
-                content_type: result.content_type.clone(),
-                content_id_str: result.content_id_str.clone(),
-                segment_index: result.segment_index,
-            };
-
-            *scores.entry(key.clone()).or_insert(0.0) += rrf_score(rank);
-            results_by_key.entry(key).or_insert(result);
-        }
-    }
-
-    let mut merged: Vec<(f32, RawSearchResult)> = results_by_key
-        .into_iter()
-        .map(|(key, mut result)| {
-            let rrf = scores.get(&key).copied().unwrap_or(0.0);
-            result.score = rrf;
-            (rrf, result)
-        })
-        .collect();
-
-    // sort by RRF score descending
-    merged.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
-    merged.into_iter().map(|(_, r)| r).collect()
-}
-
 /// Parse `LanceDB` results into `RawSearchResult` structs
 fn parse_search_results(
     batches: &[RecordBatch],
@@ -518,6 +573,10 @@ fn parse_search_results(
             .column_by_name("speaker_name")
             .and_then(|c| c.as_any().downcast_ref::<StringArray>());
 
+        let statement_ids = batch
+            .column_by_name("statement_id")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+
         for i in 0..batch.num_rows() {
             let content_id_str = content_ids.value(i);
             // FTS table uses package_id strings, embeddings table uses UUIDs
@@ -548,14 +607,24 @@ fn parse_search_results(
                 }
             });
 
+            let statement_id = statement_ids.and_then(|si| {
+                if si.is_null(i) {
+                    None
+                } else {
+                    Some(si.value(i).to_string())
+                }
+            });
+
             results.push(RawSearchResult {
                 content_id,
                 content_id_str: content_id_str.to_string(),
                 segment_index: segment_indices.value(i),
+                statement_id,
                 text: texts.value(i).to_string(),
                 start_time_ms: start_times.map_or(0, |t| t.value(i)),
                 end_time_ms: end_times.map_or(0, |t| t.value(i)),
                 score,
+                rerank_score: None,
                 content_type,
                 speaker_name,
                 title: None,
@@ -579,11 +648,12 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
     let mut floor_speech_ids: Vec<Uuid> = Vec::new();
     let mut floor_speech_event_ids: Vec<String> = Vec::new();
     let mut floor_speech_segment_keys: Vec<(Uuid, i32)> = Vec::new();
+    let mut bill_ids: Vec<Uuid> = Vec::new();
 
     for r in results.iter() {
         let is_nil = r.content_id.is_nil();
-        match r.content_type.as_str() {
-            "hearing" => {
+        match CoreContentType::from_db_str(&r.content_type) {
+            Some(CoreContentType::Hearing) => {
                 if is_nil {
                     hearing_package_ids.push(r.content_id_str.clone());
                 } else {
@@ -591,7 +661,7 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
                     hearing_segment_keys.push((r.content_id, r.segment_index));
                 }
             }
-            "floor_speech" => {
+            Some(CoreContentType::FloorSpeech) => {
                 if is_nil {
                     floor_speech_event_ids.push(r.content_id_str.clone());
                 } else {
@@ -599,6 +669,7 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
                     floor_speech_segment_keys.push((r.content_id, r.segment_index));
                 }
             }
+            Some(CoreContentType::Bill) if !is_nil => bill_ids.push(r.content_id),
             _ => {}
         }
     }
@@ -606,6 +677,7 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
     // fetch metadata by UUID
     let hearing_metadata = db.hearings().get_metadata_batch(&hearing_ids).await?;
     let floor_speech_metadata = db.floor_speeches().get_metadata_batch(&floor_speech_ids).await?;
+    let bill_metadata = db.bills().get_metadata_batch(&bill_ids).await?;
 
     // fetch metadata by package_id/event_id for FTS results
     let hearing_pkg_metadata = db
@@ -617,19 +689,13 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
         .get_metadata_batch_by_event_id(&floor_speech_event_ids)
         .await?;
 
-    let hearing_speakers = db
-        .hearing_segments()
-        .get_speakers_for_segments(&hearing_segment_keys)
-        .await?;
-    let floor_speech_speakers = db
-        .floor_speech_segments()
-        .get_speakers_for_segments(&floor_speech_segment_keys)
-        .await?;
+    let (hearing_speakers, floor_speech_speakers) =
+        fetch_all_speakers(db, &hearing_segment_keys, &floor_speech_segment_keys).await?;
 
     for r in results.iter_mut() {
         let is_nil = r.content_id.is_nil();
-        match r.content_type.as_str() {
-            "hearing" => {
+        match CoreContentType::from_db_str(&r.content_type) {
+            Some(CoreContentType::Hearing) => {
                 if is_nil {
                     if let Some(meta) = hearing_pkg_metadata.get(&r.content_id_str) {
                         r.title = Some(meta.title.clone());
@@ -655,7 +721,7 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
                     }
                 }
             }
-            "floor_speech" => {
+            Some(CoreContentType::FloorSpeech) => {
                 if is_nil {
                     if let Some(meta) = floor_speech_event_metadata.get(&r.content_id_str) {
                         r.title = Some(meta.title.clone());
@@ -679,6 +745,19 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
                     }
                 }
             }
+            Some(CoreContentType::Bill) => {
+                if let Some(meta) = bill_metadata.get(&r.content_id) {
+                    r.title = Some(match &meta.title {
+                        Some(title) => format!("{}: {title}", meta.identifier),
+                        None => meta.identifier.clone(),
+                    });
+                    r.date = meta.status_date.map(|d| d.format("%Y-%m-%d").to_string());
+                    r.chamber = meta.chamber.as_deref().map(capitalize_chamber);
+                    r.congress = Some(meta.congress);
+                    // the sponsor stands in for a speaker, as in the CLI
+                    r.speaker_name.clone_from(&meta.sponsor);
+                }
+            }
             _ => {}
         }
 
@@ -696,11 +775,28 @@ async fn enrich_results(results: &mut [SearchResult], db: &Database) -> Result<(
     Ok(())
 }
 
+/// "House" or "Senate" from a lowercase chamber name
+fn capitalize_chamber(chamber: &str) -> String {
+    let mut chars = chamber.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
 /// Expand search results with context segments from `LanceDB`
+///
+/// With `ContextScope::Statement`, context is limited to segments of the same
+/// statement so it does not bleed into another speaker's remarks. Each result's
+/// window is also assembled into `context_text` according to `format`, with
+/// speakers looked up in `PostgreSQL`.
 async fn expand_context(
     results: &mut [SearchResult],
+    db: &Database,
     lancedb_path: &str,
     context_count: i32,
+    scope: ContextScope,
+    format: ContextFormat,
 ) -> Result<(), ApiError> {
     use arrow_array::{Int32Array, StringArray};
 
@@ -708,29 +804,39 @@ async fn expand_context(
         return Ok(());
     }
 
-    let db = lancedb::connect(lancedb_path).execute().await?;
-    let table = db.open_table("text_embeddings").execute().await?;
+    let lance = lancedb::connect(lancedb_path).execute().await?;
+    let table = lance.open_table("text_embeddings").execute().await?;
 
-    // group results by content_id for efficient querying
-    let mut content_segments: HashMap<Uuid, Vec<(usize, i32)>> = HashMap::new();
+    // group results by content_id (and statement_id when scoped) for efficient querying
+    let mut content_segments: HashMap<(Uuid, Option<String>), Vec<(usize, i32)>> = HashMap::new();
     for (idx, result) in results.iter().enumerate() {
+        let statement_id = match scope {
+            ContextScope::Statement => result.statement_id.clone(),
+            ContextScope::Document => None,
+        };
         content_segments
-            .entry(result.content_id)
+            .entry((result.content_id, statement_id))
             .or_default()
             .push((idx, result.segment_index));
     }
 
-    // for each content, fetch all needed context segments in one query
-    for (content_id, segments) in content_segments {
+    // for each group, fetch all needed context segments in one query
+    for ((content_id, statement_id), segments) in content_segments {
         // calculate min and max segment indices needed
         let min_idx = segments.iter().map(|(_, idx)| idx - context_count).min().unwrap_or(0);
         let max_idx = segments.iter().map(|(_, idx)| idx + context_count).max().unwrap_or(0);
 
         // query for all segments in range for this content
-        let filter = format!(
+        let mut filter = format!(
             "content_id = '{}' AND segment_index >= {} AND segment_index <= {}",
             content_id, min_idx, max_idx
         );
+        if let Some(statement_id) = statement_id {
+            filter.push_str(&format!(
+                " AND statement_id = '{}'",
+                statement_id.replace('\'', "''")
+            ));
+        }
 
         let batches: Vec<RecordBatch> = table
             .query()
@@ -754,27 +860,54 @@ async fn expand_context(
             }
         }
 
+        // speakers for the window, used to label `context_text`
+        let keys: Vec<(Uuid, i32)> = segment_texts.keys().map(|&idx| (content_id, idx)).collect();
+        let content_type = CoreContentType::from_db_str(&results[segments[0].0].content_type);
+        let speakers = match content_type {
+            Some(CoreContentType::Hearing) => {
+                db.hearing_segments().get_speakers_for_segments(&keys).await?
+            }
+            Some(CoreContentType::FloorSpeech) => {
+                db.floor_speech_segments().get_speakers_for_segments(&keys).await?
+            }
+            _ => HashMap::new(),
+        };
+        let context_segment = |idx: i32, text: &str| ContextSegment {
+            speaker: speakers.get(&(content_id, idx)).cloned().flatten(),
+            text: text.to_string(),
+        };
+
         // populate context for each result from this content
         for (result_idx, segment_idx) in segments {
             let result = &mut results[result_idx];
 
             // get context_before (in order from earliest to just before current)
             let mut before = Vec::new();
+            let mut window = Vec::new();
             for i in (segment_idx - context_count)..segment_idx {
                 if let Some(text) = segment_texts.get(&i) {
                     before.push(text.clone());
+                    window.push(context_segment(i, text));
                 }
             }
             result.context_before = before;
 
+            let mut current = context_segment(segment_idx, &result.text);
+            if result.speaker_name.is_some() {
+                current.speaker.clone_from(&result.speaker_name);
+            }
+            window.push(current);
+
             // get context_after (in order from just after current to latest)
             let mut after = Vec::new();
             for i in (segment_idx + 1)..=(segment_idx + context_count) {
                 if let Some(text) = segment_texts.get(&i) {
                     after.push(text.clone());
+                    window.push(context_segment(i, text));
                 }
             }
             result.context_after = after;
+            result.context_text = Some(format.render(&window));
         }
     }
 
@@ -792,11 +925,123 @@ async fn expand_context(
         (status = 500, description = "Internal error")
     )
 )]
-#[allow(clippy::significant_drop_tightening)]
 pub async fn search(
     State(state): State<Arc<AppState>>,
     Query(params): Query<SearchParams>,
-) -> Result<Json<SearchResponse>, ApiError> {
+) -> Result<Response, ApiError> {
+    let start = Instant::now();
+    let fields = parse_fields(params.fields.as_deref())?;
+    let response = run_search(&state, &params).await?;
+
+    let fallback = response.mode != response.mode_used;
+    state.metrics.record_search(fallback);
+
+    tracing::info!(
+        query = %response.query,
+        query_len = response.query.len(),
+        limit = params.limit.min(100),
+        offset = params.offset,
+        mode = %response.mode,
+        mode_used = %response.mode_used,
+        fallback,
+        result_count = response.total_returned,
+        latency_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+        pg_filters = FilterParams::from_params(&params).is_ok_and(|f| f.has_pg_filters()),
+        "Search metrics"
+    );
+
+    Ok(match fields {
+        Some(fields) => Json(project_results(&response, &fields)?).into_response(),
+        None => Json(response).into_response(),
+    })
+}
+
+/// Parse the `fields` projection, rejecting unknown field names
+fn parse_fields(fields: Option<&str>) -> Result<Option<HashSet<&str>>, ApiError> {
+    let Some(fields) = fields.map(str::trim).filter(|f| !f.is_empty()) else {
+        return Ok(None);
+    };
+
+    let mut selected = HashSet::new();
+    for field in fields.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+        if !SearchResult::field_names().iter().any(|name| name == field) {
+            return Err(ApiError::Validation {
+                message: format!(
+                    "Unknown field '{}'. Available fields: {}",
+                    field,
+                    SearchResult::field_names().join(", ")
+                ),
+                field: Some("fields".into()),
+            });
+        }
+        selected.insert(field);
+    }
+
+    Ok(Some(selected))
+}
+
+/// Serialize a response, keeping only the selected keys in each result
+fn project_results(
+    response: &SearchResponse,
+    fields: &HashSet<&str>,
+) -> Result<serde_json::Value, ApiError> {
+    let mut value =
+        serde_json::to_value(response).map_err(|e| ApiError::Internal(e.to_string()))?;
+
+    if let Some(results) = value.get_mut("results").and_then(serde_json::Value::as_array_mut) {
+        for result in results.iter_mut().filter_map(serde_json::Value::as_object_mut) {
+            result.retain(|key, _| fields.contains(key.as_str()));
+        }
+    }
+
+    Ok(value)
+}
+
+/// Reorder candidates with the cross-encoder reranker
+///
+/// The model loads on first use; if it can't be loaded or scoring fails,
+/// the retrieval order is kept. A failed load isn't cached, so the next
+/// request tries again.
+async fn rerank_results(
+    state: &AppState,
+    query: &str,
+    results: Vec<RawSearchResult>,
+) -> Vec<RawSearchResult> {
+    let loaded = state
+        .reranker
+        .get_or_try_init(|| async {
+            tracing::info!("Loading reranker model...");
+            TextReranker::new().map(tokio::sync::Mutex::new)
+        })
+        .await;
+    let reranker = match loaded {
+        Ok(reranker) => reranker,
+        Err(e) => {
+            tracing::warn!("Reranker unavailable, results will not be reranked: {}", e);
+            return results;
+        }
+    };
+
+    let texts: Vec<&str> = results.iter().map(|r| r.text.as_str()).collect();
+    let ranking = reranker.lock().await.rerank(query, &texts);
+    match ranking {
+        Ok(ranking) => apply_ranking(results, &ranking)
+            .into_iter()
+            .map(|(mut result, score)| {
+                result.rerank_score = score;
+                result
+            })
+            .collect(),
+        Err(e) => {
+            tracing::warn!("Reranking failed, keeping retrieval order: {}", e);
+            results
+        }
+    }
+}
+
+/// Execute a search request and build the response
+#[allow(clippy::significant_drop_tightening)]
+async fn run_search(state: &AppState, params: &SearchParams) -> Result<SearchResponse, ApiError> {
     // validate query
     let query = params.q.trim();
     if query.is_empty() {
@@ -819,27 +1064,18 @@ pub async fn search(
         None
     };
 
-    tracing::info!(
-        query = %query,
-        limit = %limit,
-        offset = %offset,
-        mode = ?params.mode,
-        "Search request"
-    );
+    // scope to a single content item if requested
+    let single_content_filter = match params.content_id.as_deref() {
+        Some(id) => Some(build_single_content_filter(&state.db, id).await?),
+        None => None,
+    };
 
     // build content type filter
     let content_types = params.parse_content_types();
     let type_filter = build_content_type_filter(&content_types);
 
     // build PostgreSQL-based filters
-    let filter_params = FilterParams {
-        chamber: params.chamber.as_ref(),
-        committee: params.committee.as_deref(),
-        congress: params.congress,
-        from_date: params.from.as_deref(),
-        to_date: params.to.as_deref(),
-        speaker: params.speaker.as_deref(),
-    };
+    let filter_params = FilterParams::from_params(params)?;
 
     // get filtered content IDs from PostgreSQL
     let (content_id_filter, empty_filter_result) = if filter_params.has_pg_filters() {
@@ -855,7 +1091,7 @@ pub async fn search(
 
     // if PostgreSQL filter found no matching content, return empty results immediately
     if empty_filter_result {
-        return Ok(Json(SearchResponse {
+        return Ok(SearchResponse {
             query: query.to_string(),
             mode: mode.as_str().to_string(),
             mode_used: mode.as_str().to_string(),
@@ -863,33 +1099,98 @@ pub async fn search(
             total_returned: 0,
             has_more: false,
             next_offset: None,
-        }));
+        });
     }
 
     // combine all filters (speaker filter is now handled via PostgreSQL pre-filtering)
-    let combined_filter = combine_filters(vec![type_filter, content_id_filter]);
+    let combined_filter = combine_filters(vec![
+        type_filter,
+        content_id_filter,
+        single_content_filter.clone(),
+    ]);
+
+    // FTS mode supports AND / OR / -term; the index only ORs terms, so
+    // AND and exclusions are applied to over-fetched candidates afterwards
+    let boolean_query = matches!(mode, InternalMode::Fts).then(|| BooleanQuery::parse(query));
+    let search_text = match &boolean_query {
+        Some(parsed) if parsed.search_text().is_empty() => {
+            return Err(ApiError::Validation {
+                message: "FTS query needs at least one search term that is not excluded".into(),
+                field: Some("q".into()),
+            });
+        }
+        Some(parsed) => parsed.search_text(),
+        None => query.to_string(),
+    };
+    let post_filter = boolean_query.filter(BooleanQuery::has_operators);
 
     // execute search
-    let fetch_count = offset + limit + 1;
+    let mut fetch_count = offset + limit + 1;
+    if post_filter.is_some() {
+        fetch_count *= BOOLEAN_FTS_OVERFETCH;
+    }
+    if params.min_words > 0 {
+        fetch_count *= MIN_WORDS_OVERFETCH;
+    }
+    let mut speaker_boost = params.speaker_boost && matches!(mode, InternalMode::Fts);
+    if speaker_boost && !has_speaker_names(&state.lancedb_path).await? {
+        tracing::warn!("speaker_boost ignored: {} has no speaker_name column", FTS_TABLE_NAME);
+        speaker_boost = false;
+    }
+    if speaker_boost {
+        fetch_count *= SPEAKER_BOOST_OVERFETCH;
+    }
+    if params.rerank {
+        fetch_count = fetch_count.max(DEFAULT_RERANK_CANDIDATES);
+    }
 
     let (mut raw_results, mode_used) = {
         let search_future = execute_search(
             &state.lancedb_path,
-            query,
+            &search_text,
             query_embedding,
+            state.embedding_model,
             fetch_count,
             mode,
             combined_filter.as_deref(),
+            state.hybrid_oversample,
         );
         tokio::time::timeout(state.search_timeout, search_future)
             .await
             .map_err(|_| ApiError::Internal("Search timed out".into()))??
     };
 
+    // distinguish "no matches in this content" from "content not indexed"
+    if raw_results.is_empty() {
+        if let (Some(id), Some(filter)) = (params.content_id.as_deref(), &single_content_filter) {
+            if count_indexed_segments(&state.lancedb_path, filter).await? == 0 {
+                return Err(ApiError::NotFound {
+                    message: format!("No indexed segments found for content_id '{}'", id.trim()),
+                });
+            }
+        }
+    }
+
+    retain_text_matches(&mut raw_results, post_filter.as_ref(), params.min_words, |r| &r.text);
+    // a vector fallback scores by distance, which a boost would invert
+    if speaker_boost && matches!(mode_used, InternalMode::Fts) {
+        raw_results = boost_speaker_matches(
+            query,
+            raw_results,
+            |r| r.speaker_name.as_deref(),
+            |r| &mut r.score,
+        );
+    }
+
+    // rerank the whole candidate pool before paging through it
+    if params.rerank {
+        raw_results = rerank_results(state, query, raw_results).await;
+    }
+
     // skip offset
     if offset > 0 {
         if raw_results.len() <= offset {
-            return Ok(Json(SearchResponse {
+            return Ok(SearchResponse {
                 query: query.to_string(),
                 mode: mode.as_str().to_string(),
                 mode_used: mode_used.as_str().to_string(),
@@ -897,7 +1198,7 @@ pub async fn search(
                 total_returned: 0,
                 has_more: false,
                 next_offset: None,
-            }));
+            });
         }
         raw_results = raw_results.into_iter().skip(offset).collect();
     }
@@ -911,28 +1212,42 @@ pub async fn search(
     // calculate max score for normalization
     let max_score = raw_results.iter().map(|r| r.score).fold(0.0_f32, f32::max);
 
+    // highlight query terms for keyword-based modes (vector matches have no terms to point at)
+    let highlight_query = (!matches!(mode_used, InternalMode::Vector))
+        .then(|| BooleanQuery::parse(query));
+
     // convert to response
     let mut results: Vec<SearchResult> = raw_results
         .into_iter()
-        .map(|r| SearchResult {
-            content_id: r.content_id,
-            content_id_str: r.content_id_str,
-            segment_index: r.segment_index,
-            text: r.text,
-            start_time_ms: r.start_time_ms,
-            end_time_ms: r.end_time_ms,
-            score: normalize_score(r.score, mode_used, max_score),
-            content_type: r.content_type,
-            speaker_name: r.speaker_name,
-            speaker_type: None,
-            title: r.title,
-            date: None,
-            source_url: None,
-            committee: None,
-            chamber: None,
-            congress: None,
-            context_before: vec![],
-            context_after: vec![],
+        .map(|r| {
+            let match_offsets = highlight_query
+                .as_ref()
+                .map(|q| q.match_offsets(&r.text))
+                .unwrap_or_default();
+            SearchResult {
+                content_id: r.content_id,
+                content_id_str: r.content_id_str,
+                segment_index: r.segment_index,
+                statement_id: r.statement_id,
+                text: r.text,
+                start_time_ms: r.start_time_ms,
+                end_time_ms: r.end_time_ms,
+                score: normalize_score(r.score, mode_used, max_score),
+                rerank_score: r.rerank_score,
+                content_type: r.content_type,
+                speaker_name: r.speaker_name,
+                speaker_type: None,
+                title: r.title,
+                date: None,
+                source_url: None,
+                committee: None,
+                chamber: None,
+                congress: None,
+                context_before: vec![],
+                context_after: vec![],
+                context_text: None,
+                match_offsets,
+            }
         })
         .collect();
 
@@ -955,7 +1270,12 @@ pub async fn search(
     // expand context if requested
     if params.context > 0 {
         let context_count = params.context.min(10) as i32;
-        if let Err(e) = expand_context(&mut results, &state.lancedb_path, context_count).await {
+        let scope = params.context_scope;
+        let format = params.context_format;
+        if let Err(e) =
+            expand_context(&mut results, &state.db, &state.lancedb_path, context_count, scope, format)
+                .await
+        {
             tracing::warn!("Failed to expand context: {}", e);
         }
     }
@@ -977,5 +1297,5 @@ pub async fn search(
         "Search response"
     );
 
-    Ok(Json(response))
+    Ok(response)
 }
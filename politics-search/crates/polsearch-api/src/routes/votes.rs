@@ -0,0 +1,152 @@
+//! Vote endpoints
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use polsearch_core::RollCallVote;
+use polsearch_pipeline::stages::find_related_votes;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::models::{
+    PartyVoteCount, RelatedVoteResult, RelatedVotesParams, RelatedVotesResponse, VoteCounts,
+    VoteDetailResponse,
+};
+use crate::AppState;
+
+/// Get a vote by its external ID
+///
+/// Resolves the human-facing vote identifier shown in search results and
+/// returns the vote with its tallies and party breakdown.
+#[utoipa::path(
+    get,
+    path = "/votes/{id}",
+    params(
+        ("id" = String, Path, description = "External vote identifier (e.g., \"h1-116.2019\")")
+    ),
+    responses(
+        (status = 200, description = "Vote details", body = VoteDetailResponse),
+        (status = 404, description = "Vote not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_vote(
+    State(state): State<Arc<AppState>>,
+    Path(vote_id): Path<String>,
+) -> Result<Json<VoteDetailResponse>, ApiError> {
+    let vote = state
+        .db
+        .roll_call_votes()
+        .get_by_vote_id(&vote_id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("Vote with vote_id {} not found", vote_id),
+        })?;
+
+    let party_breakdown = state
+        .db
+        .individual_votes()
+        .get_party_counts(vote.id)
+        .await?
+        .into_iter()
+        .map(|(party, position, count)| PartyVoteCount {
+            party,
+            position,
+            count,
+        })
+        .collect();
+
+    Ok(Json(VoteDetailResponse {
+        id: vote.id,
+        vote_id: vote.vote_id,
+        question: vote.question,
+        date: vote.vote_date.format("%Y-%m-%d").to_string(),
+        chamber: vote.chamber,
+        congress: vote.congress,
+        session: vote.session,
+        vote_number: vote.vote_number,
+        vote_type: vote.vote_type,
+        category: vote.category,
+        subject: vote.subject,
+        result: vote.result,
+        result_text: vote.result_text,
+        requires: vote.requires,
+        source_url: vote.source_url,
+        vote_counts: VoteCounts {
+            yea: vote.yea_count,
+            nay: vote.nay_count,
+            present: vote.present_count,
+            not_voting: vote.not_voting_count,
+        },
+        party_breakdown,
+    }))
+}
+
+/// Find related votes
+///
+/// Returns the votes whose embeddings are closest to the given vote.
+#[utoipa::path(
+    get,
+    path = "/votes/{id}/related",
+    params(
+        ("id" = Uuid, Path, description = "Roll call vote ID (UUID)"),
+        RelatedVotesParams
+    ),
+    responses(
+        (status = 200, description = "Related votes", body = RelatedVotesResponse),
+        (status = 404, description = "Vote not found or not embedded"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_related_votes(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<RelatedVotesParams>,
+) -> Result<Json<RelatedVotesResponse>, ApiError> {
+    let votes_repo = state.db.roll_call_votes();
+    if votes_repo.get_by_id(id).await?.is_none() {
+        return Err(ApiError::NotFound {
+            message: format!("Vote with ID {} not found", id),
+        });
+    }
+
+    let limit = params.limit.clamp(1, 100);
+    let lancedb = lancedb::connect(&state.lancedb_path).execute().await?;
+    let related = find_related_votes(&lancedb, id, limit)
+        .await?
+        .ok_or_else(|| ApiError::NotFound {
+            message: format!("Vote with ID {} has no embedding yet", id),
+        })?;
+
+    // get_by_ids orders by date, so look up details by ID to keep similarity order
+    let ids: Vec<Uuid> = related.iter().map(|r| r.id).collect();
+    let details: HashMap<Uuid, RollCallVote> = votes_repo
+        .get_by_ids(&ids)
+        .await?
+        .into_iter()
+        .map(|v| (v.id, v))
+        .collect();
+
+    let related: Vec<RelatedVoteResult> = related
+        .into_iter()
+        .filter_map(|r| {
+            details.get(&r.id).map(|v| RelatedVoteResult {
+                id: v.id,
+                vote_id: v.vote_id.clone(),
+                question: v.question.clone(),
+                date: v.vote_date.format("%Y-%m-%d").to_string(),
+                chamber: v.chamber.clone(),
+                congress: v.congress,
+                result: v.result.clone(),
+                distance: r.distance,
+            })
+        })
+        .collect();
+
+    Ok(Json(RelatedVotesResponse {
+        vote_id: id,
+        total_returned: related.len(),
+        related,
+    }))
+}
@@ -100,39 +100,57 @@ impl ArchiveStore {
         content_id: Uuid,
         segments: &[TranscriptSegmentRaw],
     ) -> Result<(), ArchiveError> {
-        let conn = self.get_connection(podcast_id)?;
-        let content_id_str = content_id.to_string();
+        self.store_transcript_raw_batch(podcast_id, content_id, segments)?;
+        Ok(())
+    }
 
-        let mut stmt = conn.prepare(
-            r"
-            INSERT OR REPLACE INTO transcript_raw
-                (content_id, segment_index, token_confidences, token_start_times, token_end_times)
-            VALUES (?1, ?2, ?3, ?4, ?5)
-            ",
-        )?;
+    /// Store raw transcript data for an episode in a single transaction
+    ///
+    /// Either every segment is written or none are. Returns the number of rows written.
+    pub fn store_transcript_raw_batch(
+        &self,
+        podcast_id: Uuid,
+        content_id: Uuid,
+        segments: &[TranscriptSegmentRaw],
+    ) -> Result<usize, ArchiveError> {
+        let mut conn = self.get_connection(podcast_id)?;
+        let content_id_str = content_id.to_string();
 
-        for segment in segments {
-            let confidences = compress_f32_array(&segment.token_confidences)?;
-            let start_times = compress_i64_array(&segment.token_start_times_ms)?;
-            let end_times = compress_i64_array(&segment.token_end_times_ms)?;
-
-            stmt.execute(params![
-                &content_id_str,
-                segment.segment_index,
-                confidences,
-                start_times,
-                end_times,
-            ])?;
+        let tx = conn.transaction()?;
+        let mut written = 0;
+        {
+            let mut stmt = tx.prepare(
+                r"
+                INSERT OR REPLACE INTO transcript_raw
+                    (content_id, segment_index, token_confidences, token_start_times, token_end_times)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ",
+            )?;
+
+            for segment in segments {
+                let confidences = compress_f32_array(&segment.token_confidences)?;
+                let start_times = compress_i64_array(&segment.token_start_times_ms)?;
+                let end_times = compress_i64_array(&segment.token_end_times_ms)?;
+
+                written += stmt.execute(params![
+                    &content_id_str,
+                    segment.segment_index,
+                    confidences,
+                    start_times,
+                    end_times,
+                ])?;
+            }
         }
+        tx.commit()?;
 
         tracing::debug!(
             podcast_id = %podcast_id,
             content_id = %content_id,
-            segments = segments.len(),
+            segments = written,
             "Stored raw transcript data"
         );
 
-        Ok(())
+        Ok(written)
     }
 
     /// Store raw diarization data for an episode
@@ -142,33 +160,51 @@ impl ArchiveStore {
         content_id: Uuid,
         segments: &[DiarizationSegmentRaw],
     ) -> Result<(), ArchiveError> {
-        let conn = self.get_connection(podcast_id)?;
-        let content_id_str = content_id.to_string();
+        self.store_diarization_raw_batch(podcast_id, content_id, segments)?;
+        Ok(())
+    }
 
-        let mut stmt = conn.prepare(
-            r"
-            INSERT OR REPLACE INTO diarization_raw
-                (content_id, segment_index, quality_score)
-            VALUES (?1, ?2, ?3)
-            ",
-        )?;
+    /// Store raw diarization data for an episode in a single transaction
+    ///
+    /// Either every segment is written or none are. Returns the number of rows written.
+    pub fn store_diarization_raw_batch(
+        &self,
+        podcast_id: Uuid,
+        content_id: Uuid,
+        segments: &[DiarizationSegmentRaw],
+    ) -> Result<usize, ArchiveError> {
+        let mut conn = self.get_connection(podcast_id)?;
+        let content_id_str = content_id.to_string();
 
-        for segment in segments {
-            stmt.execute(params![
-                &content_id_str,
-                segment.segment_index,
-                segment.quality_score,
-            ])?;
+        let tx = conn.transaction()?;
+        let mut written = 0;
+        {
+            let mut stmt = tx.prepare(
+                r"
+                INSERT OR REPLACE INTO diarization_raw
+                    (content_id, segment_index, quality_score)
+                VALUES (?1, ?2, ?3)
+                ",
+            )?;
+
+            for segment in segments {
+                written += stmt.execute(params![
+                    &content_id_str,
+                    segment.segment_index,
+                    segment.quality_score,
+                ])?;
+            }
         }
+        tx.commit()?;
 
         tracing::debug!(
             podcast_id = %podcast_id,
             content_id = %content_id,
-            segments = segments.len(),
+            segments = written,
             "Stored raw diarization data"
         );
 
-        Ok(())
+        Ok(written)
     }
 
     /// Check if raw data exists for an episode
@@ -303,3 +339,108 @@ fn decompress_i64_array(data: &[u8]) -> Result<Vec<i64>, ArchiveError> {
     debug_assert_eq!(ints.len(), int_count);
     Ok(ints)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Archive store in a fresh temporary directory
+    fn temp_store() -> (ArchiveStore, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("polsearch-archive-test-{}", Uuid::now_v7()));
+        (ArchiveStore::new(&dir), dir)
+    }
+
+    fn transcript_segment(segment_index: i32) -> TranscriptSegmentRaw {
+        TranscriptSegmentRaw {
+            segment_index,
+            token_confidences: vec![0.9, 0.8, 0.95],
+            token_start_times_ms: vec![0, 120, 250],
+            token_end_times_ms: vec![110, 240, 400],
+        }
+    }
+
+    /// `SQLite` file change counter, incremented once per committed write transaction
+    fn file_change_counter(path: &Path) -> u32 {
+        let header = fs::read(path).expect("read database file");
+        u32::from_be_bytes(header[24..28].try_into().expect("4-byte counter"))
+    }
+
+    #[test]
+    fn test_transcript_batch_writes_all_segments() {
+        let (store, dir) = temp_store();
+        let podcast_id = Uuid::now_v7();
+        let content_id = Uuid::now_v7();
+        let segments: Vec<_> = (0..1000).map(transcript_segment).collect();
+
+        // create the schema up front so only the batch itself modifies the file
+        store
+            .store_transcript_raw_batch(podcast_id, content_id, &[])
+            .expect("create schema");
+        let db_path = store.db_path(podcast_id);
+        let before = file_change_counter(&db_path);
+
+        let written = store
+            .store_transcript_raw_batch(podcast_id, content_id, &segments)
+            .expect("batch insert");
+        assert_eq!(written, 1000);
+        assert_eq!(file_change_counter(&db_path), before + 1, "expected a single transaction");
+        assert_eq!(
+            store.count_transcript_raw(podcast_id, content_id).expect("count"),
+            1000
+        );
+        assert!(store.has_raw_data(podcast_id, content_id).expect("has_raw_data"));
+
+        // re-archiving replaces rows instead of duplicating them
+        store
+            .store_transcript_raw_batch(podcast_id, content_id, &segments)
+            .expect("second batch insert");
+        assert_eq!(
+            store.count_transcript_raw(podcast_id, content_id).expect("count"),
+            1000
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_diarization_batch_writes_all_segments() {
+        let (store, dir) = temp_store();
+        let podcast_id = Uuid::now_v7();
+        let content_id = Uuid::now_v7();
+        let segments: Vec<_> = (0..1000)
+            .map(|segment_index| DiarizationSegmentRaw {
+                segment_index,
+                quality_score: 0.75,
+            })
+            .collect();
+
+        let written = store
+            .store_diarization_raw_batch(podcast_id, content_id, &segments)
+            .expect("batch insert");
+        assert_eq!(written, 1000);
+        assert_eq!(
+            store.count_diarization_raw(podcast_id, content_id).expect("count"),
+            1000
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_empty_batch_writes_nothing() {
+        let (store, dir) = temp_store();
+        let podcast_id = Uuid::now_v7();
+        let content_id = Uuid::now_v7();
+
+        let written = store
+            .store_transcript_raw_batch(podcast_id, content_id, &[])
+            .expect("empty batch");
+        assert_eq!(written, 0);
+        assert_eq!(
+            store.count_transcript_raw(podcast_id, content_id).expect("count"),
+            0
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bench;
+pub mod bill_search;
 pub mod committees;
 pub mod db;
 pub mod embed_votes;
@@ -5,19 +7,42 @@ pub mod fetch_floor_speeches;
 pub mod fts;
 pub mod index;
 pub mod ingest_all;
+pub mod ingest_bills;
 pub mod ingest_floor_speeches;
 pub mod ingest_hearings;
 pub mod ingest_votes;
 pub mod missing_hearings;
+pub mod related_votes;
+pub mod saved_search;
 pub mod search;
+pub mod speakers;
+pub mod speech_stats;
 pub mod util;
 
-use color_eyre::eyre::Result;
-use polsearch_db::Database;
+use color_eyre::eyre::{Result, eyre};
+use polsearch_core::ContentType;
+use polsearch_db::{Database, IngestLock};
 use std::env;
+use std::path::Path;
 
 pub async fn get_database() -> Result<Database> {
     let url = env::var("DATABASE_URL")?;
     let db = Database::connect(&url).await?;
     Ok(db)
 }
+
+/// Take the ingestion lock for `content_type` from `dir`
+///
+/// Fails if another process is already ingesting the same directory, so a
+/// double-started run can't create duplicate rows or embeddings.
+pub async fn acquire_ingest_lock(
+    db: &Database,
+    content_type: ContentType,
+    dir: &Path,
+) -> Result<IngestLock> {
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    let key = format!("ingest:{}:{}", content_type, dir.display());
+    db.try_ingest_lock(&key)
+        .await?
+        .ok_or_else(|| eyre!("Ingestion already running for {}", dir.display()))
+}
@@ -0,0 +1,198 @@
+//! Benchmark search latency and result counts across modes
+
+use color_eyre::eyre::{eyre, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::path::Path;
+use std::time::Instant;
+
+use super::search::count_results;
+use crate::SearchMode;
+
+/// Modes run for every query, in report order
+const BENCH_MODES: [SearchMode; 4] = [
+    SearchMode::Hybrid,
+    SearchMode::Vector,
+    SearchMode::Fts,
+    SearchMode::Phrase,
+];
+
+/// Latency and result-count summary for one search mode
+#[derive(Debug, Serialize)]
+struct ModeReport {
+    mode: String,
+    queries: usize,
+    errors: usize,
+    /// Queries that ran without error but returned nothing
+    empty: usize,
+    mean_results: f64,
+    mean_ms: f64,
+    p50_ms: f64,
+    p90_ms: f64,
+    p99_ms: f64,
+    max_ms: f64,
+}
+
+/// Full benchmark report
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    queries: usize,
+    limit: usize,
+    hybrid_oversample: usize,
+    modes: Vec<ModeReport>,
+}
+
+/// Run the bench search command
+///
+/// Reads one query per line from `queries_path` (blank lines and lines starting
+/// with `#` are skipped) and runs each query in every search mode. The first
+/// query is run once per mode before timing starts, so the embedding model load
+/// and table opens don't land in the percentiles.
+pub async fn search(
+    queries_path: &str,
+    limit: usize,
+    hybrid_oversample: usize,
+    lancedb_path: &str,
+    json: bool,
+) -> Result<()> {
+    let queries_path = Path::new(queries_path);
+    let queries = read_queries(queries_path)?;
+    if queries.is_empty() {
+        return Err(eyre!("No queries found in {}", queries_path.display()));
+    }
+
+    let mut embedder = None;
+    let mut modes = Vec::with_capacity(BENCH_MODES.len());
+    for mode in BENCH_MODES {
+        if !json {
+            eprintln!("Benchmarking {} ({} queries)...", mode_name(mode), queries.len());
+        }
+
+        // warm-up, errors are reported by the timed run
+        let _ =
+            count_results(lancedb_path, &queries[0], limit, mode, hybrid_oversample, &mut embedder)
+                .await;
+
+        let mut latencies_ms = Vec::with_capacity(queries.len());
+        let mut result_counts = Vec::with_capacity(queries.len());
+        let mut errors = 0;
+        for query in &queries {
+            let start = Instant::now();
+            let outcome =
+                count_results(lancedb_path, query, limit, mode, hybrid_oversample, &mut embedder)
+                    .await;
+            let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+            match outcome {
+                Ok(count) => {
+                    latencies_ms.push(elapsed_ms);
+                    result_counts.push(count);
+                }
+                Err(e) => {
+                    errors += 1;
+                    tracing::warn!("{} query {:?} failed: {}", mode_name(mode), query, e);
+                }
+            }
+        }
+        modes.push(summarize(mode, latencies_ms, &result_counts, errors));
+    }
+
+    let report = BenchReport {
+        queries: queries.len(),
+        limit,
+        hybrid_oversample,
+        modes,
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report);
+    }
+    Ok(())
+}
+
+fn read_queries(path: &Path) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .collect())
+}
+
+const fn mode_name(mode: SearchMode) -> &'static str {
+    match mode {
+        SearchMode::Hybrid => "hybrid",
+        SearchMode::Vector => "vector",
+        SearchMode::Fts => "fts",
+        SearchMode::Phrase => "phrase",
+    }
+}
+
+fn summarize(
+    mode: SearchMode,
+    mut latencies_ms: Vec<f64>,
+    result_counts: &[usize],
+    errors: usize,
+) -> ModeReport {
+    latencies_ms.sort_by(f64::total_cmp);
+    let ran = latencies_ms.len();
+    let mean = |total: f64| if ran == 0 { 0.0 } else { total / ran as f64 };
+
+    ModeReport {
+        mode: mode_name(mode).to_string(),
+        queries: ran + errors,
+        errors,
+        empty: result_counts.iter().filter(|&&count| count == 0).count(),
+        mean_results: mean(result_counts.iter().sum::<usize>() as f64),
+        mean_ms: mean(latencies_ms.iter().sum()),
+        p50_ms: percentile(&latencies_ms, 50.0),
+        p90_ms: percentile(&latencies_ms, 90.0),
+        p99_ms: percentile(&latencies_ms, 99.0),
+        max_ms: latencies_ms.last().copied().unwrap_or(0.0),
+    }
+}
+
+/// Nearest-rank percentile of an ascending slice (0 when empty)
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (pct / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn print_report(report: &BenchReport) {
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Search benchmark: {} queries, limit {}, hybrid oversample {}",
+            report.queries, report.limit, report.hybrid_oversample
+        )
+        .green()
+        .bold()
+    );
+    println!();
+    println!(
+        "  {:<8} {:>9} {:>9} {:>9} {:>9} {:>9} {:>9} {:>7} {:>7}",
+        "mode", "mean ms", "p50 ms", "p90 ms", "p99 ms", "max ms", "results", "empty", "errors"
+    );
+    for row in &report.modes {
+        let errors = format!("{:>7}", row.errors);
+        println!(
+            "  {} {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>7} {}",
+            format!("{:<8}", row.mode).cyan(),
+            row.mean_ms,
+            row.p50_ms,
+            row.p90_ms,
+            row.p99_ms,
+            row.max_ms,
+            row.mean_results,
+            row.empty,
+            if row.errors > 0 { errors.red() } else { errors.normal() }
+        );
+    }
+    println!();
+}
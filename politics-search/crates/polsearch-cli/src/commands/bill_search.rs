@@ -0,0 +1,219 @@
+//! Search bills by semantic similarity and structured filters
+
+use arrow_array::{Array, Float32Array, RecordBatch, StringArray};
+use chrono::NaiveDate;
+use color_eyre::eyre::{eyre, Result};
+use colored::Colorize;
+use futures::TryStreamExt;
+use lancedb::query::{ExecutableQuery, QueryBase};
+use polsearch_core::ContentType;
+use polsearch_db::{BillFilter, BillMetadata};
+use polsearch_pipeline::stages::{verify_embedding_model, TextEmbedder};
+use polsearch_util::truncate;
+use serde::Serialize;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use super::get_database;
+use crate::OutputFormat;
+
+/// Most bill IDs inlined into a `LanceDB` `content_id IN (...)` filter
+const MAX_ID_FILTER: usize = 1000;
+
+/// Candidate multiplier when structured filters are applied after the vector search
+const FILTER_OVERFETCH: usize = 10;
+
+/// Bill search result with metadata
+#[derive(Debug, Serialize)]
+struct BillResult {
+    bill_id: Uuid,
+    identifier: String,
+    congress: i16,
+    chamber: Option<String>,
+    title: Option<String>,
+    sponsor: Option<String>,
+    status: Option<String>,
+    status_date: Option<NaiveDate>,
+    /// Vector distance for semantic results (lower is more similar)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<f32>,
+}
+
+impl BillResult {
+    fn new(bill_id: Uuid, metadata: BillMetadata, score: Option<f32>) -> Self {
+        Self {
+            bill_id,
+            identifier: metadata.identifier,
+            congress: metadata.congress,
+            chamber: metadata.chamber,
+            title: metadata.title,
+            sponsor: metadata.sponsor,
+            status: metadata.status,
+            status_date: metadata.status_date,
+            score,
+        }
+    }
+}
+
+/// Run the bills search command
+///
+/// With a query, bills are ranked by embedding similarity to it (semantic mode);
+/// without one, bills matching the filters are listed by status date (structured mode).
+pub async fn run(
+    query: Option<&str>,
+    filter: &BillFilter<'_>,
+    limit: usize,
+    lancedb_path: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let query = query.map(str::trim).filter(|q| !q.is_empty());
+    if query.is_none() && filter.is_empty() {
+        return Err(eyre!(
+            "Provide a search query or at least one of --congress, --chamber, --sponsor, --status"
+        ));
+    }
+
+    let db = get_database().await?;
+    let results: Vec<BillResult> = match query {
+        Some(query) => {
+            // resolve structured filters to bill IDs up front
+            let allowed = if filter.is_empty() {
+                None
+            } else {
+                let ids: HashSet<Uuid> =
+                    db.bills().get_ids_filtered(filter).await?.into_iter().collect();
+                if ids.is_empty() {
+                    println!("{}", "No bills match the filters".yellow());
+                    return Ok(());
+                }
+                Some(ids)
+            };
+
+            let hits = semantic_search(lancedb_path, query, allowed.as_ref(), limit).await?;
+            let ids: Vec<Uuid> = hits.iter().map(|(id, _)| *id).collect();
+            let mut metadata = db.bills().get_metadata_batch(&ids).await?;
+            hits.into_iter()
+                .filter_map(|(id, distance)| {
+                    metadata
+                        .remove(&id)
+                        .map(|m| BillResult::new(id, m, Some(distance)))
+                })
+                .collect()
+        }
+        None => {
+            let bills = db
+                .bills()
+                .get_filtered(filter, i64::try_from(limit).unwrap_or(i64::MAX))
+                .await?;
+            let ids: Vec<Uuid> = bills.iter().map(|b| b.id).collect();
+            let mut metadata = db.bills().get_metadata_batch(&ids).await?;
+            ids.into_iter()
+                .filter_map(|id| metadata.remove(&id).map(|m| BillResult::new(id, m, None)))
+                .collect()
+        }
+    };
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+        OutputFormat::Text => print_results(&results),
+    }
+    Ok(())
+}
+
+/// Nearest bills to `query`, as (bill ID, distance), optionally restricted to `allowed`
+async fn semantic_search(
+    lancedb_path: &str,
+    query: &str,
+    allowed: Option<&HashSet<Uuid>>,
+    limit: usize,
+) -> Result<Vec<(Uuid, f32)>> {
+    let lancedb = lancedb::connect(lancedb_path).execute().await?;
+    let table = lancedb.open_table("text_embeddings").execute().await?;
+    let mut embedder = TextEmbedder::from_env()?;
+    verify_embedding_model(&table, embedder.model()).await?;
+    let query_embedding = embedder.embed(query)?;
+
+    // small ID sets go into the LanceDB filter; large ones are applied afterwards
+    let mut filter = format!("content_type = '{}'", ContentType::Bill);
+    let mut fetch_count = limit;
+    let post_filter = match allowed {
+        Some(ids) if ids.len() <= MAX_ID_FILTER => {
+            let id_strs: Vec<String> = ids.iter().map(|id| format!("'{id}'")).collect();
+            filter = format!("{filter} AND content_id IN ({})", id_strs.join(", "));
+            None
+        }
+        Some(ids) => {
+            fetch_count *= FILTER_OVERFETCH;
+            Some(ids)
+        }
+        None => None,
+    };
+
+    let batches: Vec<RecordBatch> = table
+        .vector_search(query_embedding)?
+        .only_if(filter)
+        .select(lancedb::query::Select::columns(&["content_id"]))
+        .limit(fetch_count)
+        .execute()
+        .await?
+        .try_collect()
+        .await?;
+
+    let mut hits = Vec::new();
+    for batch in &batches {
+        let content_ids = batch
+            .column_by_name("content_id")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+            .ok_or_else(|| eyre!("Missing content_id column"))?;
+        let distances = batch
+            .column_by_name("_distance")
+            .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
+
+        for i in 0..batch.num_rows() {
+            let Ok(id) = Uuid::parse_str(content_ids.value(i)) else {
+                continue;
+            };
+            if post_filter.is_some_and(|ids| !ids.contains(&id)) {
+                continue;
+            }
+            hits.push((id, distances.map_or(0.0, |d| d.value(i))));
+        }
+    }
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+fn print_results(results: &[BillResult]) {
+    if results.is_empty() {
+        println!("{}", "No bills found".yellow());
+        return;
+    }
+
+    println!();
+    for (i, result) in results.iter().enumerate() {
+        let score = result
+            .score
+            .map_or_else(String::new, |d| format!(" ({d:.3})").dimmed().to_string());
+        let status = match (&result.status, result.status_date) {
+            (Some(status), Some(date)) => format!("{status} ({date})"),
+            (Some(status), None) => status.clone(),
+            (None, _) => "Unknown status".to_string(),
+        };
+        println!(
+            "{}{} {} | Congress {} | {}",
+            format!("[{}]", i + 1).yellow(),
+            score,
+            result.identifier.green(),
+            result.congress,
+            status.cyan()
+        );
+        if let Some(title) = &result.title {
+            println!("    {}", truncate(title, 100));
+        }
+        if let Some(sponsor) = &result.sponsor {
+            println!("    {}", format!("Sponsor: {sponsor}").dimmed());
+        }
+    }
+    println!();
+}
+
@@ -5,9 +5,16 @@ use color_eyre::eyre::{Result, eyre};
 use colored::Colorize;
 use futures::TryStreamExt;
 use lancedb::index::scalar::FullTextSearchQuery;
-use lancedb::query::{ExecutableQuery, QueryBase};
-use polsearch_pipeline::stages::TextEmbedder;
+use lancedb::query::{ExecutableQuery, QueryBase, Select};
+use polsearch_pipeline::stages::{verify_embedding_model, TextEmbedder, FTS_TABLE_NAME};
+use polsearch_util::spelling::{format_vocab, terms};
 use polsearch_util::truncate;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use super::saved_search::{is_saved_search_schema, print_saved_search};
 
 /// List all tables with row counts
 pub async fn tables(lancedb_path: &str) -> Result<()> {
@@ -46,6 +53,9 @@ pub async fn show(lancedb_path: &str, table_name: &str, limit: usize) -> Result<
         "text_embeddings" => print_text_embeddings(&batches)?,
         "speaker_embeddings" => print_speaker_embeddings(&batches)?,
         "speaker_centroids" => print_speaker_centroids(&batches)?,
+        _ if is_saved_search_schema(table.schema().await?.as_ref()) => {
+            print_saved_search(&batches)?;
+        }
         _ => return Err(eyre!("Unknown table: {}", table_name)),
     }
 
@@ -60,7 +70,8 @@ pub async fn search(lancedb_path: &str, query: &str, limit: usize, mode: &str) -
     let batches: Vec<RecordBatch> = match mode {
         "vector" => {
             println!("{} \"{}\"", "Vector search for:".cyan(), query);
-            let mut embedder = TextEmbedder::new()?;
+            let mut embedder = TextEmbedder::from_env()?;
+            verify_embedding_model(&table, embedder.model()).await?;
             let query_embedding = embedder.embed(query)?;
 
             let stream = table
@@ -82,7 +93,8 @@ pub async fn search(lancedb_path: &str, query: &str, limit: usize, mode: &str) -
         }
         "hybrid" => {
             println!("{} \"{}\"", "Hybrid search for:".cyan(), query);
-            let mut embedder = TextEmbedder::new()?;
+            let mut embedder = TextEmbedder::from_env()?;
+            verify_embedding_model(&table, embedder.model()).await?;
             let query_embedding = embedder.embed(query)?;
 
             let stream = table
@@ -111,6 +123,145 @@ pub async fn search(lancedb_path: &str, query: &str, limit: usize, mode: &str) -
     Ok(())
 }
 
+/// Build the spelling vocabulary used for "did you mean" suggestions
+///
+/// Counts terms in the full-text table's `text` column and writes those seen at
+/// least `min_count` times to `output` as `term<TAB>count` lines.
+pub async fn build_vocab(lancedb_path: &str, output: &str, min_count: u64) -> Result<()> {
+    use arrow_array::StringArray;
+
+    let db = lancedb::connect(lancedb_path).execute().await?;
+    let table = db.open_table(FTS_TABLE_NAME).execute().await?;
+    println!(
+        "{}",
+        format!("Building vocabulary from {FTS_TABLE_NAME}...").cyan()
+    );
+
+    let mut stream = table
+        .query()
+        .select(Select::columns(&["text"]))
+        .execute()
+        .await?;
+
+    let mut vocab: HashMap<String, u64> = HashMap::new();
+    let mut rows = 0;
+    while let Some(batch) = stream.try_next().await? {
+        rows += batch.num_rows();
+        let Some(texts) = batch
+            .column_by_name("text")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        else {
+            continue;
+        };
+        for text in texts.iter().flatten() {
+            for term in terms(text) {
+                *vocab.entry(term).or_default() += 1;
+            }
+        }
+    }
+
+    let total_terms = vocab.len();
+    vocab.retain(|_, count| *count >= min_count);
+
+    if let Some(parent) = Path::new(output).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(output, format_vocab(&vocab))?;
+
+    println!(
+        "{} {} terms ({} distinct, {} below --min-count) from {} rows to {}",
+        "Wrote".green(),
+        vocab.len(),
+        total_terms,
+        total_terms - vocab.len(),
+        rows,
+        output
+    );
+
+    Ok(())
+}
+
+/// Compare tables, content type counts, and content IDs against another `LanceDB` dataset
+///
+/// The other dataset is treated as the reference: "added" IDs exist only in
+/// `lancedb_path`, "removed" IDs exist only in `other_path`.
+pub async fn diff(lancedb_path: &str, other_path: &str, show_ids: bool) -> Result<()> {
+    let current = lancedb::connect(lancedb_path).execute().await?;
+    let other = lancedb::connect(other_path).execute().await?;
+
+    let current_tables: BTreeSet<String> = current.table_names().execute().await?.into_iter().collect();
+    let other_tables: BTreeSet<String> = other.table_names().execute().await?.into_iter().collect();
+
+    println!(
+        "{}",
+        format!("Comparing {lancedb_path} against {other_path}").cyan().bold()
+    );
+
+    for name in current_tables.difference(&other_tables) {
+        println!("  {} {}", "+".green(), format!("{name} (only in current)").green());
+    }
+    for name in other_tables.difference(&current_tables) {
+        println!("  {} {}", "-".red(), format!("{name} (only in other)").red());
+    }
+
+    for name in current_tables.intersection(&other_tables) {
+        let ours = summarize_table(&current, name).await?;
+        let theirs = summarize_table(&other, name).await?;
+
+        println!();
+        println!(
+            "{} {} -> {} rows {}",
+            format!("{name}:").cyan().bold(),
+            theirs.rows,
+            ours.rows,
+            format_delta(ours.rows, theirs.rows)
+        );
+
+        let content_types: BTreeSet<&String> = ours
+            .content_types
+            .keys()
+            .chain(theirs.content_types.keys())
+            .collect();
+        for content_type in content_types {
+            let ours_count = ours.content_types.get(content_type).copied().unwrap_or(0);
+            let theirs_count = theirs.content_types.get(content_type).copied().unwrap_or(0);
+            println!(
+                "  {}: {} -> {} {}",
+                content_type,
+                theirs_count,
+                ours_count,
+                format_delta(ours_count, theirs_count)
+            );
+        }
+
+        if let (Some(ours_ids), Some(theirs_ids)) = (&ours.content_ids, &theirs.content_ids) {
+            let mut added: Vec<&String> = ours_ids.difference(theirs_ids).collect();
+            let mut removed: Vec<&String> = theirs_ids.difference(ours_ids).collect();
+            let common = ours_ids.intersection(theirs_ids).count();
+
+            println!(
+                "  content IDs: {} common, {} added, {} removed",
+                common,
+                added.len().to_string().green(),
+                removed.len().to_string().red()
+            );
+
+            if show_ids {
+                added.sort();
+                removed.sort();
+                for id in added {
+                    println!("    {} {}", "+".green(), id);
+                }
+                for id in removed {
+                    println!("    {} {}", "-".red(), id);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn print_search_results(batches: &[RecordBatch]) -> Result<()> {
     use arrow_array::{Float32Array, Int32Array, StringArray};
 
@@ -279,3 +430,73 @@ fn print_speaker_centroids(batches: &[RecordBatch]) -> Result<()> {
 
     Ok(())
 }
+
+/// Row count, per-content-type counts, and distinct content IDs for one table
+struct TableSummary {
+    rows: usize,
+    content_types: BTreeMap<String, usize>,
+    content_ids: Option<HashSet<String>>,
+}
+
+/// Summarize a table by streaming only its `content_type` and `content_id` columns
+async fn summarize_table(db: &lancedb::Connection, name: &str) -> Result<TableSummary> {
+    use arrow_array::StringArray;
+
+    let table = db.open_table(name).execute().await?;
+    let rows = table.count_rows(None).await?;
+    let schema = table.schema().await?;
+
+    let columns: Vec<&str> = ["content_type", "content_id"]
+        .into_iter()
+        .filter(|c| schema.field_with_name(c).is_ok())
+        .collect();
+
+    let mut summary = TableSummary {
+        rows,
+        content_types: BTreeMap::new(),
+        content_ids: columns.contains(&"content_id").then(HashSet::new),
+    };
+    if columns.is_empty() {
+        return Ok(summary);
+    }
+
+    let mut stream = table
+        .query()
+        .select(Select::columns(&columns))
+        .execute()
+        .await?;
+
+    while let Some(batch) = stream.try_next().await? {
+        let content_types = batch
+            .column_by_name("content_type")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+        let content_ids = batch
+            .column_by_name("content_id")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>());
+
+        for i in 0..batch.num_rows() {
+            if let Some(types) = content_types {
+                *summary
+                    .content_types
+                    .entry(types.value(i).to_string())
+                    .or_default() += 1;
+            }
+            if let (Some(ids), Some(set)) = (content_ids, summary.content_ids.as_mut()) {
+                if !set.contains(ids.value(i)) {
+                    set.insert(ids.value(i).to_string());
+                }
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Format the change from `other` to `current` as a colored delta
+fn format_delta(current: usize, other: usize) -> String {
+    match current.cmp(&other) {
+        Ordering::Greater => format!("(+{})", current - other).green().to_string(),
+        Ordering::Less => format!("(-{})", other - current).red().to_string(),
+        Ordering::Equal => "(=)".dimmed().to_string(),
+    }
+}
@@ -1,15 +1,12 @@
 //! Embed congressional vote data for semantic search
 
-use arrow_array::{
-    types::Float32Type, Array, FixedSizeListArray, Int32Array, RecordBatch, RecordBatchIterator,
-    StringArray,
-};
-use arrow_schema::{DataType, Field, Schema};
+use arrow_array::RecordBatch;
 use color_eyre::eyre::Result;
 use colored::Colorize;
-use polsearch_core::RollCallVote;
-use polsearch_pipeline::stages::TextEmbedder;
-use std::sync::Arc;
+use polsearch_core::{ContentType, RollCallVote};
+use polsearch_pipeline::stages::{
+    append_text_embeddings, text_embeddings_batch, SegmentText, TextEmbedder,
+};
 use std::time::Instant;
 
 use super::get_database;
@@ -82,7 +79,7 @@ pub async fn run(
     }
 
     let lancedb = lancedb::connect(lancedb_path).execute().await?;
-    let mut embedder = TextEmbedder::new()?;
+    let mut embedder = TextEmbedder::from_env()?;
     let mut stats = EmbedStats::default();
 
     // Fetch votes in batches for pagination
@@ -189,7 +186,7 @@ async fn check_vote_embedded(lancedb: &lancedb::Connection, vote_id: &str) -> Re
         Err(_) => return Ok(false),
     };
 
-    let filter = format!("content_type = 'vote' AND id = '{vote_id}'");
+    let filter = format!("content_type = '{}' AND id = '{vote_id}'", ContentType::Vote);
 
     use futures::TryStreamExt;
     use lancedb::query::{ExecutableQuery, QueryBase};
@@ -243,76 +240,24 @@ async fn embed_and_write_batch(
     // Generate embeddings
     let embeddings = embedder.embed_batch(&text_refs)?;
 
-    // Build Arrow arrays
-    let schema = Arc::new(Schema::new(vec![
-        Field::new("id", DataType::Utf8, false),
-        Field::new("content_type", DataType::Utf8, false),
-        Field::new("content_id", DataType::Utf8, false),
-        Field::new("statement_id", DataType::Utf8, true),
-        Field::new("segment_index", DataType::Int32, false),
-        Field::new("start_time_ms", DataType::Int32, false),
-        Field::new("end_time_ms", DataType::Int32, false),
-        Field::new("text", DataType::Utf8, false),
-        Field::new(
-            "vector",
-            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), 384),
-            false,
-        ),
-    ]));
-
-    let ids: Vec<String> = votes.iter().map(|v| v.id.to_string()).collect();
-    let content_types: Vec<&str> = vec!["vote"; votes.len()];
-    // use vote UUID for content_id (matches hearings/floor speeches pattern)
-    let content_ids: Vec<String> = votes.iter().map(|v| v.id.to_string()).collect();
-    let statement_ids: Vec<Option<String>> = vec![None; votes.len()];
-    let segment_indices: Vec<i32> = vec![0; votes.len()];
-    let start_times: Vec<i32> = vec![0; votes.len()];
-    let end_times: Vec<i32> = vec![0; votes.len()];
-
-    // Create embedding array
-    let embedding_lists: Vec<Option<Vec<Option<f32>>>> = embeddings
+    // use vote UUID for content_id (matches hearings/floor speeches pattern);
+    // votes aren't attributed to a single speaker or printed in the record
+    let segments: Vec<SegmentText> = votes
         .iter()
-        .map(|e| Some(e.iter().copied().map(Some).collect()))
+        .zip(texts)
+        .map(|(vote, text)| SegmentText {
+            segment_id: vote.id,
+            content_id: vote.id,
+            statement_id: None,
+            segment_index: 0,
+            text,
+            party: None,
+        })
         .collect();
-    let vector_array =
-        FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(embedding_lists, 384);
-
-    let batch = RecordBatch::try_new(
-        schema.clone(),
-        vec![
-            Arc::new(StringArray::from(ids)),
-            Arc::new(StringArray::from(content_types)),
-            Arc::new(StringArray::from(content_ids)),
-            Arc::new(StringArray::from(
-                statement_ids
-                    .iter()
-                    .map(|s| s.as_deref())
-                    .collect::<Vec<_>>(),
-            )),
-            Arc::new(Int32Array::from(segment_indices)),
-            Arc::new(Int32Array::from(start_times)),
-            Arc::new(Int32Array::from(end_times)),
-            Arc::new(StringArray::from(texts)),
-            Arc::new(vector_array) as Arc<dyn Array>,
-        ],
-    )?;
-
-    // Open or create the table
-    let table = match lancedb.open_table("text_embeddings").execute().await {
-        Ok(t) => t,
-        Err(_) => {
-            println!("{}", "Creating text_embeddings table...".cyan());
-            let batches =
-                RecordBatchIterator::new(vec![Ok(batch.clone())].into_iter(), schema.clone());
-            lancedb
-                .create_table("text_embeddings", Box::new(batches))
-                .execute()
-                .await?
-        }
-    };
 
-    let batches = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
-    table.add(Box::new(batches)).execute().await?;
+    let model = embedder.model();
+    let batch = text_embeddings_batch(model, ContentType::Vote, &segments, &embeddings, None)?;
+    append_text_embeddings(lancedb, model, batch).await?;
 
     stats.votes_processed += votes.len();
     stats.embeddings_created += votes.len();
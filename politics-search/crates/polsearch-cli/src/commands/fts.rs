@@ -2,26 +2,34 @@
 
 use color_eyre::eyre::Result;
 use colored::Colorize;
-use polsearch_pipeline::stages::FtsIngester;
+use polsearch_pipeline::stages::sampling::collect_json_files;
+use polsearch_pipeline::stages::{FtsIngester, Sample};
 use std::path::Path;
 use std::time::Instant;
 
 use super::get_database;
 
 /// Run the FTS ingest command
+#[allow(clippy::too_many_arguments)]
 pub async fn ingest(
     hearings_path: Option<&str>,
     speeches_path: Option<&str>,
     votes: bool,
+    bills: bool,
     limit: Option<usize>,
+    sample: Option<Sample>,
     force: bool,
     dry_run: bool,
+    include_speaker: bool,
+    dedup_threshold: Option<f64>,
+    max_file_size: Option<u64>,
     lancedb_path: &str,
 ) -> Result<()> {
-    if hearings_path.is_none() && speeches_path.is_none() && !votes {
+    if hearings_path.is_none() && speeches_path.is_none() && !votes && !bills {
         println!(
             "{}",
-            "No sources specified. Use --hearings-path, --speeches-path, and/or --votes".yellow()
+            "No sources specified. Use --hearings-path, --speeches-path, --votes, and/or --bills"
+                .yellow()
         );
         return Ok(());
     }
@@ -29,26 +37,38 @@ pub async fn ingest(
     if dry_run {
         println!("{}", "[DRY RUN] Would process the following:".yellow());
         if let Some(path) = hearings_path {
-            let count = count_json_files(path);
-            let limited = limit.map_or(count, |l| l.min(count));
-            println!("  Hearings: {} files from {}", limited, path);
+            let count = count_json_files(path, sample, limit);
+            println!("  Hearings: {} files from {}", count, path);
         }
         if let Some(path) = speeches_path {
-            let count = count_json_files(path);
-            let limited = limit.map_or(count, |l| l.min(count));
-            println!("  Floor speeches: {} files from {}", limited, path);
+            let count = count_json_files(path, sample, limit);
+            println!("  Floor speeches: {} files from {}", count, path);
         }
         if votes {
             println!("  Votes: from PostgreSQL");
         }
+        if bills {
+            println!("  Bills: from PostgreSQL");
+        }
         if force {
             println!("  {} Force mode - will re-process existing content", "[!]".yellow());
         }
+        if include_speaker {
+            println!("  Speaker names: stored in the speaker_name column");
+        }
+        if let Some(threshold) = dedup_threshold {
+            println!("  Segment dedup: similarity >= {threshold}");
+        }
         return Ok(());
     }
 
     let db = get_database().await?;
-    let mut ingester = FtsIngester::new(db, lancedb_path, force).await?;
+    let mut ingester = FtsIngester::new(db, lancedb_path, force)
+        .await?
+        .with_sample(sample)
+        .with_speaker_names(include_speaker)
+        .with_segment_dedup(dedup_threshold)
+        .with_max_file_size(max_file_size);
 
     println!("{}", "Starting FTS ingestion (text-only, no embeddings)...".cyan());
     if force {
@@ -72,11 +92,15 @@ pub async fn ingest(
             let stats = ingester.ingest_hearings_directory(hearings_path, limit).await?;
             total_segments += stats.segments_created;
             println!(
-                "  {} hearings processed, {} skipped, {} segments",
+                "  {} hearings processed, {} skipped, {} oversized, {} segments",
                 stats.hearings_processed.to_string().green(),
                 stats.hearings_skipped.to_string().yellow(),
+                stats.files_skipped_oversized.to_string().yellow(),
                 stats.segments_created.to_string().cyan()
             );
+            if dedup_threshold.is_some() {
+                println!("  {} segments deduped", stats.segments_deduped.to_string().yellow());
+            }
         }
     }
 
@@ -94,11 +118,15 @@ pub async fn ingest(
             let stats = ingester.ingest_speeches_directory(speeches_path, limit).await?;
             total_segments += stats.segments_created;
             println!(
-                "  {} speeches processed, {} skipped, {} segments",
+                "  {} speeches processed, {} skipped, {} oversized, {} segments",
                 stats.speeches_processed.to_string().green(),
                 stats.speeches_skipped.to_string().yellow(),
+                stats.files_skipped_oversized.to_string().yellow(),
                 stats.segments_created.to_string().cyan()
             );
+            if dedup_threshold.is_some() {
+                println!("  {} segments deduped", stats.segments_deduped.to_string().yellow());
+            }
         }
     }
 
@@ -115,6 +143,19 @@ pub async fn ingest(
         );
     }
 
+    // Ingest bill titles and summaries from PostgreSQL (if requested)
+    if bills {
+        println!();
+        println!("{}", "Ingesting bills from PostgreSQL...".cyan());
+        let bill_stats = ingester.ingest_bills(limit).await?;
+        total_segments += bill_stats.segments_created;
+        println!(
+            "  {} bills processed, {} skipped",
+            bill_stats.bills_processed.to_string().green(),
+            bill_stats.bills_skipped.to_string().yellow()
+        );
+    }
+
     let duration = start.elapsed();
     println!();
     println!("{}", "FTS ingestion complete:".green().bold());
@@ -147,19 +188,7 @@ pub async fn clear(lancedb_path: &str) -> Result<()> {
     Ok(())
 }
 
-/// Count JSON files in a directory
-fn count_json_files(path: &str) -> usize {
-    let path = Path::new(path);
-    if !path.is_dir() {
-        return 0;
-    }
-
-    std::fs::read_dir(path)
-        .map(|entries| {
-            entries
-                .filter_map(Result::ok)
-                .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
-                .count()
-        })
-        .unwrap_or(0)
+/// Count JSON files that would be selected from a directory
+fn count_json_files(path: &str, sample: Option<Sample>, limit: Option<usize>) -> usize {
+    collect_json_files(Path::new(path), sample, limit).map_or(0, |files| files.len())
 }
@@ -3,11 +3,12 @@
 use color_eyre::eyre::Result;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
+use polsearch_core::ContentType;
 use polsearch_pipeline::stages::{FloorSpeechIngester, HearingIngester};
 use std::fs;
 use std::path::Path;
 
-use super::get_database;
+use super::{acquire_ingest_lock, get_database};
 
 /// Count JSON files in a directory
 fn count_json_files(path: &Path) -> usize {
@@ -97,11 +98,13 @@ pub async fn run(
             pb.set_message(format!("{} hearings", year));
 
             let db = get_database().await?;
+            let lock = acquire_ingest_lock(&db, ContentType::Hearing, hearings_dir).await?;
             let mut ingester = HearingIngester::new(db, lancedb_path, force, Some(year)).await?;
 
             let stats = ingester
                 .ingest_directory_with_progress(hearings_dir, None, Some(&pb))
                 .await?;
+            lock.release().await?;
 
             total_hearings += stats.hearings_created;
             total_embeddings += stats.embeddings_created;
@@ -112,12 +115,14 @@ pub async fn run(
             pb.set_message(format!("{} speeches", year));
 
             let db = get_database().await?;
+            let lock = acquire_ingest_lock(&db, ContentType::FloorSpeech, speeches_dir).await?;
             let mut ingester =
                 FloorSpeechIngester::new(db, lancedb_path, force, Some(year)).await?;
 
             let stats = ingester
                 .ingest_directory_with_progress(speeches_dir, None, Some(&pb))
                 .await?;
+            lock.release().await?;
 
             total_speeches += stats.speeches_created;
             total_embeddings += stats.embeddings_created;
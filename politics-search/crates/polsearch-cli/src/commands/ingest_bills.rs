@@ -0,0 +1,354 @@
+//! Ingest bill data and embed bill titles and summaries for semantic search
+
+use arrow_array::{Array, RecordBatch, StringArray};
+use chrono::NaiveDate;
+use color_eyre::eyre::{eyre, Result};
+use colored::Colorize;
+use futures::TryStreamExt;
+use lancedb::query::{ExecutableQuery, QueryBase};
+use polsearch_core::{Bill, ContentType};
+use polsearch_pipeline::stages::{
+    append_text_embeddings, content_id_filter, text_embeddings_batch, SegmentText, TextEmbedder,
+};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use walkdir::WalkDir;
+
+use super::get_database;
+
+/// Bills embedded per batch
+const EMBED_BATCH_SIZE: usize = 64;
+
+/// Statistics for bill ingestion
+#[derive(Debug, Default)]
+pub struct IngestStats {
+    pub files_processed: usize,
+    pub files_skipped: usize,
+    pub bills_saved: usize,
+    pub bills_embedded: usize,
+    pub bills_already_embedded: usize,
+}
+
+/// JSON structure for bill `data.json` files from the congress scraper
+#[derive(Debug, Deserialize)]
+struct BillJson {
+    bill_type: String,
+    number: String,
+    congress: String,
+    official_title: Option<String>,
+    short_title: Option<String>,
+    popular_title: Option<String>,
+    sponsor: Option<SponsorJson>,
+    status: Option<String>,
+    status_at: Option<String>,
+    summary: Option<SummaryJson>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SponsorJson {
+    name: String,
+    title: Option<String>,
+    state: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SummaryJson {
+    text: Option<String>,
+}
+
+/// Run the ingest bills command
+pub async fn run(
+    path: &str,
+    limit: Option<usize>,
+    force: bool,
+    dry_run: bool,
+    lancedb_path: &str,
+) -> Result<()> {
+    let bills_path = Path::new(path);
+    if !bills_path.exists() {
+        return Err(eyre!("Bills directory not found: {}", path));
+    }
+
+    let files = find_bill_files(bills_path, limit);
+    if dry_run {
+        println!(
+            "{}",
+            format!("[DRY RUN] Would process {} bill files in {}", files.len(), path).yellow()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Ingesting {} bill files from {}...", files.len(), path).cyan()
+    );
+    if force {
+        println!("{}", "Force mode enabled - will re-embed existing bills".yellow());
+    }
+
+    let start = Instant::now();
+    let db = get_database().await?;
+    let mut stats = IngestStats::default();
+
+    let mut bills = Vec::new();
+    for file in &files {
+        match parse_bill_file(file) {
+            Ok(bill) => {
+                let id = db.bills().upsert(&bill).await?;
+                bills.push(Bill { id, ..bill });
+                stats.files_processed += 1;
+                stats.bills_saved += 1;
+            }
+            Err(e) => {
+                eprintln!("{}", format!("Skipping {}: {e}", file.display()).yellow());
+                stats.files_skipped += 1;
+            }
+        }
+    }
+
+    let lancedb = lancedb::connect(lancedb_path).execute().await?;
+    let embedded = if force {
+        HashSet::new()
+    } else {
+        embedded_bill_ids(&lancedb).await?
+    };
+    let (done, to_embed): (Vec<&Bill>, Vec<&Bill>) = bills
+        .iter()
+        .filter(|bill| bill.search_text().is_some())
+        .partition(|bill| embedded.contains(&bill.id.to_string()));
+    stats.bills_already_embedded = done.len();
+
+    if !to_embed.is_empty() {
+        println!("{}", format!("Embedding {} bills...", to_embed.len()).cyan());
+        let mut embedder = TextEmbedder::from_env()?;
+        for chunk in to_embed.chunks(EMBED_BATCH_SIZE) {
+            embed_and_write_batch(&lancedb, &mut embedder, chunk, force).await?;
+            stats.bills_embedded += chunk.len();
+            println!(
+                "  Embedded {}/{} bills",
+                stats.bills_embedded.to_string().cyan(),
+                to_embed.len().to_string().dimmed()
+            );
+        }
+    }
+
+    println!();
+    println!("{}", "Ingestion complete:".green().bold());
+    println!("  Files processed:  {}", stats.files_processed.to_string().cyan());
+    println!("  Files skipped:    {}", stats.files_skipped.to_string().yellow());
+    println!("  Bills saved:      {}", stats.bills_saved.to_string().cyan());
+    println!("  Bills embedded:   {}", stats.bills_embedded.to_string().cyan());
+    println!(
+        "  Already embedded: {}",
+        stats.bills_already_embedded.to_string().yellow()
+    );
+    println!("  Time elapsed:     {:.1}s", start.elapsed().as_secs_f64());
+
+    Ok(())
+}
+
+/// Find `data.json` bill files, sorted for a stable processing order
+fn find_bill_files(dir: &Path, limit: Option<usize>) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter(|e| e.file_type().is_file() && e.file_name() == "data.json")
+        .map(walkdir::DirEntry::into_path)
+        .collect();
+    files.sort();
+    if let Some(limit) = limit {
+        files.truncate(limit);
+    }
+    files
+}
+
+/// Parse a bill `data.json` file into a `Bill`
+fn parse_bill_file(path: &Path) -> Result<Bill> {
+    let content = std::fs::read_to_string(path)?;
+    let json: BillJson = serde_json::from_str(&content)?;
+
+    let congress: i16 = json
+        .congress
+        .parse()
+        .map_err(|_| eyre!("invalid congress '{}'", json.congress))?;
+    let number: i32 = json
+        .number
+        .parse()
+        .map_err(|_| eyre!("invalid bill number '{}'", json.number))?;
+    let title = json
+        .short_title
+        .or(json.popular_title)
+        .or(json.official_title)
+        .filter(|t| !t.trim().is_empty());
+
+    let mut bill = Bill::new(congress, json.bill_type.to_lowercase(), number, title);
+    if let Some(summary) = json
+        .summary
+        .and_then(|s| s.text)
+        .filter(|s| !s.trim().is_empty())
+    {
+        bill = bill.with_summary(summary);
+    }
+    if let Some(sponsor) = json.sponsor {
+        bill = bill.with_sponsor(format_sponsor(&sponsor));
+    }
+    if let Some(status) = json.status {
+        // status_at is a date or an RFC 3339 timestamp; the date prefix is enough
+        let status_date = json
+            .status_at
+            .as_deref()
+            .and_then(|s| s.get(..10))
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+        bill = bill.with_status(status, status_date);
+    }
+    Ok(bill)
+}
+
+/// Sponsor display name, e.g. "Rep. Adam Smith [WA]"
+fn format_sponsor(sponsor: &SponsorJson) -> String {
+    let mut name = sponsor.name.clone();
+    if let Some(title) = &sponsor.title {
+        name = format!("{title} {name}");
+    }
+    if let Some(state) = &sponsor.state {
+        name = format!("{name} [{state}]");
+    }
+    name
+}
+
+/// IDs of bills that already have an embedding
+async fn embedded_bill_ids(lancedb: &lancedb::Connection) -> Result<HashSet<String>> {
+    let Ok(table) = lancedb.open_table("text_embeddings").execute().await else {
+        return Ok(HashSet::new());
+    };
+
+    let batches: Vec<RecordBatch> = table
+        .query()
+        .only_if(format!("content_type = '{}'", ContentType::Bill))
+        .select(lancedb::query::Select::columns(&["content_id"]))
+        .execute()
+        .await?
+        .try_collect()
+        .await?;
+
+    let mut ids = HashSet::new();
+    for batch in &batches {
+        let Some(content_ids) = batch
+            .column_by_name("content_id")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        else {
+            continue;
+        };
+        for i in 0..batch.num_rows() {
+            if !content_ids.is_null(i) {
+                ids.insert(content_ids.value(i).to_string());
+            }
+        }
+    }
+    Ok(ids)
+}
+
+/// Embed a batch of bills and write them to `LanceDB`, replacing old rows when `replace` is set
+async fn embed_and_write_batch(
+    lancedb: &lancedb::Connection,
+    embedder: &mut TextEmbedder,
+    bills: &[&Bill],
+    replace: bool,
+) -> Result<()> {
+    // one row per bill, keyed by the bill UUID like votes
+    let segments: Vec<SegmentText> = bills
+        .iter()
+        .filter_map(|bill| {
+            Some(SegmentText {
+                segment_id: bill.id,
+                content_id: bill.id,
+                statement_id: None,
+                segment_index: 0,
+                text: bill.search_text()?,
+                party: None,
+            })
+        })
+        .collect();
+    let text_refs: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+    let embeddings = embedder.embed_batch(&text_refs)?;
+
+    if replace {
+        if let Ok(table) = lancedb.open_table("text_embeddings").execute().await {
+            let ids: Vec<String> = segments.iter().map(|s| s.content_id.to_string()).collect();
+            table
+                .delete(&format!(
+                    "content_type = '{}' AND {}",
+                    ContentType::Bill,
+                    content_id_filter(&ids)
+                ))
+                .await?;
+        }
+    }
+
+    let model = embedder.model();
+    let batch = text_embeddings_batch(model, ContentType::Bill, &segments, &embeddings, None)?;
+    append_text_embeddings(lancedb, model, batch).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_json(name: &str, json: &str) -> Result<Bill> {
+        let path = std::env::temp_dir()
+            .join(format!("polsearch-bill-{name}-{}.json", std::process::id()));
+        std::fs::write(&path, json)?;
+        let bill = parse_bill_file(&path);
+        std::fs::remove_file(&path)?;
+        bill
+    }
+
+    #[test]
+    fn test_parse_bill_file() -> Result<()> {
+        let bill = parse_json(
+            "full",
+            r#"{
+                "bill_type": "HR", "number": "1234", "congress": "118",
+                "official_title": "To amend the Clean Water Act.",
+                "short_title": "Clean Water Act",
+                "sponsor": {"name": "Adam Smith", "title": "Rep", "state": "WA"},
+                "status": "ENACTED:SIGNED", "status_at": "2024-03-01T12:00:00-05:00",
+                "summary": {"text": "Amends the act."}
+            }"#,
+        )?;
+        assert_eq!((bill.congress, bill.bill_type.as_str(), bill.bill_number), (118, "hr", 1234));
+        assert_eq!(bill.title.as_deref(), Some("Clean Water Act"));
+        assert_eq!(bill.summary.as_deref(), Some("Amends the act."));
+        assert_eq!(bill.sponsor.as_deref(), Some("Rep Adam Smith [WA]"));
+        assert_eq!(bill.status.as_deref(), Some("ENACTED:SIGNED"));
+        assert_eq!(bill.status_date, NaiveDate::from_ymd_opt(2024, 3, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_bill_file_skips_blank_titles() -> Result<()> {
+        let bill = parse_json(
+            "blank",
+            r#"{
+                "bill_type": "s", "number": "7", "congress": "119",
+                "short_title": " ", "official_title": "A bill to do things.",
+                "summary": {"text": ""}
+            }"#,
+        )?;
+        assert_eq!(bill.title.as_deref(), Some("A bill to do things."));
+        assert_eq!(bill.summary, None);
+        assert_eq!(bill.status, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_bill_file_rejects_bad_number() {
+        let result = parse_json(
+            "bad",
+            r#"{"bill_type": "hr", "number": "12a", "congress": "118"}"#,
+        );
+        assert!(result.is_err());
+    }
+}
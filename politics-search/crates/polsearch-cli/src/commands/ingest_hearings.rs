@@ -2,16 +2,26 @@
 
 use color_eyre::eyre::Result;
 use colored::Colorize;
-use polsearch_pipeline::stages::HearingIngester;
+use polsearch_core::ContentType;
+use polsearch_pipeline::stages::sampling::collect_json_files;
+use polsearch_pipeline::stages::{HearingIngester, Sample, SpeakerPartyResolver};
 use std::path::Path;
 
-use super::get_database;
+use super::{acquire_ingest_lock, get_database};
 
 /// Run the ingest hearings command
 pub async fn run(
     path: &str,
     limit: Option<usize>,
+    sample: Option<Sample>,
     force: bool,
+    merge_statements: bool,
+    normalize_speaker: bool,
+    dedup_threshold: Option<f64>,
+    context_embed: Option<usize>,
+    legislators: Option<&str>,
+    max_file_size: Option<u64>,
+    compact_progress: bool,
     dry_run: bool,
     validate: bool,
     year: Option<i32>,
@@ -33,7 +43,10 @@ pub async fn run(
         );
 
         let db = get_database().await?;
-        let ingester = HearingIngester::new(db, lancedb_path, force, year).await?;
+        let ingester = HearingIngester::new(db, lancedb_path, force, year)
+            .await?
+            .with_sample(sample)
+            .with_max_file_size(max_file_size);
         let (valid, invalid) = ingester.validate_directory(transcript_path, limit)?;
 
         println!();
@@ -50,18 +63,7 @@ pub async fn run(
             format!("[DRY RUN] Would process files in {}", path).yellow()
         );
 
-        let mut count = 0;
-        for entry in std::fs::read_dir(transcript_path)? {
-            let entry = entry?;
-            if entry.path().extension().is_some_and(|e| e == "json") {
-                count += 1;
-                if let Some(max) = limit {
-                    if count > max {
-                        break;
-                    }
-                }
-            }
-        }
+        let count = collect_json_files(transcript_path, sample, limit)?.len();
 
         println!(
             "Would process {} JSON files{}",
@@ -81,10 +83,58 @@ pub async fn run(
     if let Some(y) = year {
         println!("{}", format!("Filtering to year {}", y).cyan());
     }
+    if merge_statements {
+        println!(
+            "{}",
+            "Merging consecutive same-speaker statements".cyan()
+        );
+    }
+    if normalize_speaker {
+        println!("{}", "Normalizing speaker labels".cyan());
+    }
+    if let Some(threshold) = dedup_threshold {
+        println!(
+            "{}",
+            format!("Deduplicating segments (similarity >= {threshold})").cyan()
+        );
+    }
+    if let Some(words) = context_embed {
+        println!(
+            "{}",
+            format!("Embedding segments with speaker and {words} words of preceding context")
+                .cyan()
+        );
+    }
+    let party_resolver = legislators
+        .map(SpeakerPartyResolver::from_legislators_yaml)
+        .transpose()?;
+    if let Some(resolver) = &party_resolver {
+        println!(
+            "{}",
+            format!("Attributing speaker party ({} members loaded)", resolver.len()).cyan()
+        );
+    }
+    if let Some(s) = sample {
+        println!(
+            "{}",
+            format!("Sampling {} files (seed {})", s.size, s.seed).cyan()
+        );
+    }
 
     let db = get_database().await?;
-    let mut ingester = HearingIngester::new(db, lancedb_path, force, year).await?;
+    let lock = acquire_ingest_lock(&db, ContentType::Hearing, transcript_path).await?;
+    let mut ingester = HearingIngester::new(db, lancedb_path, force, year)
+        .await?
+        .with_sample(sample)
+        .with_merge_statements(merge_statements)
+        .with_normalize_speakers(normalize_speaker)
+        .with_segment_dedup(dedup_threshold)
+        .with_context_embed(context_embed)
+        .with_party_resolver(party_resolver)
+        .with_max_file_size(max_file_size)
+        .with_compact_progress(compact_progress);
     let stats = ingester.ingest_directory(transcript_path, limit).await?;
+    lock.release().await?;
 
     println!();
     println!("{}", "Ingestion complete:".green().bold());
@@ -96,6 +146,14 @@ pub async fn run(
         "  Files skipped:   {}",
         stats.files_skipped.to_string().yellow()
     );
+    println!(
+        "  Oversized:       {}",
+        stats.files_skipped_oversized.to_string().yellow()
+    );
+    println!(
+        "  Changed:         {}",
+        stats.files_changed.to_string().cyan()
+    );
     println!(
         "  Hearings:        {}",
         stats.hearings_created.to_string().cyan()
@@ -108,6 +166,18 @@ pub async fn run(
         "  Segments:        {}",
         stats.segments_created.to_string().cyan()
     );
+    if dedup_threshold.is_some() {
+        println!(
+            "  Deduped:         {}",
+            stats.segments_deduped.to_string().yellow()
+        );
+    }
+    if legislators.is_some() {
+        println!(
+            "  With party:      {}",
+            stats.statements_with_party.to_string().cyan()
+        );
+    }
     println!(
         "  Embeddings:      {}",
         stats.embeddings_created.to_string().cyan()
@@ -3,12 +3,15 @@
 use chrono::NaiveDate;
 use color_eyre::eyre::{Result, WrapErr};
 use colored::Colorize;
+use polsearch_core::ContentType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
 use std::io::Write as IoWrite;
 use std::path::Path;
 
+use crate::ReportFormat;
+
 /// YAML file structure from congressional hearings fetcher
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -92,6 +95,8 @@ struct OutputMetadata {
 
 #[derive(Debug, Serialize)]
 struct MissingHearing {
+    /// `GovInfo` package ID, when a source links to one (e.g., "CHRG-118hhrg12345")
+    package_id: Option<String>,
     title: String,
     date: String,
     congress: i16,
@@ -103,12 +108,17 @@ struct MissingHearing {
 }
 
 /// Run the missing-hearings command
+///
+/// The report (YAML for text, JSON, or one JSON object per line for ndjson)
+/// goes to `output` or stdout. Progress and the summary go to stdout for the
+/// text report and to stderr for the JSON formats, so stdout stays parseable.
 pub async fn run(
     yaml_path: &str,
     transcripts_path: &str,
     output: Option<String>,
     congress_filter: Option<i16>,
     chamber_filter: Option<String>,
+    format: ReportFormat,
 ) -> Result<()> {
     let yaml_path = Path::new(yaml_path);
     let transcripts_path = Path::new(transcripts_path);
@@ -128,10 +138,17 @@ pub async fn run(
         ));
     }
 
-    println!(
+    // progress shares stdout with the text report but stays off JSON output
+    let mut log: Box<dyn IoWrite> = match format {
+        ReportFormat::Text => Box::new(std::io::stdout()),
+        ReportFormat::Json | ReportFormat::Ndjson => Box::new(std::io::stderr()),
+    };
+
+    writeln!(
+        log,
         "{}",
         format!("Loading hearings from {}...", yaml_path.display()).cyan()
-    );
+    )?;
 
     // load and parse YAML
     let yaml_content =
@@ -140,7 +157,7 @@ pub async fn run(
         serde_yaml::from_str(&yaml_content).wrap_err("Failed to parse YAML file")?;
 
     let total_in_yaml = hearings_file.hearings.len();
-    println!("  Total entries in YAML: {}", total_in_yaml.to_string().cyan());
+    writeln!(log, "  Total entries in YAML: {}", total_in_yaml.to_string().cyan())?;
 
     // today's date for filtering future hearings
     let today = NaiveDate::parse_from_str("2026-01-17", "%Y-%m-%d")
@@ -155,7 +172,7 @@ pub async fn run(
             let is_hearing = h
                 .hearing_type
                 .as_ref()
-                .is_some_and(|t| t.eq_ignore_ascii_case("hearing"));
+                .is_some_and(|t| matches!(t.parse(), Ok(ContentType::Hearing)));
 
             if !is_hearing {
                 return false;
@@ -192,23 +209,26 @@ pub async fn run(
         .collect();
 
     let hearings_only = candidate_hearings.len();
-    println!(
+    writeln!(
+        log,
         "  Past hearings (type=Hearing, date<today): {}",
         hearings_only.to_string().cyan()
-    );
+    )?;
 
     // load existing transcripts
-    println!(
+    writeln!(
+        log,
         "{}",
         format!("Loading transcripts from {}...", transcripts_path.display()).cyan()
-    );
+    )?;
 
     let transcripts = load_transcripts(transcripts_path)?;
     let existing_transcripts = transcripts.len();
-    println!(
+    writeln!(
+        log,
         "  Existing transcripts: {}",
         existing_transcripts.to_string().cyan()
-    );
+    )?;
 
     // build index for fast matching
     let transcript_keys: HashSet<String> = transcripts
@@ -217,7 +237,7 @@ pub async fn run(
         .collect();
 
     // find missing hearings
-    println!("{}", "Finding missing hearings...".cyan());
+    writeln!(log, "{}", "Finding missing hearings...".cyan())?;
 
     let mut missing: Vec<MissingHearing> = Vec::new();
 
@@ -265,8 +285,13 @@ pub async fn run(
                     .find(|s| s.url.contains("congress.gov"))
                     .map(|s| s.url.clone())
             });
+        let package_id = hearing
+            .sources
+            .as_ref()
+            .and_then(|sources| sources.iter().find_map(|s| extract_package_id(&s.url)));
 
         missing.push(MissingHearing {
+            package_id,
             title: hearing.title.clone(),
             date: date.to_string(),
             congress: hearing.congress,
@@ -283,18 +308,20 @@ pub async fn run(
 
     let missing_count = missing.len();
 
-    println!();
-    println!("{}", "Results:".green().bold());
-    println!("  Total hearings in YAML:   {}", total_in_yaml.to_string().cyan());
-    println!(
+    writeln!(log)?;
+    writeln!(log, "{}", "Results:".green().bold())?;
+    writeln!(log, "  Total hearings in YAML:   {}", total_in_yaml.to_string().cyan())?;
+    writeln!(
+        log,
         "  Past hearings (filtered): {}",
         hearings_only.to_string().cyan()
-    );
-    println!(
+    )?;
+    writeln!(
+        log,
         "  Existing transcripts:     {}",
         existing_transcripts.to_string().cyan()
-    );
-    println!("  Missing transcripts:      {}", missing_count.to_string().yellow());
+    )?;
+    writeln!(log, "  Missing transcripts:      {}", missing_count.to_string().yellow())?;
 
     // generate output
     let output_data = MissingHearingsOutput {
@@ -309,20 +336,41 @@ pub async fn run(
         missing_hearings: missing,
     };
 
-    let yaml_output =
-        serde_yaml::to_string(&output_data).wrap_err("Failed to serialize output")?;
+    let report = match format {
+        ReportFormat::Text => {
+            serde_yaml::to_string(&output_data).wrap_err("Failed to serialize output")?
+        }
+        ReportFormat::Json => {
+            let mut json = serde_json::to_string_pretty(&output_data)
+                .wrap_err("Failed to serialize output")?;
+            json.push('\n');
+            json
+        }
+        ReportFormat::Ndjson => {
+            let mut lines = String::new();
+            for hearing in &output_data.missing_hearings {
+                lines.push_str(
+                    &serde_json::to_string(hearing).wrap_err("Failed to serialize output")?,
+                );
+                lines.push('\n');
+            }
+            lines
+        }
+    };
 
     if let Some(output_path) = output {
         let mut file =
             fs::File::create(&output_path).wrap_err("Failed to create output file")?;
-        file.write_all(yaml_output.as_bytes())
+        file.write_all(report.as_bytes())
             .wrap_err("Failed to write output file")?;
-        println!();
-        println!("Output written to: {}", output_path.green());
+        writeln!(log)?;
+        writeln!(log, "Output written to: {}", output_path.green())?;
     } else {
-        println!();
-        println!("{}", "--- Missing Hearings ---".yellow().bold());
-        print!("{yaml_output}");
+        if matches!(format, ReportFormat::Text) {
+            writeln!(log)?;
+            writeln!(log, "{}", "--- Missing Hearings ---".yellow().bold())?;
+        }
+        print!("{report}");
     }
 
     Ok(())
@@ -368,6 +416,16 @@ fn load_transcript(path: &Path) -> Result<TranscriptInfo> {
     })
 }
 
+/// Extract a `GovInfo` hearing package ID from a source URL
+fn extract_package_id(url: &str) -> Option<String> {
+    let start = url.find("CHRG-")?;
+    let id: String = url[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    (id.len() > "CHRG-".len()).then_some(id)
+}
+
 /// Create a match key from congress, chamber, and date
 fn make_match_key(congress: i16, chamber: &str, date: &str) -> String {
     format!(
@@ -0,0 +1,83 @@
+//! Find votes similar to a given vote by embedding similarity
+
+use color_eyre::eyre::{eyre, Result};
+use colored::Colorize;
+use polsearch_core::RollCallVote;
+use polsearch_pipeline::stages::find_related_votes;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::get_database;
+
+/// Run the related votes command
+///
+/// `vote_id` may be the vote UUID or its roll call identifier (e.g. "h1-116.2019").
+pub async fn run(vote_id: &str, limit: usize, lancedb_path: &str) -> Result<()> {
+    let db = get_database().await?;
+
+    let vote = match Uuid::parse_str(vote_id) {
+        Ok(id) => db.roll_call_votes().get_by_id(id).await?,
+        Err(_) => db.roll_call_votes().get_by_vote_id(vote_id).await?,
+    }
+    .ok_or_else(|| eyre!("Vote not found: {vote_id}"))?;
+
+    let lancedb = lancedb::connect(lancedb_path).execute().await?;
+    let Some(related) = find_related_votes(&lancedb, vote.id, limit).await? else {
+        println!(
+            "{}",
+            format!(
+                "Vote {} has no embedding yet. Run `polsearch votes embed` first.",
+                vote.vote_id
+            )
+            .yellow()
+        );
+        return Ok(());
+    };
+
+    println!();
+    println!(
+        "{}",
+        format!("Votes related to {}", vote.vote_id).cyan().bold()
+    );
+    print_vote_line(&vote);
+    println!();
+
+    if related.is_empty() {
+        println!("{}", "No related votes found".yellow());
+        return Ok(());
+    }
+
+    let ids: Vec<Uuid> = related.iter().map(|r| r.id).collect();
+    let details: HashMap<Uuid, RollCallVote> = db
+        .roll_call_votes()
+        .get_by_ids(&ids)
+        .await?
+        .into_iter()
+        .map(|v| (v.id, v))
+        .collect();
+
+    for (i, r) in related.iter().enumerate() {
+        let Some(v) = details.get(&r.id) else {
+            continue;
+        };
+        print!(
+            "{} {} ",
+            format!("[{}]", i + 1).yellow(),
+            format!("({:.3})", r.distance).dimmed()
+        );
+        print_vote_line(v);
+    }
+
+    println!();
+    Ok(())
+}
+
+fn print_vote_line(vote: &RollCallVote) {
+    println!(
+        "{} {} | {} | {}",
+        vote.vote_id.green(),
+        vote.vote_date.format("%Y-%m-%d").to_string().dimmed(),
+        vote.question,
+        vote.result.cyan()
+    );
+}
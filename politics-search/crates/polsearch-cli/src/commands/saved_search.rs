@@ -0,0 +1,353 @@
+//! Saved search tables: persist search result sets to `LanceDB`
+
+use arrow_array::{Array, Float32Array, Int32Array, RecordBatch, RecordBatchIterator, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use chrono::Utc;
+use color_eyre::eyre::{bail, eyre, Result};
+use colored::Colorize;
+use polsearch_pipeline::stages::FTS_TABLE_NAME;
+use polsearch_util::truncate;
+use std::sync::Arc;
+
+/// Tables managed by the ingestion pipeline that must never be used as a save target
+const RESERVED_TABLES: &[&str] = &[
+    "text_embeddings",
+    FTS_TABLE_NAME,
+    "speaker_embeddings",
+    "speaker_centroids",
+];
+
+/// A single search result row to persist
+pub struct SavedResult<'a> {
+    pub content_id: &'a str,
+    pub content_type: &'a str,
+    pub segment_index: i32,
+    pub text: &'a str,
+    pub score: f32,
+    pub start_time_ms: i32,
+    pub end_time_ms: i32,
+    pub speaker_name: Option<&'a str>,
+    pub title: Option<&'a str>,
+    pub date: Option<&'a str>,
+}
+
+/// Fixed schema for saved search tables
+///
+/// Every saved table uses this schema so results from different searches can
+/// be appended to the same table.
+fn saved_search_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("query", DataType::Utf8, false),
+        Field::new("saved_at", DataType::Utf8, false),
+        Field::new("rank", DataType::Int32, false),
+        Field::new("content_id", DataType::Utf8, false),
+        Field::new("content_type", DataType::Utf8, false),
+        Field::new("segment_index", DataType::Int32, false),
+        Field::new("text", DataType::Utf8, false),
+        Field::new("score", DataType::Float32, false),
+        Field::new("start_time_ms", DataType::Int32, false),
+        Field::new("end_time_ms", DataType::Int32, false),
+        Field::new("speaker_name", DataType::Utf8, true),
+        Field::new("title", DataType::Utf8, true),
+        Field::new("date", DataType::Utf8, true),
+    ]))
+}
+
+/// Check whether a table's columns match the saved search schema
+#[must_use]
+pub fn is_saved_search_schema(schema: &Schema) -> bool {
+    let expected = saved_search_schema();
+    schema.fields().len() == expected.fields().len()
+        && expected.fields().iter().all(|f| {
+            schema
+                .field_with_name(f.name())
+                .is_ok_and(|existing| existing.data_type() == f.data_type())
+        })
+}
+
+/// Save search results to a `LanceDB` table, creating it or appending to it
+///
+/// Each row records the query string and save timestamp for provenance.
+///
+/// # Errors
+/// Returns an error if the table name is reserved, an existing table has a
+/// different schema, or the write fails
+pub async fn save_results(
+    lancedb_path: &str,
+    table_name: &str,
+    query: &str,
+    results: &[SavedResult<'_>],
+) -> Result<usize> {
+    if RESERVED_TABLES.contains(&table_name) {
+        bail!("Cannot save search results to pipeline table '{table_name}'");
+    }
+    if results.is_empty() {
+        return Ok(0);
+    }
+
+    let schema = saved_search_schema();
+    let saved_at = Utc::now().to_rfc3339();
+    let ranks: Vec<i32> = (1..).take(results.len()).collect();
+
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(StringArray::from(vec![query; results.len()])),
+            Arc::new(StringArray::from(vec![saved_at.as_str(); results.len()])),
+            Arc::new(Int32Array::from(ranks)),
+            Arc::new(StringArray::from_iter_values(results.iter().map(|r| r.content_id))),
+            Arc::new(StringArray::from_iter_values(results.iter().map(|r| r.content_type))),
+            Arc::new(Int32Array::from_iter_values(results.iter().map(|r| r.segment_index))),
+            Arc::new(StringArray::from_iter_values(results.iter().map(|r| r.text))),
+            Arc::new(Float32Array::from_iter_values(results.iter().map(|r| r.score))),
+            Arc::new(Int32Array::from_iter_values(results.iter().map(|r| r.start_time_ms))),
+            Arc::new(Int32Array::from_iter_values(results.iter().map(|r| r.end_time_ms))),
+            Arc::new(StringArray::from_iter(results.iter().map(|r| r.speaker_name))),
+            Arc::new(StringArray::from_iter(results.iter().map(|r| r.title))),
+            Arc::new(StringArray::from_iter(results.iter().map(|r| r.date))),
+        ],
+    )?;
+
+    let db = lancedb::connect(lancedb_path).execute().await?;
+    let batches = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
+
+    if let Ok(table) = db.open_table(table_name).execute().await {
+        let existing = table.schema().await?;
+        if !is_saved_search_schema(&existing) {
+            bail!("Table '{table_name}' exists but is not a saved search table");
+        }
+        table.add(Box::new(batches)).execute().await?;
+    } else {
+        db.create_table(table_name, Box::new(batches)).execute().await?;
+    }
+
+    Ok(results.len())
+}
+
+/// Print rows from a saved search table
+///
+/// # Errors
+/// Returns an error if a required column is missing
+pub fn print_saved_search(batches: &[RecordBatch]) -> Result<()> {
+    let string_col = |batch: &RecordBatch, name: &str| -> Result<StringArray> {
+        batch
+            .column_by_name(name)
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+            .cloned()
+            .ok_or_else(|| eyre!("Missing {name} column"))
+    };
+
+    let mut last_query: Option<(String, String)> = None;
+    for batch in batches {
+        let queries = string_col(batch, "query")?;
+        let saved_ats = string_col(batch, "saved_at")?;
+        let content_ids = string_col(batch, "content_id")?;
+        let texts = string_col(batch, "text")?;
+        let titles = string_col(batch, "title")?;
+        let ranks = batch
+            .column_by_name("rank")
+            .and_then(|c| c.as_any().downcast_ref::<Int32Array>())
+            .ok_or_else(|| eyre!("Missing rank column"))?;
+        let scores = batch
+            .column_by_name("score")
+            .and_then(|c| c.as_any().downcast_ref::<Float32Array>())
+            .ok_or_else(|| eyre!("Missing score column"))?;
+
+        for i in 0..batch.num_rows() {
+            // print a header each time the provenance changes
+            let provenance = (queries.value(i).to_string(), saved_ats.value(i).to_string());
+            if last_query.as_ref() != Some(&provenance) {
+                println!(
+                    "{} \"{}\" {}",
+                    "Query:".cyan().bold(),
+                    provenance.0,
+                    format!("(saved {})", provenance.1).dimmed()
+                );
+                last_query = Some(provenance);
+            }
+
+            let title = if titles.is_null(i) {
+                content_ids.value(i)
+            } else {
+                titles.value(i)
+            };
+            println!(
+                "  {}. {} {} | {}",
+                ranks.value(i),
+                format!("[{:.3}]", scores.value(i)).dimmed(),
+                truncate(title, 40),
+                truncate(texts.value(i), 60)
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use lancedb::query::ExecutableQuery;
+
+    fn result(content_id: &str) -> SavedResult<'_> {
+        SavedResult {
+            content_id,
+            content_type: "hearing",
+            segment_index: 0,
+            text: "segment text",
+            score: 0.5,
+            start_time_ms: 0,
+            end_time_ms: 0,
+            speaker_name: None,
+            title: Some("Title"),
+            date: None,
+        }
+    }
+
+    /// Empty `LanceDB` directory for one test
+    fn temp_lancedb() -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "polsearch-saved-search-test-{}",
+            uuid::Uuid::now_v7()
+        ))
+    }
+
+    #[test]
+    fn test_is_saved_search_schema() {
+        assert!(is_saved_search_schema(&saved_search_schema()));
+
+        let mut fields: Vec<Field> = saved_search_schema()
+            .fields()
+            .iter()
+            .map(|f| (**f).clone())
+            .collect();
+        fields.push(Field::new("extra", DataType::Utf8, true));
+        assert!(!is_saved_search_schema(&Schema::new(fields.clone())));
+
+        fields.pop();
+        fields.pop();
+        assert!(!is_saved_search_schema(&Schema::new(fields.clone())));
+
+        fields.push(Field::new("date", DataType::Int32, true));
+        assert!(!is_saved_search_schema(&Schema::new(fields)));
+    }
+
+    #[tokio::test]
+    async fn test_reserved_tables_rejected() {
+        let dir = temp_lancedb();
+        let path = dir.to_str().expect("utf-8 path");
+        for table in RESERVED_TABLES {
+            let err = save_results(path, table, "query", &[result("a")])
+                .await
+                .expect_err("reserved table");
+            assert!(err.to_string().contains("pipeline table"));
+        }
+        assert!(!dir.exists());
+    }
+
+    #[tokio::test]
+    async fn test_empty_results_write_nothing() {
+        let dir = temp_lancedb();
+        let saved = save_results(dir.to_str().expect("utf-8 path"), "saved", "query", &[])
+            .await
+            .expect("save");
+        assert_eq!(saved, 0);
+        assert!(!dir.exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_then_append() {
+        let dir = temp_lancedb();
+        let path = dir.to_str().expect("utf-8 path");
+
+        let saved = save_results(path, "saved", "first", &[result("a"), result("b")])
+            .await
+            .expect("create");
+        assert_eq!(saved, 2);
+        let saved = save_results(path, "saved", "second", &[result("c")])
+            .await
+            .expect("append");
+        assert_eq!(saved, 1);
+
+        let db = lancedb::connect(path).execute().await.expect("connect");
+        let table = db.open_table("saved").execute().await.expect("open");
+        let batches: Vec<RecordBatch> = table
+            .query()
+            .execute()
+            .await
+            .expect("query")
+            .try_collect()
+            .await
+            .expect("collect");
+        let mut rows: Vec<(String, i32, String)> = batches
+            .iter()
+            .flat_map(|batch| {
+                let column = |name: &str| {
+                    batch
+                        .column_by_name(name)
+                        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+                        .cloned()
+                        .expect("string column")
+                };
+                let (queries, content_ids) = (column("query"), column("content_id"));
+                let ranks = batch
+                    .column_by_name("rank")
+                    .and_then(|c| c.as_any().downcast_ref::<Int32Array>())
+                    .cloned()
+                    .expect("rank column");
+                (0..batch.num_rows())
+                    .map(|i| {
+                        (
+                            queries.value(i).to_string(),
+                            ranks.value(i),
+                            content_ids.value(i).to_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        rows.sort();
+
+        // ranks restart at 1 for each save
+        assert_eq!(
+            rows,
+            [
+                ("first".to_string(), 1, "a".to_string()),
+                ("first".to_string(), 2, "b".to_string()),
+                ("second".to_string(), 1, "c".to_string()),
+            ]
+        );
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_existing_table_with_other_schema_rejected() {
+        let dir = temp_lancedb();
+        let path = dir.to_str().expect("utf-8 path");
+
+        let schema = Arc::new(Schema::new(vec![Field::new("note", DataType::Utf8, false)]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(StringArray::from(vec!["x"]))])
+                .expect("batch");
+        let db = lancedb::connect(path).execute().await.expect("connect");
+        db.create_table(
+            "notes",
+            Box::new(RecordBatchIterator::new(
+                vec![Ok(batch)].into_iter(),
+                schema,
+            )),
+        )
+        .execute()
+        .await
+        .expect("create");
+
+        let err = save_results(path, "notes", "query", &[result("a")])
+            .await
+            .expect_err("schema mismatch");
+        assert!(err.to_string().contains("not a saved search table"));
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -1,23 +1,35 @@
 //! Search command for congressional content using `LanceDB` hybrid search
 
 use arrow_array::{Array, RecordBatch};
+use chrono::{NaiveDate, Utc};
 use color_eyre::eyre::{eyre, Result};
 use colored::Colorize;
 use futures::TryStreamExt;
 use lancedb::index::scalar::FullTextSearchQuery;
 use lancedb::query::{ExecutableQuery, QueryBase};
-use lancedb::Error as LanceError;
-use polsearch_db::{Database, FloorSpeechMetadata, HearingMetadata};
-use polsearch_pipeline::stages::{TextEmbedder, FTS_TABLE_NAME};
-use polsearch_util::truncate;
+use polsearch_core::{ContentType, PageType};
+use polsearch_db::{
+    fetch_all_speakers, BillMetadata, Database, FloorSpeechMetadata, HearingMetadata,
+};
+use polsearch_pipeline::stages::{
+    apply_ranking, boost_recent, content_id_aliases, content_id_filter, count_indexed_segments,
+    has_speaker_names, hybrid_window, is_missing_fts_index_error, phrase_search, text_fts_query,
+    verify_embedding_model, TextEmbedder, TextReranker, DEFAULT_RERANK_CANDIDATES,
+    FTS_TABLE_NAME, MAX_FILTER_CONTENT_IDS,
+};
+use polsearch_util::highlight::{matched_terms, snippet};
+use polsearch_util::spelling::parse_vocab;
+use polsearch_util::{
+    boost_speaker_matches, parse_date_bound, retain_text_matches, suggest_correction, truncate,
+    BooleanQuery, BOOLEAN_FTS_OVERFETCH, MIN_WORDS_OVERFETCH, SPEAKER_BOOST_OVERFETCH,
+};
 use serde::Serialize;
+use std::collections::HashMap;
+use terminal_size::Width;
 use uuid::Uuid;
 
-/// Check if a `LanceDB` error is due to a missing FTS inverted index
-fn is_missing_fts_index_error(e: &LanceError) -> bool {
-    let msg = e.to_string();
-    msg.contains("INVERTED index") || msg.contains("full text search")
-}
+use super::get_database;
+use super::saved_search::{save_results, SavedResult};
 
 /// Print a warning about missing FTS index with instructions
 fn print_fts_fallback_warning() {
@@ -34,7 +46,24 @@ fn print_fts_fallback_warning() {
     eprintln!();
 }
 
-use crate::{ContentTypeFilter, OutputFormat, SearchMode};
+use crate::{ContentTypeFilter, GroupBy, OutputFormat, PageTypeFilter, PartyFilter, SearchMode};
+
+/// Candidate multiplier when `--boost-recent` can promote newer results from past the limit
+const RECENCY_BOOST_OVERFETCH: usize = 3;
+
+/// Result text length in flat and grouped output when stdout isn't a terminal
+const FLAT_SNIPPET_LEN: usize = 100;
+const GROUPED_SNIPPET_LEN: usize = 80;
+
+/// Columns taken by the indent and quotes around result text in flat and grouped output
+const FLAT_TEXT_INDENT: usize = 6;
+const GROUPED_TEXT_INDENT: usize = 9;
+
+/// Shortest result text picked from the terminal width
+const MIN_SNIPPET_LEN: usize = 40;
+
+/// Bytes of context kept on either side of the first match in a highlight snippet
+const HIGHLIGHT_CONTEXT: usize = 80;
 
 /// Search result with metadata
 #[derive(Serialize)]
@@ -47,10 +76,52 @@ struct SearchResult {
     start_time_ms: i32,
     end_time_ms: i32,
     score: f32,
+    /// Cross-encoder score when `--rerank` reordered the results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rerank_score: Option<f32>,
     content_type: String,
     speaker_name: Option<String>,
     title: Option<String>,
     date: Option<String>,
+    committee: Option<String>,
+    chamber: Option<String>,
+    /// Bill action status, only for bill results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    /// Literal term matches, only for keyword modes with `--highlight-json`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    highlights: Option<Vec<Highlight>>,
+}
+
+impl From<RawSearchResult> for SearchResult {
+    fn from(r: RawSearchResult) -> Self {
+        Self {
+            content_id: r.content_id,
+            content_id_str: r.content_id_str,
+            segment_index: r.segment_index,
+            text: r.text,
+            start_time_ms: r.start_time_ms,
+            end_time_ms: r.end_time_ms,
+            score: r.score,
+            rerank_score: r.rerank_score,
+            content_type: r.content_type,
+            speaker_name: r.speaker_name,
+            title: r.title,
+            date: None,
+            committee: None,
+            chamber: None,
+            status: None,
+            highlights: None,
+        }
+    }
+}
+
+/// Matched terms in one field of a search result
+#[derive(Serialize)]
+struct Highlight {
+    field: &'static str,
+    snippet: String,
+    matched_terms: Vec<String>,
 }
 
 /// Run the search command
@@ -59,24 +130,59 @@ pub async fn run(
     query: &str,
     limit: usize,
     offset: usize,
-    group: bool,
+    group_by: Option<GroupBy>,
     mode: SearchMode,
+    rerank: bool,
+    min_words: usize,
+    hybrid_oversample: usize,
+    speaker_boost: bool,
+    boost_recent: Option<f64>,
     content_types: Vec<ContentTypeFilter>,
     from: Option<String>,
     to: Option<String>,
     speaker: Option<String>,
+    parties: &[PartyFilter],
+    page_types: &[PageTypeFilter],
     committee: Option<String>,
     chamber: Option<String>,
-    congress: Option<i16>,
+    congress_range: (Option<i16>, Option<i16>),
+    content_id: Option<String>,
     lancedb_path: &str,
     format: OutputFormat,
+    snippet_len: Option<usize>,
+    highlight_json: bool,
     context_size: usize,
+    save_to: Option<&str>,
+    vocab_path: &str,
 ) -> Result<()> {
     // Build content type filter for LanceDB
     let type_filter = build_content_type_filter(&content_types);
+    let party_filter = build_party_filter(parties);
+    let page_type_filter = build_page_type_filter(page_types);
+
+    // party and page type are only stored in text_embeddings, not the text_fts table
+    if matches!(mode, SearchMode::Fts | SearchMode::Phrase) {
+        if party_filter.is_some() {
+            return Err(eyre!("--party is only supported with --mode hybrid or --mode vector"));
+        }
+        if page_type_filter.is_some() {
+            return Err(eyre!("--page-type is only supported with --mode hybrid or --mode vector"));
+        }
+    }
+
+    if let Some(half_life) = boost_recent {
+        if !half_life.is_finite() || half_life <= 0.0 {
+            return Err(eyre!("--boost-recent half-life must be a positive number of days"));
+        }
+        // the cross-encoder decides the final order, which would discard the decay
+        if rerank {
+            return Err(eyre!("--boost-recent cannot be combined with --rerank"));
+        }
+    }
 
     // Log hearing-specific filters if used
-    if (committee.is_some() || chamber.is_some() || congress.is_some())
+    let has_congress = congress_range != (None, None);
+    if (committee.is_some() || chamber.is_some() || has_congress)
         && !content_types.iter().any(|t| {
             matches!(
                 t,
@@ -97,78 +203,231 @@ pub async fn run(
             "Note: --speaker filtering not yet implemented for congressional content".yellow()
         );
     }
-    if committee.is_some() || chamber.is_some() || congress.is_some() {
+    if committee.is_some() || chamber.is_some() {
         println!(
             "{}",
-            "Note: --committee, --chamber, --congress filtering not yet implemented".yellow()
+            "Note: --committee and --chamber filtering not yet implemented".yellow()
         );
     }
 
-    // TODO: implement date range filtering
-    if from.is_some() || to.is_some() {
-        println!(
+    let from = from
+        .map(|v| parse_date_bound(&v, true).ok_or_else(|| eyre!("Invalid --from date '{v}'")))
+        .transpose()?;
+    let to = to
+        .map(|v| parse_date_bound(&v, false).ok_or_else(|| eyre!("Invalid --to date '{v}'")))
+        .transpose()?;
+
+    let _ = context_size; // TODO: implement context expansion
+
+    // scope to a single content item if requested
+    let content_filter = match content_id.as_deref() {
+        Some(id) => Some(build_single_content_filter(id).await?),
+        None => None,
+    };
+    // congress and dates are only stored in PostgreSQL, so they are resolved to
+    // content IDs up front
+    let date_range = (from.as_deref(), to.as_deref());
+    let range_ids = resolve_range_content_ids(&content_types, congress_range, date_range).await?;
+    let range_filter = match range_ids {
+        Some(ids) if ids.is_empty() => {
+            println!("{}", "No content matches the --congress/--from/--to filters".yellow());
+            return Ok(());
+        }
+        Some(ids) => Some(content_id_filter(&ids)),
+        None => None,
+    };
+    let filters: Vec<String> =
+        [type_filter, party_filter, page_type_filter, content_filter.clone(), range_filter]
+            .into_iter()
+            .flatten()
+            .collect();
+    let filter = match filters.as_slice() {
+        [] => None,
+        [single] => Some(single.clone()),
+        _ => Some(
+            filters
+                .iter()
+                .map(|f| format!("({f})"))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        ),
+    };
+
+    // FTS mode supports AND / OR / -term; the index only ORs terms, so
+    // AND and exclusions are applied to over-fetched candidates afterwards
+    let boolean_query = matches!(mode, SearchMode::Fts).then(|| BooleanQuery::parse(query));
+    let search_text = match &boolean_query {
+        Some(parsed) if parsed.search_text().is_empty() => {
+            return Err(eyre!("FTS query needs at least one search term that is not excluded"));
+        }
+        Some(parsed) => parsed.search_text(),
+        None => query.to_string(),
+    };
+    let post_filter = boolean_query.filter(BooleanQuery::has_operators);
+
+    // execute search
+    let mut fetch_count = offset + limit + 1;
+    if post_filter.is_some() {
+        fetch_count *= BOOLEAN_FTS_OVERFETCH;
+    }
+    if min_words > 0 {
+        fetch_count *= MIN_WORDS_OVERFETCH;
+    }
+    let mut speaker_boost = speaker_boost && matches!(mode, SearchMode::Fts);
+    if speaker_boost && !has_speaker_names(lancedb_path).await? {
+        eprintln!(
             "{}",
-            "Note: date range filtering not yet implemented for congressional content".yellow()
+            "Warning: no speaker names in the FTS table, ignoring --speaker-boost.".yellow()
         );
+        eprintln!("  polsearch fts clear");
+        eprintln!("  polsearch fts ingest --include-speaker-in-fts ...");
+        speaker_boost = false;
+    }
+    if speaker_boost {
+        fetch_count *= SPEAKER_BOOST_OVERFETCH;
+    }
+    if boost_recent.is_some() {
+        fetch_count *= RECENCY_BOOST_OVERFETCH;
+    }
+    if rerank {
+        fetch_count = fetch_count.max(DEFAULT_RERANK_CANDIDATES);
+    }
+    let mut raw_results = execute_search(
+        lancedb_path,
+        &search_text,
+        fetch_count,
+        mode,
+        filter.as_deref(),
+        hybrid_oversample,
+        &mut None,
+    )
+    .await?;
+
+    // distinguish "no matches in this content" from "content not indexed"
+    if raw_results.is_empty() {
+        if let (Some(id), Some(filter)) = (content_id.as_deref(), content_filter.as_deref()) {
+            if count_indexed_segments(lancedb_path, filter).await? == 0 {
+                println!(
+                    "{}",
+                    format!("No indexed segments found for content {}", id.trim()).yellow()
+                );
+                return Ok(());
+            }
+        }
     }
 
-    let _ = context_size; // TODO: implement context expansion
+    retain_text_matches(&mut raw_results, post_filter.as_ref(), min_words, |r| &r.text);
+    if speaker_boost {
+        raw_results = boost_speaker_matches(
+            query,
+            raw_results,
+            |r| r.speaker_name.as_deref(),
+            |r| &mut r.score,
+        );
+    }
 
-    // execute search
-    let fetch_count = offset + limit + 1;
-    let mut raw_results =
-        execute_search(lancedb_path, query, fetch_count, mode, type_filter.as_deref()).await?;
+    // rerank the whole candidate pool before paging through it
+    if rerank {
+        raw_results = rerank_results(query, raw_results);
+    }
+
+    let mut results: Vec<SearchResult> = raw_results.into_iter().map(SearchResult::from).collect();
+
+    // the decay needs each result's date, so enrich the whole candidate pool before paging
+    if let Some(half_life) = boost_recent {
+        enrich_or_warn(&mut results).await;
+        results = boost_recent_results(results, half_life, mode);
+    }
 
     // skip the first `offset` results
     if offset > 0 {
-        if raw_results.len() <= offset {
+        if results.len() <= offset {
             println!("{}", "No results at this offset".yellow());
             return Ok(());
         }
-        raw_results = raw_results.into_iter().skip(offset).collect();
+        results = results.into_iter().skip(offset).collect();
     }
 
-    if raw_results.is_empty() {
-        println!("{}", "No results found".yellow());
+    if results.is_empty() {
+        match suggest_query(query, mode, vocab_path) {
+            Some(suggestion) => println!(
+                "{}",
+                format!("No results. Did you mean '{suggestion}'?").yellow()
+            ),
+            None => println!("{}", "No results found".yellow()),
+        }
         return Ok(());
     }
 
     // check if there are more results than requested
-    let has_more = raw_results.len() > limit;
+    let has_more = results.len() > limit;
     if has_more {
-        raw_results.truncate(limit);
+        results.truncate(limit);
     }
 
-    // convert to SearchResult
-    let mut results: Vec<SearchResult> = raw_results
-        .into_iter()
-        .map(|r| SearchResult {
-            content_id: r.content_id,
-            content_id_str: r.content_id_str,
-            segment_index: r.segment_index,
-            text: r.text,
-            start_time_ms: r.start_time_ms,
-            end_time_ms: r.end_time_ms,
-            score: r.score,
-            content_type: r.content_type,
-            speaker_name: r.speaker_name,
-            title: r.title,
-            date: None,
-        })
-        .collect();
-
     // enrich results with metadata from PostgreSQL
-    if let Err(e) = enrich_results(&mut results).await {
-        eprintln!("{}", format!("Warning: failed to enrich results: {e}").yellow());
+    if boost_recent.is_none() {
+        enrich_or_warn(&mut results).await;
+    }
+
+    // persist the result set if requested
+    if let Some(table_name) = save_to {
+        let content_ids: Vec<String> = results
+            .iter()
+            .map(|r| {
+                if r.content_id_str.is_empty() {
+                    r.content_id.to_string()
+                } else {
+                    r.content_id_str.clone()
+                }
+            })
+            .collect();
+        let rows: Vec<SavedResult<'_>> = results
+            .iter()
+            .zip(&content_ids)
+            .map(|(r, content_id)| SavedResult {
+                content_id,
+                content_type: &r.content_type,
+                segment_index: r.segment_index,
+                text: &r.text,
+                score: r.score,
+                start_time_ms: r.start_time_ms,
+                end_time_ms: r.end_time_ms,
+                speaker_name: r.speaker_name.as_deref(),
+                title: r.title.as_deref(),
+                date: r.date.as_deref(),
+            })
+            .collect();
+        let saved = save_results(lancedb_path, table_name, query, &rows).await?;
+        eprintln!(
+            "{}",
+            format!("Saved {saved} results to table '{table_name}'").green()
+        );
+    }
+
+    // literal highlights are meaningless for pure semantic matches
+    let wants_highlights = highlight_json
+        && matches!(format, OutputFormat::Json)
+        && !matches!(mode, SearchMode::Vector);
+    if wants_highlights {
+        let highlight_query = BooleanQuery::parse(query);
+        for result in &mut results {
+            result.highlights = Some(build_highlights(&highlight_query, result));
+        }
     }
 
     // output results
     match format {
         OutputFormat::Text => {
-            if group {
-                print_results_grouped(query, &results, offset, has_more, mode);
+            if let Some(group_by) = group_by {
+                let snippet_len =
+                    snippet_len_for(snippet_len, GROUPED_TEXT_INDENT, GROUPED_SNIPPET_LEN);
+                print_results_grouped(
+                    query, &results, offset, has_more, mode, group_by, snippet_len,
+                );
             } else {
-                print_results_flat(query, &results, limit, offset, has_more, mode);
+                let snippet_len = snippet_len_for(snippet_len, FLAT_TEXT_INDENT, FLAT_SNIPPET_LEN);
+                print_results_flat(query, &results, limit, offset, has_more, mode, snippet_len);
             }
         }
         OutputFormat::Json => {
@@ -185,6 +444,101 @@ pub async fn run(
     Ok(())
 }
 
+/// Highlights for each result field containing a literal query term match
+fn build_highlights(query: &BooleanQuery, result: &SearchResult) -> Vec<Highlight> {
+    [("text", Some(result.text.as_str())), ("title", result.title.as_deref())]
+        .into_iter()
+        .filter_map(|(field, value)| {
+            let value = value?;
+            let offsets = query.match_offsets(value);
+            Some(Highlight {
+                field,
+                snippet: snippet(value, &offsets, HIGHLIGHT_CONTEXT)?,
+                matched_terms: matched_terms(value, &offsets),
+            })
+        })
+        .collect()
+}
+
+/// Resolve `--congress` and `--from`/`--to` to the IDs of matching content in `PostgreSQL`
+///
+/// Each match contributes its UUID (embedded rows) and its `package_id` or
+/// `event_id` (FTS-only rows). Only hearings record a congress, so a congress
+/// bound excludes floor speeches; votes and bills are excluded by any bound, as
+/// in the API. Returns `None` without a bound and an empty list when nothing
+/// matches. Like the API, at most [`MAX_FILTER_CONTENT_IDS`] items are kept.
+async fn resolve_range_content_ids(
+    content_types: &[ContentTypeFilter],
+    (congress_min, congress_max): (Option<i16>, Option<i16>),
+    (from, to): (Option<&str>, Option<&str>),
+) -> Result<Option<Vec<String>>> {
+    let has_congress = congress_min.is_some() || congress_max.is_some();
+    if !has_congress && from.is_none() && to.is_none() {
+        return Ok(None);
+    }
+    let wants = |kind: ContentTypeFilter| {
+        content_types.is_empty()
+            || content_types.iter().any(|t| matches!(t, ContentTypeFilter::All) || *t == kind)
+    };
+
+    let db = get_database().await?;
+    let mut keys = Vec::new();
+    let mut remaining = MAX_FILTER_CONTENT_IDS;
+    let mut truncated = false;
+    let mut cap = |ids: &mut Vec<Uuid>| {
+        truncated |= ids.len() > remaining;
+        ids.truncate(remaining);
+        remaining -= ids.len();
+    };
+    if wants(ContentTypeFilter::Hearing) {
+        let mut ids = db
+            .hearings()
+            .get_filtered_ids(None, None, congress_min, congress_max, from, to)
+            .await?;
+        cap(&mut ids);
+        keys.extend(ids.iter().map(Uuid::to_string));
+        keys.extend(db.hearings().get_package_ids(&ids).await?);
+    }
+    if wants(ContentTypeFilter::FloorSpeech) && !has_congress {
+        let mut ids = db.floor_speeches().get_filtered_ids(None, &[], from, to).await?;
+        cap(&mut ids);
+        keys.extend(ids.iter().map(Uuid::to_string));
+        keys.extend(db.floor_speeches().get_event_ids(&ids).await?);
+    }
+    if truncated {
+        println!(
+            "{}",
+            format!(
+                "Warning: --congress/--from/--to match more than {MAX_FILTER_CONTENT_IDS} items; \
+                 searching only {MAX_FILTER_CONTENT_IDS} of them"
+            )
+            .yellow()
+        );
+    }
+    Ok(Some(keys))
+}
+
+/// Build a `LanceDB` filter scoping search to a single content item
+///
+/// Accepts a UUID or a hearing `package_id`. When `PostgreSQL` is reachable the
+/// alternate ID form is added, so both embedded (UUID-keyed) and FTS-only
+/// (`package_id`/`event_id`-keyed) segments match.
+async fn build_single_content_filter(content_id: &str) -> Result<String> {
+    let content_id = content_id.trim();
+    let uuid = Uuid::parse_str(content_id).ok();
+    if uuid.is_none() && !content_id.starts_with("CHRG-") {
+        return Err(eyre!(
+            "Invalid --content-id '{content_id}': expected a UUID or a hearing package_id (e.g. CHRG-118hhrg12345)"
+        ));
+    }
+
+    let ids = match get_database().await {
+        Ok(db) => content_id_aliases(&db, content_id).await?,
+        Err(_) => vec![content_id.to_string()],
+    };
+    Ok(content_id_filter(&ids))
+}
+
 /// JSON output structure
 #[derive(Serialize)]
 struct JsonOutput<'a> {
@@ -203,18 +557,51 @@ struct RawSearchResult {
     start_time_ms: i32,
     end_time_ms: i32,
     score: f32,
+    rerank_score: Option<f32>,
     content_type: String,
     speaker_name: Option<String>,
     title: Option<String>,
 }
 
+/// Run one search and return how many results it produced
+///
+/// Used by `bench search` to time retrieval exactly as `search` performs it,
+/// without enrichment or output. `embedder` is loaded on first use and kept,
+/// so repeated calls don't pay the model load each time.
+pub async fn count_results(
+    lancedb_path: &str,
+    query: &str,
+    limit: usize,
+    mode: SearchMode,
+    hybrid_oversample: usize,
+    embedder: &mut Option<TextEmbedder>,
+) -> Result<usize> {
+    let results =
+        execute_search(lancedb_path, query, limit, mode, None, hybrid_oversample, embedder).await?;
+    Ok(results.len())
+}
+
+/// Return the embedder in `slot`, loading it from the environment if empty
+fn cached_embedder(slot: &mut Option<TextEmbedder>) -> Result<&mut TextEmbedder> {
+    if slot.is_none() {
+        *slot = Some(TextEmbedder::from_env()?);
+    }
+    slot.as_mut().ok_or_else(|| eyre!("Text embedder failed to load"))
+}
+
 /// Execute search against `LanceDB`
+///
+/// Hybrid searches fetch `limit * hybrid_oversample` rows from each component
+/// before fusion and are trimmed back to `limit` afterwards. Vector-backed modes
+/// reuse the embedder in `embedder_slot`, loading it on first use.
 async fn execute_search(
     lancedb_path: &str,
     query: &str,
     limit: usize,
     mode: SearchMode,
     type_filter: Option<&str>,
+    hybrid_oversample: usize,
+    embedder_slot: &mut Option<TextEmbedder>,
 ) -> Result<Vec<RawSearchResult>> {
     tracing::debug!("[DEBUG] execute_search called with mode: {:?}, query: {}", mode, query);
     tracing::debug!("[DEBUG] lancedb_path: {}", lancedb_path);
@@ -225,7 +612,8 @@ async fn execute_search(
     let batches: Vec<RecordBatch> = match mode {
         SearchMode::Vector => {
             let table = db.open_table("text_embeddings").execute().await?;
-            let mut embedder = TextEmbedder::new()?;
+            let embedder = cached_embedder(embedder_slot)?;
+            verify_embedding_model(&table, embedder.model()).await?;
             let query_embedding = embedder.embed(query)?;
 
             let mut search = table.vector_search(query_embedding)?;
@@ -242,11 +630,10 @@ async fn execute_search(
             let embeddings_table = db.open_table("text_embeddings").execute().await?;
 
             // helper to attempt FTS on a table
+            let fts_query = &text_fts_query(query)?;
             let try_fts =
                 |table: lancedb::Table, filter: Option<String>| async move {
-                    let mut search = table
-                        .query()
-                        .full_text_search(FullTextSearchQuery::new(query.to_string()));
+                    let mut search = table.query().full_text_search(fts_query.clone());
                     if let Some(ref f) = filter {
                         search = search.only_if(f.clone());
                     }
@@ -278,7 +665,8 @@ async fn execute_search(
                 Err(e) if is_missing_fts_index_error(&e) => {
                     print_fts_fallback_warning();
                     // fallback to vector search on text_embeddings
-                    let mut embedder = TextEmbedder::new()?;
+                    let embedder = cached_embedder(embedder_slot)?;
+                    verify_embedding_model(&embeddings_table, embedder.model()).await?;
                     let query_embedding = embedder.embed(query)?;
                     let mut vector_search = embeddings_table.vector_search(query_embedding)?;
                     if let Some(ref filter) = filter_expr {
@@ -296,7 +684,8 @@ async fn execute_search(
         }
         SearchMode::Hybrid => {
             let table = db.open_table("text_embeddings").execute().await?;
-            let mut embedder = TextEmbedder::new()?;
+            let embedder = cached_embedder(embedder_slot)?;
+            verify_embedding_model(&table, embedder.model()).await?;
             let query_embedding = embedder.embed(query)?;
 
             // try hybrid search first
@@ -307,8 +696,15 @@ async fn execute_search(
                 search = search.only_if(filter.clone());
             }
 
-            match search.limit(limit).execute().await {
-                Ok(stream) => stream.try_collect().await?,
+            // fuse a wider window from each component, then trim to the limit
+            let window = hybrid_window(limit, hybrid_oversample);
+            match search.limit(window).execute().await {
+                Ok(stream) => {
+                    let batches: Vec<RecordBatch> = stream.try_collect().await?;
+                    let mut results = parse_search_results(&batches, mode)?;
+                    results.truncate(limit);
+                    return Ok(results);
+                }
                 Err(e) if is_missing_fts_index_error(&e) => {
                     print_fts_fallback_warning();
                     // fallback to vector-only search
@@ -333,22 +729,7 @@ async fn execute_search(
                 Err(_) => db.open_table("text_embeddings").execute().await?,
             };
 
-            let escaped_query = query.replace('\'', "''").replace('%', "\\%");
-            let like_filter = format!("text LIKE '%{escaped_query}%'");
-
-            let combined_filter = match filter_expr {
-                Some(ref type_filter) => format!("({type_filter}) AND ({like_filter})"),
-                None => like_filter,
-            };
-
-            table
-                .query()
-                .only_if(combined_filter)
-                .limit(limit)
-                .execute()
-                .await?
-                .try_collect()
-                .await?
+            phrase_search(&table, query, filter_expr.as_deref(), limit).await?
         }
     };
 
@@ -454,6 +835,7 @@ fn parse_search_results(batches: &[RecordBatch], mode: SearchMode) -> Result<Vec
                 start_time_ms: start_times.map_or(0, |t| t.value(i)),
                 end_time_ms: end_times.map_or(0, |t| t.value(i)),
                 score,
+                rerank_score: None,
                 content_type,
                 speaker_name,
                 title: None,
@@ -464,6 +846,96 @@ fn parse_search_results(batches: &[RecordBatch], mode: SearchMode) -> Result<Vec
     Ok(results)
 }
 
+/// Reorder candidates with the cross-encoder reranker
+///
+/// The model only loads when reranking is requested; if it can't be loaded
+/// or scoring fails, the retrieval order is kept.
+fn rerank_results(query: &str, results: Vec<RawSearchResult>) -> Vec<RawSearchResult> {
+    let ranking = TextReranker::new().and_then(|mut reranker| {
+        let texts: Vec<&str> = results.iter().map(|r| r.text.as_str()).collect();
+        reranker.rerank(query, &texts)
+    });
+    match ranking {
+        Ok(ranking) => apply_ranking(results, &ranking)
+            .into_iter()
+            .map(|(mut result, score)| {
+                result.rerank_score = score;
+                result
+            })
+            .collect(),
+        Err(e) => {
+            eprintln!(
+                "{}",
+                format!("Warning: reranking skipped: {e}").yellow()
+            );
+            results
+        }
+    }
+}
+
+/// Suggest a spelling correction for a keyword query that matched nothing
+///
+/// Only FTS and phrase queries are checked, and only when a vocabulary file
+/// built by `db build-vocab` exists; otherwise no suggestion is made.
+fn suggest_query(query: &str, mode: SearchMode, vocab_path: &str) -> Option<String> {
+    if !matches!(mode, SearchMode::Fts | SearchMode::Phrase) {
+        return None;
+    }
+    let contents = std::fs::read_to_string(vocab_path).ok()?;
+    suggest_correction(query, &parse_vocab(&contents))
+}
+
+/// Enrich results, warning instead of failing if `PostgreSQL` is unavailable
+async fn enrich_or_warn(results: &mut [SearchResult]) {
+    if let Err(e) = enrich_results(results).await {
+        eprintln!("{}", format!("Warning: failed to enrich results: {e}").yellow());
+    }
+}
+
+/// Apply `--boost-recent` to enriched results and re-sort them
+///
+/// Vector scores are distances (lower is better), so the decay is applied to
+/// the equivalent similarity and converted back. Phrase matches are all full
+/// matches, and the full-scan fallback scores them 0, so they start from 1.
+fn boost_recent_results(
+    mut results: Vec<SearchResult>,
+    half_life_days: f64,
+    mode: SearchMode,
+) -> Vec<SearchResult> {
+    if matches!(mode, SearchMode::Phrase) {
+        for r in &mut results {
+            r.score = 1.0;
+        }
+    }
+
+    let is_distance = matches!(mode, SearchMode::Vector);
+    if is_distance {
+        // cosine distances can exceed 2 by rounding, which would flip the decay's sign
+        for r in &mut results {
+            r.score = (1.0 - r.score / 2.0).clamp(0.0, 1.0);
+        }
+    }
+
+    let mut results = boost_recent(
+        results,
+        half_life_days,
+        Utc::now().date_naive(),
+        |r| {
+            r.date
+                .as_deref()
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        },
+        |r| &mut r.score,
+    );
+
+    if is_distance {
+        for r in &mut results {
+            r.score = 2.0 * (1.0 - r.score);
+        }
+    }
+    results
+}
+
 /// Enrich search results with metadata from `PostgreSQL`
 async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
     if results.is_empty() {
@@ -482,10 +954,12 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
     let mut floor_speech_ids: Vec<Uuid> = Vec::new();
     let mut floor_speech_event_ids: Vec<String> = Vec::new();
     let mut floor_speech_segment_keys: Vec<(Uuid, i32)> = Vec::new();
+    let mut bill_ids: Vec<Uuid> = Vec::new();
+    let mut vote_ids: Vec<Uuid> = Vec::new();
 
     for r in results.iter() {
-        match r.content_type.as_str() {
-            "hearing" => {
+        match ContentType::from_db_str(&r.content_type) {
+            Some(ContentType::Hearing) => {
                 if r.content_id == nil_uuid {
                     // FTS result - use package_id
                     hearing_package_ids.push(r.content_id_str.clone());
@@ -495,7 +969,7 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
                     hearing_segment_keys.push((r.content_id, r.segment_index));
                 }
             }
-            "floor_speech" => {
+            Some(ContentType::FloorSpeech) => {
                 if r.content_id == nil_uuid {
                     // FTS result - use event_id
                     floor_speech_event_ids.push(r.content_id_str.clone());
@@ -505,6 +979,8 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
                     floor_speech_segment_keys.push((r.content_id, r.segment_index));
                 }
             }
+            Some(ContentType::Bill) => bill_ids.push(r.content_id),
+            Some(ContentType::Vote) => vote_ids.push(r.content_id),
             _ => {}
         }
     }
@@ -512,6 +988,14 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
     // batch fetch metadata (UUID-based)
     let hearing_metadata = db.hearings().get_metadata_batch(&hearing_ids).await?;
     let floor_speech_metadata = db.floor_speeches().get_metadata_batch(&floor_speech_ids).await?;
+    let bill_metadata = db.bills().get_metadata_batch(&bill_ids).await?;
+    let vote_dates: HashMap<Uuid, String> = db
+        .roll_call_votes()
+        .get_by_ids(&vote_ids)
+        .await?
+        .into_iter()
+        .map(|v| (v.id, v.vote_date.date_naive().format("%Y-%m-%d").to_string()))
+        .collect();
 
     // batch fetch metadata (string-based for FTS)
     let hearing_metadata_by_pkg = db
@@ -524,32 +1008,22 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
         .await?;
 
     // batch fetch speakers (only for UUID-based results)
-    let hearing_speakers = db
-        .hearing_segments()
-        .get_speakers_for_segments(&hearing_segment_keys)
-        .await?;
-    let floor_speech_speakers = db
-        .floor_speech_segments()
-        .get_speakers_for_segments(&floor_speech_segment_keys)
-        .await?;
+    let (hearing_speakers, floor_speech_speakers) =
+        fetch_all_speakers(&db, &hearing_segment_keys, &floor_speech_segment_keys).await?;
 
     // apply metadata to results
     for r in results.iter_mut() {
-        match r.content_type.as_str() {
-            "hearing" => {
+        match ContentType::from_db_str(&r.content_type) {
+            Some(ContentType::Hearing) => {
                 if r.content_id == nil_uuid {
                     // FTS result - lookup by package_id
-                    if let Some(&HearingMetadata { ref title, date, .. }) =
-                        hearing_metadata_by_pkg.get(&r.content_id_str)
-                    {
-                        r.title = Some(title.clone());
-                        r.date = date.map(|d| d.format("%Y-%m-%d").to_string());
+                    if let Some(metadata) = hearing_metadata_by_pkg.get(&r.content_id_str) {
+                        apply_hearing_metadata(r, metadata);
                     }
                 } else {
                     // embeddings result - lookup by UUID
-                    if let Some(&HearingMetadata { ref title, date, .. }) = hearing_metadata.get(&r.content_id) {
-                        r.title = Some(title.clone());
-                        r.date = date.map(|d| d.format("%Y-%m-%d").to_string());
+                    if let Some(metadata) = hearing_metadata.get(&r.content_id) {
+                        apply_hearing_metadata(r, metadata);
                     }
                     if r.speaker_name.is_none() {
                         if let Some(speaker) =
@@ -560,22 +1034,16 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
                     }
                 }
             }
-            "floor_speech" => {
+            Some(ContentType::FloorSpeech) => {
                 if r.content_id == nil_uuid {
                     // FTS result - lookup by event_id
-                    if let Some(&FloorSpeechMetadata { ref title, date, .. }) =
-                        floor_speech_metadata_by_event.get(&r.content_id_str)
-                    {
-                        r.title = Some(title.clone());
-                        r.date = date.map(|d| d.format("%Y-%m-%d").to_string());
+                    if let Some(metadata) = floor_speech_metadata_by_event.get(&r.content_id_str) {
+                        apply_floor_speech_metadata(r, metadata);
                     }
                 } else {
                     // embeddings result - lookup by UUID
-                    if let Some(&FloorSpeechMetadata { ref title, date, .. }) =
-                        floor_speech_metadata.get(&r.content_id)
-                    {
-                        r.title = Some(title.clone());
-                        r.date = date.map(|d| d.format("%Y-%m-%d").to_string());
+                    if let Some(metadata) = floor_speech_metadata.get(&r.content_id) {
+                        apply_floor_speech_metadata(r, metadata);
                     }
                     if r.speaker_name.is_none() {
                         if let Some(speaker) =
@@ -586,6 +1054,14 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
                     }
                 }
             }
+            Some(ContentType::Bill) => {
+                if let Some(metadata) = bill_metadata.get(&r.content_id) {
+                    apply_bill_metadata(r, metadata);
+                }
+            }
+            Some(ContentType::Vote) => {
+                r.date = vote_dates.get(&r.content_id).cloned();
+            }
             _ => {}
         }
     }
@@ -593,6 +1069,30 @@ async fn enrich_results(results: &mut [SearchResult]) -> Result<()> {
     Ok(())
 }
 
+fn apply_hearing_metadata(result: &mut SearchResult, metadata: &HearingMetadata) {
+    result.title = Some(metadata.title.clone());
+    result.date = metadata.date.map(|d| d.format("%Y-%m-%d").to_string());
+    result.committee.clone_from(&metadata.committee);
+    result.chamber.clone_from(&metadata.chambers);
+}
+
+fn apply_floor_speech_metadata(result: &mut SearchResult, metadata: &FloorSpeechMetadata) {
+    result.title = Some(metadata.title.clone());
+    result.date = metadata.date.map(|d| d.format("%Y-%m-%d").to_string());
+    result.chamber.clone_from(&metadata.chamber);
+}
+
+fn apply_bill_metadata(result: &mut SearchResult, metadata: &BillMetadata) {
+    result.title = Some(match &metadata.title {
+        Some(title) => format!("{}: {title}", metadata.identifier),
+        None => metadata.identifier.clone(),
+    });
+    result.date = metadata.status_date.map(|d| d.format("%Y-%m-%d").to_string());
+    result.chamber.clone_from(&metadata.chamber);
+    result.speaker_name.clone_from(&metadata.sponsor);
+    result.status.clone_from(&metadata.status);
+}
+
 /// Format a score for display based on search mode
 fn format_score(score: f32, mode: SearchMode, max_score: f32) -> String {
     match mode {
@@ -616,6 +1116,18 @@ fn format_score(score: f32, mode: SearchMode, max_score: f32) -> String {
     }
 }
 
+/// Characters of result text to print on a line indented by `indent` columns
+///
+/// An explicit `--snippet-len` wins. Otherwise the text fills the terminal width,
+/// falling back to `default` when stdout isn't a terminal.
+fn snippet_len_for(explicit: Option<usize>, indent: usize, default: usize) -> usize {
+    explicit.unwrap_or_else(|| {
+        terminal_size::terminal_size().map_or(default, |(Width(width), _)| {
+            usize::from(width).saturating_sub(indent).max(MIN_SNIPPET_LEN)
+        })
+    })
+}
+
 /// Format and print search results in flat list format
 fn print_results_flat(
     query: &str,
@@ -624,6 +1136,7 @@ fn print_results_flat(
     offset: usize,
     has_more: bool,
     mode: SearchMode,
+    snippet_len: usize,
 ) {
     println!();
     println!("{}", format!("=== Search: \"{query}\" ===").cyan().bold());
@@ -644,10 +1157,11 @@ fn print_results_flat(
             .as_ref()
             .map_or_else(String::new, |s| format!(" | {}", s.cyan()));
 
-        let type_label = match result.content_type.as_str() {
-            "hearing" => "Hearing".green(),
-            "floor_speech" => "Floor Speech".blue(),
-            "vote" => "Vote".magenta(),
+        let type_label = match ContentType::from_db_str(&result.content_type) {
+            Some(ContentType::Hearing) => "Hearing".green(),
+            Some(ContentType::FloorSpeech) => "Floor Speech".blue(),
+            Some(ContentType::Vote) => "Vote".magenta(),
+            Some(ContentType::Bill) => "Bill".yellow(),
             _ => result.content_type.normal(),
         };
 
@@ -662,7 +1176,10 @@ fn print_results_flat(
         if let Some(ref title) = result.title {
             println!("    {}", truncate(title, 80).dimmed());
         }
-        println!("    \"{}\"", truncate(&result.text, 100));
+        if let Some(ref status) = result.status {
+            println!("    {}", format!("Status: {status}").dimmed());
+        }
+        println!("    \"{}\"", truncate(&result.text, snippet_len));
         println!();
     }
 
@@ -685,13 +1202,15 @@ fn print_results_flat(
     }
 }
 
-/// Format and print search results grouped by content type
+/// Format and print search results grouped by `group_by`
 fn print_results_grouped(
     query: &str,
     results: &[SearchResult],
     offset: usize,
     has_more: bool,
     mode: SearchMode,
+    group_by: GroupBy,
+    snippet_len: usize,
 ) {
     println!();
     println!("{}", format!("=== Search: \"{query}\" ===").cyan().bold());
@@ -699,36 +1218,33 @@ fn print_results_grouped(
 
     let max_score = results.iter().map(|r| r.score).fold(0.0_f32, f32::max);
 
-    // group by content type
-    use std::collections::HashMap;
-    let mut grouped: HashMap<&str, Vec<(usize, &SearchResult)>> = HashMap::new();
+    let mut grouped: HashMap<Option<&str>, Vec<(usize, &SearchResult)>> = HashMap::new();
 
     for (i, result) in results.iter().enumerate() {
         let result_num = offset + i + 1;
         grouped
-            .entry(&result.content_type)
+            .entry(group_key(result, group_by))
             .or_default()
             .push((result_num, result));
     }
 
-    // sort groups by max score
     let mut groups: Vec<_> = grouped.into_iter().collect();
-    groups.sort_by(|a, b| {
-        let max_a = a.1.iter().map(|(_, r)| r.score).fold(0.0_f32, f32::max);
-        let max_b = b.1.iter().map(|(_, r)| r.score).fold(0.0_f32, f32::max);
-        max_b
-            .partial_cmp(&max_a)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
+    if group_by == GroupBy::Date {
+        // newest first, undated last
+        groups.sort_by(|a, b| b.0.cmp(&a.0));
+    } else {
+        // sort groups by max score, with results missing the key last
+        groups.sort_by(|a, b| {
+            let max_a = a.1.iter().map(|(_, r)| r.score).fold(0.0_f32, f32::max);
+            let max_b = b.1.iter().map(|(_, r)| r.score).fold(0.0_f32, f32::max);
+            a.0.is_none()
+                .cmp(&b.0.is_none())
+                .then(max_b.partial_cmp(&max_a).unwrap_or(std::cmp::Ordering::Equal))
+        });
+    }
 
-    for (content_type, mut items) in groups {
-        let type_label = match content_type {
-            "hearing" => "Hearings".green().bold(),
-            "floor_speech" => "Floor Speeches".blue().bold(),
-            "vote" => "Votes".magenta().bold(),
-            _ => content_type.normal().bold(),
-        };
-        println!("{type_label}");
+    for (key, mut items) in groups {
+        println!("{}", group_label(key, group_by));
 
         // sort by score descending
         items.sort_by(|a, b| {
@@ -758,7 +1274,7 @@ fn print_results_grouped(
             if let Some(ref title) = result.title {
                 println!("       {}", truncate(title, 70).dimmed());
             }
-            println!("       \"{}\"", truncate(&result.text, 80));
+            println!("       \"{}\"", truncate(&result.text, snippet_len));
         }
         println!();
     }
@@ -778,6 +1294,33 @@ fn print_results_grouped(
     }
 }
 
+/// Grouping key for a result, or `None` if the result lacks that field
+fn group_key(result: &SearchResult, group_by: GroupBy) -> Option<&str> {
+    match group_by {
+        GroupBy::Source => Some(result.content_type.as_str()),
+        GroupBy::Committee => result.committee.as_deref(),
+        GroupBy::Chamber => result.chamber.as_deref(),
+        GroupBy::Date => result.date.as_deref(),
+    }
+}
+
+/// Heading for a group of results
+fn group_label(key: Option<&str>, group_by: GroupBy) -> colored::ColoredString {
+    match (group_by, key) {
+        (GroupBy::Source, Some(content_type)) => match ContentType::from_db_str(content_type) {
+            Some(ContentType::Hearing) => "Hearings".green().bold(),
+            Some(ContentType::FloorSpeech) => "Floor Speeches".blue().bold(),
+            Some(ContentType::Vote) => "Votes".magenta().bold(),
+            Some(ContentType::Bill) => "Bills".yellow().bold(),
+            _ => content_type.normal().bold(),
+        },
+        (_, Some(key)) => key.green().bold(),
+        (GroupBy::Committee, None) => "No committee".dimmed().bold(),
+        (GroupBy::Chamber, None) => "Unknown chamber".dimmed().bold(),
+        (GroupBy::Date | GroupBy::Source, None) => "Undated".dimmed().bold(),
+    }
+}
+
 /// Build a content type filter for `LanceDB` queries
 fn build_content_type_filter(types: &[ContentTypeFilter]) -> Option<String> {
     if types.is_empty() || types.iter().any(|t| matches!(t, ContentTypeFilter::All)) {
@@ -787,11 +1330,13 @@ fn build_content_type_filter(types: &[ContentTypeFilter]) -> Option<String> {
     let type_values: Vec<&str> = types
         .iter()
         .map(|t| match t {
-            ContentTypeFilter::All => "all",
-            ContentTypeFilter::Hearing => "hearing",
-            ContentTypeFilter::FloorSpeech => "floor_speech",
-            ContentTypeFilter::Vote => "vote",
+            ContentTypeFilter::All => ContentType::All,
+            ContentTypeFilter::Hearing => ContentType::Hearing,
+            ContentTypeFilter::FloorSpeech => ContentType::FloorSpeech,
+            ContentTypeFilter::Vote => ContentType::Vote,
+            ContentTypeFilter::Bill => ContentType::Bill,
         })
+        .map(|t| t.as_str())
         .collect();
 
     if type_values.is_empty() {
@@ -807,3 +1352,48 @@ fn build_content_type_filter(types: &[ContentTypeFilter]) -> Option<String> {
         ))
     }
 }
+
+/// Build `LanceDB` filter for floor speech page types
+///
+/// Other content types have no page type, so filtering on one limits results to
+/// floor speeches, as the API does.
+fn build_page_type_filter(page_types: &[PageTypeFilter]) -> Option<String> {
+    if page_types.is_empty() {
+        return None;
+    }
+
+    let codes: Vec<String> = page_types
+        .iter()
+        .map(|p| match p {
+            PageTypeFilter::House => PageType::House,
+            PageTypeFilter::Senate => PageType::Senate,
+            PageTypeFilter::Extensions => PageType::Extensions,
+            PageTypeFilter::Digest => PageType::DailyDigest,
+        })
+        .map(|p| format!("'{}'", p.code()))
+        .collect();
+    Some(format!(
+        "content_type = '{}' AND page_type IN ({})",
+        ContentType::FloorSpeech.as_str(),
+        codes.join(", ")
+    ))
+}
+
+/// Build `LanceDB` filter for speaker party
+///
+/// Segments whose speaker couldn't be attributed have a null party and never match.
+fn build_party_filter(parties: &[PartyFilter]) -> Option<String> {
+    if parties.is_empty() {
+        return None;
+    }
+
+    let codes: Vec<&str> = parties
+        .iter()
+        .map(|p| match p {
+            PartyFilter::D => "'D'",
+            PartyFilter::R => "'R'",
+            PartyFilter::I => "'I'",
+        })
+        .collect();
+    Some(format!("party IN ({})", codes.join(", ")))
+}
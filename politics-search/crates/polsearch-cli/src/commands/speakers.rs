@@ -0,0 +1,321 @@
+//! Speaker cleanup and activity commands
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use color_eyre::eyre::{eyre, Result};
+use colored::Colorize;
+use polsearch_core::{ContentType, Speaker};
+use polsearch_pipeline::stages::normalize_speaker;
+use polsearch_util::parse_date_bound;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::get_database;
+use crate::ActivityType;
+
+/// Maximum characters of statement text kept in a timeline snippet
+const SNIPPET_CHARS: usize = 200;
+
+/// Merge the `remove` speaker into `keep`
+pub async fn merge(keep: Uuid, remove: Uuid) -> Result<()> {
+    let db = get_database().await?;
+
+    let Some(kept) = db.speakers().get_by_id(keep).await? else {
+        return Err(eyre!("Speaker {keep} not found"));
+    };
+    let removed = db.speakers().get_by_id(remove).await?;
+
+    db.speakers().merge(keep, remove).await?;
+
+    println!();
+    match removed {
+        Some(removed) => println!(
+            "{} {} ({}) into {} ({})",
+            "Merged".green().bold(),
+            display_name(&removed),
+            remove.to_string().dimmed(),
+            display_name(&kept),
+            keep.to_string().dimmed()
+        ),
+        None => println!(
+            "{} {} no longer exists; nothing to merge",
+            "Skipped:".yellow(),
+            remove.to_string().dimmed()
+        ),
+    }
+    println!();
+    Ok(())
+}
+
+/// List speakers that share a normalized name
+pub async fn duplicates() -> Result<()> {
+    let db = get_database().await?;
+    let speakers = db.speakers().get_all().await?;
+
+    let mut groups: BTreeMap<String, Vec<Speaker>> = BTreeMap::new();
+    for speaker in speakers {
+        if let Some(key) = speaker.name.as_deref().and_then(duplicate_key) {
+            groups.entry(key).or_default().push(speaker);
+        }
+    }
+    groups.retain(|_, group| group.len() > 1);
+
+    println!();
+    println!("{}", "=== Likely Duplicate Speakers ===".cyan().bold());
+
+    if groups.is_empty() {
+        println!("{}", "No duplicates found".yellow());
+        println!();
+        return Ok(());
+    }
+
+    for (key, group) in &groups {
+        println!();
+        println!("{}", key.green().bold());
+        // get_all orders by appearances, so the first speaker is the best one to keep
+        for speaker in group {
+            println!(
+                "  {} {} ({} appearances{})",
+                speaker.id.to_string().dimmed(),
+                display_name(speaker),
+                speaker.total_appearances.to_string().cyan(),
+                if speaker.is_verified { ", verified" } else { "" }
+            );
+        }
+        let keep = group[0].id;
+        for speaker in &group[1..] {
+            println!(
+                "  {}",
+                format!("polsearch speakers merge --keep {keep} --remove {}", speaker.id).dimmed()
+            );
+        }
+    }
+
+    println!();
+    println!(
+        "{} groups, {} speakers",
+        groups.len().to_string().cyan(),
+        groups.values().map(Vec::len).sum::<usize>().to_string().cyan()
+    );
+    println!();
+    Ok(())
+}
+
+/// JSON timeline of a speaker's activity
+#[derive(Serialize)]
+struct Timeline<'a> {
+    speaker_id: Uuid,
+    name: &'a str,
+    slug: Option<&'a str>,
+    from: Option<String>,
+    to: Option<String>,
+    total_entries: usize,
+    entries: Vec<TimelineEntry>,
+}
+
+/// One hearing, floor speech, or vote in a timeline
+#[derive(Serialize)]
+struct TimelineEntry {
+    /// YYYY-MM-DD
+    date: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    /// Hearing, floor speech, or roll call vote ID
+    id: Uuid,
+    /// Hearing or speech title, or the vote question
+    title: String,
+    /// Start of the speaker's first statement (floor speeches; hearings don't store text)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<String>,
+    /// Number of statements the speaker made (hearings and floor speeches)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statements: Option<usize>,
+    /// Yea, Nay, Present, or Not Voting (votes)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<String>,
+    #[serde(skip)]
+    sort_date: NaiveDate,
+}
+
+/// Print a speaker's hearings, floor speeches, and votes as a chronological JSON timeline
+///
+/// Statements are grouped into one entry per hearing or floor speech. Votes are
+/// only available when the speaker is linked to a legislator.
+pub async fn timeline(
+    slug: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+    types: &[ActivityType],
+) -> Result<()> {
+    let from = from
+        .map(|v| parse_date_bound(v, true).ok_or_else(|| eyre!("Invalid --from date '{v}'")))
+        .transpose()?;
+    let to = to
+        .map(|v| parse_date_bound(v, false).ok_or_else(|| eyre!("Invalid --to date '{v}'")))
+        .transpose()?;
+    let wants = |kind: ActivityType| types.is_empty() || types.contains(&kind);
+
+    let db = get_database().await?;
+    let Some(speaker) = db.speakers().get_by_slug(slug).await? else {
+        return Err(eyre!("Speaker '{slug}' not found"));
+    };
+    // merged speakers have had their statements repointed to the canonical record
+    let speaker = match speaker.merged_into_id {
+        Some(_) => db.speakers().get_canonical(speaker.id).await?.unwrap_or(speaker),
+        None => speaker,
+    };
+
+    // statements aren't linked to speakers at ingest, so also match on the name
+    let name = speaker.name.as_deref().and_then(|n| normalize_speaker(n).name);
+    let mut entries = Vec::new();
+
+    if wants(ActivityType::Hearing) {
+        let rows = db
+            .hearing_statements()
+            .get_activity_by_speaker(speaker.id, name.as_deref(), from.as_deref(), to.as_deref())
+            .await?;
+        entries.extend(group_statements(ContentType::Hearing.as_str(), rows));
+    }
+
+    if wants(ActivityType::FloorSpeech) {
+        let rows = db
+            .floor_speech_statements()
+            .get_activity_by_speaker(speaker.id, name.as_deref(), from.as_deref(), to.as_deref())
+            .await?;
+        entries.extend(group_statements(ContentType::FloorSpeech.as_str(), rows));
+    }
+
+    if wants(ActivityType::Vote) {
+        match db.speakers().get_legislator_id(speaker.id).await? {
+            Some(legislator_id) => {
+                let rows = db
+                    .individual_votes()
+                    .get_activity_by_legislator(legislator_id, from.as_deref(), to.as_deref())
+                    .await?;
+                entries.extend(rows.into_iter().map(|(id, vote_date, question, position)| {
+                    let date = vote_date.date_naive();
+                    TimelineEntry {
+                        date: date.format("%Y-%m-%d").to_string(),
+                        kind: ContentType::Vote.as_str(),
+                        id,
+                        title: question,
+                        snippet: None,
+                        statements: None,
+                        position: Some(position),
+                        sort_date: date,
+                    }
+                }));
+            }
+            // stderr keeps stdout clean for the JSON output
+            None => eprintln!(
+                "{} {} is not linked to a legislator; no votes included",
+                "Note:".yellow(),
+                display_name(&speaker)
+            ),
+        }
+    }
+
+    // stable sort keeps each source's own ordering within a day
+    entries.sort_by_key(|entry| entry.sort_date);
+
+    let timeline = Timeline {
+        speaker_id: speaker.id,
+        name: display_name(&speaker),
+        slug: speaker.slug.as_deref(),
+        from,
+        to,
+        total_entries: entries.len(),
+        entries,
+    };
+    println!("{}", serde_json::to_string_pretty(&timeline)?);
+    Ok(())
+}
+
+/// Collapse per-statement rows (already ordered by date) into one entry per document
+fn group_statements(
+    kind: &'static str,
+    rows: Vec<(Uuid, NaiveDate, String, Option<String>)>,
+) -> Vec<TimelineEntry> {
+    let mut entries: Vec<TimelineEntry> = Vec::new();
+    for (id, date, title, text) in rows {
+        match entries.last_mut() {
+            Some(last) if last.id == id => {
+                last.statements = last.statements.map(|n| n + 1);
+            }
+            _ => entries.push(TimelineEntry {
+                date: date.format("%Y-%m-%d").to_string(),
+                kind,
+                id,
+                title,
+                snippet: text.as_deref().map(snippet),
+                statements: Some(1),
+                position: None,
+                sort_date: date,
+            }),
+        }
+    }
+    entries
+}
+
+/// First [`SNIPPET_CHARS`] characters of `text` with whitespace collapsed
+fn snippet(text: &str) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= SNIPPET_CHARS {
+        return collapsed;
+    }
+    let mut cut: String = collapsed.chars().take(SNIPPET_CHARS).collect();
+    cut.push_str("...");
+    cut
+}
+
+/// Grouping key for a speaker name: the normalized label, lowercased
+fn duplicate_key(name: &str) -> Option<String> {
+    normalize_speaker(name).label().map(str::to_lowercase)
+}
+
+fn display_name(speaker: &Speaker) -> &str {
+    speaker.name.as_deref().unwrap_or("(unidentified)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: Uuid, day: u32, text: Option<&str>) -> (Uuid, NaiveDate, String, Option<String>) {
+        let date = NaiveDate::from_ymd_opt(2024, 3, day).expect("valid date");
+        (id, date, format!("Title {day}"), text.map(String::from))
+    }
+
+    #[test]
+    fn test_group_statements_one_entry_per_document() {
+        let first = Uuid::now_v7();
+        let second = Uuid::now_v7();
+        let rows = vec![
+            row(first, 1, Some("Opening  remarks\n on the bill")),
+            row(first, 1, Some("A follow-up")),
+            row(second, 5, None),
+        ];
+
+        let entries = group_statements("floor_speech", rows);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, first);
+        assert_eq!(entries[0].statements, Some(2));
+        assert_eq!(entries[0].snippet.as_deref(), Some("Opening remarks on the bill"));
+        assert_eq!(entries[0].date, "2024-03-01");
+        assert_eq!(entries[1].id, second);
+        assert_eq!(entries[1].statements, Some(1));
+        assert_eq!(entries[1].snippet, None);
+    }
+
+    #[test]
+    fn test_snippet_truncates_on_char_boundary() {
+        assert_eq!(snippet("short text"), "short text");
+
+        let long = "é".repeat(SNIPPET_CHARS + 10);
+        let cut = snippet(&long);
+        assert_eq!(cut.chars().count(), SNIPPET_CHARS + 3);
+        assert!(cut.ends_with("..."));
+    }
+}
@@ -0,0 +1,123 @@
+//! Summarize ingested floor speeches
+
+use chrono::NaiveDate;
+use color_eyre::eyre::Result;
+use colored::Colorize;
+use serde::Serialize;
+
+use super::get_database;
+
+/// Floor speech coverage report
+#[derive(Debug, Serialize)]
+struct SpeechStats {
+    total_speeches: i64,
+    processed_speeches: i64,
+    total_statements: i64,
+    total_segments: i64,
+    first_date: Option<NaiveDate>,
+    last_date: Option<NaiveDate>,
+    by_year: Vec<CountRow<i32>>,
+    by_chamber: Vec<CountRow<String>>,
+    by_page_type: Vec<CountRow<String>>,
+    /// Months between the first and last speech with no floor speeches
+    missing_months: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CountRow<K> {
+    key: K,
+    count: i64,
+}
+
+impl<K> From<(K, i64)> for CountRow<K> {
+    fn from((key, count): (K, i64)) -> Self {
+        Self { key, count }
+    }
+}
+
+/// Run the speeches stats command
+pub async fn run(json: bool) -> Result<()> {
+    let db = get_database().await?;
+    let repo = db.floor_speeches();
+
+    let (total_statements, total_segments) = repo.total_statements_and_segments().await?;
+    let date_range = repo.date_range().await?;
+    let stats = SpeechStats {
+        total_speeches: repo.count().await?,
+        processed_speeches: repo.count_processed().await?,
+        total_statements,
+        total_segments,
+        first_date: date_range.map(|(first, _)| first),
+        last_date: date_range.map(|(_, last)| last),
+        by_year: into_rows(repo.count_by_year().await?),
+        by_chamber: into_rows(repo.count_by_chamber().await?),
+        by_page_type: into_rows(repo.count_by_page_type().await?),
+        missing_months: repo.get_missing_months().await?,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+    } else {
+        print_stats(&stats);
+    }
+    Ok(())
+}
+
+fn into_rows<K>(counts: Vec<(K, i64)>) -> Vec<CountRow<K>> {
+    counts.into_iter().map(CountRow::from).collect()
+}
+
+fn print_stats(stats: &SpeechStats) {
+    println!();
+    println!("{}", "Floor speech statistics".green().bold());
+    println!();
+    println!(
+        "  Speeches:   {} ({} processed)",
+        stats.total_speeches.to_string().cyan(),
+        stats.processed_speeches.to_string().cyan()
+    );
+    println!("  Statements: {}", stats.total_statements.to_string().cyan());
+    println!("  Segments:   {}", stats.total_segments.to_string().cyan());
+    if let (Some(first), Some(last)) = (stats.first_date, stats.last_date) {
+        println!("  Coverage:   {first} to {last}");
+    }
+
+    print_counts("By year", &stats.by_year);
+    print_counts("By chamber", &stats.by_chamber);
+    print_counts("By page type", &stats.by_page_type);
+
+    println!();
+    if stats.missing_months.is_empty() {
+        println!("{}", "No months without speeches".green());
+    } else {
+        println!(
+            "{}",
+            format!("Months with no speeches ({}):", stats.missing_months.len()).yellow()
+        );
+        for month in &stats.missing_months {
+            println!("  {month}");
+        }
+    }
+    println!();
+}
+
+fn print_counts<K: std::fmt::Display>(title: &str, rows: &[CountRow<K>]) {
+    if rows.is_empty() {
+        return;
+    }
+    let width = rows
+        .iter()
+        .map(|row| row.key.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    println!();
+    println!("{}", title.bold());
+    for row in rows {
+        println!(
+            "  {:<width$}  {:>8}",
+            row.key.to_string(),
+            row.count.to_string().cyan()
+        );
+    }
+}
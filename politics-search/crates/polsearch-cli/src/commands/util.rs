@@ -1,6 +1,6 @@
 //! Utility commands for data transfer and deployment
 
-use color_eyre::eyre::{bail, Result};
+use color_eyre::eyre::{bail, Result, WrapErr};
 use std::process::Command;
 use tracing::info;
 
@@ -36,35 +36,59 @@ pub async fn archive(paths: &[String], output: &str) -> Result<()> {
     Ok(())
 }
 
+/// Options passed through to rsync for push and pull
+#[derive(Debug, Clone, Default)]
+pub struct RsyncOptions {
+    /// List what would transfer without moving any data
+    pub dry_run: bool,
+    /// Bandwidth limit in rsync's `--bwlimit` syntax (e.g., `5000` KiB/s or `5m`)
+    pub bwlimit: Option<String>,
+}
+
 /// Push archive to remote server via rsync
-pub async fn push(archive: &str, remote: &str) -> Result<()> {
+pub async fn push(archive: &str, remote: &str, options: &RsyncOptions) -> Result<()> {
     info!("Pushing {} to {}", archive, remote);
-
-    let status = Command::new("rsync")
-        .args(["-avz", "--progress", archive, remote])
-        .status()?;
-
-    if !status.success() {
-        bail!("rsync command failed with exit code: {:?}", status.code());
+    rsync(archive, remote, options)?;
+    if options.dry_run {
+        info!("Dry run complete, nothing transferred");
+    } else {
+        info!("Push complete");
     }
-
-    info!("Push complete");
     Ok(())
 }
 
 /// Pull archive from remote server via rsync
-pub async fn pull(remote: &str, output: &str) -> Result<()> {
+pub async fn pull(remote: &str, output: &str, options: &RsyncOptions) -> Result<()> {
     info!("Pulling {} to {}", remote, output);
+    rsync(remote, output, options)?;
+    if options.dry_run {
+        info!("Dry run complete, nothing transferred");
+    } else {
+        info!("Pull complete");
+    }
+    Ok(())
+}
 
-    let status = Command::new("rsync")
-        .args(["-avz", "--progress", remote, output])
-        .status()?;
+/// Run rsync from `source` to `dest`, failing on a non-zero exit code
+fn rsync(source: &str, dest: &str, options: &RsyncOptions) -> Result<()> {
+    let mut cmd = Command::new("rsync");
+    cmd.args(["-avz", "--progress"]);
+    if options.dry_run {
+        cmd.arg("--dry-run");
+    }
+    if let Some(limit) = &options.bwlimit {
+        cmd.arg(format!("--bwlimit={limit}"));
+    }
+    cmd.args([source, dest]);
+
+    let status = cmd.status().wrap_err("failed to run rsync (is it installed?)")?;
 
     if !status.success() {
-        bail!("rsync command failed with exit code: {:?}", status.code());
+        match status.code() {
+            Some(code) => bail!("rsync command failed with exit code: {}", code),
+            None => bail!("rsync command was terminated by a signal"),
+        }
     }
-
-    info!("Pull complete");
     Ok(())
 }
 
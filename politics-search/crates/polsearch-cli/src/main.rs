@@ -1,5 +1,10 @@
 use clap::{Parser, Subcommand, ValueEnum};
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use polsearch_db::BillFilter;
+use polsearch_pipeline::stages::{
+    hybrid_oversample_from_env, max_file_size_bytes, Sample, DEFAULT_DEDUP_THRESHOLD,
+    DEFAULT_MAX_FILE_SIZE_MB, DEFAULT_SAMPLE_SEED,
+};
 use tracing_subscriber::EnvFilter;
 
 mod cli;
@@ -16,6 +21,32 @@ pub enum ContentTypeFilter {
     FloorSpeech,
     /// Congressional vote records only
     Vote,
+    /// Bill titles and summaries only
+    Bill,
+}
+
+/// Congressional Record page type filter for floor speech search
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum PageTypeFilter {
+    /// House section
+    House,
+    /// Senate section
+    Senate,
+    /// Extensions of Remarks (inserted, not spoken)
+    Extensions,
+    /// Daily Digest
+    Digest,
+}
+
+/// Speaker party filter for search
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum PartyFilter {
+    /// Democrat
+    D,
+    /// Republican
+    R,
+    /// Independent
+    I,
 }
 
 #[derive(Parser)]
@@ -61,12 +92,30 @@ enum Commands {
         command: VotesCommands,
     },
 
+    /// Ingest and search bills
+    Bills {
+        #[command(subcommand)]
+        command: BillsCommands,
+    },
+
     /// List and manage committees
     Committees {
         #[command(subcommand)]
         command: CommitteesCommands,
     },
 
+    /// Find and merge duplicate speaker records
+    Speakers {
+        #[command(subcommand)]
+        command: SpeakersCommands,
+    },
+
+    /// Measure search latency and result counts
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommands,
+    },
+
     /// Fast text-only ingestion for FTS (no embeddings)
     Fts {
         #[command(subcommand)]
@@ -130,23 +179,53 @@ enum Commands {
         #[arg(long, default_value = "0")]
         offset: usize,
 
-        /// Group results by source
+        /// Group results by source (shorthand for --group-by source)
         #[arg(long)]
         group: bool,
 
+        /// Group results by source, committee, chamber, or date
+        #[arg(long, value_enum)]
+        group_by: Option<GroupBy>,
+
         /// Search mode
         #[arg(long, value_enum, default_value = "hybrid")]
         mode: SearchMode,
 
-        /// Filter by content type (all, hearing, floor-speech, vote)
+        /// Rerank the top candidates with a cross-encoder
+        #[arg(long)]
+        rerank: bool,
+
+        /// Drop results whose text has fewer than N words (0 keeps everything)
+        #[arg(long, default_value = "0")]
+        min_words: usize,
+
+        /// Hybrid mode: fetch limit * N rows per component before fusion (higher
+        /// improves ranking quality at some latency cost; default `HYBRID_OVERSAMPLE` or 3)
+        #[arg(long)]
+        hybrid_oversample: Option<usize>,
+
+        /// FTS mode: rank results higher when their speaker is named in the query
+        #[arg(long)]
+        speaker_boost: bool,
+
+        /// Weight scores toward newer content by `2^(-age_days / N)` (default N: 365 days)
+        #[arg(
+            long,
+            value_name = "HALF_LIFE_DAYS",
+            num_args = 0..=1,
+            default_missing_value = "365"
+        )]
+        boost_recent: Option<f64>,
+
+        /// Filter by content type (all, hearing, floor-speech, vote, bill)
         #[arg(long, default_value = "all", value_delimiter = ',')]
         r#type: Vec<ContentTypeFilter>,
 
-        /// Start of date range (e.g., 2024-06)
+        /// Start of date range: YYYY, YYYY-MM, or YYYY-MM-DD (hearings and floor speeches)
         #[arg(long)]
         from: Option<String>,
 
-        /// End of date range (e.g., 2025-01)
+        /// End of date range: YYYY, YYYY-MM, or YYYY-MM-DD (hearings and floor speeches)
         #[arg(long)]
         to: Option<String>,
 
@@ -154,6 +233,15 @@ enum Commands {
         #[arg(long)]
         speaker: Option<String>,
 
+        /// Filter by speaker party (D, R, I); requires ingest with --legislators
+        #[arg(long, value_delimiter = ',', ignore_case = true)]
+        party: Vec<PartyFilter>,
+
+        /// Floor speech page types to include (house, senate, extensions, digest);
+        /// limits results to floor speeches
+        #[arg(long, value_delimiter = ',')]
+        page_type: Vec<PageTypeFilter>,
+
         /// Filter by committee (hearings only, fuzzy match)
         #[arg(long)]
         committee: Option<String>,
@@ -166,6 +254,18 @@ enum Commands {
         #[arg(long)]
         congress: Option<i16>,
 
+        /// Start of congress range, inclusive (overrides --congress)
+        #[arg(long)]
+        congress_from: Option<i16>,
+
+        /// End of congress range, inclusive (overrides --congress)
+        #[arg(long)]
+        congress_to: Option<i16>,
+
+        /// Search only within one content item (UUID, or `package_id` for hearings)
+        #[arg(long)]
+        content_id: Option<String>,
+
         /// `LanceDB` storage path
         #[arg(long, default_value = "~/.polsearch/lancedb")]
         lancedb_path: String,
@@ -174,9 +274,26 @@ enum Commands {
         #[arg(long, short = 'f', default_value = "text")]
         format: OutputFormat,
 
+        /// Characters of result text shown in text output (default: fit the terminal,
+        /// or 100 flat / 80 grouped when not a terminal)
+        #[arg(long)]
+        snippet_len: Option<usize>,
+
+        /// Add `highlights` (field, snippet, matched terms) to JSON results (not vector)
+        #[arg(long)]
+        highlight_json: bool,
+
         /// Include N segments before and after each match for context (RAG mode)
         #[arg(long, default_value = "0")]
         context: usize,
+
+        /// Save results to a `LanceDB` table (created or appended), viewable with `db show`
+        #[arg(long)]
+        save_to: Option<String>,
+
+        /// Vocabulary for "did you mean" suggestions when FTS finds nothing (see `db build-vocab`)
+        #[arg(long, default_value = "~/.polsearch/vocab.tsv")]
+        vocab: String,
     },
 }
 
@@ -187,12 +304,36 @@ pub enum SearchMode {
     Hybrid,
     /// Semantic similarity search using embeddings
     Vector,
-    /// Keyword-based full-text search (matches any terms)
+    /// Keyword-based full-text search (matches any terms; supports AND, OR, -term)
     Fts,
     /// Exact phrase matching (matches the exact phrase)
     Phrase,
 }
 
+/// Key for grouping search results
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum GroupBy {
+    /// Content type (hearings, floor speeches, votes)
+    Source,
+    /// Hearing committee
+    Committee,
+    /// House or Senate
+    Chamber,
+    /// Hearing or speech date
+    Date,
+}
+
+/// Activity type for speaker timelines
+#[derive(Clone, Copy, Debug, ValueEnum, PartialEq, Eq)]
+pub enum ActivityType {
+    /// Hearing statements
+    Hearing,
+    /// Floor speech statements
+    FloorSpeech,
+    /// Roll call vote positions (requires a linked legislator)
+    Vote,
+}
+
 /// Output format for search results
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub enum OutputFormat {
@@ -202,6 +343,17 @@ pub enum OutputFormat {
     Json,
 }
 
+/// Output format for the missing hearings report
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ReportFormat {
+    /// YAML report with a human-readable summary (default)
+    Text,
+    /// JSON report for programmatic use
+    Json,
+    /// Newline-delimited JSON, one missing hearing per line for streaming
+    Ndjson,
+}
+
 #[derive(Subcommand)]
 enum DbCommands {
     /// List all tables with row counts
@@ -209,7 +361,7 @@ enum DbCommands {
 
     /// Show rows from a table
     Show {
-        /// Table name (`text_embeddings`, `speaker_embeddings`, `speaker_centroids`)
+        /// Table name (`text_embeddings`, `speaker_embeddings`, `speaker_centroids`, or a saved search)
         table: String,
 
         /// Number of rows to show
@@ -230,6 +382,28 @@ enum DbCommands {
         #[arg(long, default_value = "vector")]
         mode: String,
     },
+
+    /// Compare tables, content type counts, and content IDs against another `LanceDB` path
+    Diff {
+        /// Reference `LanceDB` path to compare against
+        #[arg(long)]
+        other: String,
+
+        /// List content IDs that are only in one dataset
+        #[arg(long)]
+        show_ids: bool,
+    },
+
+    /// Build the spelling vocabulary used for "did you mean" suggestions on empty FTS results
+    BuildVocab {
+        /// Output vocabulary file
+        #[arg(long, default_value = "~/.polsearch/vocab.tsv")]
+        output: String,
+
+        /// Drop terms seen fewer than this many times (filters out typos in the corpus)
+        #[arg(long, default_value = "3")]
+        min_count: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -252,6 +426,41 @@ enum CommitteesCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum SpeakersCommands {
+    /// Merge a duplicate speaker into another, repointing all references
+    Merge {
+        /// Speaker ID to keep
+        #[arg(long)]
+        keep: uuid::Uuid,
+
+        /// Duplicate speaker ID to merge and delete
+        #[arg(long)]
+        remove: uuid::Uuid,
+    },
+
+    /// List likely duplicate speakers (same normalized name)
+    Duplicates,
+
+    /// Export a speaker's hearings, floor speeches, and votes as a JSON timeline
+    Timeline {
+        /// Speaker slug
+        slug: String,
+
+        /// Start of date range (e.g., 2024-06)
+        #[arg(long)]
+        from: Option<String>,
+
+        /// End of date range (e.g., 2025-01)
+        #[arg(long)]
+        to: Option<String>,
+
+        /// Activity types to include (hearing, floor-speech, vote; default: all)
+        #[arg(long, value_delimiter = ',')]
+        r#type: Vec<ActivityType>,
+    },
+}
+
 #[derive(Subcommand)]
 enum HearingsCommands {
     /// Ingest congressional hearing transcripts
@@ -264,10 +473,52 @@ enum HearingsCommands {
         #[arg(long)]
         limit: Option<usize>,
 
+        /// Process a deterministic random sample of N files (for representative testing)
+        #[arg(long)]
+        sample: Option<usize>,
+
+        /// Seed for --sample (fix for reproducible runs)
+        #[arg(long, default_value_t = DEFAULT_SAMPLE_SEED)]
+        seed: u64,
+
         /// Force re-process even if hearing exists
         #[arg(long)]
         force: bool,
 
+        /// Merge consecutive statements by the same speaker before chunking
+        #[arg(long)]
+        merge_statements: bool,
+
+        /// Store a normalized speaker name, honorific, and role alongside the raw label
+        #[arg(long)]
+        normalize_speaker: bool,
+
+        /// Drop segments that repeat earlier text from any transcript in this run before embedding
+        #[arg(long)]
+        dedup_segments: bool,
+
+        /// Similarity (0.0-1.0) at which --dedup-segments treats segments as duplicates
+        #[arg(long, default_value_t = DEFAULT_DEDUP_THRESHOLD)]
+        dedup_threshold: f64,
+
+        /// Embed each segment with its speaker and up to N trailing words of the previous
+        /// segment (stored display text is unchanged)
+        #[arg(long, value_name = "N")]
+        context_embed: Option<usize>,
+
+        /// Path to a legislators YAML file; tags each statement with its speaker's party
+        #[arg(long)]
+        legislators: Option<String>,
+
+        /// Skip transcript files larger than this many MB (0 = no limit)
+        #[arg(long, default_value_t = DEFAULT_MAX_FILE_SIZE_MB)]
+        max_file_size: u64,
+
+        /// Show a single updating status line instead of one line per file
+        /// (skips and failures are still printed)
+        #[arg(long)]
+        compact_progress: bool,
+
         /// Dry run - show what would be processed without making changes
         #[arg(long)]
         dry_run: bool,
@@ -306,6 +557,10 @@ enum HearingsCommands {
         /// Filter to specific chamber (house, senate)
         #[arg(long)]
         chamber: Option<String>,
+
+        /// Report format (text is a YAML report; ndjson emits one hearing per line)
+        #[arg(long, short = 'f', default_value = "text")]
+        format: ReportFormat,
     },
 }
 
@@ -348,10 +603,47 @@ enum SpeechesCommands {
         #[arg(long)]
         limit: Option<usize>,
 
+        /// Process a deterministic random sample of N files (for representative testing)
+        #[arg(long)]
+        sample: Option<usize>,
+
+        /// Seed for --sample (fix for reproducible runs)
+        #[arg(long, default_value_t = DEFAULT_SAMPLE_SEED)]
+        seed: u64,
+
         /// Force re-process even if speech exists
         #[arg(long)]
         force: bool,
 
+        /// Merge consecutive statements by the same speaker before chunking
+        #[arg(long)]
+        merge_statements: bool,
+
+        /// Store a normalized speaker name, honorific, and role alongside the raw label
+        #[arg(long)]
+        normalize_speaker: bool,
+
+        /// Drop segments that repeat earlier text from any transcript in this run before embedding
+        #[arg(long)]
+        dedup_segments: bool,
+
+        /// Similarity (0.0-1.0) at which --dedup-segments treats segments as duplicates
+        #[arg(long, default_value_t = DEFAULT_DEDUP_THRESHOLD)]
+        dedup_threshold: f64,
+
+        /// Embed each segment with its speaker and up to N trailing words of the previous
+        /// segment (stored display text is unchanged)
+        #[arg(long, value_name = "N")]
+        context_embed: Option<usize>,
+
+        /// Path to a legislators YAML file; tags each statement with its speaker's party
+        #[arg(long)]
+        legislators: Option<String>,
+
+        /// Skip transcript files larger than this many MB (0 = no limit)
+        #[arg(long, default_value_t = DEFAULT_MAX_FILE_SIZE_MB)]
+        max_file_size: u64,
+
         /// Dry run - show what would be processed without making changes
         #[arg(long)]
         dry_run: bool,
@@ -368,6 +660,13 @@ enum SpeechesCommands {
         #[arg(long, default_value = "~/.polsearch/lancedb")]
         lancedb_path: String,
     },
+
+    /// Summarize ingested floor speeches: counts by year, chamber, and page type, plus gaps
+    Stats {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -413,6 +712,107 @@ enum VotesCommands {
         #[arg(long, default_value = "~/.polsearch/lancedb")]
         lancedb_path: String,
     },
+
+    /// Find votes similar to a given vote (requires `votes embed`)
+    Related {
+        /// Vote UUID or roll call identifier (e.g., h1-116.2019)
+        vote_id: String,
+
+        /// Number of related votes to return
+        #[arg(long, default_value = "10")]
+        limit: usize,
+
+        /// `LanceDB` storage path
+        #[arg(long, default_value = "~/.polsearch/lancedb")]
+        lancedb_path: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum BillsCommands {
+    /// Ingest bill data files and embed bill titles and summaries
+    Ingest {
+        /// Directory with bill `data.json` files
+        #[arg(long, default_value = "data/bills")]
+        path: String,
+
+        /// Limit files to process (for testing)
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Force re-embed even if the bill is already embedded
+        #[arg(long)]
+        force: bool,
+
+        /// Dry run - show what would be processed without making changes
+        #[arg(long)]
+        dry_run: bool,
+
+        /// `LanceDB` storage path
+        #[arg(long, default_value = "~/.polsearch/lancedb")]
+        lancedb_path: String,
+    },
+
+    /// Search bills semantically, by structured filters, or both
+    Search {
+        /// Search query (omit to list bills matching the filters)
+        query: Option<String>,
+
+        /// Filter by congress number
+        #[arg(long)]
+        congress: Option<i16>,
+
+        /// Filter by originating chamber
+        #[arg(long, value_parser = ["house", "senate"], ignore_case = true)]
+        chamber: Option<String>,
+
+        /// Filter by sponsor name (case-insensitive substring)
+        #[arg(long)]
+        sponsor: Option<String>,
+
+        /// Filter by action status prefix (e.g., introduced, enacted, pass_over)
+        #[arg(long)]
+        status: Option<String>,
+
+        /// Number of results to return
+        #[arg(long, default_value = "10")]
+        limit: usize,
+
+        /// `LanceDB` storage path
+        #[arg(long, default_value = "~/.polsearch/lancedb")]
+        lancedb_path: String,
+
+        /// Output format
+        #[arg(long, short = 'f', default_value = "text")]
+        format: OutputFormat,
+    },
+}
+
+#[derive(Subcommand)]
+enum BenchCommands {
+    /// Run every query in each search mode and report latency percentiles and result counts
+    Search {
+        /// File with one query per line (blank lines and `#` comments are skipped)
+        #[arg(long)]
+        queries: String,
+
+        /// Number of results to request per query
+        #[arg(long, default_value = "10")]
+        limit: usize,
+
+        /// Hybrid mode: fetch limit * N rows per component before fusion
+        /// (default `HYBRID_OVERSAMPLE` or 3)
+        #[arg(long)]
+        hybrid_oversample: Option<usize>,
+
+        /// `LanceDB` storage path
+        #[arg(long, default_value = "~/.polsearch/lancedb")]
+        lancedb_path: String,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -431,10 +831,22 @@ enum FtsCommands {
         #[arg(long)]
         votes: bool,
 
+        /// Ingest bill titles and summaries from `PostgreSQL` (run `bills ingest` first)
+        #[arg(long)]
+        bills: bool,
+
         /// Limit files to process (for testing)
         #[arg(long)]
         limit: Option<usize>,
 
+        /// Process a deterministic random sample of N files (for representative testing)
+        #[arg(long)]
+        sample: Option<usize>,
+
+        /// Seed for --sample (fix for reproducible runs)
+        #[arg(long, default_value_t = DEFAULT_SAMPLE_SEED)]
+        seed: u64,
+
         /// Force re-process even if content exists
         #[arg(long)]
         force: bool,
@@ -442,6 +854,22 @@ enum FtsCommands {
         /// Dry run - show what would be processed without making changes
         #[arg(long)]
         dry_run: bool,
+
+        /// Store speaker names in a separate `speaker_name` column (for `--speaker-boost`)
+        #[arg(long)]
+        include_speaker_in_fts: bool,
+
+        /// Drop segments that repeat earlier text from any transcript in this run
+        #[arg(long)]
+        dedup_segments: bool,
+
+        /// Similarity (0.0-1.0) at which --dedup-segments treats segments as duplicates
+        #[arg(long, default_value_t = DEFAULT_DEDUP_THRESHOLD)]
+        dedup_threshold: f64,
+
+        /// Skip transcript files larger than this many MB (0 = no limit)
+        #[arg(long, default_value_t = DEFAULT_MAX_FILE_SIZE_MB)]
+        max_file_size: u64,
     },
 
     /// Clear/delete the FTS table to start fresh
@@ -469,6 +897,14 @@ enum UtilCommands {
         /// Remote destination (user@host:path)
         #[arg(long, short)]
         remote: String,
+
+        /// Show what would be transferred without copying anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Limit bandwidth (rsync `--bwlimit` value, e.g. 5000 for KiB/s or 5m)
+        #[arg(long)]
+        bwlimit: Option<String>,
     },
 
     /// Pull archive from remote server via rsync
@@ -479,6 +915,14 @@ enum UtilCommands {
         /// Local output path
         #[arg(long, short)]
         output: String,
+
+        /// Show what would be transferred without copying anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Limit bandwidth (rsync `--bwlimit` value, e.g. 5000 for KiB/s or 5m)
+        #[arg(long)]
+        bwlimit: Option<String>,
     },
 
     /// Extract archive to destination
@@ -525,21 +969,56 @@ async fn main() -> Result<()> {
                 DbCommands::Search { query, limit, mode } => {
                     commands::db::search(&expanded, &query, limit, &mode).await?;
                 }
+                DbCommands::Diff { other, show_ids } => {
+                    let other = shellexpand::tilde(&other).to_string();
+                    commands::db::diff(&expanded, &other, show_ids).await?;
+                }
+                DbCommands::BuildVocab { output, min_count } => {
+                    let output = shellexpand::tilde(&output).to_string();
+                    commands::db::build_vocab(&expanded, &output, min_count).await?;
+                }
             }
         }
         Commands::Hearings { command } => match command {
             HearingsCommands::Ingest {
                 path,
                 limit,
+                sample,
+                seed,
                 force,
+                merge_statements,
+                normalize_speaker,
+                dedup_segments,
+                dedup_threshold,
+                context_embed,
+                legislators,
+                max_file_size,
+                compact_progress,
                 dry_run,
                 validate,
                 year,
                 lancedb_path,
             } => {
                 let expanded = shellexpand::tilde(&lancedb_path).to_string();
-                commands::ingest_hearings::run(&path, limit, force, dry_run, validate, year, &expanded)
-                    .await?;
+                let sample = sample.map(|size| Sample::new(size, seed));
+                commands::ingest_hearings::run(
+                    &path,
+                    limit,
+                    sample,
+                    force,
+                    merge_statements,
+                    normalize_speaker,
+                    dedup_segments.then_some(dedup_threshold),
+                    context_embed,
+                    legislators.as_deref(),
+                    max_file_size_bytes(max_file_size),
+                    compact_progress,
+                    dry_run,
+                    validate,
+                    year,
+                    &expanded,
+                )
+                .await?;
             }
             HearingsCommands::Missing {
                 yaml,
@@ -547,9 +1026,17 @@ async fn main() -> Result<()> {
                 output,
                 congress,
                 chamber,
+                format,
             } => {
-                commands::missing_hearings::run(&yaml, &transcripts, output, congress, chamber)
-                    .await?;
+                commands::missing_hearings::run(
+                    &yaml,
+                    &transcripts,
+                    output,
+                    congress,
+                    chamber,
+                    format,
+                )
+                .await?;
             }
         },
         Commands::Speeches { command } => match command {
@@ -566,18 +1053,44 @@ async fn main() -> Result<()> {
             SpeechesCommands::Ingest {
                 path,
                 limit,
+                sample,
+                seed,
                 force,
+                merge_statements,
+                normalize_speaker,
+                dedup_segments,
+                dedup_threshold,
+                context_embed,
+                legislators,
+                max_file_size,
                 dry_run,
                 validate,
                 year,
                 lancedb_path,
             } => {
                 let expanded = shellexpand::tilde(&lancedb_path).to_string();
+                let sample = sample.map(|size| Sample::new(size, seed));
                 commands::ingest_floor_speeches::run(
-                    &path, limit, force, dry_run, validate, year, &expanded,
+                    &path,
+                    limit,
+                    sample,
+                    force,
+                    merge_statements,
+                    normalize_speaker,
+                    dedup_segments.then_some(dedup_threshold),
+                    context_embed,
+                    legislators.as_deref(),
+                    max_file_size_bytes(max_file_size),
+                    dry_run,
+                    validate,
+                    year,
+                    &expanded,
                 )
                 .await?;
             }
+            SpeechesCommands::Stats { json } => {
+                commands::speech_stats::run(json).await?;
+            }
         },
         Commands::Votes { command } => match command {
             VotesCommands::Ingest {
@@ -598,6 +1111,46 @@ async fn main() -> Result<()> {
                 let expanded = shellexpand::tilde(&lancedb_path).to_string();
                 commands::embed_votes::run(limit, force, dry_run, year, &expanded).await?;
             }
+            VotesCommands::Related {
+                vote_id,
+                limit,
+                lancedb_path,
+            } => {
+                let expanded = shellexpand::tilde(&lancedb_path).to_string();
+                commands::related_votes::run(&vote_id, limit, &expanded).await?;
+            }
+        },
+        Commands::Bills { command } => match command {
+            BillsCommands::Ingest {
+                path,
+                limit,
+                force,
+                dry_run,
+                lancedb_path,
+            } => {
+                let expanded = shellexpand::tilde(&lancedb_path).to_string();
+                commands::ingest_bills::run(&path, limit, force, dry_run, &expanded).await?;
+            }
+            BillsCommands::Search {
+                query,
+                congress,
+                chamber,
+                sponsor,
+                status,
+                limit,
+                lancedb_path,
+                format,
+            } => {
+                let expanded = shellexpand::tilde(&lancedb_path).to_string();
+                let filter = BillFilter {
+                    congress,
+                    chamber: chamber.as_deref(),
+                    sponsor: sponsor.as_deref(),
+                    status: status.as_deref(),
+                };
+                commands::bill_search::run(query.as_deref(), &filter, limit, &expanded, format)
+                    .await?;
+            }
         },
         Commands::Committees { command } => match command {
             CommitteesCommands::List { chamber, counts } => {
@@ -607,6 +1160,38 @@ async fn main() -> Result<()> {
                 commands::committees::search(&query).await?;
             }
         },
+        Commands::Speakers { command } => match command {
+            SpeakersCommands::Merge { keep, remove } => {
+                commands::speakers::merge(keep, remove).await?;
+            }
+            SpeakersCommands::Duplicates => {
+                commands::speakers::duplicates().await?;
+            }
+            SpeakersCommands::Timeline {
+                slug,
+                from,
+                to,
+                r#type,
+            } => {
+                commands::speakers::timeline(&slug, from.as_deref(), to.as_deref(), &r#type)
+                    .await?;
+            }
+        },
+        Commands::Bench { command } => match command {
+            BenchCommands::Search {
+                queries,
+                limit,
+                hybrid_oversample,
+                lancedb_path,
+                json,
+            } => {
+                let expanded = shellexpand::tilde(&lancedb_path).to_string();
+                let hybrid_oversample =
+                    hybrid_oversample.unwrap_or_else(hybrid_oversample_from_env);
+                commands::bench::search(&queries, limit, hybrid_oversample, &expanded, json)
+                    .await?;
+            }
+        },
         Commands::Fts {
             command,
             lancedb_path,
@@ -617,17 +1202,29 @@ async fn main() -> Result<()> {
                     hearings_path,
                     speeches_path,
                     votes,
+                    bills,
                     limit,
+                    sample,
+                    seed,
                     force,
                     dry_run,
+                    include_speaker_in_fts,
+                    dedup_segments,
+                    dedup_threshold,
+                    max_file_size,
                 } => {
                     commands::fts::ingest(
                         hearings_path.as_deref(),
                         speeches_path.as_deref(),
                         votes,
+                        bills,
                         limit,
+                        sample.map(|size| Sample::new(size, seed)),
                         force,
                         dry_run,
+                        include_speaker_in_fts,
+                        dedup_segments.then_some(dedup_threshold),
+                        max_file_size_bytes(max_file_size),
                         &expanded,
                     )
                     .await?;
@@ -641,11 +1238,23 @@ async fn main() -> Result<()> {
             UtilCommands::Archive { paths, output } => {
                 commands::util::archive(&paths, &output).await?;
             }
-            UtilCommands::Push { archive, remote } => {
-                commands::util::push(&archive, &remote).await?;
+            UtilCommands::Push {
+                archive,
+                remote,
+                dry_run,
+                bwlimit,
+            } => {
+                let options = commands::util::RsyncOptions { dry_run, bwlimit };
+                commands::util::push(&archive, &remote, &options).await?;
             }
-            UtilCommands::Pull { remote, output } => {
-                commands::util::pull(&remote, &output).await?;
+            UtilCommands::Pull {
+                remote,
+                output,
+                dry_run,
+                bwlimit,
+            } => {
+                let options = commands::util::RsyncOptions { dry_run, bwlimit };
+                commands::util::pull(&remote, &output, &options).await?;
             }
             UtilCommands::Unarchive { archive, dest } => {
                 commands::util::unarchive(&archive, &dest).await?;
@@ -679,22 +1288,51 @@ async fn main() -> Result<()> {
             limit,
             offset,
             group,
+            group_by,
             mode,
+            rerank,
+            min_words,
+            hybrid_oversample,
+            speaker_boost,
+            boost_recent,
             r#type,
             from,
             to,
             speaker,
+            party,
+            page_type,
             committee,
             chamber,
             congress,
+            congress_from,
+            congress_to,
+            content_id,
             lancedb_path,
             format,
+            snippet_len,
+            highlight_json,
             context,
+            save_to,
+            vocab,
         } => {
             let expanded = shellexpand::tilde(&lancedb_path).to_string();
+            let vocab = shellexpand::tilde(&vocab).to_string();
+            let congress_range =
+                polsearch_util::resolve_congress_range(congress, congress_from, congress_to)
+                    .ok_or_else(|| {
+                        eyre!(
+                            "--congress-from ({}) must not be greater than --congress-to ({})",
+                            congress_from.unwrap_or_default(),
+                            congress_to.unwrap_or_default()
+                        )
+                    })?;
+            let group_by = group_by.or(group.then_some(GroupBy::Source));
+            let hybrid_oversample = hybrid_oversample.unwrap_or_else(hybrid_oversample_from_env);
             commands::search::run(
-                &query, limit, offset, group, mode, r#type, from, to, speaker, committee, chamber,
-                congress, &expanded, format, context,
+                &query, limit, offset, group_by, mode, rerank, min_words, hybrid_oversample,
+                speaker_boost, boost_recent, r#type, from, to, speaker, &party, &page_type,
+                committee, chamber, congress_range, content_id, &expanded, format, snippet_len,
+                highlight_json, context, save_to.as_deref(), &vocab,
             )
             .await?;
         }
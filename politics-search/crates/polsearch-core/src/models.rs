@@ -31,7 +31,7 @@ pub use content::Content;
 pub use content_speaker::ContentSpeaker;
 pub use content_type::ContentType;
 pub use content_variant::{ContentVariant, VariantType};
-pub use floor_speech::FloorSpeech;
+pub use floor_speech::{FloorSpeech, PageType};
 pub use floor_speech_segment::FloorSpeechSegment;
 pub use floor_speech_statement::FloorSpeechStatement;
 pub use hearing::Hearing;
@@ -1,11 +1,11 @@
 //! Bill model - congressional legislation reference
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
-/// A congressional bill referenced by votes
+/// A congressional bill, referenced by votes and searchable by title and summary
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Bill {
     pub id: Uuid,
@@ -17,6 +17,14 @@ pub struct Bill {
     pub bill_number: i32,
     /// Optional bill title
     pub title: Option<String>,
+    /// Latest CRS summary text
+    pub summary: Option<String>,
+    /// Sponsor display name (e.g., "Rep. Smith, Adam [D-WA-9]")
+    pub sponsor: Option<String>,
+    /// Action status (e.g., `INTRODUCED`, `PASS_OVER:HOUSE`, `ENACTED:SIGNED`)
+    pub status: Option<String>,
+    /// Date the bill reached its current status
+    pub status_date: Option<NaiveDate>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -30,10 +38,43 @@ impl Bill {
             bill_type,
             bill_number,
             title,
+            summary: None,
+            sponsor: None,
+            status: None,
+            status_date: None,
             created_at: Utc::now(),
         }
     }
 
+    #[must_use]
+    pub fn with_summary(mut self, summary: String) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+
+    #[must_use]
+    pub fn with_sponsor(mut self, sponsor: String) -> Self {
+        self.sponsor = Some(sponsor);
+        self
+    }
+
+    #[must_use]
+    pub fn with_status(mut self, status: String, status_date: Option<NaiveDate>) -> Self {
+        self.status = Some(status);
+        self.status_date = status_date;
+        self
+    }
+
+    /// Chamber the bill originated in ("house" or "senate"), from its type prefix
+    #[must_use]
+    pub fn chamber(&self) -> Option<&'static str> {
+        match self.bill_type.chars().next() {
+            Some('h') => Some("house"),
+            Some('s') => Some("senate"),
+            _ => None,
+        }
+    }
+
     /// Returns the standard bill identifier (e.g., "H.R. 1234" or "S. 567")
     #[must_use]
     pub fn bill_identifier(&self) -> String {
@@ -49,4 +90,47 @@ impl Bill {
             other => format!("{} {}", other.to_uppercase(), self.bill_number),
         }
     }
+
+    /// Text to embed, index, and display: identifier, title, and summary
+    ///
+    /// Returns `None` for bills with neither a title nor a summary.
+    #[must_use]
+    pub fn search_text(&self) -> Option<String> {
+        let body: Vec<&str> = [self.title.as_deref(), self.summary.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .collect();
+        if body.is_empty() {
+            return None;
+        }
+        Some(format!("{}: {}", self.bill_identifier(), body.join("\n\n")))
+    }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_text_joins_title_and_summary() {
+        let bill = Bill::new(118, "hr".to_string(), 1234, Some(" Clean Water Act ".to_string()))
+            .with_summary("Amends the act.".to_string());
+        assert_eq!(
+            bill.search_text().as_deref(),
+            Some("H.R. 1234: Clean Water Act\n\nAmends the act.")
+        );
+    }
+
+    #[test]
+    fn test_search_text_uses_summary_alone() {
+        let bill = Bill::new(118, "sres".to_string(), 5, None).with_summary("A resolution.".into());
+        assert_eq!(bill.search_text().as_deref(), Some("S.Res. 5: A resolution."));
+    }
+
+    #[test]
+    fn test_search_text_none_without_title_or_summary() {
+        assert_eq!(Bill::new(118, "s".to_string(), 1, None).search_text(), None);
+    }
+}
+
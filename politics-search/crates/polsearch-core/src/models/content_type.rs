@@ -17,20 +17,33 @@ pub enum ContentType {
     FloorSpeech,
     /// Congressional vote records
     Vote,
+    /// Bill titles and summaries
+    Bill,
 }
 
 impl ContentType {
-    /// Returns the database value for this content type
+    /// Every content type, in declaration order
+    pub const ALL_VARIANTS: [Self; 5] =
+        [Self::All, Self::Hearing, Self::FloorSpeech, Self::Vote, Self::Bill];
+
+    /// Returns the value stored in the `content_type` column for this content type
     #[must_use]
-    pub const fn as_db_value(&self) -> &'static str {
+    pub const fn as_str(&self) -> &'static str {
         match self {
             Self::All => "all",
             Self::Hearing => "hearing",
             Self::FloorSpeech => "floor_speech",
             Self::Vote => "vote",
+            Self::Bill => "bill",
         }
     }
 
+    /// Parses a stored `content_type` column value (exact match)
+    #[must_use]
+    pub fn from_db_str(s: &str) -> Option<Self> {
+        Self::ALL_VARIANTS.into_iter().find(|t| t.as_str() == s)
+    }
+
     /// Returns true if this represents all content types
     #[must_use]
     pub const fn is_all(&self) -> bool {
@@ -40,12 +53,7 @@ impl ContentType {
 
 impl fmt::Display for ContentType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::All => write!(f, "all"),
-            Self::Hearing => write!(f, "hearing"),
-            Self::FloorSpeech => write!(f, "floor_speech"),
-            Self::Vote => write!(f, "vote"),
-        }
+        f.write_str(self.as_str())
     }
 }
 
@@ -54,11 +62,29 @@ impl FromStr for ContentType {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "all" => Ok(Self::All),
-            "hearing" => Ok(Self::Hearing),
-            "floor_speech" | "floorspeech" => Ok(Self::FloorSpeech),
-            "vote" => Ok(Self::Vote),
-            _ => Err(format!("Unknown content type: {s}")),
+            "floorspeech" => Ok(Self::FloorSpeech),
+            lower => Self::from_db_str(lower).ok_or_else(|| format!("Unknown content type: {s}")),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_db_str_round_trip() {
+        for content_type in ContentType::ALL_VARIANTS {
+            assert_eq!(ContentType::from_db_str(content_type.as_str()), Some(content_type));
+            assert_eq!(content_type.to_string(), content_type.as_str());
+            assert_eq!(content_type.as_str().parse::<ContentType>(), Ok(content_type));
+        }
+    }
+
+    #[test]
+    fn test_from_db_str_is_exact() {
+        assert_eq!(ContentType::from_db_str("Hearing"), None);
+        assert_eq!(ContentType::from_db_str("podcast"), None);
+        assert_eq!("FloorSpeech".parse::<ContentType>(), Ok(ContentType::FloorSpeech));
+    }
+}
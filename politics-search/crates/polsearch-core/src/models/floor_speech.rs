@@ -67,6 +67,18 @@ impl FloorSpeech {
         }
     }
 
+    /// Override the page type derived from the granule ID
+    ///
+    /// Accepts a filter name or code in any case ("senate", "S", "s") and stores
+    /// the code; empty or unrecognized values keep the derived page type.
+    #[must_use]
+    pub fn with_page_type(mut self, page_type: Option<String>) -> Self {
+        if let Some(code) = page_type.as_deref().and_then(PageType::parse).map(|p| p.code()) {
+            self.page_type = code.to_string();
+        }
+        self
+    }
+
     /// Returns true if this is a Senate floor speech
     #[must_use]
     pub fn is_senate(&self) -> bool {
@@ -80,6 +92,56 @@ impl FloorSpeech {
     }
 }
 
+/// Congressional Record section a floor speech was printed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PageType {
+    /// House section
+    House,
+    /// Senate section
+    Senate,
+    /// Extensions of Remarks (inserted into the record, not spoken)
+    Extensions,
+    /// Daily Digest
+    DailyDigest,
+}
+
+impl PageType {
+    /// Every page type, in declaration order
+    pub const ALL_VARIANTS: [Self; 4] =
+        [Self::House, Self::Senate, Self::Extensions, Self::DailyDigest];
+
+    /// Returns the letter stored in the `page_type` column
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::House => "H",
+            Self::Senate => "S",
+            Self::Extensions => "E",
+            Self::DailyDigest => "D",
+        }
+    }
+
+    /// Returns the user-facing filter name
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::House => "house",
+            Self::Senate => "senate",
+            Self::Extensions => "extensions",
+            Self::DailyDigest => "digest",
+        }
+    }
+
+    /// Parses a filter name or stored code (case-insensitive)
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        Self::ALL_VARIANTS
+            .into_iter()
+            .find(|t| t.name().eq_ignore_ascii_case(s) || t.code().eq_ignore_ascii_case(s))
+    }
+}
+
 /// Extract page type (H, S, E, D) from granule ID
 /// e.g., "CREC-2024-01-17-pt1-PgS157" -> "S"
 fn extract_page_type(granule_id: &str) -> String {
@@ -91,3 +153,35 @@ fn extract_page_type(granule_id: &str) -> String {
     }
     String::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speech(granule_id: &str) -> FloorSpeech {
+        FloorSpeech::new(
+            "event".to_string(),
+            granule_id.to_string(),
+            "Title".to_string(),
+            "Senate".to_string(),
+            NaiveDate::from_ymd_opt(2024, 1, 17).expect("valid date"),
+            "https://example.com".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_with_page_type_stores_code() {
+        let granule = "CREC-2024-01-17-pt1-PgH157";
+        assert_eq!(speech(granule).with_page_type(Some("s".into())).page_type, "S");
+        assert_eq!(speech(granule).with_page_type(Some("senate".into())).page_type, "S");
+        assert_eq!(speech(granule).with_page_type(Some("digest".into())).page_type, "D");
+    }
+
+    #[test]
+    fn test_with_page_type_keeps_derived_for_unknown() {
+        let granule = "CREC-2024-01-17-pt1-PgH157";
+        assert_eq!(speech(granule).with_page_type(None).page_type, "H");
+        assert_eq!(speech(granule).with_page_type(Some(String::new())).page_type, "H");
+        assert_eq!(speech(granule).with_page_type(Some("X".into())).page_type, "H");
+    }
+}
@@ -18,6 +18,14 @@ pub struct HearingStatement {
     pub speaker_label: String,
     /// Resolved speaker ID (after fuzzy matching)
     pub speaker_id: Option<Uuid>,
+    /// Canonical speaker name or role (set by `--normalize-speaker`)
+    pub normalized_speaker: Option<String>,
+    /// Honorific stripped from the label (e.g., "Mr.", "Senator")
+    pub speaker_honorific: Option<String>,
+    /// Canonical role (e.g., "Chair", "Presiding Officer")
+    pub speaker_role: Option<String>,
+    /// Speaker's party ("D", "R", "I"), when resolved to a member of Congress
+    pub party: Option<String>,
     /// Word count for filtering
     pub word_count: i32,
     pub created_at: DateTime<Utc>,
@@ -38,11 +46,36 @@ impl HearingStatement {
             statement_index,
             speaker_label,
             speaker_id: None,
+            normalized_speaker: None,
+            speaker_honorific: None,
+            speaker_role: None,
+            party: None,
             word_count,
             created_at: Utc::now(),
         }
     }
 
+    /// Attach normalized speaker fields alongside the raw label
+    #[must_use]
+    pub fn with_normalized_speaker(
+        mut self,
+        normalized_speaker: Option<String>,
+        speaker_honorific: Option<String>,
+        speaker_role: Option<String>,
+    ) -> Self {
+        self.normalized_speaker = normalized_speaker;
+        self.speaker_honorific = speaker_honorific;
+        self.speaker_role = speaker_role;
+        self
+    }
+
+    /// Attach the speaker's party
+    #[must_use]
+    pub fn with_party(mut self, party: Option<String>) -> Self {
+        self.party = party;
+        self
+    }
+
     /// Returns true if this statement is too short to be meaningful
     #[must_use]
     pub const fn is_too_short(&self) -> bool {
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::ContentType;
+
 /// Type of speaker in congressional proceedings
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -90,7 +92,7 @@ impl SpeakerType {
         }
 
         // hearings with Mr./Ms./Dr. prefix and no congressional title = witness
-        if content_type == "hearing"
+        if content_type == ContentType::Hearing.as_str()
             && (label_upper.starts_with("MR. ")
                 || label_upper.starts_with("MS. ")
                 || label_upper.starts_with("MRS. ")
@@ -100,7 +102,7 @@ impl SpeakerType {
         }
 
         // floor speech Mr./Ms. - use chamber
-        if content_type == "floor_speech" {
+        if content_type == ContentType::FloorSpeech.as_str() {
             return match chamber {
                 Some(c) if c.contains("Senate") => Self::Senator,
                 Some(c) if c.contains("House") => Self::Representative,
@@ -125,6 +125,8 @@ pub struct TranscriptionTask {
     pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Worker that claimed the task via `claim_next`
+    pub worker_id: Option<String>,
 }
 
 impl TranscriptionTask {
@@ -141,6 +143,7 @@ impl TranscriptionTask {
             completed_at: None,
             created_at: now,
             updated_at: now,
+            worker_id: None,
         }
     }
 
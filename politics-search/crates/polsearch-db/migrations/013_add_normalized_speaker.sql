@@ -0,0 +1,15 @@
+-- Normalized speaker fields (populated when ingesting with --normalize-speaker)
+-- speaker_label keeps the raw transcript string
+
+ALTER TABLE hearing_statements
+    ADD COLUMN normalized_speaker VARCHAR(255),
+    ADD COLUMN speaker_honorific VARCHAR(50),
+    ADD COLUMN speaker_role VARCHAR(100);
+
+ALTER TABLE floor_speech_statements
+    ADD COLUMN normalized_speaker VARCHAR(255),
+    ADD COLUMN speaker_honorific VARCHAR(50),
+    ADD COLUMN speaker_role VARCHAR(100);
+
+CREATE INDEX idx_hearing_statements_normalized_speaker ON hearing_statements(normalized_speaker);
+CREATE INDEX idx_floor_speech_statements_normalized_speaker ON floor_speech_statements(normalized_speaker);
@@ -0,0 +1,8 @@
+-- Speaker party (D/R/I) resolved from a legislators file at ingest
+-- NULL when the speaker couldn't be matched to a member of Congress
+
+ALTER TABLE hearing_statements ADD COLUMN party VARCHAR(1);
+ALTER TABLE floor_speech_statements ADD COLUMN party VARCHAR(1);
+
+CREATE INDEX idx_hearing_statements_party ON hearing_statements(party) WHERE party IS NOT NULL;
+CREATE INDEX idx_floor_speech_statements_party ON floor_speech_statements(party) WHERE party IS NOT NULL;
@@ -0,0 +1,6 @@
+-- Worker that claimed a transcription task, so concurrent workers don't double-process
+-- NULL while queued or when the task was started without claiming
+
+ALTER TABLE transcription_tasks ADD COLUMN worker_id VARCHAR(255);
+
+CREATE INDEX idx_transcription_tasks_queued_created ON transcription_tasks(created_at) WHERE status = 'queued';
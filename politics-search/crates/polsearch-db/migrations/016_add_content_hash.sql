@@ -0,0 +1,6 @@
+-- SHA-256 of a transcript's statements, so re-ingesting picks up corrected files
+-- NULL for rows ingested before hashes were recorded
+
+ALTER TABLE hearings ADD COLUMN content_hash VARCHAR(64);
+
+ALTER TABLE floor_speeches ADD COLUMN content_hash VARCHAR(64);
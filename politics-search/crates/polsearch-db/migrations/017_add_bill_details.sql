@@ -0,0 +1,9 @@
+-- Bill summary, sponsor, and action status for bill search
+-- NULL for bills only known from vote records
+
+ALTER TABLE bills ADD COLUMN summary TEXT;
+ALTER TABLE bills ADD COLUMN sponsor VARCHAR(255);
+ALTER TABLE bills ADD COLUMN status VARCHAR(50);
+ALTER TABLE bills ADD COLUMN status_date DATE;
+
+CREATE INDEX idx_bills_status ON bills(status) WHERE status IS NOT NULL;
@@ -0,0 +1,5 @@
+-- Case-insensitive prefix index for speaker autocomplete
+-- text_pattern_ops lets LOWER(name) LIKE 'prefix%' use the index regardless of collation
+
+CREATE INDEX idx_speakers_name_prefix ON speakers(LOWER(name) text_pattern_ops)
+    WHERE merged_into_id IS NULL;
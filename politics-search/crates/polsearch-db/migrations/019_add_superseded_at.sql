@@ -0,0 +1,18 @@
+-- Soft delete for --force re-ingestion: the old row is marked superseded while
+-- its replacement is written, and hard-deleted only once the replacement succeeds
+
+ALTER TABLE hearings ADD COLUMN superseded_at TIMESTAMPTZ;
+
+ALTER TABLE floor_speeches ADD COLUMN superseded_at TIMESTAMPTZ;
+
+-- A superseded row and its replacement share natural keys until finalized
+ALTER TABLE hearings DROP CONSTRAINT hearings_package_id_key;
+CREATE UNIQUE INDEX idx_hearings_package_id_current
+    ON hearings(package_id) WHERE superseded_at IS NULL;
+
+ALTER TABLE floor_speeches DROP CONSTRAINT floor_speeches_event_id_key;
+ALTER TABLE floor_speeches DROP CONSTRAINT floor_speeches_granule_id_key;
+CREATE UNIQUE INDEX idx_floor_speeches_event_id_current
+    ON floor_speeches(event_id) WHERE superseded_at IS NULL;
+CREATE UNIQUE INDEX idx_floor_speeches_granule_id_current
+    ON floor_speeches(granule_id) WHERE superseded_at IS NULL;
@@ -0,0 +1,37 @@
+//! Connection pool health probe
+
+use std::time::{Duration, Instant};
+
+use sqlx::PgPool;
+
+/// How long the `SELECT 1` probe may take before the database counts as unreachable
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Pool statistics and the result of a round-trip probe query
+#[derive(Debug, Clone, Copy)]
+pub struct PoolHealth {
+    /// Whether `SELECT 1` succeeded within the probe timeout
+    pub connected: bool,
+    /// Open connections, idle or in use
+    pub pool_size: u32,
+    /// Open connections not currently in use
+    pub idle_connections: usize,
+    /// Probe round-trip time, if it succeeded
+    pub latency: Option<Duration>,
+}
+
+impl PoolHealth {
+    /// Run the probe query and read the pool counters
+    pub(crate) async fn check(pool: &PgPool) -> Self {
+        let start = Instant::now();
+        let probe = tokio::time::timeout(PROBE_TIMEOUT, sqlx::query("SELECT 1").execute(pool));
+        let connected = matches!(probe.await, Ok(Ok(_)));
+
+        Self {
+            connected,
+            pool_size: pool.size(),
+            idle_connections: pool.num_idle(),
+            latency: connected.then(|| start.elapsed()),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Advisory lock preventing concurrent ingestion of the same source
+
+use sqlx::pool::PoolConnection;
+use sqlx::{PgPool, Postgres};
+
+use crate::DbError;
+
+/// Session-level `PostgreSQL` advisory lock held while an ingestion runs
+///
+/// The lock lives on a dedicated pooled connection. Call [`IngestLock::release`]
+/// when ingestion finishes; if the guard is dropped instead (e.g., on an early
+/// error), its connection is closed, which also releases the lock.
+pub struct IngestLock {
+    conn: Option<PoolConnection<Postgres>>,
+    key: String,
+}
+
+impl IngestLock {
+    /// Try to take the lock for `key` without waiting
+    ///
+    /// Returns `None` if another session already holds it.
+    pub(crate) async fn try_acquire(pool: &PgPool, key: &str) -> Result<Option<Self>, DbError> {
+        let mut conn = pool.acquire().await?;
+        let (acquired,): (bool,) =
+            sqlx::query_as("SELECT pg_try_advisory_lock(hashtextextended($1, 0))")
+                .bind(key)
+                .fetch_one(&mut *conn)
+                .await?;
+
+        Ok(acquired.then(|| Self {
+            conn: Some(conn),
+            key: key.to_string(),
+        }))
+    }
+
+    /// The key this lock was taken for
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Release the lock and return its connection to the pool
+    ///
+    /// # Errors
+    /// Returns `DbError` if the unlock query fails
+    pub async fn release(mut self) -> Result<(), DbError> {
+        if let Some(mut conn) = self.conn.take() {
+            sqlx::query("SELECT pg_advisory_unlock(hashtextextended($1, 0))")
+                .bind(&self.key)
+                .execute(&mut *conn)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for IngestLock {
+    fn drop(&mut self) {
+        // closing the session releases its advisory locks; returning the
+        // connection to the pool would keep the lock held
+        if let Some(conn) = self.conn.take() {
+            drop(conn.detach());
+        }
+    }
+}
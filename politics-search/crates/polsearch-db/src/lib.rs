@@ -1,13 +1,17 @@
 //! Database layer for `PolSearch`
 
 mod error;
+mod health;
+mod ingest_lock;
 mod repos;
 
 pub use error::DbError;
+pub use health::PoolHealth;
+pub use ingest_lock::IngestLock;
 pub use repos::*;
 
-use sqlx::PgPool;
 use sqlx::postgres::PgPoolOptions;
+use sqlx::{PgConnection, PgPool};
 
 /// Database connection wrapper
 #[derive(Clone)]
@@ -33,6 +37,52 @@ impl Database {
         &self.0
     }
 
+    /// Run `f` in a transaction, committing if it returns `Ok` and rolling back on `Err`
+    ///
+    /// `f` gets the transaction's connection; pass it to the repos' `*_in` methods
+    /// so writes across several tables land together or not at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f`, or `DbError` if the transaction can't begin or commit
+    pub async fn transaction<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: AsyncFnOnce(&mut PgConnection) -> Result<T, E>,
+        E: From<DbError>,
+    {
+        let mut tx = self.0.begin().await.map_err(DbError::from)?;
+        match f(&mut *tx).await {
+            Ok(value) => {
+                tx.commit().await.map_err(DbError::from)?;
+                Ok(value)
+            }
+            Err(e) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    tracing::warn!("Transaction rollback failed: {rollback_err}");
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Probe the database with `SELECT 1` and report pool statistics
+    ///
+    /// A failed or timed-out probe sets `connected` to `false`.
+    pub async fn health(&self) -> PoolHealth {
+        PoolHealth::check(&self.0).await
+    }
+
+    /// Try to take the ingestion lock for `key` (e.g., content type plus source directory)
+    ///
+    /// Returns `None` if another process is already ingesting with the same key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if a connection can't be acquired or the lock query fails
+    pub async fn try_ingest_lock(&self, key: &str) -> Result<Option<IngestLock>, DbError> {
+        IngestLock::try_acquire(&self.0, key).await
+    }
+
     /// Get the source repository
     #[must_use]
     pub const fn sources(&self) -> SourceRepo<'_> {
@@ -16,13 +16,14 @@ mod legislator;
 mod nomination;
 mod roll_call_vote;
 mod segment;
+mod segment_speakers;
 mod source;
 mod speaker;
 mod transcription_batch;
 mod transcription_task;
 
 pub use amendment::AmendmentRepo;
-pub use bill::BillRepo;
+pub use bill::{BillFilter, BillMetadata, BillRepo};
 pub use committee::CommitteeRepo;
 pub use content::ContentRepo;
 pub use content_speaker::ContentSpeakerRepo;
@@ -37,6 +38,7 @@ pub use legislator::LegislatorRepo;
 pub use nomination::NominationRepo;
 pub use roll_call_vote::RollCallVoteRepo;
 pub use segment::SegmentRepo;
+pub use segment_speakers::{SegmentSpeakerLookup, SegmentSpeakers, fetch_all_speakers};
 pub use source::SourceRepo;
 pub use speaker::SpeakerRepo;
 pub use transcription_batch::TranscriptionBatchRepo;
@@ -1,10 +1,66 @@
 //! Bill repository
 
 use crate::DbError;
+use chrono::NaiveDate;
 use polsearch_core::Bill;
 use sqlx::PgPool;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Metadata for a bill, used for search result enrichment
+#[derive(Debug, Clone)]
+pub struct BillMetadata {
+    /// Standard identifier (e.g., "H.R. 1234")
+    pub identifier: String,
+    pub congress: i16,
+    pub chamber: Option<String>,
+    pub title: Option<String>,
+    pub sponsor: Option<String>,
+    pub status: Option<String>,
+    pub status_date: Option<NaiveDate>,
+}
+
+/// Structured filters for bill search; `None` fields match every bill
+#[derive(Debug, Clone, Default)]
+pub struct BillFilter<'f> {
+    pub congress: Option<i16>,
+    /// "house" or "senate"
+    pub chamber: Option<&'f str>,
+    /// Case-insensitive substring of the sponsor name
+    pub sponsor: Option<&'f str>,
+    /// Case-insensitive status prefix (e.g., "enacted" matches "ENACTED:SIGNED")
+    pub status: Option<&'f str>,
+}
+
+/// `WHERE` clause for [`BillFilter`], bound as congress, chamber, sponsor, status ($1-$4)
+const BILL_FILTER_CLAUSE: &str = "($1::SMALLINT IS NULL OR congress = $1) \
+     AND ($2::TEXT IS NULL OR LEFT(bill_type, 1) = $2) \
+     AND ($3::TEXT IS NULL OR LOWER(sponsor) LIKE $3) \
+     AND ($4::TEXT IS NULL OR UPPER(status) LIKE $4)";
+
+impl BillFilter<'_> {
+    /// Whether any filter is set
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.congress.is_none()
+            && self.chamber.is_none()
+            && self.sponsor.is_none()
+            && self.status.is_none()
+    }
+
+    /// Chamber prefix, sponsor pattern, and status pattern for [`BILL_FILTER_CLAUSE`]
+    fn bind_values(&self) -> (Option<String>, Option<String>, Option<String>) {
+        // bill types start with the originating chamber: hr, hres, ... / s, sres, ...
+        let chamber = self
+            .chamber
+            .and_then(|c| c.chars().next())
+            .map(|c| c.to_ascii_lowercase().to_string());
+        let sponsor = self.sponsor.map(|s| format!("%{}%", s.to_lowercase()));
+        let status = self.status.map(|s| format!("{}%", s.to_uppercase()));
+        (chamber, sponsor, status)
+    }
+}
+
 pub struct BillRepo<'a> {
     pool: &'a PgPool,
 }
@@ -87,6 +143,135 @@ impl<'a> BillRepo<'a> {
         Ok(bill.id)
     }
 
+    /// Insert a bill, or update the title and details of an existing one, returning its ID
+    ///
+    /// # Errors
+    /// Returns `DbError` if the upsert fails
+    pub async fn upsert(&self, bill: &Bill) -> Result<Uuid, DbError> {
+        let (id,): (Uuid,) = sqlx::query_as(
+            r"
+            INSERT INTO bills (id, congress, bill_type, bill_number, title, summary, sponsor,
+                               status, status_date, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (congress, bill_type, bill_number) DO UPDATE SET
+                title = COALESCE(EXCLUDED.title, bills.title),
+                summary = COALESCE(EXCLUDED.summary, bills.summary),
+                sponsor = COALESCE(EXCLUDED.sponsor, bills.sponsor),
+                status = COALESCE(EXCLUDED.status, bills.status),
+                status_date = COALESCE(EXCLUDED.status_date, bills.status_date)
+            RETURNING id
+            ",
+        )
+        .bind(bill.id)
+        .bind(bill.congress)
+        .bind(&bill.bill_type)
+        .bind(bill.bill_number)
+        .bind(&bill.title)
+        .bind(&bill.summary)
+        .bind(&bill.sponsor)
+        .bind(&bill.status)
+        .bind(bill.status_date)
+        .bind(bill.created_at)
+        .fetch_one(self.pool)
+        .await?;
+        Ok(id)
+    }
+
+    /// Get bills with pagination, newest congress first
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_all_paginated(&self, offset: i64, limit: i64) -> Result<Vec<Bill>, DbError> {
+        let bills = sqlx::query_as::<_, Bill>(
+            r"
+            SELECT * FROM bills
+            ORDER BY congress DESC, bill_type, bill_number
+            LIMIT $1 OFFSET $2
+            ",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(bills)
+    }
+
+    /// Get IDs of bills matching structured filters
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_ids_filtered(&self, filter: &BillFilter<'_>) -> Result<Vec<Uuid>, DbError> {
+        let sql = format!("SELECT id FROM bills WHERE {BILL_FILTER_CLAUSE}");
+        let (chamber, sponsor, status) = filter.bind_values();
+        let ids: Vec<(Uuid,)> = sqlx::query_as(&sql)
+            .bind(filter.congress)
+            .bind(chamber)
+            .bind(sponsor)
+            .bind(status)
+            .fetch_all(self.pool)
+            .await?;
+        Ok(ids.into_iter().map(|(id,)| id).collect())
+    }
+
+    /// Get bills matching structured filters, most recent status first
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_filtered(
+        &self,
+        filter: &BillFilter<'_>,
+        limit: i64,
+    ) -> Result<Vec<Bill>, DbError> {
+        let sql = format!(
+            "SELECT * FROM bills WHERE {BILL_FILTER_CLAUSE} \
+             ORDER BY status_date DESC NULLS LAST, congress DESC, bill_number LIMIT $5"
+        );
+        let (chamber, sponsor, status) = filter.bind_values();
+        let bills = sqlx::query_as::<_, Bill>(&sql)
+            .bind(filter.congress)
+            .bind(chamber)
+            .bind(sponsor)
+            .bind(status)
+            .bind(limit)
+            .fetch_all(self.pool)
+            .await?;
+        Ok(bills)
+    }
+
+    /// Batch fetch bill metadata for search result enrichment
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_metadata_batch(
+        &self,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, BillMetadata>, DbError> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let bills = sqlx::query_as::<_, Bill>("SELECT * FROM bills WHERE id = ANY($1)")
+            .bind(ids)
+            .fetch_all(self.pool)
+            .await?;
+
+        Ok(bills
+            .into_iter()
+            .map(|bill| {
+                let metadata = BillMetadata {
+                    identifier: bill.bill_identifier(),
+                    congress: bill.congress,
+                    chamber: bill.chamber().map(str::to_string),
+                    title: bill.title,
+                    sponsor: bill.sponsor,
+                    status: bill.status,
+                    status_date: bill.status_date,
+                };
+                (bill.id, metadata)
+            })
+            .collect())
+    }
+
     /// Count all bills
     ///
     /// # Errors
@@ -98,3 +283,43 @@ impl<'a> BillRepo<'a> {
         Ok(count.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_filter_binds_nothing() {
+        let filter = BillFilter::default();
+        assert!(filter.is_empty());
+        assert_eq!(filter.bind_values(), (None, None, None));
+    }
+
+    #[test]
+    fn test_bind_values_build_patterns() {
+        let filter = BillFilter {
+            congress: Some(118),
+            chamber: Some("Senate"),
+            sponsor: Some("Smith"),
+            status: Some("enacted"),
+        };
+        assert!(!filter.is_empty());
+        assert_eq!(
+            filter.bind_values(),
+            (
+                Some("s".to_string()),
+                Some("%smith%".to_string()),
+                Some("ENACTED%".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_bind_values_chamber_prefix() {
+        let filter = BillFilter {
+            chamber: Some("house"),
+            ..BillFilter::default()
+        };
+        assert_eq!(filter.bind_values().0.as_deref(), Some("h"));
+    }
+}
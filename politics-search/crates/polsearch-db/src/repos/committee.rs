@@ -60,6 +60,18 @@ impl<'a> CommitteeRepo<'a> {
         Ok(committee)
     }
 
+    /// Fetch committee by ID
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_by_id(&self, id: Uuid) -> Result<Option<Committee>, DbError> {
+        let committee = sqlx::query_as::<_, Committee>("SELECT * FROM committees WHERE id = $1")
+            .bind(id)
+            .fetch_optional(self.pool)
+            .await?;
+        Ok(committee)
+    }
+
     /// Fetch committee by slug
     ///
     /// # Errors
@@ -125,7 +137,7 @@ impl<'a> CommitteeRepo<'a> {
             SELECT c.id, c.name, c.slug, c.chamber, c.created_at,
                    COUNT(h.id) as hearing_count
             FROM committees c
-            LEFT JOIN hearings h ON h.committee_slug = c.slug
+            LEFT JOIN hearings h ON h.committee_slug = c.slug AND h.superseded_at IS NULL
             GROUP BY c.id, c.name, c.slug, c.chamber, c.created_at
             ORDER BY hearing_count DESC, c.name
             ",
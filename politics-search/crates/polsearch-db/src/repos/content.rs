@@ -1,7 +1,7 @@
 //! Content repository
 
 use chrono::{DateTime, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::DbError;
 use polsearch_core::Content;
@@ -63,6 +63,54 @@ impl<'a> ContentRepo<'a> {
         Ok(content)
     }
 
+    /// Fetch content by its source-provided GUID
+    ///
+    /// GUIDs are only unique within a source, so both are part of the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the query fails
+    pub async fn get_by_guid(
+        &self,
+        source_id: Uuid,
+        guid: &str,
+    ) -> Result<Option<Content>, DbError> {
+        let content = sqlx::query_as::<_, Content>(
+            "SELECT * FROM content WHERE source_id = $1 AND guid = $2",
+        )
+        .bind(source_id)
+        .bind(guid)
+        .fetch_optional(self.pool)
+        .await?;
+        Ok(content)
+    }
+
+    /// Return the subset of `guids` that already exist for a source
+    ///
+    /// Lets a fetch skip known items with a single query instead of one per item.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the query fails
+    pub async fn exists_by_guids(
+        &self,
+        source_id: Uuid,
+        guids: &[String],
+    ) -> Result<HashSet<String>, DbError> {
+        if guids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT guid FROM content WHERE source_id = $1 AND guid = ANY($2)",
+        )
+        .bind(source_id)
+        .bind(guids)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|(guid,)| guid).collect())
+    }
+
     /// Fetch all content
     ///
     /// # Errors
@@ -2,8 +2,8 @@
 
 use crate::DbError;
 use chrono::NaiveDate;
-use polsearch_core::FloorSpeech;
-use sqlx::PgPool;
+use polsearch_core::{FloorSpeech, PageType};
+use sqlx::{PgExecutor, PgPool};
 use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
@@ -31,6 +31,17 @@ impl<'a> FloorSpeechRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the insert fails
     pub async fn create(&self, speech: &FloorSpeech) -> Result<(), DbError> {
+        Self::create_in(self.pool, speech).await
+    }
+
+    /// Insert a new floor speech through `executor` (e.g., a transaction connection)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the insert fails
+    pub async fn create_in(
+        executor: impl PgExecutor<'_>,
+        speech: &FloorSpeech,
+    ) -> Result<(), DbError> {
         sqlx::query(
             r"
             INSERT INTO floor_speeches (id, event_id, granule_id, title, chamber, page_type,
@@ -54,7 +65,7 @@ impl<'a> FloorSpeechRepo<'a> {
         .bind(speech.is_processed)
         .bind(speech.created_at)
         .bind(speech.updated_at)
-        .execute(self.pool)
+        .execute(executor)
         .await?;
         Ok(())
     }
@@ -71,16 +82,17 @@ impl<'a> FloorSpeechRepo<'a> {
         Ok(speech)
     }
 
-    /// Fetch floor speech by event ID
+    /// Fetch the current (not superseded) floor speech by event ID
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_by_event_id(&self, event_id: &str) -> Result<Option<FloorSpeech>, DbError> {
-        let speech =
-            sqlx::query_as::<_, FloorSpeech>("SELECT * FROM floor_speeches WHERE event_id = $1")
-                .bind(event_id)
-                .fetch_optional(self.pool)
-                .await?;
+        let speech = sqlx::query_as::<_, FloorSpeech>(
+            "SELECT * FROM floor_speeches WHERE event_id = $1 AND superseded_at IS NULL",
+        )
+        .bind(event_id)
+        .fetch_optional(self.pool)
+        .await?;
         Ok(speech)
     }
 
@@ -90,7 +102,9 @@ impl<'a> FloorSpeechRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn exists_by_event_id(&self, event_id: &str) -> Result<bool, DbError> {
         let exists: (bool,) = sqlx::query_as(
-            "SELECT EXISTS(SELECT 1 FROM floor_speeches WHERE event_id = $1)",
+            "SELECT EXISTS(
+                SELECT 1 FROM floor_speeches WHERE event_id = $1 AND superseded_at IS NULL
+            )",
         )
         .bind(event_id)
         .fetch_one(self.pool)
@@ -98,6 +112,44 @@ impl<'a> FloorSpeechRepo<'a> {
         Ok(exists.0)
     }
 
+    /// Get the stored statements hash, or `None` if it was never recorded
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_content_hash(&self, id: Uuid) -> Result<Option<String>, DbError> {
+        let hash: Option<(Option<String>,)> =
+            sqlx::query_as("SELECT content_hash FROM floor_speeches WHERE id = $1")
+                .bind(id)
+                .fetch_optional(self.pool)
+                .await?;
+        Ok(hash.and_then(|(hash,)| hash))
+    }
+
+    /// Record the statements hash of an ingested floor speech
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn set_content_hash(&self, id: Uuid, content_hash: &str) -> Result<(), DbError> {
+        Self::set_content_hash_in(self.pool, id, content_hash).await
+    }
+
+    /// Record the statements hash of an ingested floor speech through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn set_content_hash_in(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+        content_hash: &str,
+    ) -> Result<(), DbError> {
+        sqlx::query("UPDATE floor_speeches SET content_hash = $2, updated_at = NOW() WHERE id = $1")
+            .bind(id)
+            .bind(content_hash)
+            .execute(executor)
+            .await?;
+        Ok(())
+    }
+
     /// Update counts and mark as processed
     ///
     /// # Errors
@@ -107,6 +159,19 @@ impl<'a> FloorSpeechRepo<'a> {
         id: Uuid,
         total_statements: i32,
         total_segments: i32,
+    ) -> Result<(), DbError> {
+        Self::mark_processed_in(self.pool, id, total_statements, total_segments).await
+    }
+
+    /// Update counts and mark as processed through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn mark_processed_in(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+        total_statements: i32,
+        total_segments: i32,
     ) -> Result<(), DbError> {
         sqlx::query(
             r"
@@ -118,18 +183,41 @@ impl<'a> FloorSpeechRepo<'a> {
         .bind(id)
         .bind(total_statements)
         .bind(total_segments)
-        .execute(self.pool)
+        .execute(executor)
         .await?;
         Ok(())
     }
 
+    /// Fetch other floor speeches from the same date and chamber, in title order
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_related(&self, id: Uuid, limit: i64) -> Result<Vec<FloorSpeech>, DbError> {
+        let speeches = sqlx::query_as::<_, FloorSpeech>(
+            r"
+            SELECT s.* FROM floor_speeches s
+            INNER JOIN floor_speeches t
+                ON t.speech_date = s.speech_date AND t.chamber = s.chamber
+            WHERE t.id = $1 AND s.id <> t.id AND s.superseded_at IS NULL
+            ORDER BY s.title, s.id
+            LIMIT $2
+            ",
+        )
+        .bind(id)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(speeches)
+    }
+
     /// Fetch floor speeches by chamber
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_by_chamber(&self, chamber: &str) -> Result<Vec<FloorSpeech>, DbError> {
         let speeches = sqlx::query_as::<_, FloorSpeech>(
-            "SELECT * FROM floor_speeches WHERE chamber = $1 ORDER BY speech_date DESC",
+            "SELECT * FROM floor_speeches WHERE chamber = $1 AND superseded_at IS NULL
+             ORDER BY speech_date DESC",
         )
         .bind(chamber)
         .fetch_all(self.pool)
@@ -143,7 +231,8 @@ impl<'a> FloorSpeechRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn get_by_year_month(&self, year_month: &str) -> Result<Vec<FloorSpeech>, DbError> {
         let speeches = sqlx::query_as::<_, FloorSpeech>(
-            "SELECT * FROM floor_speeches WHERE year_month = $1 ORDER BY speech_date DESC",
+            "SELECT * FROM floor_speeches WHERE year_month = $1 AND superseded_at IS NULL
+             ORDER BY speech_date DESC",
         )
         .bind(year_month)
         .fetch_all(self.pool)
@@ -156,9 +245,10 @@ impl<'a> FloorSpeechRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn count(&self) -> Result<i64, DbError> {
-        let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM floor_speeches")
-            .fetch_one(self.pool)
-            .await?;
+        let count: (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM floor_speeches WHERE superseded_at IS NULL")
+                .fetch_one(self.pool)
+                .await?;
         Ok(count.0)
     }
 
@@ -167,29 +257,156 @@ impl<'a> FloorSpeechRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn count_processed(&self) -> Result<i64, DbError> {
-        let count: (i64,) =
-            sqlx::query_as("SELECT COUNT(*) FROM floor_speeches WHERE is_processed = true")
-                .fetch_one(self.pool)
-                .await?;
+        let count: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM floor_speeches
+             WHERE is_processed = true AND superseded_at IS NULL",
+        )
+        .fetch_one(self.pool)
+        .await?;
         Ok(count.0)
     }
 
+    /// Count floor speeches per calendar year of `speech_date`, oldest first
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn count_by_year(&self) -> Result<Vec<(i32, i64)>, DbError> {
+        let rows: Vec<(i32, i64)> = sqlx::query_as(
+            r"
+            SELECT EXTRACT(YEAR FROM speech_date)::int4 AS year, COUNT(*)
+            FROM floor_speeches
+            WHERE superseded_at IS NULL
+            GROUP BY year
+            ORDER BY year
+            ",
+        )
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Count floor speeches per chamber, most common first
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn count_by_chamber(&self) -> Result<Vec<(String, i64)>, DbError> {
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r"
+            SELECT chamber, COUNT(*) AS n
+            FROM floor_speeches
+            WHERE superseded_at IS NULL
+            GROUP BY chamber
+            ORDER BY n DESC, chamber
+            ",
+        )
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Count floor speeches per Congressional Record page type, most common first
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn count_by_page_type(&self) -> Result<Vec<(String, i64)>, DbError> {
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r"
+            SELECT page_type, COUNT(*) AS n
+            FROM floor_speeches
+            WHERE superseded_at IS NULL
+            GROUP BY page_type
+            ORDER BY n DESC, page_type
+            ",
+        )
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Sum `total_statements` and `total_segments` across all floor speeches
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn total_statements_and_segments(&self) -> Result<(i64, i64), DbError> {
+        let totals: (i64, i64) = sqlx::query_as(
+            r"
+            SELECT COALESCE(SUM(total_statements), 0)::int8,
+                   COALESCE(SUM(total_segments), 0)::int8
+            FROM floor_speeches
+            WHERE superseded_at IS NULL
+            ",
+        )
+        .fetch_one(self.pool)
+        .await?;
+        Ok(totals)
+    }
+
+    /// Earliest and latest `speech_date`, or `None` if there are no floor speeches
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn date_range(&self) -> Result<Option<(NaiveDate, NaiveDate)>, DbError> {
+        let (first, last): (Option<NaiveDate>, Option<NaiveDate>) = sqlx::query_as(
+            "SELECT MIN(speech_date), MAX(speech_date) FROM floor_speeches
+             WHERE superseded_at IS NULL",
+        )
+        .fetch_one(self.pool)
+        .await?;
+        Ok(first.zip(last))
+    }
+
+    /// Months (`YYYY-MM`) between the first and last speech with no floor speeches
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_missing_months(&self) -> Result<Vec<String>, DbError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r"
+            SELECT to_char(month, 'YYYY-MM') AS year_month
+            FROM generate_series(
+                (SELECT date_trunc('month', MIN(speech_date)) FROM floor_speeches
+                 WHERE superseded_at IS NULL),
+                (SELECT date_trunc('month', MAX(speech_date)) FROM floor_speeches
+                 WHERE superseded_at IS NULL),
+                interval '1 month'
+            ) AS month
+            WHERE to_char(month, 'YYYY-MM') NOT IN (
+                SELECT year_month FROM floor_speeches WHERE superseded_at IS NULL
+            )
+            ORDER BY year_month
+            ",
+        )
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|(year_month,)| year_month).collect())
+    }
+
     /// Get IDs of floor speeches matching filters for search
     ///
+    /// `page_types` restricts results to the given Congressional Record
+    /// sections; empty means any section.
+    ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_filtered_ids(
         &self,
         chamber: Option<&str>,
+        page_types: &[PageType],
         from_date: Option<&str>,
         to_date: Option<&str>,
     ) -> Result<Vec<Uuid>, DbError> {
-        let mut query = String::from("SELECT id FROM floor_speeches WHERE is_processed = true");
+        let mut query = String::from(
+            "SELECT id FROM floor_speeches WHERE is_processed = true AND superseded_at IS NULL",
+        );
         let mut params: Vec<String> = Vec::new();
 
         if let Some(c) = chamber {
             params.push(format!("chamber = '{c}'"));
         }
+        if !page_types.is_empty() {
+            let codes: Vec<String> = page_types.iter().map(|p| format!("'{}'", p.code())).collect();
+            params.push(format!("page_type IN ({})", codes.join(", ")));
+        }
         if let Some(from) = from_date {
             params.push(format!("year_month >= '{from}'"));
         }
@@ -218,6 +435,111 @@ impl<'a> FloorSpeechRepo<'a> {
         Ok(())
     }
 
+    /// Mark a floor speech as superseded by a re-ingest that is still being written
+    ///
+    /// The row and its statements stay in place, so a failed re-ingest leaves the
+    /// original speech intact. Call [`Self::finalize_supersede`] once the
+    /// replacement is written.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn supersede(&self, id: Uuid) -> Result<(), DbError> {
+        Self::supersede_in(self.pool, id).await
+    }
+
+    /// Mark a floor speech as superseded through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn supersede_in(executor: impl PgExecutor<'_>, id: Uuid) -> Result<(), DbError> {
+        sqlx::query(
+            "UPDATE floor_speeches SET superseded_at = NOW(), updated_at = NOW()
+             WHERE id = $1 AND superseded_at IS NULL",
+        )
+        .bind(id)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Hard-delete a superseded floor speech and all related data
+    ///
+    /// Does nothing if the floor speech was not superseded.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn finalize_supersede(&self, id: Uuid) -> Result<(), DbError> {
+        Self::finalize_supersede_in(self.pool, id).await
+    }
+
+    /// Hard-delete a superseded floor speech through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn finalize_supersede_in(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+    ) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM floor_speeches WHERE id = $1 AND superseded_at IS NOT NULL")
+            .bind(id)
+            .execute(executor)
+            .await?;
+        Ok(())
+    }
+
+    /// Make superseded floor speeches current again when no replacement was committed
+    ///
+    /// Only the most recently superseded row per event ID is restored, and only if
+    /// no current speech holds its event or granule ID. Returns the number of
+    /// floor speeches restored.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn restore_orphaned_superseded(&self) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            r"
+            UPDATE floor_speeches s SET superseded_at = NULL, updated_at = NOW()
+            WHERE s.id IN (
+                SELECT DISTINCT ON (event_id) id FROM floor_speeches
+                WHERE superseded_at IS NOT NULL
+                ORDER BY event_id, superseded_at DESC
+            )
+            AND NOT EXISTS (
+                SELECT 1 FROM floor_speeches c
+                WHERE (c.event_id = s.event_id OR c.granule_id = s.granule_id)
+                  AND c.superseded_at IS NULL
+            )
+            ",
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Hard-delete superseded floor speeches that have a current replacement
+    ///
+    /// Returns the deleted IDs so their embeddings can be removed too.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn delete_replaced_superseded(&self) -> Result<Vec<Uuid>, DbError> {
+        let ids: Vec<(Uuid,)> = sqlx::query_as(
+            r"
+            DELETE FROM floor_speeches s
+            WHERE s.superseded_at IS NOT NULL
+              AND EXISTS (
+                  SELECT 1 FROM floor_speeches c
+                  WHERE (c.event_id = s.event_id OR c.granule_id = s.granule_id)
+                    AND c.superseded_at IS NULL
+              )
+            RETURNING s.id
+            ",
+        )
+        .fetch_all(self.pool)
+        .await?;
+        Ok(ids.into_iter().map(|(id,)| id).collect())
+    }
+
     /// Get floor speech IDs that have statements from a speaker (case-insensitive match)
     ///
     /// # Errors
@@ -229,7 +551,8 @@ impl<'a> FloorSpeechRepo<'a> {
             SELECT DISTINCT fs.id
             FROM floor_speeches fs
             JOIN floor_speech_statements fss ON fs.id = fss.floor_speech_id
-            WHERE LOWER(fss.speaker_label) LIKE $1 AND fs.is_processed = true
+            WHERE (LOWER(fss.speaker_label) LIKE $1 OR LOWER(fss.normalized_speaker) LIKE $1)
+              AND fs.is_processed = true AND fs.superseded_at IS NULL
             ",
         )
         .bind(&pattern)
@@ -238,14 +561,28 @@ impl<'a> FloorSpeechRepo<'a> {
         Ok(ids.into_iter().map(|(id,)| id).collect())
     }
 
+    /// Get the event IDs of the given floor speeches
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_event_ids(&self, ids: &[Uuid]) -> Result<Vec<String>, DbError> {
+        let rows: Vec<(String,)> =
+            sqlx::query_as("SELECT event_id FROM floor_speeches WHERE id = ANY($1)")
+                .bind(ids)
+                .fetch_all(self.pool)
+                .await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
     /// Get all event IDs as a set for fast lookup
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_all_event_ids(&self) -> Result<HashSet<String>, DbError> {
-        let ids: Vec<(String,)> = sqlx::query_as("SELECT event_id FROM floor_speeches")
-            .fetch_all(self.pool)
-            .await?;
+        let ids: Vec<(String,)> =
+            sqlx::query_as("SELECT event_id FROM floor_speeches WHERE superseded_at IS NULL")
+                .fetch_all(self.pool)
+                .await?;
         Ok(ids.into_iter().map(|(id,)| id).collect())
     }
 
@@ -259,7 +596,8 @@ impl<'a> FloorSpeechRepo<'a> {
         }
 
         let rows: Vec<(Uuid, String, Option<String>, Option<NaiveDate>, Option<String>)> = sqlx::query_as(
-            "SELECT id, title, chamber, speech_date, source_url FROM floor_speeches WHERE id = ANY($1)",
+            "SELECT id, title, chamber, speech_date, source_url FROM floor_speeches
+             WHERE id = ANY($1) AND superseded_at IS NULL",
         )
         .bind(ids)
         .fetch_all(self.pool)
@@ -294,7 +632,8 @@ impl<'a> FloorSpeechRepo<'a> {
         }
 
         let rows: Vec<(String, String, Option<String>, Option<NaiveDate>, Option<String>)> = sqlx::query_as(
-            "SELECT event_id, title, chamber, speech_date, source_url FROM floor_speeches WHERE event_id = ANY($1)",
+            "SELECT event_id, title, chamber, speech_date, source_url FROM floor_speeches
+             WHERE event_id = ANY($1) AND superseded_at IS NULL",
         )
         .bind(event_ids)
         .fetch_all(self.pool)
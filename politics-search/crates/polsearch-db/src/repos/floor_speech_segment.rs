@@ -1,9 +1,8 @@
 //! Floor speech segment repository
 
-use crate::DbError;
+use crate::{DbError, SegmentSpeakerLookup};
 use polsearch_core::FloorSpeechSegment;
-use sqlx::PgPool;
-use std::collections::HashMap;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 pub struct FloorSpeechSegmentRepo<'a> {
@@ -45,6 +44,17 @@ impl<'a> FloorSpeechSegmentRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the insert fails
     pub async fn create_batch(&self, segments: &[FloorSpeechSegment]) -> Result<(), DbError> {
+        Self::create_batch_in(self.pool, segments).await
+    }
+
+    /// Batch insert floor speech segments through `executor` (e.g., a transaction connection)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the insert fails
+    pub async fn create_batch_in(
+        executor: impl PgExecutor<'_>,
+        segments: &[FloorSpeechSegment],
+    ) -> Result<(), DbError> {
         if segments.is_empty() {
             return Ok(());
         }
@@ -63,7 +73,7 @@ impl<'a> FloorSpeechSegmentRepo<'a> {
                 .push_bind(seg.created_at);
         });
 
-        query_builder.build().execute(self.pool).await?;
+        query_builder.build().execute(executor).await?;
         Ok(())
     }
 
@@ -97,41 +107,17 @@ impl<'a> FloorSpeechSegmentRepo<'a> {
         .await?;
         Ok(count.0)
     }
+}
 
-    /// Get speaker labels for segments (via statements)
-    /// Returns a map of (`floor_speech_id`, `segment_index`) -> `speaker_label`
-    ///
-    /// # Errors
-    /// Returns `DbError` if the query fails
-    pub async fn get_speakers_for_segments(
-        &self,
-        segment_keys: &[(Uuid, i32)],
-    ) -> Result<HashMap<(Uuid, i32), Option<String>>, DbError> {
-        if segment_keys.is_empty() {
-            return Ok(HashMap::new());
-        }
-
-        let floor_speech_ids: Vec<Uuid> = segment_keys.iter().map(|(id, _)| *id).collect();
-        let segment_indices: Vec<i32> = segment_keys.iter().map(|(_, idx)| *idx).collect();
-
-        let rows: Vec<(Uuid, i32, String)> = sqlx::query_as(
-            r"
-            SELECT fs.floor_speech_id, fs.segment_index, fst.speaker_label
-            FROM floor_speech_segments fs
-            JOIN floor_speech_statements fst ON fs.statement_id = fst.id
-            WHERE fs.floor_speech_id = ANY($1) AND fs.segment_index = ANY($2)
-            ",
-        )
-        .bind(&floor_speech_ids)
-        .bind(&segment_indices)
-        .fetch_all(self.pool)
-        .await?;
-
-        let map = rows
-            .into_iter()
-            .map(|(fsid, sidx, label)| ((fsid, sidx), Some(label)))
-            .collect();
+impl SegmentSpeakerLookup for FloorSpeechSegmentRepo<'_> {
+    const SPEAKER_QUERY: &'static str = r"
+        SELECT fs.floor_speech_id, fs.segment_index, fst.speaker_label
+        FROM floor_speech_segments fs
+        JOIN floor_speech_statements fst ON fs.statement_id = fst.id
+        WHERE fs.floor_speech_id = ANY($1) AND fs.segment_index = ANY($2)
+        ";
 
-        Ok(map)
+    fn pool(&self) -> &PgPool {
+        self.pool
     }
 }
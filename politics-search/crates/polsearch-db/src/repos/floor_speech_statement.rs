@@ -1,8 +1,10 @@
 //! Floor speech statement repository
 
+use chrono::NaiveDate;
+
 use crate::DbError;
 use polsearch_core::FloorSpeechStatement;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 pub struct FloorSpeechStatementRepo<'a> {
@@ -23,8 +25,9 @@ impl<'a> FloorSpeechStatementRepo<'a> {
         sqlx::query(
             r"
             INSERT INTO floor_speech_statements (id, floor_speech_id, statement_index, speaker_label,
-                                                  speaker_id, text, word_count, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                                                  speaker_id, normalized_speaker, speaker_honorific,
+                                                  speaker_role, party, text, word_count, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             ",
         )
         .bind(statement.id)
@@ -32,6 +35,10 @@ impl<'a> FloorSpeechStatementRepo<'a> {
         .bind(statement.statement_index)
         .bind(&statement.speaker_label)
         .bind(statement.speaker_id)
+        .bind(&statement.normalized_speaker)
+        .bind(&statement.speaker_honorific)
+        .bind(&statement.speaker_role)
+        .bind(&statement.party)
         .bind(&statement.text)
         .bind(statement.word_count)
         .bind(statement.created_at)
@@ -45,12 +52,23 @@ impl<'a> FloorSpeechStatementRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the insert fails
     pub async fn create_batch(&self, statements: &[FloorSpeechStatement]) -> Result<(), DbError> {
+        Self::create_batch_in(self.pool, statements).await
+    }
+
+    /// Batch insert floor speech statements through `executor` (e.g., a transaction connection)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the insert fails
+    pub async fn create_batch_in(
+        executor: impl PgExecutor<'_>,
+        statements: &[FloorSpeechStatement],
+    ) -> Result<(), DbError> {
         if statements.is_empty() {
             return Ok(());
         }
 
         let mut query_builder = sqlx::QueryBuilder::new(
-            "INSERT INTO floor_speech_statements (id, floor_speech_id, statement_index, speaker_label, speaker_id, text, word_count, created_at) "
+            "INSERT INTO floor_speech_statements (id, floor_speech_id, statement_index, speaker_label, speaker_id, normalized_speaker, speaker_honorific, speaker_role, party, text, word_count, created_at) "
         );
 
         query_builder.push_values(statements, |mut b, stmt| {
@@ -59,12 +77,16 @@ impl<'a> FloorSpeechStatementRepo<'a> {
                 .push_bind(stmt.statement_index)
                 .push_bind(&stmt.speaker_label)
                 .push_bind(stmt.speaker_id)
+                .push_bind(&stmt.normalized_speaker)
+                .push_bind(&stmt.speaker_honorific)
+                .push_bind(&stmt.speaker_role)
+                .push_bind(&stmt.party)
                 .push_bind(&stmt.text)
                 .push_bind(stmt.word_count)
                 .push_bind(stmt.created_at);
         });
 
-        query_builder.build().execute(self.pool).await?;
+        query_builder.build().execute(executor).await?;
         Ok(())
     }
 
@@ -138,4 +160,44 @@ impl<'a> FloorSpeechStatementRepo<'a> {
         .await?;
         Ok(count.0)
     }
+
+    /// Fetch a speaker's statements with their floor speech ID, date, title, and text, oldest first
+    ///
+    /// Statements match on `speaker_id`, or, like `get_ids_by_speaker`, on a
+    /// case-insensitive substring of `speaker_label` or `normalized_speaker`,
+    /// since ingestion doesn't link statements to speakers. `from` and `to` are
+    /// inclusive "YYYY-MM" bounds on the floor speech month.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_activity_by_speaker(
+        &self,
+        speaker_id: Uuid,
+        speaker_name: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Vec<(Uuid, NaiveDate, String, Option<String>)>, DbError> {
+        let pattern = speaker_name.map(|name| format!("%{}%", name.to_lowercase()));
+        let rows = sqlx::query_as(
+            r"
+            SELECT p.id, p.speech_date, p.title, s.text
+            FROM floor_speech_statements s
+            INNER JOIN floor_speeches p ON p.id = s.floor_speech_id
+            WHERE (s.speaker_id = $1
+                   OR LOWER(s.speaker_label) LIKE $2
+                   OR LOWER(s.normalized_speaker) LIKE $2)
+              AND p.superseded_at IS NULL
+              AND ($3::text IS NULL OR p.year_month >= $3)
+              AND ($4::text IS NULL OR p.year_month <= $4)
+            ORDER BY p.speech_date, p.id, s.statement_index
+            ",
+        )
+        .bind(speaker_id)
+        .bind(pattern)
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows)
+    }
 }
@@ -3,7 +3,7 @@
 use crate::DbError;
 use chrono::NaiveDate;
 use polsearch_core::Hearing;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
@@ -33,6 +33,17 @@ impl<'a> HearingRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the insert fails
     pub async fn create(&self, hearing: &Hearing) -> Result<(), DbError> {
+        Self::create_in(self.pool, hearing).await
+    }
+
+    /// Insert a new hearing through `executor` (e.g., a transaction connection)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the insert fails
+    pub async fn create_in(
+        executor: impl PgExecutor<'_>,
+        hearing: &Hearing,
+    ) -> Result<(), DbError> {
         sqlx::query(
             r"
             INSERT INTO hearings (id, package_id, event_id, title, committee_raw, committee_slug,
@@ -57,7 +68,7 @@ impl<'a> HearingRepo<'a> {
         .bind(hearing.is_processed)
         .bind(hearing.created_at)
         .bind(hearing.updated_at)
-        .execute(self.pool)
+        .execute(executor)
         .await?;
         Ok(())
     }
@@ -74,16 +85,17 @@ impl<'a> HearingRepo<'a> {
         Ok(hearing)
     }
 
-    /// Fetch hearing by package ID
+    /// Fetch the current (not superseded) hearing by package ID
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_by_package_id(&self, package_id: &str) -> Result<Option<Hearing>, DbError> {
-        let hearing =
-            sqlx::query_as::<_, Hearing>("SELECT * FROM hearings WHERE package_id = $1")
-                .bind(package_id)
-                .fetch_optional(self.pool)
-                .await?;
+        let hearing = sqlx::query_as::<_, Hearing>(
+            "SELECT * FROM hearings WHERE package_id = $1 AND superseded_at IS NULL",
+        )
+        .bind(package_id)
+        .fetch_optional(self.pool)
+        .await?;
         Ok(hearing)
     }
 
@@ -93,7 +105,7 @@ impl<'a> HearingRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn exists_by_package_id(&self, package_id: &str) -> Result<bool, DbError> {
         let exists: (bool,) = sqlx::query_as(
-            "SELECT EXISTS(SELECT 1 FROM hearings WHERE package_id = $1)",
+            "SELECT EXISTS(SELECT 1 FROM hearings WHERE package_id = $1 AND superseded_at IS NULL)",
         )
         .bind(package_id)
         .fetch_one(self.pool)
@@ -101,6 +113,44 @@ impl<'a> HearingRepo<'a> {
         Ok(exists.0)
     }
 
+    /// Get the stored statements hash, or `None` if it was never recorded
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_content_hash(&self, id: Uuid) -> Result<Option<String>, DbError> {
+        let hash: Option<(Option<String>,)> =
+            sqlx::query_as("SELECT content_hash FROM hearings WHERE id = $1")
+                .bind(id)
+                .fetch_optional(self.pool)
+                .await?;
+        Ok(hash.and_then(|(hash,)| hash))
+    }
+
+    /// Record the statements hash of an ingested hearing
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn set_content_hash(&self, id: Uuid, content_hash: &str) -> Result<(), DbError> {
+        Self::set_content_hash_in(self.pool, id, content_hash).await
+    }
+
+    /// Record the statements hash of an ingested hearing through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn set_content_hash_in(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+        content_hash: &str,
+    ) -> Result<(), DbError> {
+        sqlx::query("UPDATE hearings SET content_hash = $2, updated_at = NOW() WHERE id = $1")
+            .bind(id)
+            .bind(content_hash)
+            .execute(executor)
+            .await?;
+        Ok(())
+    }
+
     /// Update counts and mark as processed
     ///
     /// # Errors
@@ -110,6 +160,19 @@ impl<'a> HearingRepo<'a> {
         id: Uuid,
         total_statements: i32,
         total_segments: i32,
+    ) -> Result<(), DbError> {
+        Self::mark_processed_in(self.pool, id, total_statements, total_segments).await
+    }
+
+    /// Update counts and mark as processed through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn mark_processed_in(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+        total_statements: i32,
+        total_segments: i32,
     ) -> Result<(), DbError> {
         sqlx::query(
             r"
@@ -121,7 +184,7 @@ impl<'a> HearingRepo<'a> {
         .bind(id)
         .bind(total_statements)
         .bind(total_segments)
-        .execute(self.pool)
+        .execute(executor)
         .await?;
         Ok(())
     }
@@ -132,7 +195,8 @@ impl<'a> HearingRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn get_by_congress(&self, congress: i16) -> Result<Vec<Hearing>, DbError> {
         let hearings = sqlx::query_as::<_, Hearing>(
-            "SELECT * FROM hearings WHERE congress = $1 ORDER BY hearing_date DESC",
+            "SELECT * FROM hearings WHERE congress = $1 AND superseded_at IS NULL
+             ORDER BY hearing_date DESC",
         )
         .bind(congress)
         .fetch_all(self.pool)
@@ -146,7 +210,8 @@ impl<'a> HearingRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn get_by_chamber(&self, chamber: &str) -> Result<Vec<Hearing>, DbError> {
         let hearings = sqlx::query_as::<_, Hearing>(
-            "SELECT * FROM hearings WHERE $1 = ANY(chambers) ORDER BY hearing_date DESC",
+            "SELECT * FROM hearings WHERE $1 = ANY(chambers) AND superseded_at IS NULL
+             ORDER BY hearing_date DESC",
         )
         .bind(chamber)
         .fetch_all(self.pool)
@@ -161,7 +226,8 @@ impl<'a> HearingRepo<'a> {
     pub async fn get_by_committee_fuzzy(&self, query: &str) -> Result<Vec<Hearing>, DbError> {
         let pattern = format!("%{}%", query.to_lowercase());
         let hearings = sqlx::query_as::<_, Hearing>(
-            "SELECT * FROM hearings WHERE LOWER(committee_slug) LIKE $1 ORDER BY hearing_date DESC",
+            "SELECT * FROM hearings WHERE LOWER(committee_slug) LIKE $1 AND superseded_at IS NULL
+             ORDER BY hearing_date DESC",
         )
         .bind(pattern)
         .fetch_all(self.pool)
@@ -169,14 +235,78 @@ impl<'a> HearingRepo<'a> {
         Ok(hearings)
     }
 
+    /// Fetch other hearings from the same committee, closest in date first
+    ///
+    /// Returns nothing if the hearing has no committee or does not exist.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_related(&self, id: Uuid, limit: i64) -> Result<Vec<Hearing>, DbError> {
+        let hearings = sqlx::query_as::<_, Hearing>(
+            r"
+            SELECT h.* FROM hearings h
+            INNER JOIN hearings t ON t.committee_slug = h.committee_slug
+            WHERE t.id = $1 AND h.id <> t.id AND h.superseded_at IS NULL
+            ORDER BY ABS(h.hearing_date - t.hearing_date), h.hearing_date DESC, h.id
+            LIMIT $2
+            ",
+        )
+        .bind(id)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(hearings)
+    }
+
+    /// Fetch a page of hearings for a committee (exact slug match), newest first
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_by_committee_slug(
+        &self,
+        slug: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Hearing>, DbError> {
+        let hearings = sqlx::query_as::<_, Hearing>(
+            r"
+            SELECT * FROM hearings
+            WHERE committee_slug = $1 AND superseded_at IS NULL
+            ORDER BY hearing_date DESC, id
+            LIMIT $2 OFFSET $3
+            ",
+        )
+        .bind(slug)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(hearings)
+    }
+
+    /// Count hearings for a committee (exact slug match)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn count_by_committee_slug(&self, slug: &str) -> Result<i64, DbError> {
+        let count: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM hearings WHERE committee_slug = $1 AND superseded_at IS NULL",
+        )
+        .bind(slug)
+        .fetch_one(self.pool)
+        .await?;
+        Ok(count.0)
+    }
+
     /// Count all hearings
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn count(&self) -> Result<i64, DbError> {
-        let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM hearings")
-            .fetch_one(self.pool)
-            .await?;
+        let count: (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM hearings WHERE superseded_at IS NULL")
+                .fetch_one(self.pool)
+                .await?;
         Ok(count.0)
     }
 
@@ -185,26 +315,33 @@ impl<'a> HearingRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn count_processed(&self) -> Result<i64, DbError> {
-        let count: (i64,) =
-            sqlx::query_as("SELECT COUNT(*) FROM hearings WHERE is_processed = true")
-                .fetch_one(self.pool)
-                .await?;
+        let count: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM hearings WHERE is_processed = true AND superseded_at IS NULL",
+        )
+        .fetch_one(self.pool)
+        .await?;
         Ok(count.0)
     }
 
     /// Get IDs of hearings matching filters for search
     ///
+    /// `congress_min`/`congress_max` bound the congress range inclusively; pass
+    /// the same value for both to match a single congress.
+    ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_filtered_ids(
         &self,
         chamber: Option<&str>,
         committee: Option<&str>,
-        congress: Option<i16>,
+        congress_min: Option<i16>,
+        congress_max: Option<i16>,
         from_date: Option<&str>,
         to_date: Option<&str>,
     ) -> Result<Vec<Uuid>, DbError> {
-        let mut query = String::from("SELECT id FROM hearings WHERE is_processed = true");
+        let mut query = String::from(
+            "SELECT id FROM hearings WHERE is_processed = true AND superseded_at IS NULL",
+        );
         let mut params: Vec<String> = Vec::new();
 
         if let Some(c) = chamber {
@@ -216,8 +353,12 @@ impl<'a> HearingRepo<'a> {
                 comm.to_lowercase()
             ));
         }
-        if let Some(cong) = congress {
-            params.push(format!("congress = {cong}"));
+        match (congress_min, congress_max) {
+            (Some(min), Some(max)) if min == max => params.push(format!("congress = {min}")),
+            (Some(min), Some(max)) => params.push(format!("congress BETWEEN {min} AND {max}")),
+            (Some(min), None) => params.push(format!("congress >= {min}")),
+            (None, Some(max)) => params.push(format!("congress <= {max}")),
+            (None, None) => {}
         }
         if let Some(from) = from_date {
             params.push(format!("year_month >= '{from}'"));
@@ -240,13 +381,123 @@ impl<'a> HearingRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the delete fails
     pub async fn delete(&self, id: Uuid) -> Result<(), DbError> {
+        Self::delete_in(self.pool, id).await
+    }
+
+    /// Delete a hearing and all related data through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn delete_in(executor: impl PgExecutor<'_>, id: Uuid) -> Result<(), DbError> {
         sqlx::query("DELETE FROM hearings WHERE id = $1")
             .bind(id)
-            .execute(self.pool)
+            .execute(executor)
+            .await?;
+        Ok(())
+    }
+
+    /// Mark a hearing as superseded by a re-ingest that is still being written
+    ///
+    /// The row and its statements stay in place, so a failed re-ingest leaves the
+    /// original hearing intact. Call [`Self::finalize_supersede`] once the
+    /// replacement is written.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn supersede(&self, id: Uuid) -> Result<(), DbError> {
+        Self::supersede_in(self.pool, id).await
+    }
+
+    /// Mark a hearing as superseded through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn supersede_in(executor: impl PgExecutor<'_>, id: Uuid) -> Result<(), DbError> {
+        sqlx::query(
+            "UPDATE hearings SET superseded_at = NOW(), updated_at = NOW()
+             WHERE id = $1 AND superseded_at IS NULL",
+        )
+        .bind(id)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Hard-delete a superseded hearing and all related data
+    ///
+    /// Does nothing if the hearing was not superseded.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn finalize_supersede(&self, id: Uuid) -> Result<(), DbError> {
+        Self::finalize_supersede_in(self.pool, id).await
+    }
+
+    /// Hard-delete a superseded hearing through `executor`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn finalize_supersede_in(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+    ) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM hearings WHERE id = $1 AND superseded_at IS NOT NULL")
+            .bind(id)
+            .execute(executor)
             .await?;
         Ok(())
     }
 
+    /// Make superseded hearings current again when no replacement was committed
+    ///
+    /// Only the most recently superseded row per package ID is restored. Returns
+    /// the number of hearings restored.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the update fails
+    pub async fn restore_orphaned_superseded(&self) -> Result<u64, DbError> {
+        let result = sqlx::query(
+            r"
+            UPDATE hearings s SET superseded_at = NULL, updated_at = NOW()
+            WHERE s.id IN (
+                SELECT DISTINCT ON (package_id) id FROM hearings
+                WHERE superseded_at IS NOT NULL
+                ORDER BY package_id, superseded_at DESC
+            )
+            AND NOT EXISTS (
+                SELECT 1 FROM hearings c
+                WHERE c.package_id = s.package_id AND c.superseded_at IS NULL
+            )
+            ",
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Hard-delete superseded hearings that have a current replacement
+    ///
+    /// Returns the deleted IDs so their embeddings can be removed too.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the delete fails
+    pub async fn delete_replaced_superseded(&self) -> Result<Vec<Uuid>, DbError> {
+        let ids: Vec<(Uuid,)> = sqlx::query_as(
+            r"
+            DELETE FROM hearings s
+            WHERE s.superseded_at IS NOT NULL
+              AND EXISTS (
+                  SELECT 1 FROM hearings c
+                  WHERE c.package_id = s.package_id AND c.superseded_at IS NULL
+              )
+            RETURNING s.id
+            ",
+        )
+        .fetch_all(self.pool)
+        .await?;
+        Ok(ids.into_iter().map(|(id,)| id).collect())
+    }
+
     /// Get hearing IDs that have statements from a speaker (case-insensitive match)
     ///
     /// # Errors
@@ -258,7 +509,8 @@ impl<'a> HearingRepo<'a> {
             SELECT DISTINCT h.id
             FROM hearings h
             JOIN hearing_statements hs ON h.id = hs.hearing_id
-            WHERE LOWER(hs.speaker_label) LIKE $1 AND h.is_processed = true
+            WHERE (LOWER(hs.speaker_label) LIKE $1 OR LOWER(hs.normalized_speaker) LIKE $1)
+              AND h.is_processed = true AND h.superseded_at IS NULL
             ",
         )
         .bind(&pattern)
@@ -267,14 +519,28 @@ impl<'a> HearingRepo<'a> {
         Ok(ids.into_iter().map(|(id,)| id).collect())
     }
 
+    /// Get the package IDs of the given hearings
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_package_ids(&self, ids: &[Uuid]) -> Result<Vec<String>, DbError> {
+        let rows: Vec<(String,)> =
+            sqlx::query_as("SELECT package_id FROM hearings WHERE id = ANY($1)")
+                .bind(ids)
+                .fetch_all(self.pool)
+                .await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
     /// Get all package IDs as a set for fast lookup
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
     pub async fn get_all_package_ids(&self) -> Result<HashSet<String>, DbError> {
-        let ids: Vec<(String,)> = sqlx::query_as("SELECT package_id FROM hearings")
-            .fetch_all(self.pool)
-            .await?;
+        let ids: Vec<(String,)> =
+            sqlx::query_as("SELECT package_id FROM hearings WHERE superseded_at IS NULL")
+                .fetch_all(self.pool)
+                .await?;
         Ok(ids.into_iter().map(|(id,)| id).collect())
     }
 
@@ -297,7 +563,7 @@ impl<'a> HearingRepo<'a> {
             Option<i16>,
         )> = sqlx::query_as(
             "SELECT id, title, committee_raw, hearing_date, source_url, chambers, congress
-             FROM hearings WHERE id = ANY($1)",
+             FROM hearings WHERE id = ANY($1) AND superseded_at IS NULL",
         )
         .bind(ids)
         .fetch_all(self.pool)
@@ -344,7 +610,7 @@ impl<'a> HearingRepo<'a> {
             Option<i16>,
         )> = sqlx::query_as(
             "SELECT package_id, title, committee_raw, hearing_date, source_url, chambers, congress
-             FROM hearings WHERE package_id = ANY($1)",
+             FROM hearings WHERE package_id = ANY($1) AND superseded_at IS NULL",
         )
         .bind(package_ids)
         .fetch_all(self.pool)
@@ -369,3 +635,109 @@ impl<'a> HearingRepo<'a> {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Database;
+
+    fn hearing(tag: &str) -> Hearing {
+        Hearing::new(
+            format!("CHRG-{tag}"),
+            format!("hearing-{tag}"),
+            "Test hearing".to_string(),
+            None,
+            "senate",
+            118,
+            NaiveDate::from_ymd_opt(2024, 1, 15).expect("valid date"),
+            "https://example.com/hearing".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_transaction_commits_or_rolls_back_together() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+
+        let rolled_back = hearing(&Uuid::now_v7().to_string());
+        let result: Result<(), DbError> = db
+            .transaction(async |conn| {
+                HearingRepo::create_in(&mut *conn, &rolled_back).await?;
+                HearingRepo::mark_processed_in(&mut *conn, rolled_back.id, 1, 1).await?;
+                Err(DbError::InvalidOperation("fail after writes".to_string()))
+            })
+            .await;
+        assert!(result.is_err());
+        assert!(db.hearings().get_by_id(rolled_back.id).await.expect("query").is_none());
+
+        let committed = hearing(&Uuid::now_v7().to_string());
+        db.transaction(async |conn| {
+            HearingRepo::create_in(&mut *conn, &committed).await?;
+            HearingRepo::set_content_hash_in(&mut *conn, committed.id, "abc").await
+        })
+        .await
+        .expect("transaction");
+        let hash = db.hearings().get_content_hash(committed.id).await.expect("query");
+        assert_eq!(hash.as_deref(), Some("abc"));
+
+        db.hearings().delete(committed.id).await.expect("cleanup");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_supersede_keeps_original_until_finalized() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+        let tag = Uuid::now_v7().to_string();
+
+        let original = hearing(&tag);
+        db.hearings().create(&original).await.expect("create original");
+        db.hearings().supersede(original.id).await.expect("supersede");
+
+        // the replacement reuses the package ID while the original is still stored
+        let replacement = hearing(&tag);
+        db.hearings().create(&replacement).await.expect("create replacement");
+        let current = db.hearings().get_by_package_id(&original.package_id).await.expect("query");
+        assert_eq!(current.map(|h| h.id), Some(replacement.id));
+        assert!(db.hearings().get_by_id(original.id).await.expect("query").is_some());
+
+        // finalizing only removes superseded rows
+        db.hearings().finalize_supersede(replacement.id).await.expect("finalize");
+        db.hearings().finalize_supersede(original.id).await.expect("finalize");
+        assert!(db.hearings().get_by_id(original.id).await.expect("query").is_none());
+        assert!(db.hearings().get_by_id(replacement.id).await.expect("query").is_some());
+
+        db.hearings().delete(replacement.id).await.expect("cleanup");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_sweep_restores_orphans_and_deletes_replaced() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+
+        // superseded with no replacement: restored
+        let orphan = hearing(&Uuid::now_v7().to_string());
+        db.hearings().create(&orphan).await.expect("create orphan");
+        db.hearings().supersede(orphan.id).await.expect("supersede");
+
+        // superseded with a committed replacement: deleted
+        let tag = Uuid::now_v7().to_string();
+        let original = hearing(&tag);
+        db.hearings().create(&original).await.expect("create original");
+        db.hearings().supersede(original.id).await.expect("supersede");
+        let replacement = hearing(&tag);
+        db.hearings().create(&replacement).await.expect("create replacement");
+
+        db.hearings().restore_orphaned_superseded().await.expect("restore");
+        let deleted = db.hearings().delete_replaced_superseded().await.expect("delete");
+
+        assert!(deleted.contains(&original.id));
+        let current = db.hearings().get_by_package_id(&orphan.package_id).await.expect("query");
+        assert_eq!(current.map(|h| h.id), Some(orphan.id));
+
+        db.hearings().delete(orphan.id).await.expect("cleanup");
+        db.hearings().delete(replacement.id).await.expect("cleanup");
+    }
+}
@@ -1,9 +1,8 @@
 //! Hearing segment repository
 
-use crate::DbError;
+use crate::{DbError, SegmentSpeakerLookup};
 use polsearch_core::HearingSegment;
-use sqlx::PgPool;
-use std::collections::HashMap;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 pub struct HearingSegmentRepo<'a> {
@@ -44,6 +43,17 @@ impl<'a> HearingSegmentRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the insert fails
     pub async fn create_batch(&self, segments: &[HearingSegment]) -> Result<(), DbError> {
+        Self::create_batch_in(self.pool, segments).await
+    }
+
+    /// Batch insert hearing segments through `executor` (e.g., a transaction connection)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the insert fails
+    pub async fn create_batch_in(
+        executor: impl PgExecutor<'_>,
+        segments: &[HearingSegment],
+    ) -> Result<(), DbError> {
         if segments.is_empty() {
             return Ok(());
         }
@@ -61,7 +71,7 @@ impl<'a> HearingSegmentRepo<'a> {
                 .push_bind(seg.created_at);
         });
 
-        query_builder.build().execute(self.pool).await?;
+        query_builder.build().execute(executor).await?;
         Ok(())
     }
 
@@ -92,41 +102,17 @@ impl<'a> HearingSegmentRepo<'a> {
                 .await?;
         Ok(count.0)
     }
+}
 
-    /// Get speaker labels for segments (via statements)
-    /// Returns a map of (`hearing_id`, `segment_index`) -> `speaker_label`
-    ///
-    /// # Errors
-    /// Returns `DbError` if the query fails
-    pub async fn get_speakers_for_segments(
-        &self,
-        segment_keys: &[(Uuid, i32)],
-    ) -> Result<HashMap<(Uuid, i32), Option<String>>, DbError> {
-        if segment_keys.is_empty() {
-            return Ok(HashMap::new());
-        }
-
-        let hearing_ids: Vec<Uuid> = segment_keys.iter().map(|(id, _)| *id).collect();
-        let segment_indices: Vec<i32> = segment_keys.iter().map(|(_, idx)| *idx).collect();
-
-        let rows: Vec<(Uuid, i32, String)> = sqlx::query_as(
-            r"
-            SELECT hs.hearing_id, hs.segment_index, hst.speaker_label
-            FROM hearing_segments hs
-            JOIN hearing_statements hst ON hs.statement_id = hst.id
-            WHERE hs.hearing_id = ANY($1) AND hs.segment_index = ANY($2)
-            ",
-        )
-        .bind(&hearing_ids)
-        .bind(&segment_indices)
-        .fetch_all(self.pool)
-        .await?;
-
-        let map = rows
-            .into_iter()
-            .map(|(hid, sidx, label)| ((hid, sidx), Some(label)))
-            .collect();
+impl SegmentSpeakerLookup for HearingSegmentRepo<'_> {
+    const SPEAKER_QUERY: &'static str = r"
+        SELECT hs.hearing_id, hs.segment_index, hst.speaker_label
+        FROM hearing_segments hs
+        JOIN hearing_statements hst ON hs.statement_id = hst.id
+        WHERE hs.hearing_id = ANY($1) AND hs.segment_index = ANY($2)
+        ";
 
-        Ok(map)
+    fn pool(&self) -> &PgPool {
+        self.pool
     }
 }
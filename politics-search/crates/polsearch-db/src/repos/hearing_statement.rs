@@ -1,8 +1,10 @@
 //! Hearing statement repository
 
+use chrono::NaiveDate;
+
 use crate::DbError;
 use polsearch_core::HearingStatement;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 pub struct HearingStatementRepo<'a> {
@@ -23,8 +25,9 @@ impl<'a> HearingStatementRepo<'a> {
         sqlx::query(
             r"
             INSERT INTO hearing_statements (id, hearing_id, statement_index, speaker_label,
-                                             speaker_id, word_count, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+                                             speaker_id, normalized_speaker, speaker_honorific,
+                                             speaker_role, party, word_count, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ",
         )
         .bind(statement.id)
@@ -32,6 +35,10 @@ impl<'a> HearingStatementRepo<'a> {
         .bind(statement.statement_index)
         .bind(&statement.speaker_label)
         .bind(statement.speaker_id)
+        .bind(&statement.normalized_speaker)
+        .bind(&statement.speaker_honorific)
+        .bind(&statement.speaker_role)
+        .bind(&statement.party)
         .bind(statement.word_count)
         .bind(statement.created_at)
         .execute(self.pool)
@@ -44,12 +51,23 @@ impl<'a> HearingStatementRepo<'a> {
     /// # Errors
     /// Returns `DbError` if the insert fails
     pub async fn create_batch(&self, statements: &[HearingStatement]) -> Result<(), DbError> {
+        Self::create_batch_in(self.pool, statements).await
+    }
+
+    /// Batch insert hearing statements through `executor` (e.g., a transaction connection)
+    ///
+    /// # Errors
+    /// Returns `DbError` if the insert fails
+    pub async fn create_batch_in(
+        executor: impl PgExecutor<'_>,
+        statements: &[HearingStatement],
+    ) -> Result<(), DbError> {
         if statements.is_empty() {
             return Ok(());
         }
 
         let mut query_builder = sqlx::QueryBuilder::new(
-            "INSERT INTO hearing_statements (id, hearing_id, statement_index, speaker_label, speaker_id, word_count, created_at) "
+            "INSERT INTO hearing_statements (id, hearing_id, statement_index, speaker_label, speaker_id, normalized_speaker, speaker_honorific, speaker_role, party, word_count, created_at) "
         );
 
         query_builder.push_values(statements, |mut b, stmt| {
@@ -58,11 +76,15 @@ impl<'a> HearingStatementRepo<'a> {
                 .push_bind(stmt.statement_index)
                 .push_bind(&stmt.speaker_label)
                 .push_bind(stmt.speaker_id)
+                .push_bind(&stmt.normalized_speaker)
+                .push_bind(&stmt.speaker_honorific)
+                .push_bind(&stmt.speaker_role)
+                .push_bind(&stmt.party)
                 .push_bind(stmt.word_count)
                 .push_bind(stmt.created_at);
         });
 
-        query_builder.build().execute(self.pool).await?;
+        query_builder.build().execute(executor).await?;
         Ok(())
     }
 
@@ -72,7 +94,8 @@ impl<'a> HearingStatementRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn get_by_hearing(&self, hearing_id: Uuid) -> Result<Vec<HearingStatement>, DbError> {
         let statements = sqlx::query_as::<_, HearingStatement>(
-            r"SELECT id, hearing_id, statement_index, speaker_label, speaker_id, word_count, created_at
+            r"SELECT id, hearing_id, statement_index, speaker_label, speaker_id, normalized_speaker,
+                     speaker_honorific, speaker_role, party, word_count, created_at
               FROM hearing_statements WHERE hearing_id = $1 ORDER BY statement_index",
         )
         .bind(hearing_id)
@@ -87,7 +110,8 @@ impl<'a> HearingStatementRepo<'a> {
     /// Returns `DbError` if the query fails
     pub async fn get_by_id(&self, id: Uuid) -> Result<Option<HearingStatement>, DbError> {
         let statement = sqlx::query_as::<_, HearingStatement>(
-            r"SELECT id, hearing_id, statement_index, speaker_label, speaker_id, word_count, created_at
+            r"SELECT id, hearing_id, statement_index, speaker_label, speaker_id, normalized_speaker,
+                     speaker_honorific, speaker_role, party, word_count, created_at
               FROM hearing_statements WHERE id = $1",
         )
         .bind(id)
@@ -134,4 +158,46 @@ impl<'a> HearingStatementRepo<'a> {
                 .await?;
         Ok(count.0)
     }
+
+    /// Fetch a speaker's statements with their hearing ID, date, title, and text, oldest first
+    ///
+    /// Statements match on `speaker_id`, or, like `get_ids_by_speaker`, on a
+    /// case-insensitive substring of `speaker_label` or `normalized_speaker`,
+    /// since ingestion doesn't link statements to speakers. `from` and `to` are
+    /// inclusive "YYYY-MM" bounds on the hearing month.
+    ///
+    /// Hearing statements don't store their text, so it is always `None`.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_activity_by_speaker(
+        &self,
+        speaker_id: Uuid,
+        speaker_name: Option<&str>,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Vec<(Uuid, NaiveDate, String, Option<String>)>, DbError> {
+        let pattern = speaker_name.map(|name| format!("%{}%", name.to_lowercase()));
+        let rows = sqlx::query_as(
+            r"
+            SELECT p.id, p.hearing_date, p.title, NULL::text
+            FROM hearing_statements s
+            INNER JOIN hearings p ON p.id = s.hearing_id
+            WHERE (s.speaker_id = $1
+                   OR LOWER(s.speaker_label) LIKE $2
+                   OR LOWER(s.normalized_speaker) LIKE $2)
+              AND p.superseded_at IS NULL
+              AND ($3::text IS NULL OR p.year_month >= $3)
+              AND ($4::text IS NULL OR p.year_month <= $4)
+            ORDER BY p.hearing_date, p.id, s.statement_index
+            ",
+        )
+        .bind(speaker_id)
+        .bind(pattern)
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows)
+    }
 }
@@ -1,5 +1,7 @@
 //! Individual vote repository
 
+use chrono::{DateTime, Utc};
+
 use crate::DbError;
 use polsearch_core::IndividualVote;
 use sqlx::PgPool;
@@ -164,4 +166,35 @@ impl<'a> IndividualVoteRepo<'a> {
         .await?;
         Ok(counts)
     }
+
+    /// Fetch a legislator's positions with the roll call ID, date, and question, oldest first
+    ///
+    /// `from` and `to` are inclusive "YYYY-MM" bounds on the vote month.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_activity_by_legislator(
+        &self,
+        legislator_id: Uuid,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<Vec<(Uuid, DateTime<Utc>, String, String)>, DbError> {
+        let rows = sqlx::query_as(
+            r"
+            SELECT r.id, r.vote_date, r.question, v.position
+            FROM individual_votes v
+            INNER JOIN roll_call_votes r ON r.id = v.roll_call_vote_id
+            WHERE v.legislator_id = $1
+              AND ($2::text IS NULL OR r.year_month >= $2)
+              AND ($3::text IS NULL OR r.year_month <= $3)
+            ORDER BY r.vote_date, r.vote_number
+            ",
+        )
+        .bind(legislator_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(rows)
+    }
 }
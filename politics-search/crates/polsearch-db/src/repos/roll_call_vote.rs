@@ -209,7 +209,11 @@ impl<'a> RollCallVoteRepo<'a> {
         Ok(votes)
     }
 
-    /// Get all votes with pagination
+    /// Get all votes with pagination, newest first
+    ///
+    /// Ties on `vote_date` are broken by `id` so pages never overlap while the
+    /// table is unchanged. Prefer [`Self::get_after`] for full scans, which
+    /// stays fast at large offsets and tolerates concurrent inserts.
     ///
     /// # Errors
     /// Returns `DbError` if the query fails
@@ -219,7 +223,7 @@ impl<'a> RollCallVoteRepo<'a> {
         limit: i64,
     ) -> Result<Vec<RollCallVote>, DbError> {
         let votes = sqlx::query_as::<_, RollCallVote>(
-            "SELECT * FROM roll_call_votes ORDER BY vote_date DESC LIMIT $1 OFFSET $2",
+            "SELECT * FROM roll_call_votes ORDER BY vote_date DESC, id LIMIT $1 OFFSET $2",
         )
         .bind(limit)
         .bind(offset)
@@ -228,6 +232,35 @@ impl<'a> RollCallVoteRepo<'a> {
         Ok(votes)
     }
 
+    /// Get the next page of votes after `last_id` in `id` order
+    ///
+    /// Pass `None` for the first page, then the `id` of the last vote returned.
+    /// IDs are UUIDv7, so this is creation order: each row is visited exactly
+    /// once, rows inserted during the scan are picked up at the end, and the
+    /// scan continues even if the vote with `last_id` was deleted.
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    pub async fn get_after(
+        &self,
+        last_id: Option<Uuid>,
+        size: i64,
+    ) -> Result<Vec<RollCallVote>, DbError> {
+        let votes = sqlx::query_as::<_, RollCallVote>(
+            r"
+            SELECT * FROM roll_call_votes
+            WHERE $1::UUID IS NULL OR id > $1
+            ORDER BY id
+            LIMIT $2
+            ",
+        )
+        .bind(last_id)
+        .bind(size)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(votes)
+    }
+
     /// Get votes by their `vote_ids` (string identifiers like "h1-116.2019")
     ///
     /// # Errors
@@ -299,3 +332,102 @@ impl<'a> RollCallVoteRepo<'a> {
         Ok(votes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Database;
+    use chrono::Utc;
+    use std::collections::HashSet;
+
+    fn vote(tag: &str) -> RollCallVote {
+        RollCallVote::new(
+            format!("test-{tag}"),
+            118,
+            "House".to_string(),
+            "2024".to_string(),
+            1,
+            Utc::now(),
+            "On Passage".to_string(),
+            "Passed".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_get_after_visits_each_row_once_across_inserts() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+        let repo = db.roll_call_votes();
+
+        let mut ours: Vec<RollCallVote> =
+            (0..5).map(|_| vote(&Uuid::now_v7().to_string())).collect();
+        for v in &ours {
+            repo.create(v).await.expect("insert");
+        }
+
+        let mut seen: Vec<Uuid> = Vec::new();
+        let mut last_id = None;
+        let mut inserted_mid_scan = false;
+        loop {
+            let page = repo.get_after(last_id, 2).await.expect("page");
+            let Some(last) = page.last() else {
+                break;
+            };
+            last_id = Some(last.id);
+            seen.extend(page.iter().map(|v| v.id));
+
+            // a row inserted mid-scan sorts after the cursor and is still visited
+            if !inserted_mid_scan {
+                let late = vote(&Uuid::now_v7().to_string());
+                repo.create(&late).await.expect("insert mid-scan");
+                ours.push(late);
+                inserted_mid_scan = true;
+            }
+        }
+
+        let unique: HashSet<Uuid> = seen.iter().copied().collect();
+        assert_eq!(unique.len(), seen.len(), "a row was visited twice");
+        for v in &ours {
+            assert!(unique.contains(&v.id), "vote {} was skipped", v.vote_id);
+        }
+
+        for v in &ours {
+            sqlx::query("DELETE FROM roll_call_votes WHERE id = $1")
+                .bind(v.id)
+                .execute(db.pool())
+                .await
+                .expect("cleanup");
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_get_after_continues_past_deleted_cursor() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+        let repo = db.roll_call_votes();
+
+        let ours: Vec<RollCallVote> = (0..3).map(|_| vote(&Uuid::now_v7().to_string())).collect();
+        for v in &ours {
+            repo.create(v).await.expect("insert");
+        }
+
+        // the cursor row disappears between pages
+        sqlx::query("DELETE FROM roll_call_votes WHERE id = $1")
+            .bind(ours[0].id)
+            .execute(db.pool())
+            .await
+            .expect("delete cursor row");
+        let page = repo.get_after(Some(ours[0].id), 1).await.expect("page");
+        assert_eq!(page.first().map(|v| v.id), Some(ours[1].id));
+
+        for v in &ours[1..] {
+            sqlx::query("DELETE FROM roll_call_votes WHERE id = $1")
+                .bind(v.id)
+                .execute(db.pool())
+                .await
+                .expect("cleanup");
+        }
+    }
+}
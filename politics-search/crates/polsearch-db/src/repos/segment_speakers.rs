@@ -0,0 +1,136 @@
+//! Speaker lookup shared by hearing and floor speech segments
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use crate::{Database, DbError};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Map of (`parent_id`, `segment_index`) -> `speaker_label`
+pub type SegmentSpeakers = HashMap<(Uuid, i32), Option<String>>;
+
+/// Segment repositories whose segments resolve to a speaker through their statement
+pub trait SegmentSpeakerLookup {
+    /// Query returning `(parent_id, segment_index, speaker_label)` rows for parent IDs
+    /// bound to `$1` and segment indices bound to `$2`
+    const SPEAKER_QUERY: &'static str;
+
+    /// Connection pool the lookup runs on
+    fn pool(&self) -> &PgPool;
+
+    /// Get speaker labels for segments (via statements)
+    /// Returns a map of (`parent_id`, `segment_index`) -> `speaker_label`
+    ///
+    /// # Errors
+    /// Returns `DbError` if the query fails
+    fn get_speakers_for_segments(
+        &self,
+        segment_keys: &[(Uuid, i32)],
+    ) -> impl Future<Output = Result<SegmentSpeakers, DbError>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            if segment_keys.is_empty() {
+                return Ok(HashMap::new());
+            }
+
+            let parent_ids: Vec<Uuid> = segment_keys.iter().map(|(id, _)| *id).collect();
+            let segment_indices: Vec<i32> = segment_keys.iter().map(|(_, idx)| *idx).collect();
+
+            let rows: Vec<(Uuid, i32, String)> = sqlx::query_as(Self::SPEAKER_QUERY)
+                .bind(&parent_ids)
+                .bind(&segment_indices)
+                .fetch_all(self.pool())
+                .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|(parent_id, segment_index, label)| ((parent_id, segment_index), Some(label)))
+                .collect())
+        }
+    }
+}
+
+/// Fetch speakers for hearing and floor speech segments concurrently
+///
+/// Returns `(hearing_speakers, floor_speech_speakers)`.
+///
+/// # Errors
+/// Returns `DbError` if either query fails
+pub async fn fetch_all_speakers(
+    db: &Database,
+    hearing_keys: &[(Uuid, i32)],
+    speech_keys: &[(Uuid, i32)],
+) -> Result<(SegmentSpeakers, SegmentSpeakers), DbError> {
+    let hearing_segments = db.hearing_segments();
+    let floor_speech_segments = db.floor_speech_segments();
+    let (hearing_speakers, floor_speech_speakers) = tokio::join!(
+        hearing_segments.get_speakers_for_segments(hearing_keys),
+        floor_speech_segments.get_speakers_for_segments(speech_keys),
+    );
+    Ok((hearing_speakers?, floor_speech_speakers?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use polsearch_core::{
+        FloorSpeech, FloorSpeechSegment, FloorSpeechStatement, Hearing, HearingSegment,
+        HearingStatement,
+    };
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_fetch_all_speakers_populates_both_maps() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+        let tag = Uuid::now_v7().to_string();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).expect("valid date");
+
+        let hearing = Hearing::new(
+            format!("CHRG-{tag}"),
+            format!("hearing-{tag}"),
+            "Test hearing".to_string(),
+            None,
+            "senate",
+            118,
+            date,
+            "https://example.com/hearing".to_string(),
+        );
+        db.hearings().create(&hearing).await.expect("create hearing");
+        let statement = HearingStatement::new(hearing.id, 0, "The Chair".to_string(), 5);
+        db.hearing_statements().create(&statement).await.expect("create statement");
+        let segment = HearingSegment::new(hearing.id, statement.id, 0, 0);
+        db.hearing_segments().create(&segment).await.expect("create segment");
+
+        let speech = FloorSpeech::new(
+            format!("speech-{tag}"),
+            format!("CREC-2024-01-15-pt1-PgS100-{tag}"),
+            "Test speech".to_string(),
+            "senate".to_string(),
+            date,
+            "https://example.com/speech".to_string(),
+        );
+        db.floor_speeches().create(&speech).await.expect("create speech");
+        let statement =
+            FloorSpeechStatement::new(speech.id, 0, "Mr. SMITH".to_string(), "Hello".to_string());
+        db.floor_speech_statements().create(&statement).await.expect("create statement");
+        let segment = FloorSpeechSegment::new(speech.id, statement.id, 0, 0, "Hello");
+        db.floor_speech_segments().create(&segment).await.expect("create segment");
+
+        let result = fetch_all_speakers(&db, &[(hearing.id, 0)], &[(speech.id, 0)]).await;
+
+        db.hearings().delete(hearing.id).await.expect("cleanup hearing");
+        db.floor_speeches().delete(speech.id).await.expect("cleanup speech");
+
+        let (hearing_speakers, floor_speech_speakers) = result.expect("fetch speakers");
+        assert_eq!(hearing_speakers.get(&(hearing.id, 0)), Some(&Some("The Chair".to_string())));
+        assert_eq!(
+            floor_speech_speakers.get(&(speech.id, 0)),
+            Some(&Some("Mr. SMITH".to_string()))
+        );
+    }
+}
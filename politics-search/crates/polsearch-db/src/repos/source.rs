@@ -74,8 +74,11 @@ impl<'a> SourceRepo<'a> {
 
     /// Find source by fuzzy matching against name or slug
     ///
-    /// Tries exact slug match first, then falls back to fuzzy matching
-    /// using Jaro-Winkler similarity. Returns the best match if score > 0.7.
+    /// Tries exact slug match first, then a word-order independent token match
+    /// (every query word must appear in the name or slug), then falls back to
+    /// fuzzy matching using Jaro-Winkler similarity. Returns the best match if
+    /// score > 0.7. A token match naming more than one source is ambiguous and
+    /// returns `None`.
     ///
     /// # Errors
     ///
@@ -88,8 +91,18 @@ impl<'a> SourceRepo<'a> {
             return Ok(Some(source));
         }
 
-        // fuzzy match against all sources
+        // all query tokens present, in any order
+        let mut matches = self.find_by_tokens(query, 2).await?;
+        if matches.len() > 1 {
+            return Ok(None);
+        }
+        if let Some(source) = matches.pop() {
+            return Ok(Some(source));
+        }
+
         let sources = self.get_all().await?;
+
+        // fuzzy match against all sources
         let query_lower = query.to_lowercase();
 
         let best = sources
@@ -106,6 +119,37 @@ impl<'a> SourceRepo<'a> {
         Ok(best.map(|(p, _)| p))
     }
 
+    /// Fetch up to `limit` sources whose name or slug contains every query token
+    ///
+    /// Tokens match case-insensitively and in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the query fails
+    pub async fn find_by_tokens(&self, query: &str, limit: i64) -> Result<Vec<Source>, DbError> {
+        let patterns = token_patterns(query);
+        if patterns.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let sources = sqlx::query_as::<_, Source>(
+            r"
+            SELECT * FROM sources
+            WHERE (
+                SELECT bool_and(name ILIKE pattern OR slug ILIKE pattern)
+                FROM unnest($1::TEXT[]) AS pattern
+            )
+            ORDER BY tier, name
+            LIMIT $2
+            ",
+        )
+        .bind(patterns)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(sources)
+    }
+
     /// Fetch all sources
     ///
     /// # Errors
@@ -185,3 +229,66 @@ impl<'a> SourceRepo<'a> {
         Ok(i32::try_from(count.0).unwrap_or(i32::MAX))
     }
 }
+
+/// `ILIKE` patterns matching each alphanumeric query token anywhere
+///
+/// Tokens are alphanumeric, so they contain no `LIKE` wildcards to escape.
+fn token_patterns(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| format!("%{t}%"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Database;
+    use polsearch_core::SourceType;
+
+    #[test]
+    fn test_token_patterns() {
+        assert_eq!(token_patterns("Bitcoin  What-Did"), vec!["%Bitcoin%", "%What%", "%Did%"]);
+        assert!(token_patterns(" - ").is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_find_by_fuzzy_match_tokens() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+        let repo = db.sources();
+
+        let tag = Uuid::now_v7().simple().to_string();
+        let mut created = Vec::new();
+        for name in ["What Bitcoin Did", "The Bitcoin Standard Podcast"] {
+            let name = format!("{name} {tag}");
+            let source = Source::new(
+                name.clone(),
+                slugify(&name),
+                format!("https://example.com/{}", slugify(&name)),
+                1,
+                SourceType::Audio,
+            );
+            repo.create(&source).await.expect("insert");
+            created.push(source);
+        }
+
+        // word order doesn't matter
+        let found = repo.find_by_fuzzy_match(&format!("{tag} did what")).await.expect("find");
+        assert_eq!(found.map(|s| s.id), Some(created[0].id));
+
+        // a unique prefix resolves
+        let found = repo.find_by_fuzzy_match(&format!("standa {tag}")).await.expect("find");
+        assert_eq!(found.map(|s| s.id), Some(created[1].id));
+
+        // a token shared by both is ambiguous
+        let found = repo.find_by_fuzzy_match(&format!("bitcoin {tag}")).await.expect("find");
+        assert!(found.is_none());
+
+        for source in &created {
+            repo.delete(source.id).await.expect("cleanup");
+        }
+    }
+}
@@ -83,6 +83,30 @@ impl<'a> SpeakerRepo<'a> {
         Ok(speakers)
     }
 
+    /// Fetch non-merged speakers whose name starts with `prefix`, most frequent first
+    ///
+    /// Matching is case-insensitive and uses the `LOWER(name)` prefix index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the query fails
+    pub async fn autocomplete(&self, prefix: &str, limit: i64) -> Result<Vec<Speaker>, DbError> {
+        let pattern = format!("{}%", escape_like(&prefix.to_lowercase()));
+        let speakers = sqlx::query_as::<_, Speaker>(
+            r"
+            SELECT * FROM speakers
+            WHERE merged_into_id IS NULL AND LOWER(name) LIKE $1
+            ORDER BY total_appearances DESC, name
+            LIMIT $2
+            ",
+        )
+        .bind(pattern)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+        Ok(speakers)
+    }
+
     /// Follow the merge chain to find the canonical speaker
     ///
     /// # Errors
@@ -106,6 +130,20 @@ impl<'a> SpeakerRepo<'a> {
         Ok(speaker)
     }
 
+    /// Fetch the legislator a speaker is linked to, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the query fails
+    pub async fn get_legislator_id(&self, id: Uuid) -> Result<Option<Uuid>, DbError> {
+        let row: Option<(Option<Uuid>,)> =
+            sqlx::query_as("SELECT legislator_id FROM speakers WHERE id = $1")
+                .bind(id)
+                .fetch_optional(self.pool)
+                .await?;
+        Ok(row.and_then(|(legislator_id,)| legislator_id))
+    }
+
     /// Update a speaker
     ///
     /// # Errors
@@ -131,31 +169,85 @@ impl<'a> SpeakerRepo<'a> {
         Ok(())
     }
 
-    /// Merge one speaker into another
+    /// Merge a duplicate speaker into the one being kept
+    ///
+    /// Repoints content speakers, aliases, statements, and merge pointers from
+    /// `remove` to `keep`, folds appearance counts together, and deletes
+    /// `remove`, all in one transaction. Merging a speaker that no longer
+    /// exists is a no-op, so re-running a merge is safe.
     ///
     /// # Errors
     ///
-    /// Returns `DbError` if the merge fails or is invalid
-    pub async fn merge(&self, from_id: Uuid, into_id: Uuid) -> Result<(), DbError> {
-        if from_id == into_id {
+    /// Returns `DbError::InvalidOperation` if `keep == remove`,
+    /// `DbError::NotFound` if `keep` does not exist, or `DbError` if any
+    /// statement fails (in which case nothing is changed)
+    pub async fn merge(&self, keep: Uuid, remove: Uuid) -> Result<(), DbError> {
+        if keep == remove {
             return Err(DbError::InvalidOperation(
                 "Cannot merge speaker into itself".into(),
             ));
         }
 
-        // check for circular merge
-        let into_speaker = self.get_by_id(into_id).await?;
-        if let Some(s) = into_speaker
-            && s.merged_into_id == Some(from_id)
-        {
-            return Err(DbError::InvalidOperation("Circular merge detected".into()));
+        let mut tx = self.pool.begin().await?;
+
+        // lock both rows so concurrent merges serialize
+        let locked: Vec<(Uuid,)> =
+            sqlx::query_as("SELECT id FROM speakers WHERE id = ANY($1) FOR UPDATE")
+                .bind([keep, remove])
+                .fetch_all(&mut *tx)
+                .await?;
+        if !locked.iter().any(|(id,)| *id == keep) {
+            return Err(DbError::NotFound(format!("Speaker {keep}")));
+        }
+        if !locked.iter().any(|(id,)| *id == remove) {
+            // already merged
+            return Ok(());
         }
 
-        sqlx::query("UPDATE speakers SET merged_into_id = $2, updated_at = NOW() WHERE id = $1")
-            .bind(from_id)
-            .bind(into_id)
-            .execute(self.pool)
+        for (table, column) in [
+            ("content_speakers", "speaker_id"),
+            ("speaker_aliases", "resolved_speaker_id"),
+            ("hearing_statements", "speaker_id"),
+            ("floor_speech_statements", "speaker_id"),
+        ] {
+            sqlx::query(&format!("UPDATE {table} SET {column} = $1 WHERE {column} = $2"))
+                .bind(keep)
+                .bind(remove)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        sqlx::query(
+            "UPDATE speakers SET merged_into_id = $1, updated_at = NOW() WHERE merged_into_id = $2 AND id <> $1",
+        )
+        .bind(keep)
+        .bind(remove)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r"
+            UPDATE speakers k
+            SET total_appearances = k.total_appearances + r.total_appearances,
+                is_verified = k.is_verified OR r.is_verified,
+                legislator_id = COALESCE(k.legislator_id, r.legislator_id),
+                merged_into_id = NULLIF(k.merged_into_id, r.id),
+                updated_at = NOW()
+            FROM speakers r
+            WHERE k.id = $1 AND r.id = $2
+            ",
+        )
+        .bind(keep)
+        .bind(remove)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM speakers WHERE id = $1")
+            .bind(remove)
+            .execute(&mut *tx)
             .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -198,3 +290,15 @@ impl<'a> SpeakerRepo<'a> {
         Ok(i32::try_from(count.0).unwrap_or(i32::MAX))
     }
 }
+
+/// Escape `LIKE` wildcards so user input matches literally
+fn escape_like(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
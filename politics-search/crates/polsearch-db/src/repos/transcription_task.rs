@@ -202,6 +202,35 @@ impl<'a> TranscriptionTaskRepo<'a> {
         Ok(())
     }
 
+    /// Atomically claim the oldest queued task for `worker_id`
+    ///
+    /// Uses `FOR UPDATE SKIP LOCKED` so concurrent workers never claim the same task.
+    /// Returns `None` when the queue is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DbError` if the update fails
+    pub async fn claim_next(&self, worker_id: &str) -> Result<Option<TranscriptionTask>, DbError> {
+        let task = sqlx::query_as::<_, TranscriptionTask>(
+            r"
+            UPDATE transcription_tasks
+            SET status = 'processing', worker_id = $1, started_at = NOW(), updated_at = NOW()
+            WHERE id = (
+                SELECT id FROM transcription_tasks
+                WHERE status = 'queued'
+                ORDER BY created_at, id
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            ",
+        )
+        .bind(worker_id)
+        .fetch_optional(self.pool)
+        .await?;
+        Ok(task)
+    }
+
     /// Mark task as completed and update batch `completed_content` count
     ///
     /// # Errors
@@ -337,7 +366,8 @@ impl<'a> TranscriptionTaskRepo<'a> {
 
     /// Requeue tasks stuck in processing for longer than the specified minutes
     ///
-    /// Returns the requeued tasks
+    /// Clears the claiming worker (e.g., one that crashed) so the task can be claimed
+    /// again. Returns the requeued tasks.
     ///
     /// # Errors
     ///
@@ -349,7 +379,7 @@ impl<'a> TranscriptionTaskRepo<'a> {
         let tasks = sqlx::query_as::<_, TranscriptionTask>(
             r"
             UPDATE transcription_tasks
-            SET status = 'queued', updated_at = NOW()
+            SET status = 'queued', worker_id = NULL, updated_at = NOW()
             WHERE status = 'processing'
               AND started_at < NOW() - make_interval(mins => $1::int)
             RETURNING *
@@ -402,3 +432,60 @@ impl<'a> TranscriptionTaskRepo<'a> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Database;
+    use polsearch_core::{Content, Source, SourceType, TranscriptionBatch};
+
+    #[tokio::test]
+    #[ignore = "requires a migrated Postgres database at DATABASE_URL"]
+    async fn test_concurrent_claims_return_different_tasks() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let db = Database::connect(&url).await.expect("connect");
+
+        let slug = format!("claim-test-{}", Uuid::now_v7());
+        let source = Source::new(
+            slug.clone(),
+            slug.clone(),
+            format!("https://example.com/{slug}"),
+            1,
+            SourceType::Audio,
+        );
+        db.sources().create(&source).await.expect("create source");
+        let batch = TranscriptionBatch::new(slug);
+        db.batches().create(&batch).await.expect("create batch");
+
+        // backdate the tasks so they sort ahead of anything else in the queue
+        let mut task_ids = Vec::new();
+        for i in 0..2 {
+            let content = Content::new(
+                source.id,
+                format!("guid-{i}"),
+                format!("Episode {i}"),
+                Utc::now(),
+                format!("https://example.com/{i}.mp3"),
+            );
+            db.content().create(&content).await.expect("create content");
+            let mut task = TranscriptionTask::new(batch.id, content.id);
+            task.created_at = chrono::DateTime::UNIX_EPOCH;
+            db.tasks().create(&task).await.expect("create task");
+            task_ids.push(task.id);
+        }
+
+        let tasks = db.tasks();
+        let (a, b) = tokio::join!(tasks.claim_next("worker-a"), tasks.claim_next("worker-b"));
+        let a = a.expect("claim a").expect("task for a");
+        let b = b.expect("claim b").expect("task for b");
+
+        db.batches().delete(batch.id).await.expect("cleanup batch");
+        db.sources().delete(source.id).await.expect("cleanup source");
+
+        assert_ne!(a.id, b.id);
+        assert!(task_ids.contains(&a.id) && task_ids.contains(&b.id));
+        assert_eq!(a.worker_id.as_deref(), Some("worker-a"));
+        assert_eq!(b.worker_id.as_deref(), Some("worker-b"));
+        assert_eq!(a.task_status(), TaskStatus::Processing);
+    }
+}
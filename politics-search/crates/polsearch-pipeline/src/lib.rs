@@ -2,7 +2,7 @@
 //!
 //! This crate provides pipeline stages that:
 //! - Download podcast audio files
-//! - Generate text embeddings (384-dim, fastembed)
+//! - Generate text embeddings (fastembed, model selected by `EMBEDDING_MODEL`)
 
 pub mod config;
 pub mod stages;
@@ -3,27 +3,84 @@
 //! Each stage is a module that handles one step of the pipeline:
 //! - download: Fetch audio from URL
 //! - embed: Generate text embeddings
+//! - `embedding_schema`: Backfill added columns and verify the embedding model of tables
 //! - chunk: Split long text into embeddable segments
+//! - hybrid: Reciprocal rank fusion and its oversampling window for hybrid search
+//! - `content_hash`: Hash transcript statements to detect changed files on re-ingest
+//! - `content_scope`: Scope searches to content items under all their `LanceDB` keys
+//! - `context_embed`: Prepend speaker and preceding context to the text used for embedding
 //! - `ingest_hearings`: Parse and ingest congressional hearing transcripts
 //! - `ingest_floor_speeches`: Parse and ingest Congressional Record floor speeches
 //! - `ingest_fts`: Fast text-only ingestion for FTS (no embeddings)
+//! - legislators: Load members of Congress from a congress-legislators file
+//! - `phrase_search`: Find exact phrases, prefiltering through the FTS index when present
 //! - `procedural_filter`: Filter low-value procedural statements
+//! - recency: Weight search result scores toward newer content
+//! - `related_votes`: Find similar votes by embedding distance
+//! - rerank: Reorder retrieved segments with a cross-encoder
+//! - `speaker_normalize`: Canonicalize raw speaker labels into name, honorific, and role
+//! - `speaker_party`: Attribute speakers to a party using a legislators file
+//! - `sampling`: Enumerate input files, select deterministic subsets, and guard file sizes
+//! - `segment_dedup`: Drop exact and near-duplicate segments across an ingest run
+//! - `statement_merge`: Collapse consecutive same-speaker statements
 //! - `crec_parser`: Parse CREC HTML documents
 
 pub mod chunk;
+pub mod content_hash;
+pub mod content_scope;
+pub mod context_embed;
 pub mod crec_parser;
 pub mod download;
 pub mod embed;
+pub mod embedding_schema;
+pub mod hybrid;
 pub mod ingest_floor_speeches;
 pub mod ingest_fts;
 pub mod ingest_hearings;
+pub mod legislators;
+pub mod phrase_search;
 pub mod procedural_filter;
+pub mod recency;
+pub mod related_votes;
+pub mod rerank;
+pub mod sampling;
+pub mod segment_dedup;
+pub mod speaker_normalize;
+pub mod speaker_party;
+pub mod statement_merge;
 
 pub use chunk::TextChunker;
+pub use content_hash::statements_hash;
+pub use content_scope::{
+    content_id_aliases, content_id_filter, count_indexed_segments, MAX_FILTER_CONTENT_IDS,
+};
+pub use context_embed::{EmbeddingBatch, EmbeddingContext};
 pub use crec_parser::{parse_crec_html, parse_crec_text, CrecStatement};
 pub use download::download_audio;
-pub use embed::TextEmbedder;
+pub use embed::{EmbeddingModel, TextEmbedder, EMBEDDING_MODEL_ENV};
+pub use embedding_schema::{
+    append_text_embeddings, check_embedding_model, ensure_optional_columns,
+    stamp_embedding_model, text_embeddings_batch, text_embeddings_schema, verify_embedding_model,
+    with_embedding_model, SegmentText, EMBEDDING_MODEL_METADATA_KEY, LEGACY_EMBEDDING_MODEL,
+    OPTIONAL_STRING_COLUMNS,
+};
+pub use hybrid::{
+    hybrid_oversample_from_env, hybrid_window, merge_with_rrf, rrf_score,
+    DEFAULT_HYBRID_OVERSAMPLE, HYBRID_OVERSAMPLE_ENV, RRF_K,
+};
 pub use ingest_floor_speeches::{FloorSpeechIngester, FloorSpeechIngestStats, FloorSpeechJson};
-pub use ingest_fts::{FtsIngester, FtsIngestStats, FTS_TABLE_NAME};
+pub use ingest_fts::{
+    has_speaker_names, text_fts_query, FtsIngester, FtsIngestStats, FTS_TABLE_NAME,
+};
 pub use ingest_hearings::{HearingIngester, IngestStats, TranscriptJson};
+pub use legislators::{Chamber, Legislator, LegislatorLookup, Party};
+pub use phrase_search::{is_missing_fts_index_error, phrase_search, PHRASE_FTS_CANDIDATES};
 pub use procedural_filter::{is_procedural_crec_title, should_skip_statement};
+pub use recency::{boost_recent, recency_decay, DEFAULT_RECENCY_HALF_LIFE_DAYS};
+pub use related_votes::{find_related_votes, RelatedVote};
+pub use rerank::{apply_ranking, TextReranker, DEFAULT_RERANK_CANDIDATES};
+pub use sampling::{max_file_size_bytes, Sample, DEFAULT_MAX_FILE_SIZE_MB, DEFAULT_SAMPLE_SEED};
+pub use segment_dedup::{SegmentDeduper, DEFAULT_DEDUP_THRESHOLD};
+pub use speaker_normalize::{normalize_speaker, NormalizedSpeaker};
+pub use speaker_party::{SpeakerPartyResolver, SpeakerVenue};
+pub use statement_merge::merge_consecutive_statements;
@@ -0,0 +1,47 @@
+//! Content hashes for detecting changed transcripts on re-ingest
+
+use sha2::{Digest, Sha256};
+
+/// Hex SHA-256 of a transcript's `(speaker, text)` statements, in order
+///
+/// Each field is length-prefixed so text can't shift between fields or
+/// statements without changing the hash.
+#[must_use]
+pub fn statements_hash<'a>(statements: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    let mut hasher = Sha256::new();
+    for (speaker, text) in statements {
+        for field in [speaker, text] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_stable_hex() {
+        let hash = statements_hash([("Mr. SMITH", "I yield back.")]);
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, statements_hash([("Mr. SMITH", "I yield back.")]));
+    }
+
+    #[test]
+    fn test_hash_changes_with_content() {
+        let original = statements_hash([("Mr. SMITH", "I yield back.")]);
+        assert_ne!(original, statements_hash([("Mr. SMITH", "I yield back the balance.")]));
+        assert_ne!(original, statements_hash([("Ms. JONES", "I yield back.")]));
+    }
+
+    #[test]
+    fn test_hash_respects_field_boundaries() {
+        assert_ne!(statements_hash([("ab", "c")]), statements_hash([("a", "bc")]));
+        assert_ne!(
+            statements_hash([("a", "b"), ("c", "d")]),
+            statements_hash([("c", "d"), ("a", "b")])
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Scope `LanceDB` searches to specific content items
+//!
+//! Embedded segments are keyed by content UUID while FTS-only segments are
+//! keyed by hearing `package_id` or floor speech `event_id`, so a scope names
+//! both forms when they are known.
+
+use color_eyre::eyre::Result;
+use polsearch_db::{Database, DbError};
+use uuid::Uuid;
+
+use super::ingest_fts::FTS_TABLE_NAME;
+
+/// Most content items a search filter names, to keep filter expressions short
+pub const MAX_FILTER_CONTENT_IDS: usize = 1000;
+
+/// Every key a content item's segments may be stored under, starting with `content_id`
+///
+/// A UUID adds the hearing `package_id` or floor speech `event_id`; a known
+/// hearing `package_id` adds the hearing UUID. Unknown IDs are returned alone.
+///
+/// # Errors
+/// Returns an error if the lookups fail
+pub async fn content_id_aliases(db: &Database, content_id: &str) -> Result<Vec<String>, DbError> {
+    let content_id = content_id.trim();
+    let mut ids = vec![content_id.to_string()];
+    if let Ok(id) = Uuid::parse_str(content_id) {
+        if let Some(hearing) = db.hearings().get_by_id(id).await? {
+            ids.push(hearing.package_id);
+        } else if let Some(speech) = db.floor_speeches().get_by_id(id).await? {
+            ids.push(speech.event_id);
+        }
+    } else if let Some(hearing) = db.hearings().get_by_package_id(content_id).await? {
+        ids.push(hearing.id.to_string());
+    }
+    Ok(ids)
+}
+
+/// Build a `content_id IN (...)` filter for `LanceDB`
+#[must_use]
+pub fn content_id_filter(ids: &[String]) -> String {
+    let quoted: Vec<String> =
+        ids.iter().map(|id| format!("'{}'", id.replace('\'', "''"))).collect();
+    format!("content_id IN ({})", quoted.join(", "))
+}
+
+/// Count indexed segments matching a filter across the `LanceDB` text tables
+///
+/// # Errors
+/// Returns an error if `LanceDB` can't be opened or a count fails
+pub async fn count_indexed_segments(lancedb_path: &str, filter: &str) -> Result<usize> {
+    let db = lancedb::connect(lancedb_path).execute().await?;
+    let mut total = 0;
+    for table_name in ["text_embeddings", FTS_TABLE_NAME] {
+        if let Ok(table) = db.open_table(table_name).execute().await {
+            total += table.count_rows(Some(filter.to_string())).await?;
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_id_filter_quotes_ids() {
+        let ids = vec!["CHRG-118hhrg1".to_string(), "o'brien".to_string()];
+        assert_eq!(content_id_filter(&ids), "content_id IN ('CHRG-118hhrg1', 'o''brien')");
+    }
+}
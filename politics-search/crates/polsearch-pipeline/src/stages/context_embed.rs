@@ -0,0 +1,153 @@
+//! Context-augmented text for embedding short segments
+//!
+//! A segment like "Yes, I agree." carries almost no meaning on its own. When
+//! enabled, each segment is embedded as its speaker label plus the tail of the
+//! preceding segment in the same content item, followed by the segment itself.
+//! Only the embedded text is augmented; the stored display text is unchanged.
+
+use super::embedding_schema::SegmentText;
+
+/// Builds embedding text for consecutive segments of one content item
+#[derive(Debug)]
+pub struct EmbeddingContext {
+    words: usize,
+    previous: Option<String>,
+}
+
+impl EmbeddingContext {
+    /// Create a context that carries up to `words` trailing words of the previous segment
+    #[must_use]
+    pub const fn new(words: usize) -> Self {
+        Self {
+            words,
+            previous: None,
+        }
+    }
+
+    /// Text to embed for the next segment, remembering `text` as context for the one after
+    pub fn augment(&mut self, speaker: &str, text: &str) -> String {
+        let tail = self
+            .previous
+            .as_deref()
+            .map(|previous| trailing_words(previous, self.words))
+            .unwrap_or_default();
+        self.previous = Some(text.to_string());
+
+        let speaker = speaker.trim();
+        match (tail.is_empty(), speaker.is_empty()) {
+            (true, true) => text.to_string(),
+            (true, false) => format!("{speaker}: {text}"),
+            (false, true) => format!("... {tail}\n{text}"),
+            (false, false) => format!("... {tail}\n{speaker}: {text}"),
+        }
+    }
+}
+
+/// Segments of one content item and the text each one is embedded as
+///
+/// `texts` holds the display text written to `LanceDB`; `embed_texts[i]` is the
+/// input embedded for `texts[i]`, augmented when a context is set.
+#[derive(Debug)]
+pub struct EmbeddingBatch {
+    context: Option<EmbeddingContext>,
+    pub texts: Vec<SegmentText>,
+    pub embed_texts: Vec<String>,
+}
+
+impl EmbeddingBatch {
+    /// Create a batch, augmenting embedded text with up to `context_words` words
+    /// of the previous segment (`None` embeds each segment's text alone)
+    #[must_use]
+    pub fn new(context_words: Option<usize>) -> Self {
+        Self {
+            context: context_words.map(EmbeddingContext::new),
+            texts: Vec::new(),
+            embed_texts: Vec::new(),
+        }
+    }
+
+    /// Add the next segment, spoken by `speaker`
+    pub fn push(&mut self, speaker: &str, segment: SegmentText) {
+        let embed_text = self.context.as_mut().map_or_else(
+            || segment.text.clone(),
+            |context| context.augment(speaker, &segment.text),
+        );
+        self.embed_texts.push(embed_text);
+        self.texts.push(segment);
+    }
+}
+
+/// Last `count` whitespace-separated words of `text`, joined by single spaces
+fn trailing_words(text: &str, count: usize) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    words[words.len().saturating_sub(count)..].join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_first_segment_gets_speaker_only() {
+        let mut context = EmbeddingContext::new(5);
+        assert_eq!(
+            context.augment("Mr. SMITH", "The bill cuts taxes."),
+            "Mr. SMITH: The bill cuts taxes."
+        );
+    }
+
+    fn segment(index: i32, text: &str) -> SegmentText {
+        SegmentText {
+            segment_id: Uuid::now_v7(),
+            content_id: Uuid::nil(),
+            statement_id: None,
+            segment_index: index,
+            text: text.to_string(),
+            party: None,
+        }
+    }
+
+    #[test]
+    fn test_batch_stores_display_text_and_embeds_augmented_text() {
+        let mut batch = EmbeddingBatch::new(Some(4));
+        let first = "The amendment raises the cap on the child tax credit.";
+        batch.push("Mr. SMITH", segment(0, first));
+        batch.push("Ms. JONES", segment(1, "Yes, I agree."));
+
+        let display: Vec<&str> = batch.texts.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            display,
+            ["The amendment raises the cap on the child tax credit.", "Yes, I agree."]
+        );
+        assert_eq!(
+            batch.embed_texts,
+            [
+                "Mr. SMITH: The amendment raises the cap on the child tax credit.",
+                "... the child tax credit.\nMs. JONES: Yes, I agree.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_without_context_embeds_display_text() {
+        let mut batch = EmbeddingBatch::new(None);
+        batch.push("Mr. SMITH", segment(0, "The bill cuts taxes."));
+        assert_eq!(batch.embed_texts, ["The bill cuts taxes."]);
+        assert_eq!(batch.texts[0].text, "The bill cuts taxes.");
+    }
+
+    #[test]
+    fn test_zero_words_keeps_only_speaker() {
+        let mut context = EmbeddingContext::new(0);
+        context.augment("Mr. SMITH", "First segment.");
+        assert_eq!(context.augment("", "Second segment."), "Second segment.");
+    }
+
+    #[test]
+    fn test_trailing_words() {
+        assert_eq!(trailing_words("one two  three\nfour", 2), "three four");
+        assert_eq!(trailing_words("one two", 5), "one two");
+        assert_eq!(trailing_words("", 3), "");
+    }
+}
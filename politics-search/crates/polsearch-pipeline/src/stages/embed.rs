@@ -1,40 +1,180 @@
 //! Text embedding stage using fastembed
 
-use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
+use std::fmt;
+use std::str::FromStr;
 
-/// Text embedder using BGE-small-en-v1.5 (384-dim)
-pub struct TextEmbedder(TextEmbedding);
+use color_eyre::eyre::{eyre, Result};
+use fastembed::{InitOptions, TextEmbedding};
+
+/// Environment variable selecting the embedding model
+pub const EMBEDDING_MODEL_ENV: &str = "EMBEDDING_MODEL";
+
+/// Supported fastembed models
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmbeddingModel {
+    /// all-MiniLM-L6-v2 (384-dim)
+    AllMiniLmL6V2,
+    /// BGE-small-en-v1.5 (384-dim)
+    #[default]
+    BgeSmallEnV15,
+    /// BGE-base-en-v1.5 (768-dim)
+    BgeBaseEnV15,
+}
+
+impl EmbeddingModel {
+    /// All supported models
+    pub const ALL: [Self; 3] = [Self::AllMiniLmL6V2, Self::BgeSmallEnV15, Self::BgeBaseEnV15];
+
+    /// Name used for `EMBEDDING_MODEL` and stored in table metadata
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::AllMiniLmL6V2 => "all-minilm-l6-v2",
+            Self::BgeSmallEnV15 => "bge-small-en-v1.5",
+            Self::BgeBaseEnV15 => "bge-base-en-v1.5",
+        }
+    }
+
+    /// Length of the vectors this model produces
+    #[must_use]
+    pub const fn dimension(self) -> i32 {
+        match self {
+            Self::AllMiniLmL6V2 | Self::BgeSmallEnV15 => 384,
+            Self::BgeBaseEnV15 => 768,
+        }
+    }
+
+    /// Read the model from `EMBEDDING_MODEL`, defaulting to BGE-small-en-v1.5 when unset
+    ///
+    /// # Errors
+    /// Returns an error if the variable names an unsupported model
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(EMBEDDING_MODEL_ENV) {
+            Ok(value) if !value.trim().is_empty() => value.parse(),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    const fn fastembed_model(self) -> fastembed::EmbeddingModel {
+        match self {
+            Self::AllMiniLmL6V2 => fastembed::EmbeddingModel::AllMiniLML6V2,
+            Self::BgeSmallEnV15 => fastembed::EmbeddingModel::BGESmallENV15,
+            Self::BgeBaseEnV15 => fastembed::EmbeddingModel::BGEBaseENV15,
+        }
+    }
+}
+
+impl fmt::Display for EmbeddingModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for EmbeddingModel {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let normalized = s.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|model| model.name() == normalized)
+            .ok_or_else(|| {
+                let supported: Vec<&str> = Self::ALL.iter().map(|m| m.name()).collect();
+                eyre!(
+                    "Unsupported embedding model '{}' (supported: {})",
+                    s,
+                    supported.join(", ")
+                )
+            })
+    }
+}
+
+/// Text embedder wrapping a fastembed model
+pub struct TextEmbedder {
+    inner: TextEmbedding,
+    model: EmbeddingModel,
+}
 
 impl TextEmbedder {
-    /// Initialize the embedding model
+    /// Initialize the default embedding model (BGE-small-en-v1.5)
+    ///
+    /// # Errors
+    /// Returns an error if the embedding model fails to initialize
+    pub fn new() -> Result<Self> {
+        Self::with_model(EmbeddingModel::default())
+    }
+
+    /// Initialize the model selected by `EMBEDDING_MODEL`
+    ///
+    /// # Errors
+    /// Returns an error if the variable is invalid or the model fails to initialize
+    pub fn from_env() -> Result<Self> {
+        Self::with_model(EmbeddingModel::from_env()?)
+    }
+
+    /// Initialize a specific embedding model
     ///
     /// # Errors
     /// Returns an error if the embedding model fails to initialize
-    pub fn new() -> color_eyre::Result<Self> {
-        let model = TextEmbedding::try_new(InitOptions::new(EmbeddingModel::BGESmallENV15))
-            .map_err(|e| color_eyre::eyre::eyre!("Failed to initialize embedding model: {}", e))?;
-        Ok(Self(model))
+    pub fn with_model(model: EmbeddingModel) -> Result<Self> {
+        let inner = TextEmbedding::try_new(InitOptions::new(model.fastembed_model()))
+            .map_err(|e| eyre!("Failed to initialize embedding model {}: {}", model, e))?;
+        Ok(Self { inner, model })
+    }
+
+    /// The model this embedder runs
+    #[must_use]
+    pub const fn model(&self) -> EmbeddingModel {
+        self.model
     }
 
     /// Embed a batch of text segments
     ///
     /// # Errors
     /// Returns an error if embedding generation fails
-    pub fn embed_batch(&mut self, texts: &[&str]) -> color_eyre::Result<Vec<Vec<f32>>> {
-        self.0
+    pub fn embed_batch(&mut self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        self.inner
             .embed(texts, None)
-            .map_err(|e| color_eyre::eyre::eyre!("Embedding failed: {}", e))
+            .map_err(|e| eyre!("Embedding failed: {}", e))
     }
 
     /// Embed a single text
     ///
     /// # Errors
     /// Returns an error if embedding generation fails
-    pub fn embed(&mut self, text: &str) -> color_eyre::Result<Vec<f32>> {
+    pub fn embed(&mut self, text: &str) -> Result<Vec<f32>> {
         let mut embeddings = self
-            .0
+            .inner
             .embed(vec![text], None)
-            .map_err(|e| color_eyre::eyre::eyre!("Embedding failed: {}", e))?;
+            .map_err(|e| eyre!("Embedding failed: {}", e))?;
         Ok(embeddings.swap_remove(0))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_names_round_trip() {
+        for model in EmbeddingModel::ALL {
+            assert_eq!(model.name().parse::<EmbeddingModel>().ok(), Some(model));
+        }
+        assert_eq!(
+            " BGE-Small-EN-v1.5 ".parse::<EmbeddingModel>().ok(),
+            Some(EmbeddingModel::BgeSmallEnV15)
+        );
+    }
+
+    #[test]
+    fn test_unknown_model_lists_supported() {
+        let err = "ada-002".parse::<EmbeddingModel>().expect_err("unknown model");
+        assert!(err.to_string().contains("bge-small-en-v1.5"));
+    }
+
+    #[test]
+    fn test_dimensions() {
+        assert_eq!(EmbeddingModel::default().dimension(), 384);
+        assert_eq!(EmbeddingModel::BgeBaseEnV15.dimension(), 768);
+    }
+}
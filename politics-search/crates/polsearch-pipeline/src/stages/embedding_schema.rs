@@ -0,0 +1,322 @@
+//! Schema bookkeeping for embedding tables
+//!
+//! Every writer builds `text_embeddings` rows with [`text_embeddings_batch`] and
+//! appends them with [`append_text_embeddings`], so the schema is defined once.
+//!
+//! Tables created by earlier versions lack the optional columns; writers call
+//! [`ensure_optional_columns`] before appending so the schemas line up.
+//!
+//! New tables record the embedding model in their schema metadata. Writers and
+//! vector searches call [`verify_embedding_model`] so vectors from different
+//! models never get mixed or compared. Tables without the metadata were built
+//! with [`LEGACY_EMBEDDING_MODEL`], and the next append records it.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{
+    types::Float32Type, Array, FixedSizeListArray, Int32Array, RecordBatch, RecordBatchIterator,
+    StringArray,
+};
+use arrow_schema::{DataType, Field, Schema};
+use color_eyre::eyre::{bail, Result};
+use lancedb::table::NewColumnTransform;
+use polsearch_core::ContentType;
+use tracing::info;
+use uuid::Uuid;
+
+use super::embed::{EmbeddingModel, EMBEDDING_MODEL_ENV};
+
+/// A segment's text and keys, written as one `text_embeddings` row
+///
+/// Votes and bills are embedded whole, as one segment keyed by their own UUID
+/// with no statement.
+#[derive(Debug, Clone)]
+pub struct SegmentText {
+    pub segment_id: Uuid,
+    pub content_id: Uuid,
+    pub statement_id: Option<Uuid>,
+    pub segment_index: i32,
+    /// Display text (without any embedding context)
+    pub text: String,
+    pub party: Option<&'static str>,
+}
+
+/// Schema metadata key holding the embedding model name
+pub const EMBEDDING_MODEL_METADATA_KEY: &str = "embedding_model";
+
+/// Model of tables built before the model was recorded in their metadata
+pub const LEGACY_EMBEDDING_MODEL: EmbeddingModel = EmbeddingModel::BgeSmallEnV15;
+
+/// Nullable string columns appended after `vector`, in schema order
+pub const OPTIONAL_STRING_COLUMNS: [&str; 2] = ["party", "page_type"];
+
+/// Add any missing [`OPTIONAL_STRING_COLUMNS`] to an existing `LanceDB` table as nulls
+///
+/// # Errors
+/// Returns an error if the schema can't be read or the columns can't be added
+pub async fn ensure_optional_columns(table: &lancedb::Table) -> Result<()> {
+    let schema = table.schema().await?;
+    let missing: Vec<(String, String)> = OPTIONAL_STRING_COLUMNS
+        .iter()
+        .filter(|name| schema.field_with_name(name).is_err())
+        .map(|name| ((*name).to_string(), "CAST(NULL AS VARCHAR)".to_string()))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    table
+        .add_columns(NewColumnTransform::SqlExpressions(missing), None)
+        .await?;
+    Ok(())
+}
+
+/// Attach the embedding model name to a table schema's metadata
+#[must_use]
+pub fn with_embedding_model(schema: Schema, model: EmbeddingModel) -> Schema {
+    schema.with_metadata(HashMap::from([(
+        EMBEDDING_MODEL_METADATA_KEY.to_string(),
+        model.name().to_string(),
+    )]))
+}
+
+/// Check that a table's vectors were produced by `model`
+///
+/// Tables without model metadata are taken to hold [`LEGACY_EMBEDDING_MODEL`] vectors.
+///
+/// # Errors
+/// Returns an error if the recorded model or the vector dimension doesn't match
+pub fn check_embedding_model(schema: &Schema, model: EmbeddingModel) -> Result<()> {
+    let stored = schema
+        .metadata()
+        .get(EMBEDDING_MODEL_METADATA_KEY)
+        .map_or(LEGACY_EMBEDDING_MODEL.name(), String::as_str);
+    if stored != model.name() {
+        bail!(
+            "Embedding model mismatch: table was built with '{}' but {} is '{}'; \
+             set {}={} or re-ingest",
+            stored,
+            EMBEDDING_MODEL_ENV,
+            model,
+            EMBEDDING_MODEL_ENV,
+            stored
+        );
+    }
+
+    let dimension = schema
+        .field_with_name("vector")
+        .ok()
+        .and_then(|field| match field.data_type() {
+            DataType::FixedSizeList(_, dimension) => Some(*dimension),
+            _ => None,
+        });
+    if let Some(dimension) = dimension.filter(|dimension| *dimension != model.dimension()) {
+        bail!(
+            "Embedding dimension mismatch: table stores {}-dim vectors but '{}' produces {}",
+            dimension,
+            model,
+            model.dimension()
+        );
+    }
+    Ok(())
+}
+
+/// Check that an existing `LanceDB` table's vectors were produced by `model`
+///
+/// # Errors
+/// Returns an error if the schema can't be read or the model doesn't match
+pub async fn verify_embedding_model(table: &lancedb::Table, model: EmbeddingModel) -> Result<()> {
+    let schema = table.schema().await?;
+    check_embedding_model(&schema, model)
+}
+
+/// Schema of the `text_embeddings` table, with `model` recorded in its metadata
+#[must_use]
+pub fn text_embeddings_schema(model: EmbeddingModel) -> Arc<Schema> {
+    let mut fields = vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("content_type", DataType::Utf8, false),
+        Field::new("content_id", DataType::Utf8, false),
+        Field::new("statement_id", DataType::Utf8, true),
+        Field::new("segment_index", DataType::Int32, false),
+        Field::new("start_time_ms", DataType::Int32, false),
+        Field::new("end_time_ms", DataType::Int32, false),
+        Field::new("text", DataType::Utf8, false),
+        Field::new(
+            "vector",
+            DataType::FixedSizeList(
+                Arc::new(Field::new("item", DataType::Float32, true)),
+                model.dimension(),
+            ),
+            false,
+        ),
+    ];
+    fields.extend(OPTIONAL_STRING_COLUMNS.map(|name| Field::new(name, DataType::Utf8, true)));
+    Arc::new(with_embedding_model(Schema::new(fields), model))
+}
+
+/// Build `text_embeddings` rows for segments of one content type
+///
+/// `embeddings[i]` is the vector for `texts[i]`. Every row gets `page_type`;
+/// segments carry no timestamps, so both time columns are 0.
+///
+/// # Errors
+/// Returns an error if the columns don't match the schema
+pub fn text_embeddings_batch(
+    model: EmbeddingModel,
+    content_type: ContentType,
+    texts: &[SegmentText],
+    embeddings: &[Vec<f32>],
+    page_type: Option<&str>,
+) -> Result<RecordBatch> {
+    let ids: Vec<String> = texts.iter().map(|t| t.segment_id.to_string()).collect();
+    let content_types: Vec<&str> = vec![content_type.as_str(); texts.len()];
+    let content_ids: Vec<String> = texts.iter().map(|t| t.content_id.to_string()).collect();
+    let statement_ids: Vec<Option<String>> =
+        texts.iter().map(|t| t.statement_id.map(|id| id.to_string())).collect();
+    let segment_indices: Vec<i32> = texts.iter().map(|t| t.segment_index).collect();
+    let zeros: Vec<i32> = vec![0; texts.len()];
+    let text_values: Vec<&str> = texts.iter().map(|t| t.text.as_str()).collect();
+    let parties: Vec<Option<&str>> = texts.iter().map(|t| t.party).collect();
+    let page_types: Vec<Option<&str>> = vec![page_type; texts.len()];
+
+    let embedding_lists: Vec<Option<Vec<Option<f32>>>> = embeddings
+        .iter()
+        .map(|e| Some(e.iter().copied().map(Some).collect()))
+        .collect();
+    let vector_array = FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
+        embedding_lists,
+        model.dimension(),
+    );
+
+    Ok(RecordBatch::try_new(
+        text_embeddings_schema(model),
+        vec![
+            Arc::new(StringArray::from(ids)),
+            Arc::new(StringArray::from(content_types)),
+            Arc::new(StringArray::from(content_ids)),
+            Arc::new(StringArray::from(statement_ids)),
+            Arc::new(Int32Array::from(segment_indices)),
+            Arc::new(Int32Array::from(zeros.clone())),
+            Arc::new(Int32Array::from(zeros)),
+            Arc::new(StringArray::from(text_values)),
+            Arc::new(vector_array) as Arc<dyn Array>,
+            Arc::new(StringArray::from(parties)),
+            Arc::new(StringArray::from(page_types)),
+        ],
+    )?)
+}
+
+/// Record `model` in the metadata of a table built before it was recorded
+///
+/// Call after [`verify_embedding_model`], so the stamp matches the stored vectors.
+///
+/// # Errors
+/// Returns an error if the schema can't be read or the metadata can't be written
+pub async fn stamp_embedding_model(table: &lancedb::Table, model: EmbeddingModel) -> Result<()> {
+    let schema = table.schema().await?;
+    if schema.metadata().contains_key(EMBEDDING_MODEL_METADATA_KEY) {
+        return Ok(());
+    }
+    // only local tables expose their schema metadata for writing
+    let Some(native) = table.as_native() else {
+        return Ok(());
+    };
+    info!("Recording embedding model '{}' in {}", model, table.name());
+    native
+        .replace_schema_metadata([(
+            EMBEDDING_MODEL_METADATA_KEY.to_string(),
+            model.name().to_string(),
+        )])
+        .await?;
+    Ok(())
+}
+
+/// Append rows to `text_embeddings`, creating the table on the first write
+///
+/// An existing table is checked against `model`, stamped with it if it predates
+/// model metadata, and given any missing optional columns before the append.
+///
+/// # Errors
+/// Returns an error if the model doesn't match or the write fails
+pub async fn append_text_embeddings(
+    lancedb: &lancedb::Connection,
+    model: EmbeddingModel,
+    batch: RecordBatch,
+) -> Result<()> {
+    let schema = batch.schema();
+    let batches = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
+    match lancedb.open_table("text_embeddings").execute().await {
+        Ok(table) => {
+            verify_embedding_model(&table, model).await?;
+            stamp_embedding_model(&table, model).await?;
+            ensure_optional_columns(&table).await?;
+            table.add(Box::new(batches)).execute().await?;
+        }
+        Err(_) => {
+            info!("Creating text_embeddings table");
+            lancedb
+                .create_table("text_embeddings", Box::new(batches))
+                .execute()
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(dimension: i32) -> Schema {
+        let item = Arc::new(Field::new("item", DataType::Float32, true));
+        Schema::new(vec![Field::new("vector", DataType::FixedSizeList(item, dimension), false)])
+    }
+
+    #[test]
+    fn test_matching_model_passes() {
+        let schema = with_embedding_model(schema(384), EmbeddingModel::BgeSmallEnV15);
+        assert!(check_embedding_model(&schema, EmbeddingModel::BgeSmallEnV15).is_ok());
+    }
+
+    #[test]
+    fn test_model_name_mismatch_fails() {
+        let schema = with_embedding_model(schema(384), EmbeddingModel::AllMiniLmL6V2);
+        let err = check_embedding_model(&schema, EmbeddingModel::BgeSmallEnV15)
+            .expect_err("different model");
+        assert!(err.to_string().contains("all-minilm-l6-v2"));
+    }
+
+    #[test]
+    fn test_legacy_table_is_bge_small() {
+        assert!(check_embedding_model(&schema(384), EmbeddingModel::BgeSmallEnV15).is_ok());
+        assert!(check_embedding_model(&schema(384), EmbeddingModel::BgeBaseEnV15).is_err());
+        // same dimension, different model
+        let err = check_embedding_model(&schema(384), EmbeddingModel::AllMiniLmL6V2)
+            .expect_err("legacy table holds bge-small vectors");
+        assert!(err.to_string().contains(LEGACY_EMBEDDING_MODEL.name()));
+    }
+
+    #[test]
+    fn test_text_embeddings_batch_matches_schema() -> Result<()> {
+        let model = EmbeddingModel::BgeSmallEnV15;
+        let id = Uuid::now_v7();
+        let texts = vec![SegmentText {
+            segment_id: id,
+            content_id: id,
+            statement_id: None,
+            segment_index: 0,
+            text: "H.R. 1: For the People Act".to_string(),
+            party: None,
+        }];
+        let embeddings = vec![vec![0.0; model.dimension() as usize]];
+
+        let batch = text_embeddings_batch(model, ContentType::Bill, &texts, &embeddings, None)?;
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(batch.schema(), text_embeddings_schema(model));
+        let statement_ids = batch.column_by_name("statement_id").expect("statement_id column");
+        assert!(statement_ids.is_null(0));
+        check_embedding_model(&batch.schema(), model)
+    }
+}
@@ -0,0 +1,159 @@
+//! Reciprocal rank fusion and its result window for hybrid (vector + FTS) search
+//!
+//! Reciprocal rank fusion only sees the rows each component returns. A segment
+//! ranked just past `limit` by both the vector and the FTS search would beat
+//! rows that only one of them ranks highly, but it is never fused if each
+//! component stops at `limit`. Hybrid searches therefore fetch
+//! `limit * factor` rows from each component, fuse them, and trim the fused
+//! list back to `limit`.
+//!
+//! Higher factors surface more of these agreed-upon segments at the cost of
+//! larger component queries and more rows to fuse, so latency grows with the
+//! factor. A factor of 1 disables oversampling.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// RRF constant (standard value)
+pub const RRF_K: usize = 60;
+
+/// Environment variable that sets the hybrid oversampling factor
+pub const HYBRID_OVERSAMPLE_ENV: &str = "HYBRID_OVERSAMPLE";
+
+/// Oversampling factor used when `HYBRID_OVERSAMPLE` is unset or invalid
+pub const DEFAULT_HYBRID_OVERSAMPLE: usize = 3;
+
+/// Read the oversampling factor from `HYBRID_OVERSAMPLE`
+///
+/// Falls back to [`DEFAULT_HYBRID_OVERSAMPLE`] if the variable is unset, not a
+/// number, or zero.
+#[must_use]
+pub fn hybrid_oversample_from_env() -> usize {
+    std::env::var(HYBRID_OVERSAMPLE_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|&factor| factor > 0)
+        .unwrap_or(DEFAULT_HYBRID_OVERSAMPLE)
+}
+
+/// Rows to fetch from each hybrid component for `limit` fused results
+#[must_use]
+pub const fn hybrid_window(limit: usize, factor: usize) -> usize {
+    let factor = if factor == 0 { 1 } else { factor };
+    limit.saturating_mul(factor)
+}
+
+/// Reciprocal rank fusion score of a 0-based rank
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn rrf_score(rank: usize) -> f32 {
+    1.0 / (RRF_K + rank + 1) as f32
+}
+
+/// Merge ranked result lists with reciprocal rank fusion
+///
+/// Results with the same `key` are fused into the first one seen, and each
+/// result's `score` is set to its summed RRF score. Returns the results best
+/// first; ties keep the order they were first seen in.
+pub fn merge_with_rrf<T, K: Hash + Eq>(
+    result_sets: Vec<Vec<T>>,
+    key: impl Fn(&T) -> K,
+    score: impl Fn(&mut T) -> &mut f32,
+) -> Vec<T> {
+    let mut positions: HashMap<K, usize> = HashMap::new();
+    let mut fused: Vec<(f32, T)> = Vec::new();
+
+    for results in result_sets {
+        for (rank, result) in results.into_iter().enumerate() {
+            match positions.entry(key(&result)) {
+                Entry::Occupied(entry) => fused[*entry.get()].0 += rrf_score(rank),
+                Entry::Vacant(entry) => {
+                    entry.insert(fused.len());
+                    fused.push((rrf_score(rank), result));
+                }
+            }
+        }
+    }
+
+    fused.sort_by(|a, b| b.0.total_cmp(&a.0));
+    fused
+        .into_iter()
+        .map(|(rrf, mut result)| {
+            *score(&mut result) = rrf;
+            result
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fuse the first `window` IDs of each ranking and keep the top `limit`
+    fn fuse(rankings: &[Vec<u32>], window: usize, limit: usize) -> Vec<u32> {
+        let result_sets = rankings
+            .iter()
+            .map(|ranking| ranking.iter().take(window).map(|&id| (id, 0.0)).collect())
+            .collect();
+        let mut fused = merge_with_rrf(result_sets, |r: &(u32, f32)| r.0, |r| &mut r.1);
+        fused.truncate(limit);
+        fused.into_iter().map(|(id, _)| id).collect()
+    }
+
+    fn recall(results: &[u32], relevant: &[u32]) -> usize {
+        results.iter().filter(|id| relevant.contains(id)).count()
+    }
+
+    #[test]
+    fn test_oversampling_improves_recall_at_5() {
+        // each component's top 5 is noise only it ranks highly (100s vs 200s);
+        // the relevant segments (1-5) sit just past the top 5 in both
+        let vector = vec![101, 102, 103, 104, 105, 1, 2, 3, 4, 5, 106, 107];
+        let fts = vec![201, 202, 203, 204, 205, 3, 1, 5, 2, 4, 206, 207];
+        let relevant = [1, 2, 3, 4, 5];
+        let limit = 5;
+
+        let without = fuse(&[vector.clone(), fts.clone()], hybrid_window(limit, 1), limit);
+        let with = fuse(
+            &[vector, fts],
+            hybrid_window(limit, DEFAULT_HYBRID_OVERSAMPLE),
+            limit,
+        );
+
+        assert_eq!(recall(&without, &relevant), 0);
+        assert_eq!(recall(&with, &relevant), 5);
+    }
+
+    #[test]
+    fn test_merge_with_rrf_fuses_shared_results() {
+        let vector = vec![(1, 0.9), (2, 0.8)];
+        let fts = vec![(2, 12.0), (3, 9.0)];
+        let merged = merge_with_rrf(vec![vector, fts], |r: &(u32, f32)| r.0, |r| &mut r.1);
+
+        let ids: Vec<u32> = merged.iter().map(|r| r.0).collect();
+        assert_eq!(ids, vec![2, 1, 3]);
+        assert!((merged[0].1 - (rrf_score(1) + rrf_score(0))).abs() < f32::EPSILON);
+        assert!((merged[1].1 - rrf_score(0)).abs() < f32::EPSILON);
+        assert!((merged[2].1 - rrf_score(1)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_merge_with_rrf_ties_keep_first_seen_order() {
+        let merged = merge_with_rrf(
+            vec![vec![(1, 0.0), (2, 0.0)], vec![(3, 0.0), (4, 0.0)]],
+            |r: &(u32, f32)| r.0,
+            |r| &mut r.1,
+        );
+        let ids: Vec<u32> = merged.iter().map(|r| r.0).collect();
+        assert_eq!(ids, vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn test_hybrid_window() {
+        assert_eq!(hybrid_window(5, 3), 15);
+        assert_eq!(hybrid_window(5, 1), 5);
+        assert_eq!(hybrid_window(5, 0), 5);
+        assert_eq!(hybrid_window(usize::MAX, 2), usize::MAX);
+    }
+}
@@ -1,23 +1,29 @@
 //! Floor speech ingestion from JSON transcript files
 
-use arrow_array::{
-    types::Float32Type, Array, FixedSizeListArray, Int32Array, RecordBatch, RecordBatchIterator,
-    StringArray,
-};
-use arrow_schema::{DataType, Field, Schema};
 use chrono::{Datelike, NaiveDate};
-use color_eyre::eyre::{bail, eyre, Result};
-use polsearch_core::{FloorSpeech, FloorSpeechSegment, FloorSpeechStatement};
-use polsearch_db::Database;
+use color_eyre::eyre::{eyre, Result};
+use polsearch_core::{ContentType, FloorSpeech, FloorSpeechSegment, FloorSpeechStatement};
+use polsearch_db::{
+    Database, FloorSpeechRepo, FloorSpeechSegmentRepo, FloorSpeechStatementRepo,
+};
 use serde::Deserialize;
 use std::fs;
 use std::path::Path;
-use std::sync::Arc;
 use tracing::{info, warn};
 
 use super::chunk::TextChunker;
+use super::content_hash::statements_hash;
+use super::context_embed::EmbeddingBatch;
 use super::embed::TextEmbedder;
+use super::embedding_schema::{append_text_embeddings, text_embeddings_batch, SegmentText};
 use super::procedural_filter::should_skip_statement;
+use super::sampling::{
+    collect_json_files, max_file_size_bytes, oversized_file, Sample, DEFAULT_MAX_FILE_SIZE_MB,
+};
+use super::segment_dedup::SegmentDeduper;
+use super::speaker_party::{SpeakerPartyResolver, SpeakerVenue};
+use super::speaker_normalize::normalize_speaker;
+use super::statement_merge::merge_consecutive_statements;
 
 /// Raw floor speech JSON structure (output from fetch-floor-speeches)
 #[derive(Debug, Deserialize)]
@@ -45,9 +51,14 @@ pub struct FloorSpeechStatementJson {
 pub struct FloorSpeechIngestStats {
     pub files_processed: usize,
     pub files_skipped: usize,
+    /// Existing floor speeches re-ingested because their statements changed
+    pub files_changed: usize,
+    pub files_skipped_oversized: usize,
     pub speeches_created: usize,
     pub statements_created: usize,
     pub segments_created: usize,
+    pub segments_deduped: usize,
+    pub statements_with_party: usize,
     pub embeddings_created: usize,
 }
 
@@ -59,15 +70,23 @@ pub struct FloorSpeechIngester {
     lancedb: lancedb::Connection,
     force: bool,
     year_filter: Option<i32>,
+    sample: Option<Sample>,
+    merge_statements: bool,
+    normalize_speakers: bool,
+    deduper: Option<SegmentDeduper>,
+    context_embed_words: Option<usize>,
+    party_resolver: Option<SpeakerPartyResolver>,
+    max_file_size: Option<u64>,
 }
 
 impl FloorSpeechIngester {
     /// Creates a new floor speech ingester
     ///
     /// # Errors
-    /// Returns an error if embedding model or `LanceDB` fails to initialize
+    /// Returns an error if `EMBEDDING_MODEL` is invalid or the embedding model or `LanceDB`
+    /// fails to initialize
     pub async fn new(db: Database, lancedb_path: &str, force: bool, year_filter: Option<i32>) -> Result<Self> {
-        let embedder = TextEmbedder::new()?;
+        let embedder = TextEmbedder::from_env()?;
         let lancedb = lancedb::connect(lancedb_path).execute().await?;
 
         Ok(Self {
@@ -77,43 +96,104 @@ impl FloorSpeechIngester {
             lancedb,
             force,
             year_filter,
+            sample: None,
+            merge_statements: false,
+            normalize_speakers: false,
+            deduper: None,
+            context_embed_words: None,
+            party_resolver: None,
+            max_file_size: max_file_size_bytes(DEFAULT_MAX_FILE_SIZE_MB),
         })
     }
 
+    /// Restrict directory ingestion to a deterministic sample of files
+    #[must_use]
+    pub const fn with_sample(mut self, sample: Option<Sample>) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    /// Merge consecutive same-speaker statements before chunking
+    #[must_use]
+    pub const fn with_merge_statements(mut self, merge_statements: bool) -> Self {
+        self.merge_statements = merge_statements;
+        self
+    }
+
+    /// Store a normalized name, honorific, and role alongside each raw speaker label
+    #[must_use]
+    pub const fn with_normalize_speakers(mut self, normalize_speakers: bool) -> Self {
+        self.normalize_speakers = normalize_speakers;
+        self
+    }
+
+    /// Drop segments whose normalized text is at least `threshold` similar to
+    /// an earlier segment of any file in this run (`None` disables dedup)
+    #[must_use]
+    pub fn with_segment_dedup(mut self, threshold: Option<f64>) -> Self {
+        self.deduper = threshold.map(SegmentDeduper::new);
+        self
+    }
+
+    /// Embed each segment with its speaker and up to `words` trailing words of the
+    /// preceding segment (`None` embeds the segment text alone)
+    ///
+    /// The stored display text is not affected.
+    #[must_use]
+    pub const fn with_context_embed(mut self, words: Option<usize>) -> Self {
+        self.context_embed_words = words;
+        self
+    }
+
+    /// Attribute each statement's speaker to a party using `resolver`
+    #[must_use]
+    pub fn with_party_resolver(mut self, resolver: Option<SpeakerPartyResolver>) -> Self {
+        self.party_resolver = resolver;
+        self
+    }
+
+    /// Skip files larger than `max_bytes` instead of reading them (`None` disables the check)
+    #[must_use]
+    pub const fn with_max_file_size(mut self, max_bytes: Option<u64>) -> Self {
+        self.max_file_size = max_bytes;
+        self
+    }
+
     /// Ingest a single floor speech JSON file
     ///
+    /// Segments dropped as duplicates are matched only against files that were
+    /// stored; a file that fails leaves nothing behind in the deduper.
+    ///
     /// # Errors
     /// Returns an error if parsing or database operations fail
     pub async fn ingest_file(&mut self, path: &Path) -> Result<FloorSpeechIngestStats> {
-        let mut stats = FloorSpeechIngestStats::default();
+        let result = self.try_ingest_file(path).await;
+        if let Some(deduper) = self.deduper.as_mut() {
+            if result.is_ok() {
+                deduper.commit();
+            } else {
+                deduper.rollback();
+            }
+        }
+        result
+    }
 
-        let content = fs::read_to_string(path)?;
-        let speech_json: FloorSpeechJson = serde_json::from_str(&content)
-            .map_err(|e| eyre!("Failed to parse {}: {}", path.display(), e))?;
+    async fn try_ingest_file(&mut self, path: &Path) -> Result<FloorSpeechIngestStats> {
+        let mut stats = FloorSpeechIngestStats::default();
 
-        // check if already exists
-        if !self.force
-            && self
-                .db
-                .floor_speeches()
-                .exists_by_event_id(&speech_json.event_id)
-                .await?
-        {
-            stats.files_skipped += 1;
+        if let Some(size) = oversized_file(path, self.max_file_size)? {
+            warn!(
+                "Skipping oversized file {} ({:.1} MB)",
+                path.display(),
+                size as f64 / (1024.0 * 1024.0)
+            );
+            stats.files_skipped_oversized += 1;
             return Ok(stats);
         }
 
-        // delete existing if force mode
-        if self.force {
-            if let Some(existing) = self
-                .db
-                .floor_speeches()
-                .get_by_event_id(&speech_json.event_id)
-                .await?
-            {
-                self.db.floor_speeches().delete(existing.id).await?;
-            }
-        }
+        let content = fs::read_to_string(path)?;
+        let speech_json: FloorSpeechJson = serde_json::from_str(&content)
+            .map_err(|e| eyre!("Failed to parse {}: {}", path.display(), e))?;
 
         // parse date
         let speech_date = NaiveDate::parse_from_str(&speech_json.date, "%Y-%m-%d")
@@ -127,7 +207,38 @@ impl FloorSpeechIngester {
             }
         }
 
-        // create floor speech record
+        // skip unchanged speeches; re-ingest existing ones whose statements changed
+        let content_hash = statements_hash(
+            speech_json.statements.iter().map(|s| (s.speaker.as_str(), s.text.as_str())),
+        );
+        let mut replaced_id = None;
+        if let Some(existing) = self
+            .db
+            .floor_speeches()
+            .get_by_event_id(&speech_json.event_id)
+            .await?
+        {
+            if !self.force {
+                match self.db.floor_speeches().get_content_hash(existing.id).await? {
+                    Some(stored) if stored != content_hash => stats.files_changed += 1,
+                    // an unfinished earlier ingest has no hash yet; ingest it again
+                    None if !existing.is_processed => {}
+                    stored => {
+                        // rows ingested before hashes were recorded are assumed current
+                        if stored.is_none() {
+                            self.db
+                                .floor_speeches()
+                                .set_content_hash(existing.id, &content_hash)
+                                .await?;
+                        }
+                        stats.files_skipped += 1;
+                        return Ok(stats);
+                    }
+                }
+            }
+            replaced_id = Some(existing.id);
+        }
+
         let floor_speech = FloorSpeech::new(
             speech_json.event_id.clone(),
             speech_json.granule_id.clone(),
@@ -135,17 +246,28 @@ impl FloorSpeechIngester {
             speech_json.chamber.clone(),
             speech_date,
             speech_json.source_url.clone(),
-        );
-        self.db.floor_speeches().create(&floor_speech).await?;
+        )
+        .with_page_type(speech_json.page_type.clone());
         stats.speeches_created += 1;
 
         // process statements and create segments
         let mut all_statements = Vec::new();
         let mut all_segments = Vec::new();
-        let mut all_texts = Vec::new();
         let mut segment_index = 0;
+        let mut segment_texts = EmbeddingBatch::new(self.context_embed_words);
+
+        // merge consecutive same-speaker statements if requested
+        let statements = if self.merge_statements {
+            merge_consecutive_statements(
+                speech_json.statements,
+                |s| s.speaker.as_str(),
+                |s| &mut s.text,
+            )
+        } else {
+            speech_json.statements
+        };
 
-        for stmt_json in &speech_json.statements {
+        for stmt_json in &statements {
             // skip procedural statements
             if should_skip_statement(&stmt_json.text) {
                 continue;
@@ -157,12 +279,35 @@ impl FloorSpeechIngester {
                 stmt_json.speaker.clone(),
                 stmt_json.text.clone(),
             );
+            let statement = if self.normalize_speakers {
+                let normalized = normalize_speaker(&stmt_json.speaker);
+                statement.with_normalized_speaker(
+                    normalized.label().map(String::from),
+                    normalized.honorific,
+                    normalized.role,
+                )
+            } else {
+                statement
+            };
+            let chamber = Some(speech_json.chamber.as_str());
+            let party = self
+                .party_resolver
+                .as_ref()
+                .and_then(|r| r.resolve(&stmt_json.speaker, chamber, SpeakerVenue::Floor));
+            if party.is_some() {
+                stats.statements_with_party += 1;
+            }
+            let statement = statement.with_party(party.map(String::from));
             all_statements.push(statement.clone());
             stats.statements_created += 1;
 
             // chunk the statement
             let chunks = self.chunker.chunk(&stmt_json.text);
             for (chunk_idx, chunk_text) in chunks.iter().enumerate() {
+                if self.deduper.as_mut().is_some_and(|d| d.is_duplicate(chunk_text)) {
+                    stats.segments_deduped += 1;
+                    continue;
+                }
                 let segment = FloorSpeechSegment::new(
                     floor_speech.id,
                     statement.id,
@@ -171,134 +316,128 @@ impl FloorSpeechIngester {
                     chunk_text,
                 );
                 all_segments.push(segment.clone());
-                all_texts.push((
-                    segment.id,
-                    floor_speech.id,
-                    statement.id,
-                    segment_index,
-                    chunk_text.clone(),
-                ));
+                segment_texts.push(
+                    &stmt_json.speaker,
+                    SegmentText {
+                        segment_id: segment.id,
+                        content_id: floor_speech.id,
+                        statement_id: Some(statement.id),
+                        segment_index,
+                        text: chunk_text.clone(),
+                        party,
+                    },
+                );
                 segment_index += 1;
                 stats.segments_created += 1;
             }
         }
 
-        // batch insert statements and segments
-        self.db
-            .floor_speech_statements()
-            .create_batch(&all_statements)
-            .await?;
-        self.db
-            .floor_speech_segments()
-            .create_batch(&all_segments)
-            .await?;
-
-        // generate embeddings and write to LanceDB
-        if !all_texts.is_empty() {
-            let text_refs: Vec<&str> = all_texts.iter().map(|(_, _, _, _, t)| t.as_str()).collect();
-            let embeddings = self.embedder.embed_batch(&text_refs)?;
-            stats.embeddings_created += embeddings.len();
-
-            self.write_to_lancedb(&all_texts, &embeddings).await?;
-        }
+        // generate embeddings before touching either store
+        let embeddings = if segment_texts.texts.is_empty() {
+            Vec::new()
+        } else {
+            // embed the (possibly context-augmented) text; LanceDB stores the display text
+            let text_refs: Vec<&str> =
+                segment_texts.embed_texts.iter().map(String::as_str).collect();
+            self.embedder.embed_batch(&text_refs)?
+        };
+        stats.embeddings_created += embeddings.len();
+
+        // all Postgres writes share one transaction, so a failure anywhere leaves the
+        // original speech in place; embeddings are swapped around the commit the same
+        // way as for hearings
+        let total_statements = stats.statements_created as i32;
+        let total_segments = stats.segments_created as i32;
+        let written = self
+            .db
+            .transaction(async |conn| {
+                if let Some(old_id) = replaced_id {
+                    FloorSpeechRepo::supersede_in(&mut *conn, old_id).await?;
+                }
+                FloorSpeechRepo::create_in(&mut *conn, &floor_speech).await?;
+                FloorSpeechStatementRepo::create_batch_in(&mut *conn, &all_statements).await?;
+                FloorSpeechSegmentRepo::create_batch_in(&mut *conn, &all_segments).await?;
+                FloorSpeechRepo::mark_processed_in(
+                    &mut *conn,
+                    floor_speech.id,
+                    total_statements,
+                    total_segments,
+                )
+                .await?;
+                FloorSpeechRepo::set_content_hash_in(&mut *conn, floor_speech.id, &content_hash)
+                    .await?;
+                if let Some(old_id) = replaced_id {
+                    FloorSpeechRepo::finalize_supersede_in(&mut *conn, old_id).await?;
+                }
 
-        // mark floor speech as processed
-        self.db
-            .floor_speeches()
-            .mark_processed(
-                floor_speech.id,
-                stats.statements_created as i32,
-                stats.segments_created as i32,
-            )
-            .await?;
+                if !segment_texts.texts.is_empty() {
+                    let page_type = &floor_speech.page_type;
+                    self.write_to_lancedb(&segment_texts.texts, &embeddings, page_type)
+                        .await?;
+                }
+                Ok::<_, color_eyre::eyre::Report>(())
+            })
+            .await;
+
+        if let Err(e) = written {
+            if let Err(cleanup) = self.delete_embeddings(floor_speech.id).await {
+                warn!("Failed to remove embeddings of {}: {}", floor_speech.event_id, cleanup);
+            }
+            return Err(e);
+        }
+        if let Some(old_id) = replaced_id {
+            if let Err(e) = self.delete_embeddings(old_id).await {
+                warn!("Failed to remove replaced embeddings of {}: {}", floor_speech.event_id, e);
+            }
+        }
 
         stats.files_processed += 1;
         Ok(stats)
     }
 
+    /// Clean up floor speeches left superseded by an interrupted re-ingest
+    ///
+    /// A superseded speech with a current replacement is deleted along with its
+    /// embeddings; one without a replacement is made current again.
+    ///
+    /// # Errors
+    /// Returns an error if a database or `LanceDB` operation fails
+    pub async fn sweep_superseded(&self) -> Result<()> {
+        let restored = self.db.floor_speeches().restore_orphaned_superseded().await?;
+        let deleted = self.db.floor_speeches().delete_replaced_superseded().await?;
+        for id in &deleted {
+            self.delete_embeddings(*id).await?;
+        }
+        if restored > 0 || !deleted.is_empty() {
+            warn!(
+                "Swept superseded floor speeches: {} restored, {} deleted",
+                restored,
+                deleted.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Delete all embeddings for a floor speech
+    async fn delete_embeddings(&self, id: uuid::Uuid) -> Result<()> {
+        if let Ok(table) = self.lancedb.open_table("text_embeddings").execute().await {
+            table.delete(&format!("content_id = '{id}'")).await?;
+        }
+        Ok(())
+    }
+
     /// Write embeddings to `LanceDB`
     async fn write_to_lancedb(
         &self,
-        texts: &[(uuid::Uuid, uuid::Uuid, uuid::Uuid, i32, String)],
+        texts: &[SegmentText],
         embeddings: &[Vec<f32>],
+        page_type: &str,
     ) -> Result<()> {
-        let schema = Arc::new(Schema::new(vec![
-            Field::new("id", DataType::Utf8, false),
-            Field::new("content_type", DataType::Utf8, false),
-            Field::new("content_id", DataType::Utf8, false),
-            Field::new("statement_id", DataType::Utf8, true),
-            Field::new("segment_index", DataType::Int32, false),
-            Field::new("start_time_ms", DataType::Int32, false),
-            Field::new("end_time_ms", DataType::Int32, false),
-            Field::new("text", DataType::Utf8, false),
-            Field::new(
-                "vector",
-                DataType::FixedSizeList(
-                    Arc::new(Field::new("item", DataType::Float32, true)),
-                    384,
-                ),
-                false,
-            ),
-        ]));
-
-        let ids: Vec<String> = texts.iter().map(|(id, _, _, _, _)| id.to_string()).collect();
-        let content_types: Vec<&str> = vec!["floor_speech"; texts.len()];
-        let content_ids: Vec<String> = texts
-            .iter()
-            .map(|(_, cid, _, _, _)| cid.to_string())
-            .collect();
-        let statement_ids: Vec<String> = texts
-            .iter()
-            .map(|(_, _, sid, _, _)| sid.to_string())
-            .collect();
-        let segment_indices: Vec<i32> = texts.iter().map(|(_, _, _, idx, _)| *idx).collect();
-        let text_values: Vec<&str> = texts.iter().map(|(_, _, _, _, t)| t.as_str()).collect();
-
-        // floor speech segments don't have timestamps, use 0
-        let start_times: Vec<i32> = vec![0; texts.len()];
-        let end_times: Vec<i32> = vec![0; texts.len()];
-
-        // create embedding array
-        let embedding_lists: Vec<Option<Vec<Option<f32>>>> = embeddings
-            .iter()
-            .map(|e| Some(e.iter().copied().map(Some).collect()))
-            .collect();
-        let vector_array =
-            FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(embedding_lists, 384);
-
-        let batch = RecordBatch::try_new(
-            schema.clone(),
-            vec![
-                Arc::new(StringArray::from(ids)),
-                Arc::new(StringArray::from(content_types)),
-                Arc::new(StringArray::from(content_ids)),
-                Arc::new(StringArray::from(statement_ids)),
-                Arc::new(Int32Array::from(segment_indices)),
-                Arc::new(Int32Array::from(start_times)),
-                Arc::new(Int32Array::from(end_times)),
-                Arc::new(StringArray::from(text_values)),
-                Arc::new(vector_array) as Arc<dyn Array>,
-            ],
-        )?;
-
-        // open or create the table
-        let table = match self.lancedb.open_table("text_embeddings").execute().await {
-            Ok(t) => t,
-            Err(_) => {
-                info!("Creating text_embeddings table");
-                let batches =
-                    RecordBatchIterator::new(vec![Ok(batch.clone())].into_iter(), schema.clone());
-                self.lancedb
-                    .create_table("text_embeddings", Box::new(batches))
-                    .execute()
-                    .await?
-            }
-        };
-
-        let batches = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
-        table.add(Box::new(batches)).execute().await?;
-
-        Ok(())
+        let model = self.embedder.model();
+        let page_type = Some(page_type).filter(|p| !p.is_empty());
+        let batch =
+            text_embeddings_batch(model, ContentType::FloorSpeech, texts, embeddings, page_type)?;
+        append_text_embeddings(&self.lancedb, model, batch).await
     }
 
     /// Ingest all JSON files in a directory
@@ -326,21 +465,9 @@ impl FloorSpeechIngester {
         progress_bar: Option<&indicatif::ProgressBar>,
     ) -> Result<FloorSpeechIngestStats> {
         let mut total_stats = FloorSpeechIngestStats::default();
+        self.sweep_superseded().await?;
 
-        if !path.is_dir() {
-            bail!("Path is not a directory: {}", path.display());
-        }
-
-        let mut entries: Vec<_> = fs::read_dir(path)?
-            .filter_map(Result::ok)
-            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
-            .collect();
-
-        entries.sort_by_key(std::fs::DirEntry::path);
-
-        if let Some(max) = limit {
-            entries.truncate(max);
-        }
+        let entries = collect_json_files(path, self.sample, limit)?;
 
         let total = entries.len();
         let show_output = progress_bar.is_none();
@@ -349,19 +476,24 @@ impl FloorSpeechIngester {
             info!("Processing {} floor speech files", total);
         }
 
-        for (i, entry) in entries.into_iter().enumerate() {
-            let file_path = entry.path();
+        for (i, file_path) in entries.into_iter().enumerate() {
             match self.ingest_file(&file_path).await {
                 Ok(stats) => {
-                    let skipped = stats.files_skipped > 0;
+                    let skipped = stats.files_skipped > 0 || stats.files_skipped_oversized > 0;
                     if let Some(pb) = progress_bar {
                         pb.inc(1);
                     } else if skipped {
+                        let reason = if stats.files_skipped_oversized > 0 {
+                            "oversized"
+                        } else {
+                            "already exists"
+                        };
                         info!(
-                            "[{}/{}] Skipped {} (already exists)",
+                            "[{}/{}] Skipped {} ({})",
                             i + 1,
                             total,
-                            file_path.display()
+                            file_path.display(),
+                            reason
                         );
                     } else {
                         info!(
@@ -374,9 +506,13 @@ impl FloorSpeechIngester {
                     }
                     total_stats.files_processed += stats.files_processed;
                     total_stats.files_skipped += stats.files_skipped;
+                    total_stats.files_changed += stats.files_changed;
+                    total_stats.files_skipped_oversized += stats.files_skipped_oversized;
                     total_stats.speeches_created += stats.speeches_created;
                     total_stats.statements_created += stats.statements_created;
                     total_stats.segments_created += stats.segments_created;
+                    total_stats.segments_deduped += stats.segments_deduped;
+                    total_stats.statements_with_party += stats.statements_with_party;
                     total_stats.embeddings_created += stats.embeddings_created;
                 }
                 Err(e) => {
@@ -401,24 +537,15 @@ impl FloorSpeechIngester {
     /// # Errors
     /// Returns an error if directory reading fails
     pub fn validate_directory(&self, path: &Path, limit: Option<usize>) -> Result<(usize, usize)> {
-        if !path.is_dir() {
-            bail!("Path is not a directory: {}", path.display());
-        }
-
         let mut valid = 0;
         let mut invalid = 0;
 
-        let mut entries: Vec<_> = fs::read_dir(path)?
-            .filter_map(Result::ok)
-            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
-            .collect();
-
-        if let Some(max) = limit {
-            entries.truncate(max);
-        }
-
-        for entry in entries {
-            let file_path = entry.path();
+        for file_path in collect_json_files(path, self.sample, limit)? {
+            if let Some(size) = oversized_file(&file_path, self.max_file_size)? {
+                warn!("Oversized file {} ({} bytes)", file_path.display(), size);
+                invalid += 1;
+                continue;
+            }
             match fs::read_to_string(&file_path) {
                 Ok(content) => match serde_json::from_str::<FloorSpeechJson>(&content) {
                     Ok(_) => valid += 1,
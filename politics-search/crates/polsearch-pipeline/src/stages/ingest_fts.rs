@@ -1,26 +1,68 @@
 //! FTS-only ingestion for fast text search without embeddings
+//!
+//! With [`FtsIngester::with_speaker_names`], each segment's normalized speaker
+//! name goes into a separate `speaker_name` column. Text queries only search
+//! the `text` column (see [`text_fts_query`]); search can then boost results
+//! whose speaker is named in the query (see [`has_speaker_names`]).
+//!
+//! Hearing and floor speech rows carry the [`statements_hash`] of their
+//! transcript, and bill rows a hash of their text, so re-ingesting changed
+//! content replaces its rows instead of skipping it. Every row is also stamped
+//! with the `ingest_run` that wrote it; replacing content deletes its rows from
+//! other runs, so rows left by an interrupted run are removed on the next one.
 
 use arrow_array::{Array, Int32Array, RecordBatch, RecordBatchIterator, StringArray};
 use arrow_schema::{DataType, Field, Schema};
-use color_eyre::eyre::{bail, Result};
-use polsearch_core::RollCallVote;
+use color_eyre::eyre::Result;
+use lancedb::index::scalar::FullTextSearchQuery;
+use lancedb::table::NewColumnTransform;
+use polsearch_core::{Bill, ContentType, RollCallVote};
 use polsearch_db::Database;
 use rayon::prelude::*;
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::fs;
-use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{info, warn};
 
 use super::chunk::TextChunker;
+use super::content_hash::statements_hash;
 use super::procedural_filter::should_skip_statement;
+use super::sampling::{
+    collect_json_files, max_file_size_bytes, oversized_file, Sample, DEFAULT_MAX_FILE_SIZE_MB,
+};
+use super::segment_dedup::SegmentDeduper;
+use super::speaker_normalize::normalize_speaker;
 
 /// FTS table name
 pub const FTS_TABLE_NAME: &str = "text_fts";
 
+/// Full-text query matching only the `text` column
+///
+/// Without a column, `LanceDB` searches every FTS-indexed column, so speaker
+/// names in an indexed `speaker_name` column would count toward text relevance.
+///
+/// # Errors
+/// Returns an error if the query can't be restricted to the column
+pub fn text_fts_query(query: &str) -> lancedb::Result<FullTextSearchQuery> {
+    Ok(FullTextSearchQuery::new(query.to_string()).with_column("text".to_string())?)
+}
+
+/// Returns true if the FTS table has a `speaker_name` column to boost on
+///
+/// # Errors
+/// Returns an error if `LanceDB` can't be opened or the schema can't be read
+pub async fn has_speaker_names(lancedb_path: &str) -> Result<bool> {
+    let db = lancedb::connect(lancedb_path).execute().await?;
+    let Ok(table) = db.open_table(FTS_TABLE_NAME).execute().await else {
+        return Ok(false);
+    };
+    Ok(table.schema().await?.field_with_name("speaker_name").is_ok())
+}
+
 /// Raw transcript JSON structure (same as `ingest_hearings`)
 #[derive(Debug, Deserialize)]
 pub struct TranscriptJson {
@@ -73,7 +115,11 @@ pub struct FtsIngestStats {
     pub speeches_skipped: usize,
     pub votes_processed: usize,
     pub votes_skipped: usize,
+    pub bills_processed: usize,
+    pub bills_skipped: usize,
+    pub files_skipped_oversized: usize,
     pub segments_created: usize,
+    pub segments_deduped: usize,
 }
 
 /// FTS record for writing to `LanceDB`
@@ -85,12 +131,54 @@ struct FtsRecord {
     statement_id: Option<String>,
     segment_index: i32,
     text: String,
+    speaker_name: Option<String>,
+    content_hash: Option<String>,
 }
 
 /// Result of parsing a single file
 struct ParseResult {
     records: Vec<FtsRecord>,
     skipped: bool,
+    oversized: bool,
+    /// Content ID when this file replaces rows already in the table
+    replaces: Option<String>,
+}
+
+impl ParseResult {
+    const fn skipped() -> Self {
+        Self {
+            records: vec![],
+            skipped: true,
+            oversized: false,
+            replaces: None,
+        }
+    }
+
+    const fn oversized() -> Self {
+        Self {
+            records: vec![],
+            skipped: true,
+            oversized: true,
+            replaces: None,
+        }
+    }
+}
+
+/// Compare a file's hash against the rows already in the table
+///
+/// Returns `Err` with a skipped result if the rows are current, otherwise the
+/// content ID to replace, if any rows exist.
+fn hash_check(
+    existing: &HashMap<String, Option<String>>,
+    content_id: &str,
+    content_hash: &str,
+) -> Result<Option<String>, ParseResult> {
+    match existing.get(content_id) {
+        Some(Some(stored)) if stored == content_hash => Err(ParseResult::skipped()),
+        // rows written before hashes were recorded are replaced once
+        Some(_) => Ok(Some(content_id.to_string())),
+        None => Ok(None),
+    }
 }
 
 /// FTS ingester for text-only ingestion without embeddings
@@ -98,6 +186,14 @@ pub struct FtsIngester {
     db: Database,
     lancedb: lancedb::Connection,
     force: bool,
+    sample: Option<Sample>,
+    include_speaker: bool,
+    max_file_size: Option<u64>,
+    deduper: Option<SegmentDeduper>,
+    /// Stamped on every row this ingester writes
+    run_id: String,
+    /// Set once the table is known to have every optional column
+    columns_checked: AtomicBool,
 }
 
 impl FtsIngester {
@@ -108,7 +204,61 @@ impl FtsIngester {
     pub async fn new(db: Database, lancedb_path: &str, force: bool) -> Result<Self> {
         let lancedb = lancedb::connect(lancedb_path).execute().await?;
 
-        Ok(Self { db, lancedb, force })
+        Ok(Self {
+            db,
+            lancedb,
+            force,
+            sample: None,
+            include_speaker: false,
+            max_file_size: max_file_size_bytes(DEFAULT_MAX_FILE_SIZE_MB),
+            deduper: None,
+            run_id: uuid::Uuid::now_v7().to_string(),
+            columns_checked: AtomicBool::new(false),
+        })
+    }
+
+    /// Restrict directory ingestion to a deterministic sample of files
+    #[must_use]
+    pub const fn with_sample(mut self, sample: Option<Sample>) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    /// Store each statement's normalized speaker name in the `speaker_name` column
+    #[must_use]
+    pub const fn with_speaker_names(mut self, include_speaker: bool) -> Self {
+        self.include_speaker = include_speaker;
+        self
+    }
+
+    /// Skip files larger than `max_bytes` without reading them (`None` disables the limit)
+    #[must_use]
+    pub const fn with_max_file_size(mut self, max_bytes: Option<u64>) -> Self {
+        self.max_file_size = max_bytes;
+        self
+    }
+
+    /// Drop segments whose normalized text is at least `threshold` similar to
+    /// an earlier segment of any file in this run (`None` disables dedup)
+    #[must_use]
+    pub fn with_segment_dedup(mut self, threshold: Option<f64>) -> Self {
+        self.deduper = threshold.map(SegmentDeduper::new);
+        self
+    }
+
+    /// Drop a file's records that duplicate earlier segments, returning how many
+    ///
+    /// The remaining records are renumbered so segment indices stay contiguous.
+    fn dedup_records(&mut self, records: &mut Vec<FtsRecord>) -> usize {
+        let Some(deduper) = self.deduper.as_mut() else {
+            return 0;
+        };
+        let before = records.len();
+        records.retain(|record| !deduper.is_duplicate(&record.text));
+        for (index, record) in (0..).zip(records.iter_mut()) {
+            record.segment_index = index;
+        }
+        before - records.len()
     }
 
     /// Get the FTS table schema (no vector column)
@@ -120,15 +270,35 @@ impl FtsIngester {
             Field::new("statement_id", DataType::Utf8, true),
             Field::new("segment_index", DataType::Int32, false),
             Field::new("text", DataType::Utf8, false),
+            Field::new("speaker_name", DataType::Utf8, true),
+            Field::new("content_hash", DataType::Utf8, true),
+            Field::new("ingest_run", DataType::Utf8, true),
         ]))
     }
 
-    /// Write FTS records to `LanceDB`
-    async fn write_to_lancedb(&self, records: &[FtsRecord]) -> Result<()> {
-        if records.is_empty() {
+    /// Add the nullable columns to a table created before they existed, once per run
+    async fn ensure_optional_columns(&self, table: &lancedb::Table) -> Result<()> {
+        if self.columns_checked.load(Ordering::Relaxed) {
             return Ok(());
         }
+        let schema = table.schema().await?;
+        let missing: Vec<(String, String)> = ["speaker_name", "content_hash", "ingest_run"]
+            .iter()
+            .filter(|name| schema.field_with_name(name).is_err())
+            .map(|name| ((*name).to_string(), "CAST(NULL AS VARCHAR)".to_string()))
+            .collect();
+        if !missing.is_empty() {
+            info!("Adding {} column(s) to {}", missing.len(), FTS_TABLE_NAME);
+            table
+                .add_columns(NewColumnTransform::SqlExpressions(missing), None)
+                .await?;
+        }
+        self.columns_checked.store(true, Ordering::Relaxed);
+        Ok(())
+    }
 
+    /// Build a record batch of FTS rows stamped with `run_id`
+    fn record_batch(records: &[FtsRecord], run_id: &str) -> Result<RecordBatch> {
         let schema = Self::fts_schema();
 
         let ids: Vec<&str> = records.iter().map(|r| r.id.as_str()).collect();
@@ -140,9 +310,14 @@ impl FtsIngester {
             .collect();
         let segment_indices: Vec<i32> = records.iter().map(|r| r.segment_index).collect();
         let texts: Vec<&str> = records.iter().map(|r| r.text.as_str()).collect();
+        let speaker_names: Vec<Option<&str>> =
+            records.iter().map(|r| r.speaker_name.as_deref()).collect();
+        let content_hashes: Vec<Option<&str>> =
+            records.iter().map(|r| r.content_hash.as_deref()).collect();
+        let ingest_runs: Vec<&str> = vec![run_id; records.len()];
 
         let batch = RecordBatch::try_new(
-            schema.clone(),
+            schema,
             vec![
                 Arc::new(StringArray::from(ids)),
                 Arc::new(StringArray::from(content_types)),
@@ -150,11 +325,28 @@ impl FtsIngester {
                 Arc::new(StringArray::from(statement_ids)),
                 Arc::new(Int32Array::from(segment_indices)),
                 Arc::new(StringArray::from(texts)),
+                Arc::new(StringArray::from(speaker_names)),
+                Arc::new(StringArray::from(content_hashes)),
+                Arc::new(StringArray::from(ingest_runs)),
             ],
         )?;
+        Ok(batch)
+    }
+
+    /// Write FTS records to `LanceDB`
+    async fn write_to_lancedb(&self, records: &[FtsRecord]) -> Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let schema = Self::fts_schema();
+        let batch = Self::record_batch(records, &self.run_id)?;
 
         let table = match self.lancedb.open_table(FTS_TABLE_NAME).execute().await {
-            Ok(t) => t,
+            Ok(t) => {
+                self.ensure_optional_columns(&t).await?;
+                t
+            }
             Err(_) => {
                 info!("Creating {} table", FTS_TABLE_NAME);
                 let batches =
@@ -173,7 +365,28 @@ impl FtsIngester {
     }
 
     /// Parse a single hearing JSON file (pure CPU work, no async)
-    fn parse_hearing_file(path: &Path, skip_ids: &HashSet<String>) -> Option<ParseResult> {
+    fn parse_hearing_file(
+        path: &Path,
+        existing: &HashMap<String, Option<String>>,
+        include_speaker: bool,
+        max_file_size: Option<u64>,
+    ) -> Option<ParseResult> {
+        match oversized_file(path, max_file_size) {
+            Ok(Some(size)) => {
+                warn!(
+                    "Skipping oversized file {} ({:.1} MB)",
+                    path.display(),
+                    size as f64 / (1024.0 * 1024.0)
+                );
+                return Some(ParseResult::oversized());
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!("Failed to read {}: {}", path.display(), e);
+                return None;
+            }
+        }
+
         let content = match fs::read_to_string(path) {
             Ok(c) => c,
             Err(e) => {
@@ -190,13 +403,13 @@ impl FtsIngester {
             }
         };
 
-        // Check if should skip
-        if skip_ids.contains(&transcript.package_id) {
-            return Some(ParseResult {
-                records: vec![],
-                skipped: true,
-            });
-        }
+        let content_hash = statements_hash(
+            transcript.statements.iter().map(|s| (s.speaker.as_str(), s.text.as_str())),
+        );
+        let replaces = match hash_check(existing, &transcript.package_id, &content_hash) {
+            Ok(replaces) => replaces,
+            Err(skipped) => return Some(skipped),
+        };
 
         let chunker = TextChunker::default();
         let mut records = Vec::new();
@@ -209,16 +422,19 @@ impl FtsIngester {
 
             let statement_id = uuid::Uuid::now_v7();
             let chunks = chunker.chunk(&stmt_json.text);
+            let speaker_name = fts_speaker_name(&stmt_json.speaker, include_speaker);
 
             for chunk_text in &chunks {
                 let segment_id = uuid::Uuid::now_v7();
                 records.push(FtsRecord {
                     id: segment_id.to_string(),
-                    content_type: "hearing".to_string(),
+                    content_type: ContentType::Hearing.to_string(),
                     content_id: transcript.package_id.clone(),
                     statement_id: Some(statement_id.to_string()),
                     segment_index,
                     text: chunk_text.clone(),
+                    speaker_name: speaker_name.clone(),
+                    content_hash: Some(content_hash.clone()),
                 });
                 segment_index += 1;
             }
@@ -227,11 +443,34 @@ impl FtsIngester {
         Some(ParseResult {
             records,
             skipped: false,
+            oversized: false,
+            replaces,
         })
     }
 
     /// Parse a single floor speech JSON file (pure CPU work, no async)
-    fn parse_speech_file(path: &Path, skip_ids: &HashSet<String>) -> Option<ParseResult> {
+    fn parse_speech_file(
+        path: &Path,
+        existing: &HashMap<String, Option<String>>,
+        include_speaker: bool,
+        max_file_size: Option<u64>,
+    ) -> Option<ParseResult> {
+        match oversized_file(path, max_file_size) {
+            Ok(Some(size)) => {
+                warn!(
+                    "Skipping oversized file {} ({:.1} MB)",
+                    path.display(),
+                    size as f64 / (1024.0 * 1024.0)
+                );
+                return Some(ParseResult::oversized());
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!("Failed to read {}: {}", path.display(), e);
+                return None;
+            }
+        }
+
         let content = match fs::read_to_string(path) {
             Ok(c) => c,
             Err(e) => {
@@ -248,13 +487,13 @@ impl FtsIngester {
             }
         };
 
-        // Check if should skip
-        if skip_ids.contains(&speech.event_id) {
-            return Some(ParseResult {
-                records: vec![],
-                skipped: true,
-            });
-        }
+        let content_hash = statements_hash(
+            speech.statements.iter().map(|s| (s.speaker.as_str(), s.text.as_str())),
+        );
+        let replaces = match hash_check(existing, &speech.event_id, &content_hash) {
+            Ok(replaces) => replaces,
+            Err(skipped) => return Some(skipped),
+        };
 
         let chunker = TextChunker::default();
         let mut records = Vec::new();
@@ -267,16 +506,19 @@ impl FtsIngester {
 
             let statement_id = uuid::Uuid::now_v7();
             let chunks = chunker.chunk(&stmt_json.text);
+            let speaker_name = fts_speaker_name(&stmt_json.speaker, include_speaker);
 
             for chunk_text in &chunks {
                 let segment_id = uuid::Uuid::now_v7();
                 records.push(FtsRecord {
                     id: segment_id.to_string(),
-                    content_type: "floor_speech".to_string(),
+                    content_type: ContentType::FloorSpeech.to_string(),
                     content_id: speech.event_id.clone(),
                     statement_id: Some(statement_id.to_string()),
                     segment_index,
                     text: chunk_text.clone(),
+                    speaker_name: speaker_name.clone(),
+                    content_hash: Some(content_hash.clone()),
                 });
                 segment_index += 1;
             }
@@ -285,60 +527,76 @@ impl FtsIngester {
         Some(ParseResult {
             records,
             skipped: false,
+            oversized: false,
+            replaces,
         })
     }
 
-    /// Get existing hearing content IDs from `LanceDB` FTS table
-    async fn get_existing_hearing_ids(&self) -> Result<HashSet<String>> {
-        if self.force {
-            return Ok(HashSet::new());
-        }
-        self.get_existing_content_ids("hearing").await
-    }
-
-    /// Get existing floor speech content IDs from `LanceDB` FTS table
-    async fn get_existing_speech_ids(&self) -> Result<HashSet<String>> {
+    /// Get existing content IDs and their content hashes from the `LanceDB` FTS table
+    ///
+    /// A content ID maps to `None` if any of its rows lacks a hash or the rows
+    /// disagree, so it gets re-ingested. Empty when forcing.
+    async fn get_existing_content_hashes(
+        &self,
+        content_type: ContentType,
+    ) -> Result<HashMap<String, Option<String>>> {
         if self.force {
-            return Ok(HashSet::new());
+            return Ok(HashMap::new());
         }
-        self.get_existing_content_ids("floor_speech").await
-    }
-
-    /// Get existing content IDs from `LanceDB` FTS table for a given content type
-    async fn get_existing_content_ids(&self, content_type: &str) -> Result<HashSet<String>> {
-        use arrow_array::cast::AsArray;
-        use futures::TryStreamExt;
-        use lancedb::query::{ExecutableQuery, QueryBase};
 
         let table = match self.lancedb.open_table(FTS_TABLE_NAME).execute().await {
             Ok(t) => t,
-            Err(_) => return Ok(HashSet::new()),
+            Err(_) => return Ok(HashMap::new()),
         };
+        self.ensure_optional_columns(&table).await?;
+        stored_content_hashes(&table, content_type).await
+    }
 
-        let filter = format!("content_type = '{content_type}'");
-
-        let batches: Vec<RecordBatch> = table
-            .query()
-            .select(lancedb::query::Select::columns(&["content_id"]))
-            .only_if(filter)
-            .execute()
-            .await?
-            .try_collect()
-            .await?;
-
-        let mut ids = HashSet::new();
-        for batch in batches {
-            if let Some(col) = batch.column_by_name("content_id") {
-                let string_array = col.as_string::<i32>();
-                for i in 0..string_array.len() {
-                    if !string_array.is_null(i) {
-                        ids.insert(string_array.value(i).to_string());
-                    }
-                }
+    /// Write a run's records in batches, then delete the rows they replace
+    ///
+    /// Segments kept by the deduper are committed only once the write succeeds,
+    /// so a failed run doesn't hide them from the next one.
+    async fn write_records(
+        &mut self,
+        records: &[FtsRecord],
+        replaced: &[String],
+    ) -> Result<()> {
+        let written = self.write_record_batches(records, replaced).await;
+        if let Some(deduper) = self.deduper.as_mut() {
+            if written.is_ok() {
+                deduper.commit();
+            } else {
+                deduper.rollback();
             }
         }
+        written
+    }
 
-        Ok(ids)
+    async fn write_record_batches(
+        &self,
+        records: &[FtsRecord],
+        replaced: &[String],
+    ) -> Result<()> {
+        const BATCH_SIZE: usize = 10000;
+        let total_batches = records.len().div_ceil(BATCH_SIZE);
+        for (i, chunk) in records.chunks(BATCH_SIZE).enumerate() {
+            self.write_to_lancedb(chunk).await?;
+            info!("Written batch {}/{} ({} records)", i + 1, total_batches, chunk.len());
+        }
+        self.delete_replaced_rows(replaced).await
+    }
+
+    /// Delete the rows of re-ingested files that the new rows replace
+    ///
+    /// Runs after the new rows are written, so a failed write leaves the old rows searchable.
+    async fn delete_replaced_rows(&self, replaced: &[String]) -> Result<()> {
+        if replaced.is_empty() {
+            return Ok(());
+        }
+        let table = self.lancedb.open_table(FTS_TABLE_NAME).execute().await?;
+        delete_rows_from_other_runs(&table, replaced, &self.run_id).await?;
+        info!("Replaced rows for {} changed files", replaced.len());
+        Ok(())
     }
 
     /// Ingest hearings from a directory using parallel processing
@@ -350,30 +608,18 @@ impl FtsIngester {
         path: &Path,
         limit: Option<usize>,
     ) -> Result<FtsIngestStats> {
-        if !path.is_dir() {
-            bail!("Path is not a directory: {}", path.display());
-        }
-
-        let mut entries: Vec<PathBuf> = fs::read_dir(path)?
-            .filter_map(Result::ok)
-            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
-            .map(|e| e.path())
-            .collect();
-
-        entries.sort();
-
-        if let Some(max) = limit {
-            entries.truncate(max);
-        }
+        let entries = collect_json_files(path, self.sample, limit)?;
 
         let total = entries.len();
         info!("Processing {} hearing files for FTS (parallel)", total);
 
-        // Get existing IDs to skip
-        let skip_ids = self.get_existing_hearing_ids().await?;
-        info!("Found {} existing hearings to skip", skip_ids.len());
+        // Unchanged hearings are skipped; changed ones replace their rows
+        let existing = self.get_existing_content_hashes(ContentType::Hearing).await?;
+        info!("Found {} existing hearings", existing.len());
 
         // Progress tracking
+        let include_speaker = self.include_speaker;
+        let max_file_size = self.max_file_size;
         let processed_count = AtomicUsize::new(0);
         let start_time = Instant::now();
 
@@ -381,7 +627,8 @@ impl FtsIngester {
         let results: Vec<ParseResult> = entries
             .par_iter()
             .filter_map(|path| {
-                let result = Self::parse_hearing_file(path, &skip_ids);
+                let result =
+                    Self::parse_hearing_file(path, &existing, include_speaker, max_file_size);
                 let count = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
                 if count % 500 == 0 || count == total {
                     let elapsed = start_time.elapsed().as_secs_f64();
@@ -404,29 +651,23 @@ impl FtsIngester {
         // Aggregate stats and records
         let mut stats = FtsIngestStats::default();
         let mut all_records = Vec::new();
+        let mut replaced = Vec::new();
 
-        for result in results {
-            if result.skipped {
+        for mut result in results {
+            if result.oversized {
+                stats.files_skipped_oversized += 1;
+            } else if result.skipped {
                 stats.hearings_skipped += 1;
             } else {
                 stats.hearings_processed += 1;
+                stats.segments_deduped += self.dedup_records(&mut result.records);
                 stats.segments_created += result.records.len();
                 all_records.extend(result.records);
+                replaced.extend(result.replaces);
             }
         }
 
-        // Write to LanceDB in batches
-        const BATCH_SIZE: usize = 10000;
-        let total_records = all_records.len();
-        for (i, chunk) in all_records.chunks(BATCH_SIZE).enumerate() {
-            self.write_to_lancedb(chunk).await?;
-            info!(
-                "Written batch {}/{} ({} records)",
-                i + 1,
-                total_records.div_ceil(BATCH_SIZE),
-                chunk.len()
-            );
-        }
+        self.write_records(&all_records, &replaced).await?;
 
         info!(
             "Hearings complete: {} processed, {} skipped, {} segments",
@@ -445,30 +686,18 @@ impl FtsIngester {
         path: &Path,
         limit: Option<usize>,
     ) -> Result<FtsIngestStats> {
-        if !path.is_dir() {
-            bail!("Path is not a directory: {}", path.display());
-        }
-
-        let mut entries: Vec<PathBuf> = fs::read_dir(path)?
-            .filter_map(Result::ok)
-            .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
-            .map(|e| e.path())
-            .collect();
-
-        entries.sort();
-
-        if let Some(max) = limit {
-            entries.truncate(max);
-        }
+        let entries = collect_json_files(path, self.sample, limit)?;
 
         let total = entries.len();
         info!("Processing {} floor speech files for FTS (parallel)", total);
 
-        // Get existing IDs to skip
-        let skip_ids = self.get_existing_speech_ids().await?;
-        info!("Found {} existing speeches to skip", skip_ids.len());
+        // Unchanged speeches are skipped; changed ones replace their rows
+        let existing = self.get_existing_content_hashes(ContentType::FloorSpeech).await?;
+        info!("Found {} existing speeches", existing.len());
 
         // Progress tracking
+        let include_speaker = self.include_speaker;
+        let max_file_size = self.max_file_size;
         let processed_count = AtomicUsize::new(0);
         let start_time = Instant::now();
 
@@ -476,7 +705,8 @@ impl FtsIngester {
         let results: Vec<ParseResult> = entries
             .par_iter()
             .filter_map(|path| {
-                let result = Self::parse_speech_file(path, &skip_ids);
+                let result =
+                    Self::parse_speech_file(path, &existing, include_speaker, max_file_size);
                 let count = processed_count.fetch_add(1, Ordering::Relaxed) + 1;
                 if count % 500 == 0 || count == total {
                     let elapsed = start_time.elapsed().as_secs_f64();
@@ -499,29 +729,23 @@ impl FtsIngester {
         // Aggregate stats and records
         let mut stats = FtsIngestStats::default();
         let mut all_records = Vec::new();
+        let mut replaced = Vec::new();
 
-        for result in results {
-            if result.skipped {
+        for mut result in results {
+            if result.oversized {
+                stats.files_skipped_oversized += 1;
+            } else if result.skipped {
                 stats.speeches_skipped += 1;
             } else {
                 stats.speeches_processed += 1;
+                stats.segments_deduped += self.dedup_records(&mut result.records);
                 stats.segments_created += result.records.len();
                 all_records.extend(result.records);
+                replaced.extend(result.replaces);
             }
         }
 
-        // Write to LanceDB in batches
-        const BATCH_SIZE: usize = 10000;
-        let total_records = all_records.len();
-        for (i, chunk) in all_records.chunks(BATCH_SIZE).enumerate() {
-            self.write_to_lancedb(chunk).await?;
-            info!(
-                "Written batch {}/{} ({} records)",
-                i + 1,
-                total_records.div_ceil(BATCH_SIZE),
-                chunk.len()
-            );
-        }
+        self.write_records(&all_records, &replaced).await?;
 
         info!(
             "Speeches complete: {} processed, {} skipped, {} segments",
@@ -542,11 +766,13 @@ impl FtsIngester {
         info!("Found {} votes in database", total_count);
 
         const BATCH_SIZE: i64 = 500;
-        let mut offset = 0i64;
+        let mut fetched = 0i64;
+        // keyset cursor: stable across inserts and fast regardless of how far in we are
+        let mut last_id = None;
         let max_votes = limit.map_or(i64::MAX, |l| l as i64);
 
         loop {
-            let remaining = max_votes - offset;
+            let remaining = max_votes - fetched;
             if remaining <= 0 {
                 break;
             }
@@ -555,7 +781,7 @@ impl FtsIngester {
             let votes = self
                 .db
                 .roll_call_votes()
-                .get_all_paginated(offset, fetch_size)
+                .get_after(last_id, fetch_size)
                 .await?;
 
             if votes.is_empty() {
@@ -585,11 +811,13 @@ impl FtsIngester {
                         let text = build_vote_text(v);
                         FtsRecord {
                             id: v.id.to_string(),
-                            content_type: "vote".to_string(),
+                            content_type: ContentType::Vote.to_string(),
                             content_id: v.id.to_string(),
                             statement_id: None,
                             segment_index: 0,
                             text,
+                            speaker_name: None,
+                            content_hash: None,
                         }
                     })
                     .collect();
@@ -599,7 +827,7 @@ impl FtsIngester {
                 self.write_to_lancedb(&records).await?;
             }
 
-            let processed = offset + votes.len() as i64;
+            let processed = fetched + votes.len() as i64;
             if processed % 500 == 0 || votes.len() < BATCH_SIZE as usize {
                 info!(
                     "Votes: {}/{} processed, {} skipped",
@@ -607,7 +835,8 @@ impl FtsIngester {
                 );
             }
 
-            offset += votes.len() as i64;
+            fetched += votes.len() as i64;
+            last_id = votes.last().map(|v| v.id);
 
             if votes.len() < BATCH_SIZE as usize {
                 break;
@@ -617,6 +846,67 @@ impl FtsIngester {
         Ok(stats)
     }
 
+    /// Ingest bill titles and summaries from `PostgreSQL`, one row per bill
+    ///
+    /// Rows carry a hash of the bill text, so bills whose title or summary
+    /// changed are replaced and unchanged bills are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if database operations fail
+    pub async fn ingest_bills(&mut self, limit: Option<usize>) -> Result<FtsIngestStats> {
+        const BATCH_SIZE: i64 = 500;
+
+        let mut stats = FtsIngestStats::default();
+        let total_count = self.db.bills().count().await?;
+        info!("Found {} bills in database", total_count);
+
+        let existing = self.get_existing_content_hashes(ContentType::Bill).await?;
+        let max_bills = limit.map_or(i64::MAX, |l| l as i64);
+        let mut offset = 0i64;
+
+        while offset < max_bills {
+            let bills = self
+                .db
+                .bills()
+                .get_all_paginated(offset, BATCH_SIZE.min(max_bills - offset))
+                .await?;
+            if bills.is_empty() {
+                break;
+            }
+
+            let mut records = Vec::new();
+            let mut replaced = Vec::new();
+            for (bill, text) in bills.iter().filter_map(|b| Some((b, b.search_text()?))) {
+                let content_id = bill.id.to_string();
+                let content_hash = statements_hash([("", text.as_str())]);
+                match hash_check(&existing, &content_id, &content_hash) {
+                    Ok(replaces) => replaced.extend(replaces),
+                    Err(_) => {
+                        stats.bills_skipped += 1;
+                        continue;
+                    }
+                }
+                records.push(bill_record(bill, text, content_hash));
+            }
+
+            stats.bills_processed += records.len();
+            stats.segments_created += records.len();
+            self.write_to_lancedb(&records).await?;
+            self.delete_replaced_rows(&replaced).await?;
+
+            offset += bills.len() as i64;
+            info!(
+                "Bills: {}/{} processed, {} skipped",
+                stats.bills_processed, total_count, stats.bills_skipped
+            );
+            if bills.len() < BATCH_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(stats)
+    }
+
     /// Check if a vote already exists in the FTS table
     async fn check_vote_exists(&self, vote_id: &str) -> Result<bool> {
         use futures::TryStreamExt;
@@ -627,7 +917,7 @@ impl FtsIngester {
             Err(_) => return Ok(false),
         };
 
-        let filter = format!("content_type = 'vote' AND id = '{vote_id}'");
+        let filter = format!("content_type = '{}' AND id = '{vote_id}'", ContentType::Vote);
 
         let batches: Vec<RecordBatch> = table
             .query()
@@ -641,7 +931,7 @@ impl FtsIngester {
         Ok(batches.iter().any(|b| b.num_rows() > 0))
     }
 
-    /// Create FTS index on the text column
+    /// Create the FTS index on the text column
     ///
     /// # Errors
     /// Returns an error if index creation fails
@@ -667,6 +957,108 @@ impl FtsIngester {
     }
 }
 
+/// Delete the rows of `content_ids` that were not written by `run_id`
+///
+/// Rows from an earlier run that was interrupted before its delete are
+/// removed too, even when they already carry the new content hash.
+async fn delete_rows_from_other_runs(
+    table: &lancedb::Table,
+    content_ids: &[String],
+    run_id: &str,
+) -> Result<()> {
+    const DELETE_CHUNK: usize = 200;
+
+    for chunk in content_ids.chunks(DELETE_CHUNK) {
+        let ids = chunk
+            .iter()
+            .map(|content_id| format!("'{content_id}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        table
+            .delete(&format!(
+                "content_id IN ({ids}) AND (ingest_run IS NULL OR ingest_run <> '{run_id}')"
+            ))
+            .await?;
+    }
+    Ok(())
+}
+
+/// Content IDs of `content_type` in the FTS table mapped to their content hash
+///
+/// A content ID maps to `None` if any of its rows lacks a hash or the rows disagree.
+async fn stored_content_hashes(
+    table: &lancedb::Table,
+    content_type: ContentType,
+) -> Result<HashMap<String, Option<String>>> {
+    use arrow_array::cast::AsArray;
+    use futures::TryStreamExt;
+    use lancedb::query::{ExecutableQuery, QueryBase};
+
+    let filter = format!("content_type = '{content_type}'");
+
+    let batches: Vec<RecordBatch> = table
+        .query()
+        .select(lancedb::query::Select::columns(&[
+            "content_id",
+            "content_hash",
+        ]))
+        .only_if(filter)
+        .execute()
+        .await?
+        .try_collect()
+        .await?;
+
+    let mut hashes: HashMap<String, Option<String>> = HashMap::new();
+    for batch in batches {
+        let (Some(ids), Some(hash_col)) = (
+            batch.column_by_name("content_id"),
+            batch.column_by_name("content_hash"),
+        ) else {
+            continue;
+        };
+        let ids = ids.as_string::<i32>();
+        let hash_col = hash_col.as_string::<i32>();
+        for i in 0..ids.len() {
+            if ids.is_null(i) {
+                continue;
+            }
+            let hash = (!hash_col.is_null(i)).then(|| hash_col.value(i).to_string());
+            hashes
+                .entry(ids.value(i).to_string())
+                .and_modify(|stored| {
+                    if *stored != hash {
+                        *stored = None;
+                    }
+                })
+                .or_insert(hash);
+        }
+    }
+
+    Ok(hashes)
+}
+
+/// Normalized speaker name for the `speaker_name` column, if enabled and known
+fn fts_speaker_name(raw: &str, include_speaker: bool) -> Option<String> {
+    if !include_speaker {
+        return None;
+    }
+    normalize_speaker(raw).name
+}
+
+/// FTS row for a bill, keyed by the bill UUID like its embedding
+fn bill_record(bill: &Bill, text: String, content_hash: String) -> FtsRecord {
+    FtsRecord {
+        id: bill.id.to_string(),
+        content_type: ContentType::Bill.to_string(),
+        content_id: bill.id.to_string(),
+        statement_id: None,
+        segment_index: 0,
+        text,
+        speaker_name: None,
+        content_hash: Some(content_hash),
+    }
+}
+
 /// Build searchable text from vote data
 fn build_vote_text(vote: &RollCallVote) -> String {
     let mut parts = vec![vote.question.clone()];
@@ -685,3 +1077,128 @@ fn build_vote_text(vote: &RollCallVote) -> String {
 
     parts.join(". ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use lancedb::query::{ExecutableQuery, QueryBase};
+
+    fn hearing_rows(content_id: &str, content_hash: &str, count: i32) -> Vec<FtsRecord> {
+        (0..count)
+            .map(|segment_index| FtsRecord {
+                id: uuid::Uuid::now_v7().to_string(),
+                content_type: ContentType::Hearing.to_string(),
+                content_id: content_id.to_string(),
+                statement_id: None,
+                segment_index,
+                text: format!("segment {segment_index}"),
+                speaker_name: None,
+                content_hash: Some(content_hash.to_string()),
+            })
+            .collect()
+    }
+
+    async fn add_rows(table: &lancedb::Table, records: &[FtsRecord], run_id: &str) {
+        let batch = FtsIngester::record_batch(records, run_id).expect("record batch");
+        let batches =
+            RecordBatchIterator::new(vec![Ok(batch)].into_iter(), FtsIngester::fts_schema());
+        table
+            .add(Box::new(batches))
+            .execute()
+            .await
+            .expect("add rows");
+    }
+
+    /// `ingest_run` of every row of `content_id`, sorted
+    async fn row_runs(table: &lancedb::Table, content_id: &str) -> Vec<String> {
+        use arrow_array::cast::AsArray;
+
+        let batches: Vec<RecordBatch> = table
+            .query()
+            .select(lancedb::query::Select::columns(&["ingest_run"]))
+            .only_if(format!("content_id = '{content_id}'"))
+            .execute()
+            .await
+            .expect("query")
+            .try_collect()
+            .await
+            .expect("collect");
+        let mut runs: Vec<String> = batches
+            .iter()
+            .flat_map(|batch| {
+                let runs = batch
+                    .column_by_name("ingest_run")
+                    .expect("ingest_run column")
+                    .as_string::<i32>();
+                (0..runs.len())
+                    .map(|i| runs.value(i).to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        runs.sort();
+        runs
+    }
+
+    #[tokio::test]
+    async fn test_rerun_after_interrupted_replace_leaves_no_duplicates() {
+        let dir = std::env::temp_dir().join(format!("polsearch-fts-test-{}", uuid::Uuid::now_v7()));
+        let conn = lancedb::connect(dir.to_str().expect("utf-8 path"))
+            .execute()
+            .await
+            .expect("connect");
+
+        // run 1 stores two hearings; run 2 changes both and dies before its
+        // delete, once partway through writing and once after all its rows
+        let mut first = hearing_rows("CHRG-partial", "old", 3);
+        first.extend(hearing_rows("CHRG-unwritten-delete", "old", 3));
+        first.extend(hearing_rows("CHRG-untouched", "old", 2));
+        let batch = FtsIngester::record_batch(&first, "run-1").expect("record batch");
+        let batches =
+            RecordBatchIterator::new(vec![Ok(batch)].into_iter(), FtsIngester::fts_schema());
+        let table = conn
+            .create_table(FTS_TABLE_NAME, Box::new(batches))
+            .execute()
+            .await
+            .expect("create table");
+        add_rows(&table, &hearing_rows("CHRG-partial", "new", 2), "run-2").await;
+        add_rows(
+            &table,
+            &hearing_rows("CHRG-unwritten-delete", "new", 4),
+            "run-2",
+        )
+        .await;
+
+        // run 3 sees mixed hashes and replaces every row
+        let existing = stored_content_hashes(&table, ContentType::Hearing)
+            .await
+            .expect("hashes");
+        let mut replaced = Vec::new();
+        for content_id in ["CHRG-partial", "CHRG-unwritten-delete"] {
+            assert_eq!(existing.get(content_id), Some(&None));
+            let replaces = hash_check(&existing, content_id, "new").ok().flatten();
+            assert_eq!(replaces.as_deref(), Some(content_id));
+            add_rows(&table, &hearing_rows(content_id, "new", 4), "run-3").await;
+            replaced.extend(replaces);
+        }
+        delete_rows_from_other_runs(&table, &replaced, "run-3")
+            .await
+            .expect("delete");
+
+        assert_eq!(row_runs(&table, "CHRG-partial").await, vec!["run-3"; 4]);
+        assert_eq!(
+            row_runs(&table, "CHRG-unwritten-delete").await,
+            vec!["run-3"; 4]
+        );
+        assert_eq!(row_runs(&table, "CHRG-untouched").await, vec!["run-1"; 2]);
+
+        // the next run finds them current
+        let existing = stored_content_hashes(&table, ContentType::Hearing)
+            .await
+            .expect("hashes");
+        assert!(hash_check(&existing, "CHRG-partial", "new").is_err());
+        assert!(hash_check(&existing, "CHRG-unwritten-delete", "new").is_err());
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -1,25 +1,30 @@
 //! Hearing ingestion from JSON transcript files
 
-use arrow_array::{
-    types::Float32Type, Array, FixedSizeListArray, Int32Array, RecordBatch, RecordBatchIterator,
-    StringArray,
-};
-use arrow_schema::{DataType, Field, Schema};
 use chrono::{Datelike, NaiveDate};
-use color_eyre::eyre::{bail, eyre, Result};
+use color_eyre::eyre::{eyre, Result};
 use colored::Colorize;
-use polsearch_core::{Hearing, HearingSegment, HearingStatement};
-use polsearch_db::Database;
+use polsearch_core::{ContentType, Hearing, HearingSegment, HearingStatement};
+use polsearch_db::{Database, HearingRepo, HearingSegmentRepo, HearingStatementRepo};
 use serde::Deserialize;
 use std::fs;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
-use std::sync::Arc;
 use std::time::Instant;
 use tracing::warn;
 
 use super::chunk::TextChunker;
+use super::content_hash::statements_hash;
+use super::context_embed::EmbeddingBatch;
 use super::embed::TextEmbedder;
+use super::embedding_schema::{append_text_embeddings, text_embeddings_batch, SegmentText};
 use super::procedural_filter::should_skip_statement;
+use super::sampling::{
+    collect_json_files, max_file_size_bytes, oversized_file, Sample, DEFAULT_MAX_FILE_SIZE_MB,
+};
+use super::segment_dedup::SegmentDeduper;
+use super::speaker_party::{SpeakerPartyResolver, SpeakerVenue};
+use super::speaker_normalize::normalize_speaker;
+use super::statement_merge::merge_consecutive_statements;
 
 /// Raw transcript JSON structure
 #[derive(Debug, Deserialize)]
@@ -48,9 +53,14 @@ pub struct StatementJson {
 pub struct IngestStats {
     pub files_processed: usize,
     pub files_skipped: usize,
+    /// Existing hearings re-ingested because their statements changed
+    pub files_changed: usize,
+    pub files_skipped_oversized: usize,
     pub hearings_created: usize,
     pub statements_created: usize,
     pub segments_created: usize,
+    pub segments_deduped: usize,
+    pub statements_with_party: usize,
     pub embeddings_created: usize,
 }
 
@@ -62,15 +72,97 @@ pub struct HearingIngester {
     lancedb: lancedb::Connection,
     force: bool,
     year_filter: Option<i32>,
+    sample: Option<Sample>,
+    merge_statements: bool,
+    normalize_speakers: bool,
+    deduper: Option<SegmentDeduper>,
+    context_embed_words: Option<usize>,
+    party_resolver: Option<SpeakerPartyResolver>,
+    max_file_size: Option<u64>,
+    compact_progress: bool,
+}
+
+/// Files between status lines when compact progress is not written to a terminal
+const COMPACT_PLAIN_INTERVAL: usize = 100;
+
+/// Single status line for `--compact-progress`
+///
+/// On a terminal the line is redrawn in place with a carriage return; otherwise
+/// (logs, pipes) a plain status line is printed every
+/// [`COMPACT_PLAIN_INTERVAL`] files so the output stays readable.
+struct CompactProgress {
+    total: usize,
+    processed: usize,
+    skipped: usize,
+    failed: usize,
+    started: Instant,
+    is_tty: bool,
+}
+
+impl CompactProgress {
+    fn new(total: usize) -> Self {
+        Self {
+            total,
+            processed: 0,
+            skipped: 0,
+            failed: 0,
+            started: Instant::now(),
+            is_tty: std::io::stdout().is_terminal(),
+        }
+    }
+
+    fn done(&self) -> usize {
+        self.processed + self.skipped + self.failed
+    }
+
+    fn status(&self, current: &Path) -> String {
+        let done = self.done();
+        let rate = done as f64 / self.started.elapsed().as_secs_f64().max(f64::EPSILON);
+        let name = current.file_name().map_or_else(
+            || current.display().to_string(),
+            |n| n.to_string_lossy().into_owned(),
+        );
+        format!(
+            "[{}/{}] {} processed, {} skipped, {} failed ({:.1} files/s) {}",
+            done, self.total, self.processed, self.skipped, self.failed, rate, name
+        )
+    }
+
+    /// Redraw the status line after a file finishes
+    fn update(&self, current: &Path) {
+        let done = self.done();
+        if self.is_tty {
+            print!("\r\x1b[2K{}", self.status(current));
+            let _ = std::io::stdout().flush();
+        } else if done % COMPACT_PLAIN_INTERVAL == 0 || done == self.total {
+            println!("{}", self.status(current));
+        }
+    }
+
+    /// Print a full line (skip or error) without mangling the status line
+    fn line(&self, message: &str) {
+        if self.is_tty {
+            print!("\r\x1b[2K");
+        }
+        println!("{message}");
+    }
+
+    /// End the in-place status line so the summary starts on a fresh line
+    fn finish(&self) {
+        if self.is_tty && self.done() > 0 {
+            println!();
+        }
+    }
 }
 
 impl HearingIngester {
     /// Creates a new hearing ingester
     ///
     /// # Errors
-    /// Returns an error if embedding model or `LanceDB` fails to initialize
+    /// Returns an error if `EMBEDDING_MODEL` is invalid or the embedding model or `LanceDB`
+    /// fails to initialize
     pub async fn new(db: Database, lancedb_path: &str, force: bool, year_filter: Option<i32>) -> Result<Self> {
-        let embedder = TextEmbedder::new()?;
+        let embedder = TextEmbedder::from_env()?;
         let lancedb = lancedb::connect(lancedb_path).execute().await?;
 
         Ok(Self {
@@ -80,32 +172,115 @@ impl HearingIngester {
             lancedb,
             force,
             year_filter,
+            sample: None,
+            merge_statements: false,
+            normalize_speakers: false,
+            deduper: None,
+            context_embed_words: None,
+            party_resolver: None,
+            max_file_size: max_file_size_bytes(DEFAULT_MAX_FILE_SIZE_MB),
+            compact_progress: false,
         })
     }
 
+    /// Restrict directory ingestion to a deterministic sample of files
+    #[must_use]
+    pub const fn with_sample(mut self, sample: Option<Sample>) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    /// Merge consecutive same-speaker statements before chunking
+    #[must_use]
+    pub const fn with_merge_statements(mut self, merge_statements: bool) -> Self {
+        self.merge_statements = merge_statements;
+        self
+    }
+
+    /// Store a normalized name, honorific, and role alongside each raw speaker label
+    #[must_use]
+    pub const fn with_normalize_speakers(mut self, normalize_speakers: bool) -> Self {
+        self.normalize_speakers = normalize_speakers;
+        self
+    }
+
+    /// Drop segments whose normalized text is at least `threshold` similar to
+    /// an earlier segment of any file in this run (`None` disables dedup)
+    #[must_use]
+    pub fn with_segment_dedup(mut self, threshold: Option<f64>) -> Self {
+        self.deduper = threshold.map(SegmentDeduper::new);
+        self
+    }
+
+    /// Embed each segment with its speaker and up to `words` trailing words of the
+    /// preceding segment (`None` embeds the segment text alone)
+    ///
+    /// The stored display text is not affected.
+    #[must_use]
+    pub const fn with_context_embed(mut self, words: Option<usize>) -> Self {
+        self.context_embed_words = words;
+        self
+    }
+
+    /// Attribute each statement's speaker to a party using `resolver`
+    #[must_use]
+    pub fn with_party_resolver(mut self, resolver: Option<SpeakerPartyResolver>) -> Self {
+        self.party_resolver = resolver;
+        self
+    }
+
+    /// Skip files larger than `max_bytes` instead of reading them (`None` disables the check)
+    #[must_use]
+    pub const fn with_max_file_size(mut self, max_bytes: Option<u64>) -> Self {
+        self.max_file_size = max_bytes;
+        self
+    }
+
+    /// Replace per-file output lines with a single updating status line
+    ///
+    /// Skips and failures are still printed on their own lines. Has no effect
+    /// when a progress bar is passed to [`Self::ingest_directory_with_progress`].
+    #[must_use]
+    pub const fn with_compact_progress(mut self, compact_progress: bool) -> Self {
+        self.compact_progress = compact_progress;
+        self
+    }
+
     /// Ingest a single transcript JSON file
     ///
+    /// Segments dropped as duplicates are matched only against files that were
+    /// stored; a file that fails leaves nothing behind in the deduper.
+    ///
     /// # Errors
     /// Returns an error if parsing or database operations fail
     pub async fn ingest_file(&mut self, path: &Path) -> Result<IngestStats> {
-        let mut stats = IngestStats::default();
+        let result = self.try_ingest_file(path).await;
+        if let Some(deduper) = self.deduper.as_mut() {
+            if result.is_ok() {
+                deduper.commit();
+            } else {
+                deduper.rollback();
+            }
+        }
+        result
+    }
 
-        let content = fs::read_to_string(path)?;
-        let transcript: TranscriptJson = serde_json::from_str(&content)
-            .map_err(|e| eyre!("Failed to parse {}: {}", path.display(), e))?;
+    async fn try_ingest_file(&mut self, path: &Path) -> Result<IngestStats> {
+        let mut stats = IngestStats::default();
 
-        // Check if already exists
-        if !self.force && self.db.hearings().exists_by_package_id(&transcript.package_id).await? {
-            stats.files_skipped += 1;
+        if let Some(size) = oversized_file(path, self.max_file_size)? {
+            warn!(
+                "Skipping oversized file {} ({:.1} MB)",
+                path.display(),
+                size as f64 / (1024.0 * 1024.0)
+            );
+            stats.files_skipped_oversized += 1;
             return Ok(stats);
         }
 
-        // Delete existing if force mode
-        if self.force {
-            if let Some(existing) = self.db.hearings().get_by_package_id(&transcript.package_id).await? {
-                self.db.hearings().delete(existing.id).await?;
-            }
-        }
+        let content = fs::read_to_string(path)?;
+        let transcript: TranscriptJson = serde_json::from_str(&content)
+            .map_err(|e| eyre!("Failed to parse {}: {}", path.display(), e))?;
 
         // Parse date
         let hearing_date = NaiveDate::parse_from_str(&transcript.date, "%Y-%m-%d")
@@ -119,6 +294,30 @@ impl HearingIngester {
             }
         }
 
+        // Skip unchanged hearings; re-ingest existing ones whose statements changed
+        let content_hash = statements_hash(
+            transcript.statements.iter().map(|s| (s.speaker.as_str(), s.text.as_str())),
+        );
+        let mut replaced_id = None;
+        if let Some(existing) = self.db.hearings().get_by_package_id(&transcript.package_id).await? {
+            if !self.force {
+                match self.db.hearings().get_content_hash(existing.id).await? {
+                    Some(stored) if stored != content_hash => stats.files_changed += 1,
+                    // An unfinished earlier ingest has no hash yet; ingest it again
+                    None if !existing.is_processed => {}
+                    stored => {
+                        // Rows ingested before hashes were recorded are assumed current
+                        if stored.is_none() {
+                            self.db.hearings().set_content_hash(existing.id, &content_hash).await?;
+                        }
+                        stats.files_skipped += 1;
+                        return Ok(stats);
+                    }
+                }
+            }
+            replaced_id = Some(existing.id);
+        }
+
         // Create hearing record
         let hearing = Hearing::new(
             transcript.package_id.clone(),
@@ -130,16 +329,26 @@ impl HearingIngester {
             hearing_date,
             transcript.source_url.clone(),
         );
-        self.db.hearings().create(&hearing).await?;
         stats.hearings_created += 1;
 
         // Process statements and create segments
         let mut all_statements = Vec::new();
         let mut all_segments = Vec::new();
-        let mut all_texts = Vec::new();
         let mut segment_index = 0;
+        let mut segment_texts = EmbeddingBatch::new(self.context_embed_words);
+
+        // Merge consecutive same-speaker statements if requested
+        let statements = if self.merge_statements {
+            merge_consecutive_statements(
+                transcript.statements,
+                |s| s.speaker.as_str(),
+                |s| &mut s.text,
+            )
+        } else {
+            transcript.statements
+        };
 
-        for stmt_json in &transcript.statements {
+        for stmt_json in &statements {
             // Skip procedural statements
             if should_skip_statement(&stmt_json.text) {
                 continue;
@@ -152,12 +361,35 @@ impl HearingIngester {
                 stmt_json.speaker.clone(),
                 word_count,
             );
+            let statement = if self.normalize_speakers {
+                let normalized = normalize_speaker(&stmt_json.speaker);
+                statement.with_normalized_speaker(
+                    normalized.label().map(String::from),
+                    normalized.honorific,
+                    normalized.role,
+                )
+            } else {
+                statement
+            };
+            let chamber = Some(transcript.chamber.as_str());
+            let party = self
+                .party_resolver
+                .as_ref()
+                .and_then(|r| r.resolve(&stmt_json.speaker, chamber, SpeakerVenue::Hearing));
+            if party.is_some() {
+                stats.statements_with_party += 1;
+            }
+            let statement = statement.with_party(party.map(String::from));
             all_statements.push(statement.clone());
             stats.statements_created += 1;
 
             // Chunk the statement
             let chunks = self.chunker.chunk(&stmt_json.text);
             for (chunk_idx, chunk_text) in chunks.iter().enumerate() {
+                if self.deduper.as_mut().is_some_and(|d| d.is_duplicate(chunk_text)) {
+                    stats.segments_deduped += 1;
+                    continue;
+                }
                 let segment = HearingSegment::new(
                     hearing.id,
                     statement.id,
@@ -165,117 +397,122 @@ impl HearingIngester {
                     chunk_idx as i32,
                 );
                 all_segments.push(segment.clone());
-                all_texts.push((segment.id, hearing.id, statement.id, segment_index, chunk_text.clone()));
+                segment_texts.push(
+                    &stmt_json.speaker,
+                    SegmentText {
+                        segment_id: segment.id,
+                        content_id: hearing.id,
+                        statement_id: Some(statement.id),
+                        segment_index,
+                        text: chunk_text.clone(),
+                        party,
+                    },
+                );
                 segment_index += 1;
                 stats.segments_created += 1;
             }
         }
 
-        // Batch insert statements and segments
-        self.db.hearing_statements().create_batch(&all_statements).await?;
-        self.db.hearing_segments().create_batch(&all_segments).await?;
+        // Generate embeddings before touching either store
+        let embeddings = if segment_texts.texts.is_empty() {
+            Vec::new()
+        } else {
+            // Embed the (possibly context-augmented) text; LanceDB stores the display text
+            let text_refs: Vec<&str> =
+                segment_texts.embed_texts.iter().map(String::as_str).collect();
+            self.embedder.embed_batch(&text_refs)?
+        };
+        stats.embeddings_created += embeddings.len();
+
+        // All Postgres writes share one transaction, so a failure anywhere leaves the
+        // original hearing in place. Embeddings are swapped around the commit: the
+        // new ones are written inside it and removed again if it fails, and the old
+        // ones are removed only once the replacement is committed.
+        let total_statements = stats.statements_created as i32;
+        let total_segments = stats.segments_created as i32;
+        let written = self
+            .db
+            .transaction(async |conn| {
+                if let Some(old_id) = replaced_id {
+                    HearingRepo::supersede_in(&mut *conn, old_id).await?;
+                }
+                HearingRepo::create_in(&mut *conn, &hearing).await?;
+                HearingStatementRepo::create_batch_in(&mut *conn, &all_statements).await?;
+                HearingSegmentRepo::create_batch_in(&mut *conn, &all_segments).await?;
+                HearingRepo::mark_processed_in(
+                    &mut *conn,
+                    hearing.id,
+                    total_statements,
+                    total_segments,
+                )
+                .await?;
+                HearingRepo::set_content_hash_in(&mut *conn, hearing.id, &content_hash).await?;
+                if let Some(old_id) = replaced_id {
+                    HearingRepo::finalize_supersede_in(&mut *conn, old_id).await?;
+                }
 
-        // Generate embeddings and write to LanceDB
-        if !all_texts.is_empty() {
-            let text_refs: Vec<&str> = all_texts.iter().map(|(_, _, _, _, t)| t.as_str()).collect();
-            let embeddings = self.embedder.embed_batch(&text_refs)?;
-            stats.embeddings_created += embeddings.len();
+                if !segment_texts.texts.is_empty() {
+                    self.write_to_lancedb(&segment_texts.texts, &embeddings).await?;
+                }
+                Ok::<_, color_eyre::eyre::Report>(())
+            })
+            .await;
 
-            self.write_to_lancedb(&all_texts, &embeddings).await?;
+        if let Err(e) = written {
+            if let Err(cleanup) = self.delete_embeddings(hearing.id).await {
+                warn!("Failed to remove embeddings of {}: {}", hearing.package_id, cleanup);
+            }
+            return Err(e);
+        }
+        if let Some(old_id) = replaced_id {
+            if let Err(e) = self.delete_embeddings(old_id).await {
+                warn!("Failed to remove replaced embeddings of {}: {}", hearing.package_id, e);
+            }
         }
-
-        // Mark hearing as processed
-        self.db.hearings().mark_processed(
-            hearing.id,
-            stats.statements_created as i32,
-            stats.segments_created as i32,
-        ).await?;
 
         stats.files_processed += 1;
         Ok(stats)
     }
 
-    /// Write embeddings to `LanceDB`
-    async fn write_to_lancedb(
-        &self,
-        texts: &[(uuid::Uuid, uuid::Uuid, uuid::Uuid, i32, String)],
-        embeddings: &[Vec<f32>],
-    ) -> Result<()> {
-        let schema = Arc::new(Schema::new(vec![
-            Field::new("id", DataType::Utf8, false),
-            Field::new("content_type", DataType::Utf8, false),
-            Field::new("content_id", DataType::Utf8, false),
-            Field::new("statement_id", DataType::Utf8, true),
-            Field::new("segment_index", DataType::Int32, false),
-            Field::new("start_time_ms", DataType::Int32, false),
-            Field::new("end_time_ms", DataType::Int32, false),
-            Field::new("text", DataType::Utf8, false),
-            Field::new(
-                "vector",
-                DataType::FixedSizeList(
-                    Arc::new(Field::new("item", DataType::Float32, true)),
-                    384,
-                ),
-                false,
-            ),
-        ]));
-
-        let ids: Vec<String> = texts.iter().map(|(id, _, _, _, _)| id.to_string()).collect();
-        let content_types: Vec<&str> = vec!["hearing"; texts.len()];
-        let content_ids: Vec<String> = texts.iter().map(|(_, cid, _, _, _)| cid.to_string()).collect();
-        let statement_ids: Vec<String> = texts.iter().map(|(_, _, sid, _, _)| sid.to_string()).collect();
-        let segment_indices: Vec<i32> = texts.iter().map(|(_, _, _, idx, _)| *idx).collect();
-        let text_values: Vec<&str> = texts.iter().map(|(_, _, _, _, t)| t.as_str()).collect();
-
-        // Hearing segments don't have timestamps, use 0
-        let start_times: Vec<i32> = vec![0; texts.len()];
-        let end_times: Vec<i32> = vec![0; texts.len()];
-
-        // Create embedding array using from_iter_primitive
-        let embedding_lists: Vec<Option<Vec<Option<f32>>>> = embeddings
-            .iter()
-            .map(|e| Some(e.iter().copied().map(Some).collect()))
-            .collect();
-        let vector_array =
-            FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
-                embedding_lists,
-                384,
+    /// Clean up hearings left superseded by an interrupted re-ingest
+    ///
+    /// A superseded hearing with a current replacement is deleted along with its
+    /// embeddings; one without a replacement is made current again.
+    ///
+    /// # Errors
+    /// Returns an error if a database or `LanceDB` operation fails
+    pub async fn sweep_superseded(&self) -> Result<()> {
+        let restored = self.db.hearings().restore_orphaned_superseded().await?;
+        let deleted = self.db.hearings().delete_replaced_superseded().await?;
+        for id in &deleted {
+            self.delete_embeddings(*id).await?;
+        }
+        if restored > 0 || !deleted.is_empty() {
+            warn!(
+                "Swept superseded hearings: {} restored, {} deleted",
+                restored,
+                deleted.len()
             );
+        }
+        Ok(())
+    }
 
-        let batch = RecordBatch::try_new(
-            schema.clone(),
-            vec![
-                Arc::new(StringArray::from(ids)),
-                Arc::new(StringArray::from(content_types)),
-                Arc::new(StringArray::from(content_ids)),
-                Arc::new(StringArray::from(statement_ids)),
-                Arc::new(Int32Array::from(segment_indices)),
-                Arc::new(Int32Array::from(start_times)),
-                Arc::new(Int32Array::from(end_times)),
-                Arc::new(StringArray::from(text_values)),
-                Arc::new(vector_array) as Arc<dyn Array>,
-            ],
-        )?;
-
-        // Open or create the table
-        let table = match self.lancedb.open_table("text_embeddings").execute().await {
-            Ok(t) => t,
-            Err(_) => {
-                println!("{}", "Creating text_embeddings table...".cyan());
-                let batches = RecordBatchIterator::new(vec![Ok(batch.clone())].into_iter(), schema.clone());
-                self.lancedb
-                    .create_table("text_embeddings", Box::new(batches))
-                    .execute()
-                    .await?
-            }
-        };
-
-        let batches = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
-        table.add(Box::new(batches)).execute().await?;
-
+    /// Delete all embeddings for a hearing
+    async fn delete_embeddings(&self, id: uuid::Uuid) -> Result<()> {
+        if let Ok(table) = self.lancedb.open_table("text_embeddings").execute().await {
+            table.delete(&format!("content_id = '{id}'")).await?;
+        }
         Ok(())
     }
 
+    /// Write embeddings to `LanceDB`
+    async fn write_to_lancedb(&self, texts: &[SegmentText], embeddings: &[Vec<f32>]) -> Result<()> {
+        let model = self.embedder.model();
+        // hearings aren't printed in the Congressional Record
+        let batch = text_embeddings_batch(model, ContentType::Hearing, texts, embeddings, None)?;
+        append_text_embeddings(&self.lancedb, model, batch).await
+    }
+
     /// Ingest all JSON files in a directory
     ///
     /// # Errors
@@ -301,42 +538,40 @@ impl HearingIngester {
         progress_bar: Option<&indicatif::ProgressBar>,
     ) -> Result<IngestStats> {
         let mut total_stats = IngestStats::default();
+        self.sweep_superseded().await?;
 
-        if !path.is_dir() {
-            bail!("Path is not a directory: {}", path.display());
-        }
-
-        let mut entries: Vec<_> = fs::read_dir(path)?
-            .filter_map(Result::ok)
-            .filter(|e| {
-                e.path()
-                    .extension()
-                    .is_some_and(|ext| ext == "json")
-            })
-            .collect();
-
-        entries.sort_by_key(|a| a.path());
-
-        if let Some(max) = limit {
-            entries.truncate(max);
-        }
+        let entries = collect_json_files(path, self.sample, limit)?;
 
         let total = entries.len();
         let show_output = progress_bar.is_none();
+        let mut compact =
+            (show_output && self.compact_progress).then(|| CompactProgress::new(total));
 
         if show_output {
             println!("{}", format!("Processing {} transcript files...", total).cyan());
         }
 
-        for (i, entry) in entries.into_iter().enumerate() {
-            let file_path = entry.path();
+        for (i, file_path) in entries.into_iter().enumerate() {
             let progress = format!("[{}/{}]", i + 1, total).dimmed();
             let start = Instant::now();
             match self.ingest_file(&file_path).await {
                 Ok(stats) => {
-                    let skipped = stats.files_skipped > 0;
+                    let skipped = stats.files_skipped > 0 || stats.files_skipped_oversized > 0;
                     if let Some(pb) = progress_bar {
                         pb.inc(1);
+                    } else if let Some(compact) = compact.as_mut() {
+                        if skipped {
+                            compact.skipped += 1;
+                            compact.line(&format!(
+                                "{} {} {}",
+                                progress,
+                                "Skipped".yellow(),
+                                file_path.display()
+                            ));
+                        } else {
+                            compact.processed += 1;
+                        }
+                        compact.update(&file_path);
                     } else {
                         let duration = start.elapsed();
                         if skipped {
@@ -354,25 +589,33 @@ impl HearingIngester {
                     }
                     total_stats.files_processed += stats.files_processed;
                     total_stats.files_skipped += stats.files_skipped;
+                    total_stats.files_changed += stats.files_changed;
+                    total_stats.files_skipped_oversized += stats.files_skipped_oversized;
                     total_stats.hearings_created += stats.hearings_created;
                     total_stats.statements_created += stats.statements_created;
                     total_stats.segments_created += stats.segments_created;
+                    total_stats.segments_deduped += stats.segments_deduped;
+                    total_stats.statements_with_party += stats.statements_with_party;
                     total_stats.embeddings_created += stats.embeddings_created;
                 }
                 Err(e) => {
-                    if show_output {
-                        println!(
-                            "{} {} {}: {}",
-                            progress,
-                            "Failed".red(),
-                            file_path.display(),
-                            e
-                        );
+                    let message =
+                        format!("{} {} {}: {}", progress, "Failed".red(), file_path.display(), e);
+                    if let Some(compact) = compact.as_mut() {
+                        compact.failed += 1;
+                        compact.line(&message);
+                        compact.update(&file_path);
+                    } else if show_output {
+                        println!("{message}");
                     }
                 }
             }
         }
 
+        if let Some(compact) = &compact {
+            compact.finish();
+        }
+
         Ok(total_stats)
     }
 
@@ -381,28 +624,15 @@ impl HearingIngester {
     /// # Errors
     /// Returns an error if directory reading fails
     pub fn validate_directory(&self, path: &Path, limit: Option<usize>) -> Result<(usize, usize)> {
-        if !path.is_dir() {
-            bail!("Path is not a directory: {}", path.display());
-        }
-
         let mut valid = 0;
         let mut invalid = 0;
 
-        let mut entries: Vec<_> = fs::read_dir(path)?
-            .filter_map(Result::ok)
-            .filter(|e| {
-                e.path()
-                    .extension()
-                    .is_some_and(|ext| ext == "json")
-            })
-            .collect();
-
-        if let Some(max) = limit {
-            entries.truncate(max);
-        }
-
-        for entry in entries {
-            let file_path = entry.path();
+        for file_path in collect_json_files(path, self.sample, limit)? {
+            if let Some(size) = oversized_file(&file_path, self.max_file_size)? {
+                warn!("Oversized file {} ({} bytes)", file_path.display(), size);
+                invalid += 1;
+                continue;
+            }
             match fs::read_to_string(&file_path) {
                 Ok(content) => match serde_json::from_str::<TranscriptJson>(&content) {
                     Ok(_) => valid += 1,
@@ -0,0 +1,284 @@
+//! Members of Congress loaded from @unitedstates/congress-legislators YAML
+//!
+//! Only the fields needed to attribute transcript speakers are kept: name,
+//! chamber, and party of each member's most recent term.
+
+use std::collections::HashMap;
+
+use color_eyre::eyre::{Result, WrapErr};
+use serde::Deserialize;
+
+/// Chamber of a member's most recent term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chamber {
+    House,
+    Senate,
+}
+
+impl Chamber {
+    /// Parse "House"/"Senate" (case-insensitive)
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        if s.eq_ignore_ascii_case("house") {
+            Some(Self::House)
+        } else if s.eq_ignore_ascii_case("senate") {
+            Some(Self::Senate)
+        } else {
+            None
+        }
+    }
+}
+
+/// Party of a member's most recent term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Party {
+    Democrat,
+    Republican,
+    Independent,
+    Other,
+}
+
+impl Party {
+    /// Single-letter code stored on statements, `None` for parties outside D/R/I
+    #[must_use]
+    pub const fn code(self) -> Option<&'static str> {
+        match self {
+            Self::Democrat => Some("D"),
+            Self::Republican => Some("R"),
+            Self::Independent => Some("I"),
+            Self::Other => None,
+        }
+    }
+}
+
+/// A member of Congress
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Legislator {
+    pub bioguide_id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub chamber: Chamber,
+    pub party: Party,
+}
+
+/// Members indexed by normalized last name
+#[derive(Debug, Default)]
+pub struct LegislatorLookup {
+    members: Vec<Legislator>,
+    by_bioguide: HashMap<String, usize>,
+    by_last_name: HashMap<String, Vec<usize>>,
+}
+
+impl LegislatorLookup {
+    /// Load members from a congress-legislators YAML file
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read or parsed
+    pub fn from_yaml_file(path: &str) -> Result<Self> {
+        let content =
+            std::fs::read_to_string(path).wrap_err_with(|| format!("failed to read {path}"))?;
+        Self::from_yaml_str(&content)
+    }
+
+    /// Load members from congress-legislators YAML text
+    ///
+    /// Entries without a bioguide ID or a House/Senate term are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if the YAML can't be parsed
+    pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+        let entries: Vec<LegislatorYaml> =
+            serde_yaml::from_str(yaml).wrap_err("failed to parse legislators YAML")?;
+        let mut lookup = Self::default();
+        for entry in entries {
+            if let Some(member) = entry.into_legislator() {
+                lookup.add(member);
+            }
+        }
+        Ok(lookup)
+    }
+
+    /// Add a member, replacing any earlier entry with the same bioguide ID
+    pub fn add(&mut self, member: Legislator) {
+        if let Some(&index) = self.by_bioguide.get(&member.bioguide_id) {
+            let previous_key = normalize_name(&self.members[index].last_name);
+            if let Some(indices) = self.by_last_name.get_mut(&previous_key) {
+                indices.retain(|&i| i != index);
+            }
+            self.members[index] = member;
+            self.index_last_name(index);
+            return;
+        }
+        self.by_bioguide.insert(member.bioguide_id.clone(), self.members.len());
+        self.members.push(member);
+        self.index_last_name(self.members.len() - 1);
+    }
+
+    fn index_last_name(&mut self, index: usize) {
+        self.by_last_name
+            .entry(normalize_name(&self.members[index].last_name))
+            .or_default()
+            .push(index);
+    }
+
+    /// Members with this last name, ignoring case and punctuation ("O'Rourke" = "orourke")
+    #[must_use]
+    pub fn find_by_last_name(&self, last_name: &str) -> Vec<&Legislator> {
+        self.by_last_name
+            .get(&normalize_name(last_name))
+            .map(|indices| indices.iter().map(|&index| &self.members[index]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Number of members loaded
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Whether no members are loaded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+/// Lowercase a name and drop everything but letters and digits
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct LegislatorYaml {
+    id: LegislatorId,
+    name: LegislatorName,
+    terms: Vec<LegislatorTerm>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegislatorId {
+    bioguide: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegislatorName {
+    first: String,
+    last: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegislatorTerm {
+    #[serde(rename = "type")]
+    term_type: String,
+    party: Option<String>,
+}
+
+impl LegislatorYaml {
+    fn into_legislator(self) -> Option<Legislator> {
+        let term = self.terms.into_iter().last()?;
+        let chamber = match term.term_type.as_str() {
+            "sen" => Chamber::Senate,
+            "rep" => Chamber::House,
+            _ => return None,
+        };
+        let party = match term.party.as_deref() {
+            Some("Democrat") => Party::Democrat,
+            Some("Republican") => Party::Republican,
+            Some("Independent") => Party::Independent,
+            _ => Party::Other,
+        };
+        Some(Legislator {
+            bioguide_id: self.id.bioguide?,
+            first_name: self.name.first,
+            last_name: self.name.last,
+            chamber,
+            party,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGISLATORS_YAML: &str = r"
+- id:
+    bioguide: O000170
+  name:
+    first: Beto
+    last: O'Rourke
+  terms:
+    - type: rep
+      state: TX
+      party: Democrat
+      district: 16
+- id:
+    bioguide: S001217
+  name:
+    first: Rick
+    last: Scott
+  terms:
+    - type: rep
+      state: FL
+      party: Republican
+    - type: sen
+      state: FL
+      party: Republican
+- id:
+    bioguide: S001189
+  name:
+    first: Austin
+    last: Scott
+  terms:
+    - type: rep
+      state: GA
+      party: Republican
+      district: 8
+- id:
+    govtrack: 1
+  name:
+    first: No
+    last: Bioguide
+  terms:
+    - type: rep
+      state: GA
+      party: Democrat
+";
+
+    #[test]
+    fn test_loads_most_recent_term() {
+        let lookup = LegislatorLookup::from_yaml_str(LEGISLATORS_YAML).expect("parse");
+        assert_eq!(lookup.len(), 3);
+
+        let scotts = lookup.find_by_last_name("SCOTT");
+        let chambers: Vec<Chamber> = scotts.iter().map(|m| m.chamber).collect();
+        assert_eq!(chambers, [Chamber::Senate, Chamber::House]);
+        assert_eq!(lookup.find_by_last_name("orourke").len(), 1);
+        assert!(lookup.find_by_last_name("Bioguide").is_empty());
+    }
+
+    #[test]
+    fn test_re_adding_member_replaces_entry() {
+        let mut lookup = LegislatorLookup::default();
+        let member = Legislator {
+            bioguide_id: "S000148".to_string(),
+            first_name: "Charles".to_string(),
+            last_name: "Schumer".to_string(),
+            chamber: Chamber::House,
+            party: Party::Democrat,
+        };
+        lookup.add(member.clone());
+        lookup.add(Legislator {
+            chamber: Chamber::Senate,
+            ..member
+        });
+
+        assert_eq!(lookup.len(), 1);
+        let schumers = lookup.find_by_last_name("Schumer");
+        assert_eq!(schumers.len(), 1);
+        assert_eq!(schumers[0].chamber, Chamber::Senate);
+    }
+}
@@ -0,0 +1,220 @@
+//! Exact phrase search over a `LanceDB` text table
+//!
+//! When the table has an FTS index, the phrase's terms are matched through the
+//! index first to get a candidate pool, and only those rows are checked for the
+//! exact phrase. Any row containing the phrase also contains all of its terms,
+//! so the index never hides a match; it only ranks it. If the table has no FTS
+//! index, or the pool runs out before `limit` matches are found, a
+//! `text LIKE '%phrase%'` scan runs instead, so results are never fewer than
+//! without the prefilter.
+//!
+//! Both paths log their row counts and elapsed time at debug level
+//! (`RUST_LOG=polsearch_pipeline=debug`). They have not been benchmarked
+//! against each other.
+
+use std::time::Instant;
+
+use arrow_array::{Array, RecordBatch, StringArray};
+use color_eyre::eyre::Result;
+use futures::TryStreamExt;
+use lancedb::query::{ExecutableQuery, QueryBase};
+
+use super::ingest_fts::text_fts_query;
+
+/// FTS candidates fetched per requested result before checking the exact phrase
+pub const PHRASE_FTS_CANDIDATES: usize = 20;
+
+/// Find rows whose `text` contains `phrase` (case-sensitive, like `LIKE`)
+///
+/// `filter` is an extra `LanceDB` filter expression (e.g. content type).
+///
+/// # Errors
+/// Returns an error if a `LanceDB` query fails for a reason other than a missing FTS index
+pub async fn phrase_search(
+    table: &lancedb::Table,
+    phrase: &str,
+    filter: Option<&str>,
+    limit: usize,
+) -> Result<Vec<RecordBatch>> {
+    if let Some(batches) = fts_prefiltered_search(table, phrase, filter, limit).await? {
+        return Ok(batches);
+    }
+
+    let start = Instant::now();
+    let escaped = phrase.replace('\'', "''").replace('%', "\\%");
+    let like_filter = format!("text LIKE '%{escaped}%'");
+    let combined = match filter {
+        Some(filter) => format!("({filter}) AND ({like_filter})"),
+        None => like_filter,
+    };
+    let batches: Vec<RecordBatch> = table
+        .query()
+        .only_if(combined)
+        .limit(limit)
+        .execute()
+        .await?
+        .try_collect()
+        .await?;
+    tracing::debug!(
+        rows = batches.iter().map(RecordBatch::num_rows).sum::<usize>(),
+        elapsed_ms = start.elapsed().as_millis(),
+        "phrase search: full scan"
+    );
+    Ok(batches)
+}
+
+/// Phrase matches among the FTS candidates for the phrase's terms
+///
+/// Returns `None` when the table has no FTS index, or when the candidate pool
+/// was exhausted without filling `limit` (matches may lie beyond it).
+async fn fts_prefiltered_search(
+    table: &lancedb::Table,
+    phrase: &str,
+    filter: Option<&str>,
+    limit: usize,
+) -> Result<Option<Vec<RecordBatch>>> {
+    if phrase.split_whitespace().next().is_none() {
+        return Ok(None);
+    }
+
+    let start = Instant::now();
+    let pool_size = limit.saturating_mul(PHRASE_FTS_CANDIDATES).max(limit);
+    let mut search = table.query().full_text_search(text_fts_query(phrase)?);
+    if let Some(filter) = filter {
+        search = search.only_if(filter.to_string());
+    }
+    let candidates: Vec<RecordBatch> = match search.limit(pool_size).execute().await {
+        Ok(stream) => stream.try_collect().await?,
+        Err(e) if is_missing_fts_index_error(&e) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let candidate_count: usize = candidates.iter().map(RecordBatch::num_rows).sum();
+    let mut matched = 0;
+    let mut batches = Vec::new();
+    for batch in &candidates {
+        for slice in phrase_match_slices(batch, phrase, limit - matched) {
+            matched += slice.num_rows();
+            batches.push(slice);
+        }
+        if matched == limit {
+            break;
+        }
+    }
+
+    tracing::debug!(
+        candidates = candidate_count,
+        matched,
+        elapsed_ms = start.elapsed().as_millis(),
+        "phrase search: FTS prefilter"
+    );
+
+    // a full pool that didn't fill the limit may have more matches beyond it
+    if matched < limit && candidate_count >= pool_size {
+        return Ok(None);
+    }
+    Ok(Some(batches))
+}
+
+/// Contiguous slices of `batch` whose `text` contains `phrase`, up to `max_rows` rows
+fn phrase_match_slices(batch: &RecordBatch, phrase: &str, max_rows: usize) -> Vec<RecordBatch> {
+    let Some(texts) = batch
+        .column_by_name("text")
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+    else {
+        return Vec::new();
+    };
+
+    let mut slices = Vec::new();
+    let mut remaining = max_rows;
+    let mut run_start: Option<usize> = None;
+    for i in 0..=batch.num_rows() {
+        let keep = i < batch.num_rows()
+            && remaining > 0
+            && !texts.is_null(i)
+            && texts.value(i).contains(phrase);
+        match (keep, run_start) {
+            (true, None) => run_start = Some(i),
+            (false, Some(start)) => {
+                slices.push(batch.slice(start, i - start));
+                run_start = None;
+            }
+            _ => {}
+        }
+        if keep {
+            remaining -= 1;
+        }
+    }
+    slices
+}
+
+/// Check if a `LanceDB` error is due to a missing FTS inverted index
+#[must_use]
+pub fn is_missing_fts_index_error(e: &lancedb::Error) -> bool {
+    let msg = e.to_string();
+    msg.contains("INVERTED index") || msg.contains("full text search")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Int32Array;
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn batch(texts: &[&str]) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("segment_index", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, false),
+        ]));
+        let indices: Vec<i32> = (0..).take(texts.len()).collect();
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int32Array::from(indices)),
+                Arc::new(StringArray::from(texts.to_vec())),
+            ],
+        )
+        .expect("valid batch")
+    }
+
+    fn segment_indices(slices: &[RecordBatch]) -> Vec<i32> {
+        slices
+            .iter()
+            .flat_map(|b| {
+                let col = b
+                    .column_by_name("segment_index")
+                    .and_then(|c| c.as_any().downcast_ref::<Int32Array>())
+                    .expect("segment_index column");
+                col.values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_keeps_only_exact_phrase_rows() {
+        let b = batch(&[
+            "the child tax credit expires",
+            "a credit for each child and a tax cut",
+            "expand the child tax credit now",
+            "Child Tax Credit",
+        ]);
+        let slices = phrase_match_slices(&b, "child tax credit", 10);
+        assert_eq!(segment_indices(&slices), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_contiguous_matches_share_a_slice() {
+        let b = batch(&["farm bill", "the farm bill", "no match", "farm bill again"]);
+        let slices = phrase_match_slices(&b, "farm bill", 10);
+        assert_eq!(slices.len(), 2);
+        assert_eq!(segment_indices(&slices), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_stops_at_max_rows() {
+        let b = batch(&["farm bill", "farm bill", "farm bill"]);
+        assert_eq!(segment_indices(&phrase_match_slices(&b, "farm bill", 2)), vec![0, 1]);
+        assert!(phrase_match_slices(&b, "farm bill", 0).is_empty());
+    }
+}
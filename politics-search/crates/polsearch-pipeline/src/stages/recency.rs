@@ -0,0 +1,100 @@
+//! Recency weighting for search results
+//!
+//! Multiplies each result's relevance score by `2^(-age_days / half_life)`,
+//! so a result's score halves every `half_life` days. Results
+//! without a date are ranked as if they were the oldest dated result, so
+//! missing metadata never promotes them.
+
+use chrono::NaiveDate;
+
+/// Half-life in days used when `--boost-recent` is given without a value
+pub const DEFAULT_RECENCY_HALF_LIFE_DAYS: f64 = 365.0;
+
+/// Score multiplier for content that is `age_days` old
+///
+/// Future dates are treated as today.
+#[must_use]
+pub fn recency_decay(age_days: i64, half_life_days: f64) -> f32 {
+    (-std::f64::consts::LN_2 * age_days.max(0) as f64 / half_life_days).exp() as f32
+}
+
+/// Multiply each item's score by its [`recency_decay`] and sort highest first
+///
+/// `date` returns the item's publication date; `score` returns its relevance
+/// score, where higher is better. The sort is stable, so items with equal
+/// boosted scores keep their retrieval order.
+#[must_use]
+pub fn boost_recent<T>(
+    items: Vec<T>,
+    half_life_days: f64,
+    today: NaiveDate,
+    date: impl Fn(&T) -> Option<NaiveDate>,
+    score: impl Fn(&mut T) -> &mut f32,
+) -> Vec<T> {
+    let decays: Vec<Option<f32>> = items
+        .iter()
+        .map(|item| date(item).map(|d| recency_decay((today - d).num_days(), half_life_days)))
+        .collect();
+    let oldest = decays.iter().flatten().copied().reduce(f32::min).unwrap_or(1.0);
+
+    let mut boosted: Vec<(f32, T)> = items
+        .into_iter()
+        .zip(decays)
+        .map(|(mut item, decay)| {
+            let boosted_score = score(&mut item);
+            *boosted_score *= decay.unwrap_or(oldest);
+            (*boosted_score, item)
+        })
+        .collect();
+    boosted.sort_by(|a, b| b.0.total_cmp(&a.0));
+    boosted.into_iter().map(|(_, item)| item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item {
+        id: &'static str,
+        date: Option<NaiveDate>,
+        score: f32,
+    }
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).expect("valid date")
+    }
+
+    fn boosted(items: Vec<Item>, today: NaiveDate) -> Vec<&'static str> {
+        boost_recent(items, DEFAULT_RECENCY_HALF_LIFE_DAYS, today, |r| r.date, |r| &mut r.score)
+            .into_iter()
+            .map(|r| r.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_newer_result_ranks_first_with_equal_scores() {
+        let items = vec![
+            Item { id: "old", date: Some(day(2020, 3, 1)), score: 0.5 },
+            Item { id: "new", date: Some(day(2025, 3, 1)), score: 0.5 },
+        ];
+        assert_eq!(boosted(items, day(2025, 6, 1)), vec!["new", "old"]);
+    }
+
+    #[test]
+    fn test_undated_result_ranks_as_oldest() {
+        let items = vec![
+            Item { id: "undated", date: None, score: 0.5 },
+            Item { id: "old", date: Some(day(2020, 3, 1)), score: 0.5 },
+            Item { id: "new", date: Some(day(2025, 3, 1)), score: 0.5 },
+        ];
+        assert_eq!(boosted(items, day(2025, 6, 1)), vec!["new", "undated", "old"]);
+    }
+
+    #[test]
+    fn test_recency_decay() {
+        assert!((recency_decay(0, 365.0) - 1.0).abs() < f32::EPSILON);
+        assert!((recency_decay(-30, 365.0) - 1.0).abs() < f32::EPSILON);
+        assert!((recency_decay(365, 365.0) - 0.5).abs() < 1e-6);
+        assert!((recency_decay(730, 365.0) - 0.25).abs() < 1e-6);
+    }
+}
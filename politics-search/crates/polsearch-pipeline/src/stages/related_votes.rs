@@ -0,0 +1,97 @@
+//! Related vote lookup by embedding similarity
+
+use arrow_array::{Array, FixedSizeListArray, Float32Array, RecordBatch, StringArray};
+use color_eyre::eyre::{eyre, Result};
+use futures::TryStreamExt;
+use lancedb::query::{ExecutableQuery, QueryBase};
+use polsearch_core::ContentType;
+use uuid::Uuid;
+
+/// A vote similar to the query vote
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedVote {
+    /// Roll call vote ID
+    pub id: Uuid,
+    /// Vector distance from the query vote (lower is more similar)
+    pub distance: f32,
+}
+
+/// Find the votes most similar to `vote_id` using its stored embedding
+///
+/// Returns `None` if the vote has not been embedded yet (see `votes embed`).
+///
+/// # Errors
+/// Returns an error if `LanceDB` queries fail or the embeddings table is missing
+pub async fn find_related_votes(
+    lancedb: &lancedb::Connection,
+    vote_id: Uuid,
+    limit: usize,
+) -> Result<Option<Vec<RelatedVote>>> {
+    let table = lancedb.open_table("text_embeddings").execute().await?;
+    let vote_type = ContentType::Vote;
+
+    let batches: Vec<RecordBatch> = table
+        .query()
+        .only_if(format!(
+            "content_type = '{vote_type}' AND content_id = '{vote_id}'"
+        ))
+        .select(lancedb::query::Select::columns(&["vector"]))
+        .limit(1)
+        .execute()
+        .await?
+        .try_collect()
+        .await?;
+
+    let Some(vector) = batches.iter().find_map(first_vector) else {
+        return Ok(None);
+    };
+
+    let batches: Vec<RecordBatch> = table
+        .vector_search(vector)?
+        .only_if(format!(
+            "content_type = '{vote_type}' AND content_id != '{vote_id}'"
+        ))
+        .select(lancedb::query::Select::columns(&["content_id"]))
+        .limit(limit)
+        .execute()
+        .await?
+        .try_collect()
+        .await?;
+
+    let mut related = Vec::new();
+    for batch in &batches {
+        let content_ids = batch
+            .column_by_name("content_id")
+            .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+            .ok_or_else(|| eyre!("Missing content_id column"))?;
+        let distances = batch
+            .column_by_name("_distance")
+            .and_then(|c| c.as_any().downcast_ref::<Float32Array>());
+
+        for i in 0..batch.num_rows() {
+            let Ok(id) = Uuid::parse_str(content_ids.value(i)) else {
+                continue;
+            };
+            related.push(RelatedVote {
+                id,
+                distance: distances.map_or(0.0, |d| d.value(i)),
+            });
+        }
+    }
+
+    Ok(Some(related))
+}
+
+/// Extract the first row's embedding vector from a batch
+fn first_vector(batch: &RecordBatch) -> Option<Vec<f32>> {
+    if batch.num_rows() == 0 {
+        return None;
+    }
+    let vectors = batch
+        .column_by_name("vector")?
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()?;
+    let values = vectors.value(0);
+    let floats = values.as_any().downcast_ref::<Float32Array>()?;
+    Some(floats.values().to_vec())
+}
@@ -0,0 +1,175 @@
+//! Input file enumeration, deterministic sampling, and size guards for ingestion
+
+use color_eyre::eyre::{bail, Result};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Seed used for `--sample` when `--seed` is not given
+pub const DEFAULT_SAMPLE_SEED: u64 = 42;
+
+/// Default `--max-file-size` in megabytes
+pub const DEFAULT_MAX_FILE_SIZE_MB: u64 = 256;
+
+/// Convert a `--max-file-size` value in megabytes to a byte limit (0 disables the limit)
+#[must_use]
+pub const fn max_file_size_bytes(megabytes: u64) -> Option<u64> {
+    if megabytes == 0 {
+        None
+    } else {
+        Some(megabytes.saturating_mul(1024 * 1024))
+    }
+}
+
+/// Returns the file size if it exceeds `max_bytes`, without reading the file
+///
+/// # Errors
+/// Returns an error if the file metadata cannot be read
+pub fn oversized_file(path: &Path, max_bytes: Option<u64>) -> Result<Option<u64>> {
+    let Some(max) = max_bytes else {
+        return Ok(None);
+    };
+    let size = fs::metadata(path)?.len();
+    Ok((size > max).then_some(size))
+}
+
+/// A seeded random subset of the input files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    /// Number of files to select
+    pub size: usize,
+    /// Seed for the shuffle, fixed for reproducible runs
+    pub seed: u64,
+}
+
+impl Sample {
+    /// Creates a new sample with the given size and seed
+    #[must_use]
+    pub const fn new(size: usize, seed: u64) -> Self {
+        Self { size, seed }
+    }
+}
+
+/// List all JSON files in a directory, sorted by path
+///
+/// # Errors
+/// Returns an error if the path is not a directory or cannot be read
+pub fn list_json_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        bail!("Path is not a directory: {}", path.display());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(path)?
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+
+    files.sort();
+    Ok(files)
+}
+
+/// Select the files to process from a sorted file list
+///
+/// The sample (if any) is applied first and keeps files in sorted order,
+/// then `limit` truncates the result.
+#[must_use]
+pub fn select_files(
+    mut files: Vec<PathBuf>,
+    sample: Option<Sample>,
+    limit: Option<usize>,
+) -> Vec<PathBuf> {
+    if let Some(sample) = sample {
+        if sample.size < files.len() {
+            let mut rng = StdRng::seed_from_u64(sample.seed);
+            files.shuffle(&mut rng);
+            files.truncate(sample.size);
+            files.sort();
+        }
+    }
+
+    if let Some(max) = limit {
+        files.truncate(max);
+    }
+
+    files
+}
+
+/// List and select JSON files in a directory in one step
+///
+/// # Errors
+/// Returns an error if the path is not a directory or cannot be read
+pub fn collect_json_files(
+    path: &Path,
+    sample: Option<Sample>,
+    limit: Option<usize>,
+) -> Result<Vec<PathBuf>> {
+    Ok(select_files(list_json_files(path)?, sample, limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(n: usize) -> Vec<PathBuf> {
+        (0..n)
+            .map(|i| PathBuf::from(format!("CHRG-{i:04}.json")))
+            .collect()
+    }
+
+    #[test]
+    fn test_sample_is_deterministic() {
+        let a = select_files(files(100), Some(Sample::new(10, 7)), None);
+        let b = select_files(files(100), Some(Sample::new(10, 7)), None);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 10);
+    }
+
+    #[test]
+    fn test_sample_is_sorted_and_spread() {
+        let selected = select_files(files(100), Some(Sample::new(10, 7)), None);
+        let mut sorted = selected.clone();
+        sorted.sort();
+        assert_eq!(selected, sorted);
+        assert_ne!(selected, files(10));
+    }
+
+    #[test]
+    fn test_sample_larger_than_input() {
+        let selected = select_files(files(5), Some(Sample::new(10, 7)), None);
+        assert_eq!(selected, files(5));
+    }
+
+    #[test]
+    fn test_max_file_size_bytes() {
+        assert_eq!(max_file_size_bytes(0), None);
+        assert_eq!(max_file_size_bytes(1), Some(1024 * 1024));
+        assert_eq!(max_file_size_bytes(u64::MAX), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_oversized_file() -> Result<()> {
+        let path = std::env::temp_dir()
+            .join(format!("polsearch-size-{}.json", std::process::id()));
+        fs::write(&path, vec![b' '; 2048])?;
+
+        assert_eq!(oversized_file(&path, Some(1024))?, Some(2048));
+        assert_eq!(oversized_file(&path, Some(4096))?, None);
+        assert_eq!(oversized_file(&path, None)?, None);
+
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_limit_applies_after_sample() {
+        let selected = select_files(files(100), Some(Sample::new(10, 7)), Some(3));
+        assert_eq!(selected.len(), 3);
+        assert_eq!(
+            selected,
+            select_files(files(100), Some(Sample::new(10, 7)), None)[..3]
+        );
+    }
+}
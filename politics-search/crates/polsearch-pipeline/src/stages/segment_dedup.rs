@@ -0,0 +1,255 @@
+//! Near-duplicate segment detection across an ingest run
+//!
+//! The Congressional Record often reprints the same text across granules
+//! (e.g., extensions of remarks repeating a floor statement), so one deduper
+//! spans every file of a run. Segments are compared on their normalized text:
+//! first by exact hash, then by the Jaccard similarity of their word shingles,
+//! estimated from `MinHash` signatures. Candidates are looked up by signature
+//! band, so each check costs the same however many segments came before.
+//!
+//! Segments checked since the last [`SegmentDeduper::commit`] are pending: a
+//! file whose write fails calls [`SegmentDeduper::rollback`] so its segments
+//! don't hide repeats in later files.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Default Jaccard similarity at or above which a segment counts as a duplicate
+pub const DEFAULT_DEDUP_THRESHOLD: f64 = 0.9;
+
+/// Number of consecutive words per shingle
+const SHINGLE_WORDS: usize = 3;
+
+/// `MinHash` values per signature
+const SIGNATURE_LEN: usize = 32;
+
+/// Signature values per band; segments sharing any band are compared
+///
+/// Eight bands of four find pairs at 0.9 similarity almost surely; at 0.6
+/// roughly two in three pairs become candidates.
+const BAND_ROWS: usize = 4;
+
+type Signature = [u32; SIGNATURE_LEN];
+
+/// Tracks segments seen so far and flags exact or near-identical repeats
+#[derive(Debug)]
+pub struct SegmentDeduper {
+    threshold: f64,
+    exact: HashSet<u64>,
+    signatures: Vec<Signature>,
+    /// Band hash to the first signature with that band
+    bands: HashMap<u64, usize>,
+    /// Exact hashes and band hashes added since the last commit
+    pending_exact: Vec<u64>,
+    pending_bands: Vec<u64>,
+    /// Number of signatures recorded as of the last commit
+    committed: usize,
+}
+
+impl SegmentDeduper {
+    /// Create a deduper that drops segments with similarity `>= threshold`
+    ///
+    /// The threshold is clamped to `0.0..=1.0`; `1.0` only drops segments
+    /// whose normalized text is identical.
+    #[must_use]
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold: threshold.clamp(0.0, 1.0),
+            exact: HashSet::new(),
+            signatures: Vec::new(),
+            bands: HashMap::new(),
+            pending_exact: Vec::new(),
+            pending_bands: Vec::new(),
+            committed: 0,
+        }
+    }
+
+    /// Check `text` against previously seen segments, remembering it as pending if new
+    ///
+    /// Returns `true` if `text` duplicates an earlier segment and should be dropped.
+    pub fn is_duplicate(&mut self, text: &str) -> bool {
+        let normalized = normalize_text(text);
+        let exact = hash_of(&normalized);
+        if !self.exact.insert(exact) {
+            return true;
+        }
+        self.pending_exact.push(exact);
+        if self.threshold >= 1.0 {
+            return false;
+        }
+
+        let signature = minhash(&shingle_hashes(&normalized));
+        let band_keys: Vec<u64> = signature
+            .chunks(BAND_ROWS)
+            .enumerate()
+            .map(|band| hash_of(&band))
+            .collect();
+        if band_keys
+            .iter()
+            .filter_map(|key| self.bands.get(key))
+            .any(|&seen| similarity(&self.signatures[seen], &signature) >= self.threshold)
+        {
+            return true;
+        }
+
+        let index = self.signatures.len();
+        for key in band_keys {
+            if let Entry::Vacant(entry) = self.bands.entry(key) {
+                entry.insert(index);
+                self.pending_bands.push(key);
+            }
+        }
+        self.signatures.push(signature);
+        false
+    }
+
+    /// Keep the pending segments, once the file they came from is stored
+    pub fn commit(&mut self) {
+        self.pending_exact.clear();
+        self.pending_bands.clear();
+        self.committed = self.signatures.len();
+    }
+
+    /// Forget the pending segments, after the file they came from failed to store
+    pub fn rollback(&mut self) {
+        for exact in self.pending_exact.drain(..) {
+            self.exact.remove(&exact);
+        }
+        for key in self.pending_bands.drain(..) {
+            self.bands.remove(&key);
+        }
+        self.signatures.truncate(self.committed);
+    }
+}
+
+/// Lowercase `text` and keep only its words, single-space separated
+#[must_use]
+pub fn normalize_text(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes of each run of `SHINGLE_WORDS` words (or the whole text if shorter)
+fn shingle_hashes(normalized: &str) -> HashSet<u64> {
+    let words: Vec<&str> = normalized.split(' ').filter(|w| !w.is_empty()).collect();
+    if words.len() <= SHINGLE_WORDS {
+        return std::iter::once(hash_of(&words)).collect();
+    }
+    words.windows(SHINGLE_WORDS).map(hash_of).collect()
+}
+
+/// `MinHash` signature: the minimum of each seeded rehash over the shingles
+fn minhash(shingles: &HashSet<u64>) -> Signature {
+    let mut signature = [u32::MAX; SIGNATURE_LEN];
+    for (i, slot) in signature.iter_mut().enumerate() {
+        let seed = (i as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        for &shingle in shingles {
+            *slot = (*slot).min((mix(shingle ^ seed) >> 32) as u32);
+        }
+    }
+    signature
+}
+
+/// `SplitMix64` finalizer, spreading nearby inputs across the output range
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// Estimated Jaccard similarity: the share of signature values that agree
+#[allow(clippy::cast_precision_loss)]
+fn similarity(a: &Signature, b: &Signature) -> f64 {
+    let agree = a.iter().zip(b).filter(|(x, y)| x == y).count();
+    agree as f64 / SIGNATURE_LEN as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whitespace_only_difference_collapses() {
+        let mut deduper = SegmentDeduper::new(DEFAULT_DEDUP_THRESHOLD);
+        assert!(!deduper.is_duplicate("Mr. President, I rise today to honor\nthe veterans of Ohio."));
+        assert!(deduper.is_duplicate("Mr.  President,  I rise today to honor   the veterans of Ohio. "));
+    }
+
+    #[test]
+    fn test_case_and_punctuation_ignored() {
+        let mut deduper = SegmentDeduper::new(1.0);
+        assert!(!deduper.is_duplicate("I yield back the balance of my time."));
+        assert!(deduper.is_duplicate("i yield back the balance of my time"));
+    }
+
+    #[test]
+    fn test_near_duplicate_above_threshold() {
+        let base = "the committee heard testimony on rural broadband deployment and the \
+                    challenges facing small providers in reaching unserved households across \
+                    the mountain west and the great plains during the past fiscal year";
+        let mut deduper = SegmentDeduper::new(0.8);
+        assert!(!deduper.is_duplicate(base));
+        assert!(deduper.is_duplicate(&format!("{base} today")));
+    }
+
+    #[test]
+    fn test_distinct_segments_kept() {
+        let mut deduper = SegmentDeduper::new(DEFAULT_DEDUP_THRESHOLD);
+        assert!(!deduper.is_duplicate("The farm bill extends crop insurance programs."));
+        assert!(!deduper.is_duplicate("The defense bill funds shipbuilding programs."));
+    }
+
+    #[test]
+    fn test_exact_threshold_keeps_near_duplicates() {
+        let mut deduper = SegmentDeduper::new(1.0);
+        assert!(!deduper.is_duplicate("one two three four five six"));
+        assert!(!deduper.is_duplicate("one two three four five seven"));
+    }
+
+    #[test]
+    fn test_repeat_found_among_many_segments() {
+        let reprint = "the senator from ohio rose to honor the veterans of the buckeye state \
+                       who served in the korean war and returned home to build our towns";
+        let mut deduper = SegmentDeduper::new(DEFAULT_DEDUP_THRESHOLD);
+        assert!(!deduper.is_duplicate(reprint));
+        for i in 0..1000 {
+            assert!(!deduper.is_duplicate(&format!("distinct remarks number {i} on item {i}")));
+        }
+        // reprinted in a later granule with a different lead-in
+        assert!(deduper.is_duplicate(&format!("{reprint} today")));
+    }
+
+    #[test]
+    fn test_rolled_back_file_does_not_hide_later_segments() {
+        let statement = "the senator from ohio rose to honor the veterans of the buckeye state";
+        let mut deduper = SegmentDeduper::new(DEFAULT_DEDUP_THRESHOLD);
+        assert!(!deduper.is_duplicate("an earlier file that was stored"));
+        deduper.commit();
+
+        // first file: its write fails after the segments were checked
+        assert!(!deduper.is_duplicate(statement));
+        assert!(deduper.is_duplicate(&format!("{statement} today")));
+        deduper.rollback();
+
+        // second file keeps the segments, and committed ones still match
+        assert!(!deduper.is_duplicate(&format!("{statement} today")));
+        assert!(deduper.is_duplicate(statement));
+        assert!(deduper.is_duplicate("an earlier file that was stored"));
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        assert_eq!(normalize_text("  Hello,\n\tWORLD!  "), "hello world");
+        assert_eq!(normalize_text(""), "");
+    }
+}
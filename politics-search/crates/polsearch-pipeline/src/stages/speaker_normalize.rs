@@ -0,0 +1,233 @@
+//! Canonicalization of raw transcript speaker labels
+
+/// Role labels and their canonical form, longest labels first so
+/// "speaker pro tempore" wins over "speaker"
+const ROLES: &[(&str, &str)] = &[
+    ("acting president pro tempore", "President Pro Tempore"),
+    ("president pro tempore", "President Pro Tempore"),
+    ("speaker pro tempore", "Speaker Pro Tempore"),
+    ("presiding officer", "Presiding Officer"),
+    ("ranking member", "Ranking Member"),
+    ("vice chairwoman", "Vice Chair"),
+    ("vice chairman", "Vice Chair"),
+    ("vice president", "Vice President"),
+    ("chairwoman", "Chair"),
+    ("chairperson", "Chair"),
+    ("vice chair", "Vice Chair"),
+    ("chairman", "Chair"),
+    ("speaker", "Speaker"),
+    ("chair", "Chair"),
+    ("clerk", "Clerk"),
+];
+
+/// Honorifics recognized in front of a name, in canonical form
+const HONORIFICS: &[&str] = &[
+    "Congresswoman",
+    "Representative",
+    "Congressman",
+    "Senator",
+    "Mrs.",
+    "Miss",
+    "Hon.",
+    "Rep.",
+    "Sen.",
+    "Mr.",
+    "Ms.",
+    "Dr.",
+];
+
+/// Words kept lowercase inside a name (e.g., "Jackson Lee of Texas")
+const LOWERCASE_WORDS: &[&str] = &["of", "and"];
+
+/// A speaker label split into its canonical parts
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NormalizedSpeaker {
+    /// Title-cased name without honorific or role (e.g., "Smith")
+    pub name: Option<String>,
+    /// Honorific stripped from the label (e.g., "Mr.", "Senator")
+    pub honorific: Option<String>,
+    /// Canonical role (e.g., "Chair", "Presiding Officer")
+    pub role: Option<String>,
+}
+
+impl NormalizedSpeaker {
+    /// Canonical display label: the name if present, otherwise the role
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.name.as_deref().or(self.role.as_deref())
+    }
+}
+
+/// Normalize a raw speaker label from a transcript
+///
+/// Strips a leading "The", maps role labels ("The CHAIRMAN", "Chairwoman
+/// WATERS") to a canonical role, separates honorifics, and title-cases the
+/// remaining name.
+#[must_use]
+pub fn normalize_speaker(raw: &str) -> NormalizedSpeaker {
+    let mut rest = raw.trim().trim_end_matches([':', '.']).trim();
+    if let Some(stripped) = strip_prefix_word(rest, "the") {
+        rest = stripped;
+    }
+
+    let mut role = None;
+    for (label, canonical) in ROLES {
+        if let Some(stripped) = strip_prefix_word(rest, label) {
+            role = Some((*canonical).to_string());
+            rest = stripped;
+            break;
+        }
+    }
+
+    let mut honorific = None;
+    for candidate in HONORIFICS {
+        if let Some(stripped) = strip_prefix_word(rest, candidate) {
+            honorific = Some((*candidate).to_string());
+            rest = stripped;
+            break;
+        }
+    }
+
+    let name = title_case(rest);
+    NormalizedSpeaker {
+        name: (!name.is_empty()).then_some(name),
+        honorific,
+        role,
+    }
+}
+
+/// Strip `word` from the start of `s` (case-insensitive) if followed by a word boundary
+fn strip_prefix_word<'a>(s: &'a str, word: &str) -> Option<&'a str> {
+    let head = s.get(..word.len())?;
+    if !head.eq_ignore_ascii_case(word) {
+        return None;
+    }
+    let tail = &s[word.len()..];
+    if tail.is_empty() || tail.starts_with(char::is_whitespace) {
+        Some(tail.trim_start())
+    } else {
+        None
+    }
+}
+
+/// Title-case a name, keeping joining words lowercase
+fn title_case(s: &str) -> String {
+    s.split_whitespace()
+        .enumerate()
+        .map(|(i, word)| {
+            let lower = word.to_lowercase();
+            if i > 0 && LOWERCASE_WORDS.contains(&lower.as_str()) {
+                lower
+            } else {
+                title_case_word(word)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Title-case one word, capitalizing after hyphens and apostrophes and
+/// preserving a "Mc" prefix (e.g., `McCARTHY` -> `McCarthy`)
+fn title_case_word(word: &str) -> String {
+    let (prefix, rest) = match word.strip_prefix("Mc") {
+        Some(rest) if !rest.is_empty() => ("Mc", rest),
+        _ => ("", word),
+    };
+
+    let mut out = String::with_capacity(word.len());
+    out.push_str(prefix);
+    let mut capitalize = true;
+    for c in rest.chars() {
+        if capitalize {
+            out.extend(c.to_uppercase());
+        } else {
+            out.extend(c.to_lowercase());
+        }
+        capitalize = c == '-' || c == '\'';
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speaker(name: Option<&str>, honorific: Option<&str>, role: Option<&str>) -> NormalizedSpeaker {
+        NormalizedSpeaker {
+            name: name.map(String::from),
+            honorific: honorific.map(String::from),
+            role: role.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_uppercase_and_mixed_case_names_match() {
+        let expected = speaker(Some("Smith"), Some("Mr."), None);
+        assert_eq!(normalize_speaker("Mr. SMITH"), expected);
+        assert_eq!(normalize_speaker("Mr. Smith"), expected);
+        assert_eq!(normalize_speaker("  MR. SMITH. "), expected);
+    }
+
+    #[test]
+    fn test_role_labels() {
+        assert_eq!(normalize_speaker("The CHAIRMAN"), speaker(None, None, Some("Chair")));
+        assert_eq!(normalize_speaker("The CHAIRWOMAN"), speaker(None, None, Some("Chair")));
+        assert_eq!(
+            normalize_speaker("The PRESIDING OFFICER"),
+            speaker(None, None, Some("Presiding Officer"))
+        );
+        assert_eq!(
+            normalize_speaker("The SPEAKER pro tempore"),
+            speaker(None, None, Some("Speaker Pro Tempore"))
+        );
+        assert_eq!(
+            normalize_speaker("The ACTING PRESIDENT pro tempore"),
+            speaker(None, None, Some("President Pro Tempore"))
+        );
+    }
+
+    #[test]
+    fn test_role_with_name() {
+        assert_eq!(normalize_speaker("Chairman CROW"), speaker(Some("Crow"), None, Some("Chair")));
+        assert_eq!(
+            normalize_speaker("Ranking Member Ms. SPEIER"),
+            speaker(Some("Speier"), Some("Ms."), Some("Ranking Member"))
+        );
+    }
+
+    #[test]
+    fn test_honorifics() {
+        assert_eq!(normalize_speaker("Senator WARREN"), speaker(Some("Warren"), Some("Senator"), None));
+        assert_eq!(normalize_speaker("Dr. Fauci"), speaker(Some("Fauci"), Some("Dr."), None));
+        assert_eq!(normalize_speaker("Mrs. MURRAY"), speaker(Some("Murray"), Some("Mrs."), None));
+    }
+
+    #[test]
+    fn test_name_casing() {
+        assert_eq!(normalize_speaker("Mr. McCARTHY").name.as_deref(), Some("McCarthy"));
+        assert_eq!(normalize_speaker("Mr. O'ROURKE").name.as_deref(), Some("O'Rourke"));
+        assert_eq!(
+            normalize_speaker("Ms. JACKSON LEE of Texas").name.as_deref(),
+            Some("Jackson Lee of Texas")
+        );
+        assert_eq!(
+            normalize_speaker("Mrs. WATSON COLEMAN").name.as_deref(),
+            Some("Watson Coleman")
+        );
+        assert_eq!(normalize_speaker("Ms. BLUNT-ROCHESTER").name.as_deref(), Some("Blunt-Rochester"));
+    }
+
+    #[test]
+    fn test_prefix_requires_word_boundary() {
+        // "Chairs" and "Theodore" must not be treated as role/article prefixes
+        assert_eq!(normalize_speaker("CHAIRS").role, None);
+        assert_eq!(normalize_speaker("Theodore ROOSEVELT").name.as_deref(), Some("Theodore Roosevelt"));
+    }
+
+    #[test]
+    fn test_label() {
+        assert_eq!(normalize_speaker("Mr. SMITH").label(), Some("Smith"));
+        assert_eq!(normalize_speaker("The CHAIRMAN").label(), Some("Chair"));
+        assert_eq!(normalize_speaker("").label(), None);
+    }
+}
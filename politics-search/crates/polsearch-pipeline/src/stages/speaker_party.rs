@@ -0,0 +1,194 @@
+//! Party attribution for transcript speakers
+//!
+//! Resolves a raw speaker label (e.g., "Mr. SMITH of Texas") to a member of
+//! Congress from a legislators YAML file and returns their party. Only labels
+//! that identify a member are resolved: a member honorific ("Senator",
+//! "Representative"), a committee role ("Chairman GRASSLEY"), or "Mr."/"Ms."
+//! on the floor, where only members are recognized. At hearings a plain
+//! "Mr. JOHNSON" is as likely a witness or staffer, so it stays unresolved, as
+//! do names shared by several members.
+
+use color_eyre::eyre::Result;
+
+use super::legislators::{Chamber, LegislatorLookup};
+use super::speaker_normalize::normalize_speaker;
+
+/// Where a label was spoken, which decides whether "Mr."/"Ms." marks a member
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeakerVenue {
+    /// Committee hearings, where witnesses and staff also speak
+    Hearing,
+    /// The House or Senate floor, where only members are recognized
+    Floor,
+}
+
+/// Committee roles held by members (as canonicalized by [`normalize_speaker`])
+const MEMBER_ROLES: &[&str] = &[
+    "Chair",
+    "Vice Chair",
+    "Ranking Member",
+    "Speaker",
+    "Speaker Pro Tempore",
+    "President Pro Tempore",
+];
+
+/// Resolves speaker labels to party codes ("D", "R", "I")
+#[derive(Debug, Default)]
+pub struct SpeakerPartyResolver {
+    lookup: LegislatorLookup,
+}
+
+impl SpeakerPartyResolver {
+    /// Build a resolver from an already loaded member lookup
+    #[must_use]
+    pub const fn from_lookup(lookup: LegislatorLookup) -> Self {
+        Self { lookup }
+    }
+
+    /// Load a resolver from a @unitedstates/congress-legislators YAML file
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read or parsed
+    pub fn from_legislators_yaml(path: &str) -> Result<Self> {
+        Ok(Self::from_lookup(LegislatorLookup::from_yaml_file(path)?))
+    }
+
+    /// Number of members the resolver can match
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lookup.len()
+    }
+
+    /// Whether the resolver has no members loaded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lookup.is_empty()
+    }
+
+    /// Resolve a raw speaker label to a party code
+    ///
+    /// `chamber` ("House"/"Senate", case-insensitive) narrows the candidates
+    /// unless the honorific already names a chamber. Returns `None` unless the
+    /// label identifies a member and exactly one member matches the name.
+    #[must_use]
+    pub fn resolve(
+        &self,
+        raw_speaker: &str,
+        chamber: Option<&str>,
+        venue: SpeakerVenue,
+    ) -> Option<&'static str> {
+        let normalized = normalize_speaker(raw_speaker);
+        let name = normalized.name.as_deref()?;
+        let has_member_role = normalized
+            .role
+            .as_deref()
+            .is_some_and(|role| MEMBER_ROLES.contains(&role));
+
+        let honorific_chamber = match normalized.honorific.as_deref() {
+            Some("Senator" | "Sen.") => Some(Chamber::Senate),
+            Some("Representative" | "Rep." | "Congressman" | "Congresswoman") => {
+                Some(Chamber::House)
+            }
+            Some("Mr." | "Ms." | "Mrs." | "Miss")
+                if venue == SpeakerVenue::Floor || has_member_role =>
+            {
+                None
+            }
+            None if has_member_role => None,
+            // witnesses ("Dr.", "Hon.", "Mr." at a hearing) and bare names
+            _ => return None,
+        };
+
+        // "Jackson Lee of Texas" -> "Jackson Lee"
+        let name = name.split(" of ").next().unwrap_or(name);
+        let mut candidates = self.lookup.find_by_last_name(name);
+        if candidates.is_empty() {
+            // "Tim Scott" -> first name "Tim", last name "Scott"
+            if let Some((first, last)) = name.split_once(' ') {
+                candidates = self.lookup.find_by_last_name(last);
+                candidates.retain(|member| member.first_name.eq_ignore_ascii_case(first));
+            }
+        }
+        if let Some(chamber) = honorific_chamber.or_else(|| chamber.and_then(Chamber::parse)) {
+            candidates.retain(|member| member.chamber == chamber);
+        }
+
+        match candidates.as_slice() {
+            [member] => member.party.code(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stages::legislators::{Legislator, Party};
+
+    use SpeakerVenue::{Floor, Hearing};
+
+    fn member(first: &str, last: &str, chamber: Chamber, party: Party) -> Legislator {
+        Legislator {
+            bioguide_id: format!("{first}{last}"),
+            first_name: first.to_string(),
+            last_name: last.to_string(),
+            chamber,
+            party,
+        }
+    }
+
+    fn resolver() -> SpeakerPartyResolver {
+        let mut lookup = LegislatorLookup::default();
+        lookup.add(member("Elizabeth", "Warren", Chamber::Senate, Party::Democrat));
+        lookup.add(member("Sheila", "Jackson Lee", Chamber::House, Party::Democrat));
+        lookup.add(member("Tim", "Scott", Chamber::Senate, Party::Republican));
+        lookup.add(member("Bobby", "Scott", Chamber::House, Party::Democrat));
+        lookup.add(member("Austin", "Scott", Chamber::House, Party::Republican));
+        lookup.add(member("Bernard", "Sanders", Chamber::Senate, Party::Independent));
+        lookup.add(member("Mike", "Johnson", Chamber::House, Party::Republican));
+        SpeakerPartyResolver::from_lookup(lookup)
+    }
+
+    #[test]
+    fn test_resolves_member_labels() {
+        let r = resolver();
+        assert_eq!(r.resolve("Ms. WARREN", None, Floor), Some("D"));
+        assert_eq!(r.resolve("Senator Warren", Some("Senate"), Hearing), Some("D"));
+        assert_eq!(r.resolve("Mr. SANDERS", None, Floor), Some("I"));
+        assert_eq!(r.resolve("Chairman SANDERS", Some("Senate"), Hearing), Some("I"));
+    }
+
+    #[test]
+    fn test_strips_state_suffix() {
+        let r = resolver();
+        assert_eq!(r.resolve("Ms. JACKSON LEE of Texas", Some("House"), Floor), Some("D"));
+    }
+
+    #[test]
+    fn test_hearing_witnesses_stay_unresolved() {
+        let r = resolver();
+        // a witness or staffer sharing a member's surname
+        assert_eq!(r.resolve("Mr. JOHNSON", Some("House"), Hearing), None);
+        assert_eq!(r.resolve("Dr. WARREN", Some("Senate"), Hearing), None);
+        assert_eq!(r.resolve("Hon. SANDERS", None, Floor), None);
+        assert_eq!(r.resolve("Mr. JOHNSON", Some("House"), Floor), Some("R"));
+    }
+
+    #[test]
+    fn test_shared_last_name_needs_a_unique_member() {
+        let r = resolver();
+        assert_eq!(r.resolve("Mr. SCOTT", Some("Senate"), Floor), Some("R"));
+        // two House members named Scott
+        assert_eq!(r.resolve("Mr. SCOTT", Some("House"), Floor), None);
+        assert_eq!(r.resolve("Representative Bobby Scott", None, Hearing), Some("D"));
+        // the honorific names the chamber even at a hearing of the other one
+        assert_eq!(r.resolve("Senator SCOTT", Some("House"), Hearing), Some("R"));
+    }
+
+    #[test]
+    fn test_unresolved_speakers() {
+        let r = resolver();
+        assert_eq!(r.resolve("The PRESIDING OFFICER", Some("Senate"), Floor), None);
+        assert_eq!(r.resolve("Mr. FAUCI", None, Floor), None);
+    }
+}
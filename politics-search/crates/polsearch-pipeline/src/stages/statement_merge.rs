@@ -0,0 +1,82 @@
+//! Merging of consecutive same-speaker statements before chunking
+
+/// Separator placed between merged statement texts
+const MERGE_SEPARATOR: &str = "\n\n";
+
+/// Collapse runs of consecutive statements by the same speaker into one
+///
+/// The merged statement keeps the first statement's index; texts are joined
+/// with a blank line so sentence boundaries survive chunking.
+#[must_use]
+pub fn merge_consecutive_statements<T, S, M>(statements: Vec<T>, speaker: S, text: M) -> Vec<T>
+where
+    S: Fn(&T) -> &str,
+    M: Fn(&mut T) -> &mut String,
+{
+    let mut merged: Vec<T> = Vec::with_capacity(statements.len());
+
+    for mut stmt in statements {
+        if let Some(prev) = merged.last_mut() {
+            if speaker(prev) == speaker(&stmt) {
+                let next_text = std::mem::take(text(&mut stmt));
+                let prev_text = text(prev);
+                prev_text.push_str(MERGE_SEPARATOR);
+                prev_text.push_str(&next_text);
+                continue;
+            }
+        }
+        merged.push(stmt);
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stages::ingest_hearings::StatementJson;
+    use crate::stages::procedural_filter::should_skip_statement;
+
+    fn stmt(speaker: &str, text: &str, index: i32) -> StatementJson {
+        StatementJson {
+            speaker: speaker.to_string(),
+            text: text.to_string(),
+            index,
+        }
+    }
+
+    fn merge(statements: Vec<StatementJson>) -> Vec<StatementJson> {
+        merge_consecutive_statements(statements, |s| s.speaker.as_str(), |s| &mut s.text)
+    }
+
+    #[test]
+    fn test_three_consecutive_statements_merge() {
+        let statements = vec![
+            stmt("Mr. SMITH", "The economic impact of this policy", 3),
+            stmt("Mr. SMITH", "has been devastating for rural communities", 4),
+            stmt("Mr. SMITH", "across the nation.", 5),
+        ];
+        assert!(statements.iter().all(|s| should_skip_statement(&s.text)));
+
+        let merged = merge(statements);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].index, 3);
+        assert!(merged[0].text.starts_with("The economic impact"));
+        assert!(merged[0].text.ends_with("across the nation."));
+        assert!(!should_skip_statement(&merged[0].text));
+    }
+
+    #[test]
+    fn test_different_speakers_not_merged() {
+        let merged = merge(vec![
+            stmt("Mr. SMITH", "First remark", 0),
+            stmt("Ms. JONES", "Second remark", 1),
+            stmt("Mr. SMITH", "Third remark", 2),
+        ]);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(
+            merged.iter().map(|s| s.index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+}
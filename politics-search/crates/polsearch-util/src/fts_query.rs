@@ -0,0 +1,268 @@
+//! Boolean query parsing for full-text search
+//!
+//! Grammar (operators are case-sensitive, so "and"/"or" stay search terms):
+//!
+//! ```text
+//! query := group (["OR"] group)*     adjacent groups are OR'ed
+//! group := term ("AND" term)*
+//! term  := word | "-" word           "-word" excludes matches anywhere in the query
+//! ```
+//!
+//! `climate tax` and `climate OR tax` both match either term (the index's
+//! default behavior), `climate AND tax` requires both, and `climate -subsidy`
+//! drops results mentioning "subsidy". AND binds tighter than OR, so
+//! `a b AND c` means `a OR (b AND c)`.
+
+use std::collections::HashSet;
+
+/// Candidate multiplier when boolean operators require post-filtering FTS results
+pub const BOOLEAN_FTS_OVERFETCH: usize = 5;
+
+/// A parsed full-text query in disjunctive normal form
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BooleanQuery {
+    /// OR'ed groups of AND'ed terms (lowercased)
+    pub groups: Vec<Vec<String>>,
+    /// Terms that must not appear (lowercased)
+    pub excluded: Vec<String>,
+}
+
+impl BooleanQuery {
+    /// Parse a query string
+    ///
+    /// # Examples
+    /// ```
+    /// use polsearch_util::BooleanQuery;
+    ///
+    /// let q = BooleanQuery::parse("climate AND tax -subsidy");
+    /// assert_eq!(q.groups, vec![vec!["climate".to_string(), "tax".to_string()]]);
+    /// assert_eq!(q.excluded, vec!["subsidy".to_string()]);
+    /// ```
+    #[must_use]
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = Self::default();
+        let mut current: Vec<String> = Vec::new();
+        let mut pending_and = false;
+
+        for token in query.split_whitespace() {
+            match token {
+                "AND" => pending_and = !current.is_empty(),
+                "OR" => pending_and = false,
+                "-" => {}
+                _ => {
+                    if let Some(excluded) = token.strip_prefix('-') {
+                        parsed.excluded.push(excluded.to_lowercase());
+                        continue;
+                    }
+                    if !pending_and && !current.is_empty() {
+                        parsed.groups.push(std::mem::take(&mut current));
+                    }
+                    current.push(token.to_lowercase());
+                    pending_and = false;
+                }
+            }
+        }
+        if !current.is_empty() {
+            parsed.groups.push(current);
+        }
+
+        parsed
+    }
+
+    /// Whether the query needs post-filtering beyond the index's OR matching
+    #[must_use]
+    pub fn has_operators(&self) -> bool {
+        !self.excluded.is_empty() || self.groups.iter().any(|g| g.len() > 1)
+    }
+
+    /// Positive terms joined for the full-text index (which matches any term)
+    #[must_use]
+    pub fn search_text(&self) -> String {
+        let mut seen = HashSet::new();
+        self.groups
+            .iter()
+            .flatten()
+            .filter(|t| seen.insert(t.as_str()))
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Check whether `text` satisfies the query
+    ///
+    /// Terms match whole words, case-insensitively.
+    #[must_use]
+    pub fn matches(&self, text: &str) -> bool {
+        let lowered = text.to_lowercase();
+        let words: HashSet<&str> = split_words(&lowered).collect();
+        let contains = |term: &String| split_words(term).all(|w| words.contains(w));
+
+        let included = self.groups.is_empty() || self.groups.iter().any(|g| g.iter().all(contains));
+        included && !self.excluded.iter().any(contains)
+    }
+
+    /// Byte ranges of the words in `text` that match a positive query term
+    ///
+    /// Matching is whole-word and case-insensitive, like [`Self::matches`]. Ranges
+    /// are in text order and always fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// use polsearch_util::BooleanQuery;
+    ///
+    /// let q = BooleanQuery::parse("climate -subsidy");
+    /// assert_eq!(q.match_offsets("Climate subsidy, climate!"), vec![(0, 7), (17, 24)]);
+    /// ```
+    #[must_use]
+    pub fn match_offsets(&self, text: &str) -> Vec<(usize, usize)> {
+        let terms: HashSet<&str> =
+            self.groups.iter().flatten().flat_map(|t| split_words(t)).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        word_spans(text)
+            .into_iter()
+            .filter(|&(start, end)| terms.contains(text[start..end].to_lowercase().as_str()))
+            .collect()
+    }
+}
+
+fn split_words(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty())
+}
+
+/// Byte ranges of the alphanumeric words in `s`, using the same boundaries as `split_words`
+fn word_spans(s: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(i);
+        } else if let Some(word_start) = start.take() {
+            spans.push((word_start, i));
+        }
+    }
+    if let Some(word_start) = start {
+        spans.push((word_start, s.len()));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(groups: &[&[&str]]) -> Vec<Vec<String>> {
+        groups
+            .iter()
+            .map(|g| g.iter().map(ToString::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_bare_words_keep_or_behavior() {
+        let q = BooleanQuery::parse("climate change policy");
+        assert_eq!(q.groups, terms(&[&["climate"], &["change"], &["policy"]]));
+        assert!(!q.has_operators());
+        assert_eq!(q.search_text(), "climate change policy");
+        assert!(q.matches("Policy matters."));
+    }
+
+    #[test]
+    fn test_and() {
+        let q = BooleanQuery::parse("climate AND tax");
+        assert_eq!(q.groups, terms(&[&["climate", "tax"]]));
+        assert!(q.has_operators());
+        assert_eq!(q.search_text(), "climate tax");
+        assert!(q.matches("A carbon tax would address climate risk"));
+        assert!(!q.matches("Climate change is real"));
+    }
+
+    #[test]
+    fn test_exclusion() {
+        let q = BooleanQuery::parse("climate -subsidy");
+        assert_eq!(q.groups, terms(&[&["climate"]]));
+        assert_eq!(q.excluded, vec!["subsidy".to_string()]);
+        assert_eq!(q.search_text(), "climate");
+        assert!(q.matches("climate resilience"));
+        assert!(!q.matches("a climate subsidy"));
+    }
+
+    #[test]
+    fn test_or_keyword() {
+        let q = BooleanQuery::parse("climate OR tax");
+        assert_eq!(q.groups, terms(&[&["climate"], &["tax"]]));
+        assert!(!q.has_operators());
+        assert_eq!(q.search_text(), "climate tax");
+    }
+
+    #[test]
+    fn test_mixed_expression() {
+        // AND binds tighter than OR
+        let q = BooleanQuery::parse("energy OR climate AND tax -subsidy");
+        assert_eq!(q.groups, terms(&[&["energy"], &["climate", "tax"]]));
+        assert!(q.matches("energy independence"));
+        assert!(q.matches("a climate tax"));
+        assert!(!q.matches("climate only"));
+        assert!(!q.matches("energy subsidy"));
+    }
+
+    #[test]
+    fn test_lowercase_operators_are_terms() {
+        let q = BooleanQuery::parse("research and development");
+        assert_eq!(q.groups, terms(&[&["research"], &["and"], &["development"]]));
+        assert!(!q.has_operators());
+    }
+
+    #[test]
+    fn test_dangling_operators_ignored() {
+        let q = BooleanQuery::parse("AND climate AND");
+        assert_eq!(q.groups, terms(&[&["climate"]]));
+        assert!(!q.has_operators());
+
+        let q = BooleanQuery::parse("climate - tax");
+        assert_eq!(q.groups, terms(&[&["climate"], &["tax"]]));
+        assert!(q.excluded.is_empty());
+    }
+
+    #[test]
+    fn test_matches_whole_words() {
+        let q = BooleanQuery::parse("tax AND credit");
+        assert!(q.matches("The TAX-credit expires"));
+        assert!(!q.matches("taxation creditors"));
+    }
+
+    #[test]
+    fn test_match_offsets() {
+        let q = BooleanQuery::parse("tax AND credit");
+        let text = "The TAX-credit expires; taxation is separate. Credit!";
+        assert_eq!(q.match_offsets(text), vec![(4, 7), (8, 14), (46, 52)]);
+        assert_eq!(&text[46..52], "Credit");
+    }
+
+    #[test]
+    fn test_match_offsets_multibyte() {
+        let q = BooleanQuery::parse("énergie");
+        let text = "L’énergie — ÉNERGIE";
+        let offsets = q.match_offsets(text);
+        assert_eq!(offsets.len(), 2);
+        for (start, end) in offsets {
+            assert!(text.is_char_boundary(start) && text.is_char_boundary(end));
+            assert_eq!(text[start..end].to_lowercase(), "énergie");
+        }
+    }
+
+    #[test]
+    fn test_match_offsets_no_terms() {
+        assert!(BooleanQuery::parse("-subsidy").match_offsets("a subsidy").is_empty());
+        assert!(BooleanQuery::parse("tax").match_offsets("").is_empty());
+    }
+
+    #[test]
+    fn test_only_exclusions() {
+        let q = BooleanQuery::parse("-subsidy");
+        assert!(q.groups.is_empty());
+        assert_eq!(q.search_text(), "");
+        assert!(q.matches("anything else"));
+    }
+}
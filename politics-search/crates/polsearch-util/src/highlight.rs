@@ -0,0 +1,127 @@
+//! Snippets and matched terms for highlighting keyword search results
+//!
+//! Both functions take the byte ranges produced by
+//! [`BooleanQuery::match_offsets`](crate::BooleanQuery::match_offsets).
+
+/// A window of `text` around the first match, with `context` bytes on either side
+///
+/// The window is widened or narrowed to whole words and marked with `...` where
+/// it cuts the text. Returns `None` if there are no matches.
+///
+/// # Examples
+/// ```
+/// use polsearch_util::highlight::snippet;
+///
+/// let text = "The committee will now consider the climate provisions of the bill.";
+/// assert_eq!(
+///     snippet(text, &[(36, 43)], 12).as_deref(),
+///     Some("...the climate provisions...")
+/// );
+/// assert_eq!(snippet(text, &[], 12), None);
+/// ```
+#[must_use]
+pub fn snippet(text: &str, offsets: &[(usize, usize)], context: usize) -> Option<String> {
+    let &(start, end) = offsets.first()?;
+
+    let mut lo = floor_char_boundary(text, start.saturating_sub(context));
+    if lo > 0 {
+        // drop the partial word the window starts in
+        lo = text[lo..start]
+            .char_indices()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(start, |(i, c)| lo + i + c.len_utf8());
+    }
+    let mut hi = floor_char_boundary(text, end.saturating_add(context).min(text.len()));
+    if hi < text.len() {
+        // drop the partial word the window ends in
+        hi = text[end..hi].rfind(char::is_whitespace).map_or(end, |i| end + i);
+    }
+
+    let prefix = if lo > 0 { "..." } else { "" };
+    let suffix = if hi < text.len() { "..." } else { "" };
+    Some(format!("{prefix}{}{suffix}", text[lo..hi].trim()))
+}
+
+/// Distinct matched words, lowercased, in order of first appearance
+///
+/// # Examples
+/// ```
+/// use polsearch_util::highlight::matched_terms;
+///
+/// let text = "Climate policy and climate change";
+/// assert_eq!(matched_terms(text, &[(0, 7), (19, 26), (27, 33)]), vec!["climate", "change"]);
+/// ```
+#[must_use]
+pub fn matched_terms(text: &str, offsets: &[(usize, usize)]) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for &(start, end) in offsets {
+        let Some(word) = text.get(start..end) else {
+            continue;
+        };
+        let word = word.to_lowercase();
+        if !terms.contains(&word) {
+            terms.push(word);
+        }
+    }
+    terms
+}
+
+/// Largest index `<= index` that falls on a UTF-8 character boundary
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BooleanQuery;
+
+    #[test]
+    fn test_snippet_whole_text_when_short() {
+        let text = "Climate change matters.";
+        let offsets = BooleanQuery::parse("climate").match_offsets(text);
+        assert_eq!(snippet(text, &offsets, 50).as_deref(), Some("Climate change matters."));
+    }
+
+    #[test]
+    fn test_snippet_trims_to_words() {
+        let text = "We must act on climate change before it is too late for everyone.";
+        let offsets = BooleanQuery::parse("climate").match_offsets(text);
+        assert_eq!(
+            snippet(text, &offsets, 10).as_deref(),
+            Some("...act on climate change...")
+        );
+    }
+
+    #[test]
+    fn test_snippet_multibyte_context() {
+        let text = "Señor Pérez habló sobre el clima en México hoy";
+        let offsets = BooleanQuery::parse("clima").match_offsets(text);
+        let snippet = snippet(text, &offsets, 9).expect("match");
+        assert!(snippet.contains("clima"));
+        assert!(snippet.starts_with("..."));
+    }
+
+    #[test]
+    fn test_snippet_multibyte_whitespace() {
+        // GPO text separates words with no-break spaces; U+3000 is three bytes
+        for space in ['\u{a0}', '\u{2009}', '\u{3000}'] {
+            let text = ["the", "committee", "will", "consider", "climate", "provisions", "today"]
+                .join(&space.to_string());
+            let start = text.find("climate").expect("term present");
+            let offsets = [(start, start + "climate".len())];
+            let snippet = snippet(&text, &offsets, 12).expect("match");
+            assert!(snippet.starts_with(&format!("...consider{space}climate")), "{snippet:?}");
+        }
+    }
+
+    #[test]
+    fn test_matched_terms_dedupes_case_insensitively() {
+        let text = "Tax cuts. TAX credits. Tax.";
+        let offsets = BooleanQuery::parse("tax credits").match_offsets(text);
+        assert_eq!(matched_terms(text, &offsets), vec!["tax", "credits"]);
+    }
+}
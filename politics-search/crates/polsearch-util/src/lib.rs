@@ -2,6 +2,13 @@
 
 use chrono::{DateTime, Datelike, Utc};
 
+pub mod fts_query;
+pub mod highlight;
+pub mod spelling;
+
+pub use fts_query::{BooleanQuery, BOOLEAN_FTS_OVERFETCH};
+pub use spelling::suggest_correction;
+
 /// Converts a name to a URL-safe slug
 ///
 /// # Examples
@@ -48,6 +55,74 @@ pub fn split_year_month(year_month: &str) -> Option<(i32, u32)> {
     Some((year, month))
 }
 
+/// Normalizes a date range bound to "YYYY-MM"
+///
+/// Accepts "YYYY", "YYYY-MM", or "YYYY-MM-DD" (the day is dropped since filters
+/// are monthly). A bare year expands to January when used as a start bound and
+/// December when used as an end bound.
+///
+/// # Examples
+/// ```
+/// assert_eq!(polsearch_util::parse_date_bound("2024", true), Some("2024-01".to_string()));
+/// assert_eq!(polsearch_util::parse_date_bound("2025", false), Some("2025-12".to_string()));
+/// assert_eq!(polsearch_util::parse_date_bound("2024-06", false), Some("2024-06".to_string()));
+/// assert_eq!(polsearch_util::parse_date_bound("last year", true), None);
+/// ```
+#[must_use]
+pub fn parse_date_bound(s: &str, is_start: bool) -> Option<String> {
+    let parts: Vec<&str> = s.trim().split('-').collect();
+    let year = parts[0];
+    if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let month: u32 = match parts.as_slice() {
+        [_] => if is_start { 1 } else { 12 },
+        [_, month] => month.parse().ok()?,
+        [_, month, day] => {
+            let day: u32 = day.parse().ok()?;
+            if !(1..=31).contains(&day) {
+                return None;
+            }
+            month.parse().ok()?
+        }
+        _ => return None,
+    };
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    Some(format!("{year}-{month:02}"))
+}
+
+/// Resolves a congress filter to an inclusive `(min, max)` range
+///
+/// An explicit range (`from`/`to`, either end optional) takes precedence over a
+/// single `congress`. Returns `None` if the range start is after its end.
+///
+/// # Examples
+/// ```
+/// use polsearch_util::resolve_congress_range;
+/// assert_eq!(resolve_congress_range(Some(118), None, None), Some((Some(118), Some(118))));
+/// assert_eq!(resolve_congress_range(Some(118), Some(115), None), Some((Some(115), None)));
+/// assert_eq!(resolve_congress_range(None, Some(118), Some(117)), None);
+/// ```
+#[must_use]
+pub const fn resolve_congress_range(
+    congress: Option<i16>,
+    from: Option<i16>,
+    to: Option<i16>,
+) -> Option<(Option<i16>, Option<i16>)> {
+    if from.is_none() && to.is_none() {
+        return Some((congress, congress));
+    }
+    if let (Some(min), Some(max)) = (from, to) {
+        if min > max {
+            return None;
+        }
+    }
+    Some((from, to))
+}
+
 /// Generates a batch name from date range parameters
 ///
 /// # Examples
@@ -71,7 +146,149 @@ pub fn batch_name_from_range(from: &str, to: &str) -> String {
     format!("{from} to {to}")
 }
 
-/// Truncates a string to a maximum length, adding "..." if truncated
+/// Lists each "YYYY-MM" month from `from` through `to`, inclusive
+///
+/// Returns an empty list if either bound isn't a valid "YYYY-MM" string or
+/// `from` is after `to`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(polsearch_util::months_between("2024-06", "2024-06"), vec!["2024-06"]);
+/// assert_eq!(
+///     polsearch_util::months_between("2024-11", "2025-02"),
+///     vec!["2024-11", "2024-12", "2025-01", "2025-02"]
+/// );
+/// assert!(polsearch_util::months_between("2025-02", "2024-11").is_empty());
+/// ```
+#[must_use]
+pub fn months_between(from: &str, to: &str) -> Vec<String> {
+    let (Some((mut year, mut month)), Some(end)) = (split_year_month(from), split_year_month(to))
+    else {
+        return Vec::new();
+    };
+
+    let mut months = Vec::new();
+    while (year, month) <= end {
+        months.push(format!("{year}-{month:02}"));
+        if month == 12 {
+            year += 1;
+            month = 1;
+        } else {
+            month += 1;
+        }
+    }
+    months
+}
+
+/// Whether `text` has at least `min_words` whitespace-separated words
+///
+/// Used to drop trivially short segments ("Thank you.", "I yield back.") from
+/// search results. A minimum of 0 keeps everything.
+///
+/// # Examples
+/// ```
+/// assert!(polsearch_util::has_min_words("I yield back.", 3));
+/// assert!(!polsearch_util::has_min_words("Thank you.", 3));
+/// assert!(polsearch_util::has_min_words("", 0));
+/// ```
+#[must_use]
+pub fn has_min_words(text: &str, min_words: usize) -> bool {
+    min_words == 0 || text.split_whitespace().nth(min_words - 1).is_some()
+}
+
+/// Returns true if any word of `speaker_name` appears as a word in `query` (case-insensitive)
+///
+/// # Examples
+/// ```
+/// assert!(polsearch_util::mentions_speaker("what did Schumer say", "Schumer"));
+/// assert!(!polsearch_util::mentions_speaker("farm bill", "Schumer"));
+/// ```
+#[must_use]
+pub fn mentions_speaker(query: &str, speaker_name: &str) -> bool {
+    let words = |s: &str| -> Vec<String> {
+        s.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    };
+    let query_words = words(query);
+    words(speaker_name).iter().any(|name| query_words.contains(name))
+}
+
+/// Candidate multiplier when a minimum word count drops short results afterwards
+pub const MIN_WORDS_OVERFETCH: usize = 2;
+
+/// Drop items whose text doesn't satisfy `boolean`, if given, or has fewer than `min_words` words
+///
+/// # Examples
+/// ```
+/// use polsearch_util::BooleanQuery;
+///
+/// let mut texts = vec!["climate tax credits", "climate subsidy", "climate"];
+/// let query = BooleanQuery::parse("climate -subsidy");
+/// polsearch_util::retain_text_matches(&mut texts, Some(&query), 2, |t| *t);
+/// assert_eq!(texts, vec!["climate tax credits"]);
+/// ```
+pub fn retain_text_matches<T>(
+    items: &mut Vec<T>,
+    boolean: Option<&BooleanQuery>,
+    min_words: usize,
+    text: impl Fn(&T) -> &str,
+) {
+    items.retain(|item| {
+        let text = text(item);
+        boolean.is_none_or(|query| query.matches(text)) && has_min_words(text, min_words)
+    });
+}
+
+/// Candidate multiplier when a speaker boost can promote results from past the limit
+pub const SPEAKER_BOOST_OVERFETCH: usize = 3;
+
+/// FTS score multiplier for results whose speaker is named in the query
+pub const SPEAKER_MATCH_BOOST: f32 = 2.0;
+
+/// Multiply the score of items whose speaker is named in `query`, then sort highest first
+///
+/// `speaker` returns the item's speaker name; `score` returns its relevance
+/// score, where higher is better. The sort is stable.
+///
+/// # Examples
+/// ```
+/// let results = vec![("Mr. GRASSLEY", 1.0_f32), ("Mr. SCHUMER", 0.6)];
+/// let boosted = polsearch_util::boost_speaker_matches(
+///     "schumer debt limit",
+///     results,
+///     |r| Some(r.0),
+///     |r| &mut r.1,
+/// );
+/// assert_eq!(boosted[0].0, "Mr. SCHUMER");
+/// ```
+#[must_use]
+pub fn boost_speaker_matches<T>(
+    query: &str,
+    items: Vec<T>,
+    speaker: impl Fn(&T) -> Option<&str>,
+    score: impl Fn(&mut T) -> &mut f32,
+) -> Vec<T> {
+    let mut boosted: Vec<(f32, T)> = items
+        .into_iter()
+        .map(|mut item| {
+            let named = speaker(&item).is_some_and(|name| mentions_speaker(query, name));
+            let item_score = score(&mut item);
+            if named {
+                *item_score *= SPEAKER_MATCH_BOOST;
+            }
+            (*item_score, item)
+        })
+        .collect();
+    boosted.sort_by(|a, b| b.0.total_cmp(&a.0));
+    boosted.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Truncates a string to at most `max_len` characters, adding "..." if truncated
+///
+/// Counts characters rather than bytes, so multi-byte text is never split
+/// mid-character.
 ///
 /// # Examples
 /// ```
@@ -80,11 +297,14 @@ pub fn batch_name_from_range(from: &str, to: &str) -> String {
 /// ```
 #[must_use]
 pub fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len - 3])
+    if s.chars().nth(max_len).is_none() {
+        return s.to_string();
     }
+    let end = s
+        .char_indices()
+        .nth(max_len.saturating_sub(3))
+        .map_or(s.len(), |(i, _)| i);
+    format!("{}...", &s[..end])
 }
 
 #[cfg(test)]
@@ -122,6 +342,20 @@ mod tests {
         assert_eq!(split_year_month("2024-00"), None);
     }
 
+    #[test]
+    fn test_parse_date_bound() {
+        assert_eq!(parse_date_bound("2024", true), Some("2024-01".to_string()));
+        assert_eq!(parse_date_bound("2024", false), Some("2024-12".to_string()));
+        assert_eq!(parse_date_bound("2024-3", true), Some("2024-03".to_string()));
+        assert_eq!(parse_date_bound(" 2025-11 ", false), Some("2025-11".to_string()));
+        assert_eq!(parse_date_bound("2024-13", true), None);
+        assert_eq!(parse_date_bound("24", true), None);
+        assert_eq!(parse_date_bound("2024-01-15", false), Some("2024-01".to_string()));
+        assert_eq!(parse_date_bound("2024-01-99", true), None);
+        assert_eq!(parse_date_bound("abcd", true), None);
+        assert_eq!(parse_date_bound("", false), None);
+    }
+
     #[test]
     fn test_batch_names() {
         assert_eq!(batch_name_from_month("2026-01"), "2026-01");
@@ -131,4 +365,47 @@ mod tests {
             "2024-06 to 2025-01"
         );
     }
+
+    #[test]
+    fn test_months_between() {
+        assert_eq!(months_between("2024-06", "2024-06"), vec!["2024-06"]);
+        assert_eq!(
+            months_between("2024-11", "2025-02"),
+            vec!["2024-11", "2024-12", "2025-01", "2025-02"]
+        );
+        assert_eq!(months_between("2024-01", "2024-12").len(), 12);
+        assert!(months_between("2025-02", "2024-11").is_empty());
+        assert!(months_between("2024-1x", "2024-03").is_empty());
+        assert!(months_between("2024-01", "2024-13").is_empty());
+    }
+
+    #[test]
+    fn test_has_min_words() {
+        let segments = ["Thank you.", "The amendment raises the cap on the credit."];
+        let kept: Vec<&str> = segments
+            .into_iter()
+            .filter(|text| has_min_words(text, 5))
+            .collect();
+        assert_eq!(kept, vec!["The amendment raises the cap on the credit."]);
+
+        assert!(has_min_words("Thank you.", 0));
+        assert!(has_min_words("Thank you.", 2));
+        assert!(!has_min_words("   ", 1));
+    }
+
+    #[test]
+    fn test_mentions_speaker() {
+        assert!(mentions_speaker("schumer on the debt limit", "Schumer"));
+        assert!(mentions_speaker("Van Hollen's amendment", "Van Hollen"));
+        assert!(!mentions_speaker("Schumerism", "Schumer"));
+        assert!(!mentions_speaker("debt limit", ""));
+    }
+
+    #[test]
+    fn test_truncate_multibyte() {
+        assert_eq!(truncate("señor presidente", 8), "señor...");
+        assert_eq!(truncate("日本語のテキスト", 8), "日本語のテキスト");
+        assert_eq!(truncate("日本語のテキストです", 8), "日本語のテ...");
+        assert_eq!(truncate("hello", 2), "...");
+    }
 }
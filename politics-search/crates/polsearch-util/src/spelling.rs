@@ -0,0 +1,187 @@
+//! "Did you mean" spelling suggestions for full-text queries
+//!
+//! The vocabulary is a term -> corpus frequency map, stored on disk as one
+//! `term<TAB>count` line per term. Query words missing from it are replaced by
+//! the closest known term within a small edit distance, preferring the more
+//! frequent term on ties.
+
+use std::collections::HashMap;
+
+/// Words shorter than this are never corrected
+const MIN_WORD_LEN: usize = 3;
+
+/// Split text into lowercase alphabetic vocabulary terms
+///
+/// # Examples
+/// ```
+/// let text = "Climate-change, the IRA's 2022 act";
+/// let terms: Vec<String> = polsearch_util::spelling::terms(text).collect();
+/// assert_eq!(terms, vec!["climate", "change", "the", "ira", "act"]);
+/// ```
+pub fn terms(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphabetic())
+        .filter(|word| word.chars().count() >= MIN_WORD_LEN)
+        .map(str::to_lowercase)
+}
+
+/// Parse a vocabulary file of `term<TAB>count` lines, skipping malformed lines
+#[must_use]
+pub fn parse_vocab(contents: &str) -> HashMap<String, u64> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (term, count) = line.split_once('\t')?;
+            Some((term.to_string(), count.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// Render a vocabulary as `term<TAB>count` lines, most frequent first
+#[must_use]
+pub fn format_vocab(vocab: &HashMap<String, u64>) -> String {
+    let mut entries: Vec<(&String, &u64)> = vocab.iter().collect();
+    entries.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    entries
+        .into_iter()
+        .map(|(term, count)| format!("{term}\t{count}\n"))
+        .collect()
+}
+
+/// Suggest a corrected query, or `None` if every word is known or has no close match
+///
+/// Boolean operators (`AND`, `OR`) and `-` exclusion prefixes are preserved.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use polsearch_util::spelling::suggest_correction;
+///
+/// let vocab = HashMap::from([("climate".to_string(), 120), ("change".to_string(), 80)]);
+/// assert_eq!(suggest_correction("climbate change", &vocab), Some("climate change".to_string()));
+/// assert_eq!(suggest_correction("climate change", &vocab), None);
+/// ```
+#[must_use]
+pub fn suggest_correction(query: &str, vocab: &HashMap<String, u64>) -> Option<String> {
+    let mut changed = false;
+    let words: Vec<String> = query
+        .split_whitespace()
+        .map(|token| {
+            if token == "AND" || token == "OR" {
+                return token.to_string();
+            }
+            let (prefix, word) = token
+                .strip_prefix('-')
+                .map_or(("", token), |rest| ("-", rest));
+            let lower = word.to_lowercase();
+            if lower.chars().count() < MIN_WORD_LEN
+                || !lower.chars().all(char::is_alphabetic)
+                || vocab.contains_key(&lower)
+            {
+                return token.to_string();
+            }
+            closest_term(&lower, vocab).map_or_else(
+                || token.to_string(),
+                |term| {
+                    changed = true;
+                    format!("{prefix}{term}")
+                },
+            )
+        })
+        .collect();
+
+    changed.then(|| words.join(" "))
+}
+
+/// Closest vocabulary term within the allowed edit distance for `word`
+fn closest_term<'a>(word: &str, vocab: &'a HashMap<String, u64>) -> Option<&'a str> {
+    let word_len = word.chars().count();
+    let max_distance = if word_len <= 4 { 1 } else { 2 };
+
+    vocab
+        .iter()
+        .filter(|(term, _)| term.chars().count().abs_diff(word_len) <= max_distance)
+        .filter_map(|(term, &count)| {
+            let distance = edit_distance(word, term);
+            (distance <= max_distance).then_some((distance, count, term.as_str()))
+        })
+        .min_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)).then(a.2.cmp(b.2)))
+        .map(|(_, _, term)| term)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocab() -> HashMap<String, u64> {
+        [("climate", 500), ("change", 300), ("tax", 200), ("taxes", 150), ("senate", 400)]
+            .into_iter()
+            .map(|(term, count)| (term.to_string(), count))
+            .collect()
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("climbate", "climate"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_suggest_correction_fixes_misspelling() {
+        assert_eq!(
+            suggest_correction("climbate", &vocab()),
+            Some("climate".to_string())
+        );
+        assert_eq!(
+            suggest_correction("Senat AND climte", &vocab()),
+            Some("senate AND climate".to_string())
+        );
+    }
+
+    #[test]
+    fn test_suggest_correction_keeps_exclusions() {
+        assert_eq!(
+            suggest_correction("climate -chnage", &vocab()),
+            Some("climate -change".to_string())
+        );
+    }
+
+    #[test]
+    fn test_suggest_correction_none_when_known_or_far() {
+        assert_eq!(suggest_correction("climate tax", &vocab()), None);
+        assert_eq!(suggest_correction("filibuster", &vocab()), None);
+        assert_eq!(suggest_correction("", &vocab()), None);
+    }
+
+    #[test]
+    fn test_suggest_correction_prefers_frequent_term() {
+        // "taxs" is one edit from both "tax" and "taxes"
+        assert_eq!(suggest_correction("taxs", &vocab()), Some("tax".to_string()));
+    }
+
+    #[test]
+    fn test_vocab_round_trip() {
+        let vocab = vocab();
+        let parsed = parse_vocab(&format_vocab(&vocab));
+        assert_eq!(parsed, vocab);
+        assert!(parse_vocab("bad line\nclimate\tnope\n").is_empty());
+    }
+}